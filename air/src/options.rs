@@ -1,9 +1,20 @@
 use core::ops::Deref;
+use vm_core::utils::{
+    collections::Vec, string::ToString, ByteReader, Deserializable, DeserializationError,
+    Serializable, SliceReader,
+};
 use winter_air::{FieldExtension, HashFunction, ProofOptions as WinterProofOptions};
 
-/// TODO: add docs
-#[derive(Clone)]
-pub struct ProofOptions(WinterProofOptions);
+/// Options of the STARK protocol used to generate proofs of Miden VM program executions.
+///
+/// Two sets of options are equal if they describe the same proof parameters, regardless of their
+/// seeds (see [ProofOptions::with_zk_seed()]). The seed is not a parameter of the generated proofs
+/// and is not serialized; thus, options are always equal to their deserialized copies.
+#[derive(Clone, Debug)]
+pub struct ProofOptions {
+    options: WinterProofOptions,
+    zk_seed: Option<[u8; 32]>,
+}
 
 impl ProofOptions {
     pub fn new(
@@ -15,7 +26,7 @@ impl ProofOptions {
         fri_folding_factor: usize,
         fri_max_remainder_size: usize,
     ) -> Self {
-        Self(WinterProofOptions::new(
+        Self::from_winter(WinterProofOptions::new(
             num_queries,
             blowup_factor,
             grinding_factor,
@@ -27,7 +38,7 @@ impl ProofOptions {
     }
 
     pub fn with_96_bit_security() -> Self {
-        Self(WinterProofOptions::new(
+        Self::from_winter(WinterProofOptions::new(
             27,
             8,
             16,
@@ -39,7 +50,7 @@ impl ProofOptions {
     }
 
     pub fn with_128_bit_security() -> Self {
-        Self(WinterProofOptions::new(
+        Self::from_winter(WinterProofOptions::new(
             27,
             16,
             21,
@@ -50,8 +61,109 @@ impl ProofOptions {
         ))
     }
 
+    /// Returns these options with the specified seed for the random values which the prover
+    /// injects into the execution trace.
+    ///
+    /// By default, the random values are derived from the hash of the program, and thus, proofs
+    /// of the same execution generated with the same options are identical. With an explicit
+    /// seed, a proof can be regenerated identically only if the same seed is supplied again.
+    ///
+    /// # Security considerations
+    /// The random values are injected only to stabilize the degrees of the trace polynomials; the
+    /// prover does not currently provide zero knowledge, and the seed affects neither the
+    /// soundness of the proof nor what the proof reveals about the secret inputs. If blinding of
+    /// the trace is added in the future, the seed will determine the blinding values: anyone who
+    /// knows the seed will be able to remove the blinding, and reusing a seed across executions
+    /// would leak information about their inputs. Thus, the seed should be generated with a
+    /// cryptographically secure random generator, used for a single execution, and kept as secret
+    /// as the inputs of the execution.
+    pub fn with_zk_seed(mut self, seed: [u8; 32]) -> Self {
+        self.zk_seed = Some(seed);
+        self
+    }
+
+    /// Returns the seed for the random values which the prover injects into the execution trace,
+    /// or None if the values are derived from the hash of the program.
+    pub fn zk_seed(&self) -> Option<[u8; 32]> {
+        self.zk_seed
+    }
+
     pub fn into_inner(self) -> WinterProofOptions {
-        self.0
+        self.options
+    }
+
+    /// Returns proof options wrapping the specified Winterfell options, without a seed.
+    fn from_winter(options: WinterProofOptions) -> Self {
+        Self {
+            options,
+            zk_seed: None,
+        }
+    }
+
+    // SERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes these options into a vector of bytes.
+    ///
+    /// The bytes can be shared to make sure that proofs are generated with exactly the same
+    /// options, and can be deserialized via [ProofOptions::from_bytes()]. The seed set via
+    /// [ProofOptions::with_zk_seed()] is not serialized, since it should be kept secret; it is
+    /// also ignored when comparing options.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.options.to_bytes()
+    }
+
+    /// Deserializes proof options from the provided bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes do not represent valid proof options (e.g., the number of
+    /// queries is greater than 128), or if not all of the provided bytes were consumed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let num_queries = source.read_u8()? as usize;
+        let blowup_factor = source.read_u8()? as usize;
+        let grinding_factor = source.read_u8()? as u32;
+        let hash_fn = HashFunction::read_from(&mut source)?;
+        let field_extension = FieldExtension::read_from(&mut source)?;
+        let fri_folding_factor = source.read_u8()? as usize;
+        let fri_max_remainder_size_log = source.read_u8()? as u32;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+
+        // the checks mirror the assertions of the Winterfell constructor, which would panic on
+        // invalid options
+        check_option(
+            (1..=128).contains(&num_queries),
+            "number of queries must be between 1 and 128",
+        )?;
+        check_option(
+            blowup_factor.is_power_of_two()
+                && (WinterProofOptions::MIN_BLOWUP_FACTOR..=128).contains(&blowup_factor),
+            "blowup factor must be a power of two between 2 and 128",
+        )?;
+        check_option(
+            grinding_factor <= 32,
+            "grinding factor cannot be greater than 32",
+        )?;
+        check_option(
+            [4, 8, 16].contains(&fri_folding_factor),
+            "FRI folding factor must be 4, 8, or 16",
+        )?;
+        check_option(
+            (5..=10).contains(&fri_max_remainder_size_log),
+            "FRI max remainder size must be a power of two between 32 and 1024",
+        )?;
+
+        Ok(Self::new(
+            num_queries,
+            blowup_factor,
+            grinding_factor,
+            hash_fn,
+            field_extension,
+            fri_folding_factor,
+            1 << fri_max_remainder_size_log,
+        ))
     }
 }
 
@@ -61,10 +173,32 @@ impl Default for ProofOptions {
     }
 }
 
+impl PartialEq for ProofOptions {
+    /// Compares the proof parameters of the options, ignoring their seeds.
+    fn eq(&self, other: &Self) -> bool {
+        self.options == other.options
+    }
+}
+
+impl Eq for ProofOptions {}
+
 impl Deref for ProofOptions {
     type Target = WinterProofOptions;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.options
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an error with the specified message if the condition on a deserialized option does not
+/// hold.
+fn check_option(condition: bool, message: &str) -> Result<(), DeserializationError> {
+    if condition {
+        Ok(())
+    } else {
+        Err(DeserializationError::InvalidValue(message.to_string()))
     }
 }
@@ -0,0 +1,74 @@
+use super::{parsers::ConstantMap, AssemblyError, Felt, ToString};
+use crate::StarkField;
+
+// CONSTANTS PARSER
+// ================================================================================================
+
+/// Parses constant definitions from the provided source and adds them to the specified map.
+///
+/// The source consists of lines of the form `NAME=value`, where the name must start with a letter
+/// or an underscore followed by letters, digits, or underscores, and the value must be a valid
+/// field element in decimal or hexadecimal (prefixed with `0x`) representation. Whitespace around
+/// names and values is ignored, as are empty lines and lines starting with `#`.
+///
+/// # Errors
+/// Returns an error with the number of the offending line (starting from 1) if a line is not a
+/// valid constant definition, or if the constant defined by a line is already defined.
+pub(super) fn parse_constants(
+    source: &str,
+    constants: &mut ConstantMap,
+) -> Result<(), AssemblyError> {
+    for (line_idx, line) in source.lines().enumerate() {
+        let line_num = line_idx + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (name, value) = line.split_once('=').ok_or_else(|| {
+            AssemblyError::invalid_constant(line_num, "expected definition of the form NAME=value")
+        })?;
+        let (name, value) = (name.trim(), value.trim());
+
+        if !is_valid_name(name) {
+            let reason = format!("invalid constant name '{name}'");
+            return Err(AssemblyError::invalid_constant(line_num, &reason));
+        }
+        let value = parse_value(value).ok_or_else(|| {
+            let reason =
+                format!("value '{value}' of constant '{name}' is not a valid field element");
+            AssemblyError::invalid_constant(line_num, &reason)
+        })?;
+        if constants.insert(name.to_string(), value).is_some() {
+            let reason = format!("constant '{name}' is already defined");
+            return Err(AssemblyError::invalid_constant(line_num, &reason));
+        }
+    }
+
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns true if the provided name starts with a letter or an underscore and consists only of
+/// letters, digits, and underscores.
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// Parses a decimal or a hexadecimal (prefixed with `0x`) value into a field element; returns None
+/// if the value is malformed or is not smaller than the field modulus.
+fn parse_value(value: &str) -> Option<Felt> {
+    let value = match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+        None => value.parse::<u64>().ok()?,
+    };
+    (value < Felt::MODULUS).then(|| Felt::new(value))
+}
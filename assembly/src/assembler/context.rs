@@ -1,14 +1,24 @@
 use super::{
-    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Kernel, Procedure, ProcedureCache,
-    ProcedureId, String, ToString, Vec,
+    AssemblyError, BTreeSet, CallSet, CodeBlock, CodeBlockTable, Kernel, Procedure, ProcedureCache,
+    ProcedureId, SourceLocation, String, ToString, Vec,
 };
 use crate::MODULE_PATH_DELIM;
+use vm_core::chiplets::hasher::Digest;
 
 // CONSTANTS
 // ================================================================================================
 
 const MAIN_PROC_NAME: &str = "#main";
 
+// TYPE ALIASES
+// ================================================================================================
+
+/// (hash, name) pairs of procedures in the code block table of a program.
+type ProcedureNames = Vec<(Digest, String)>;
+
+/// (name, body) pairs of procedures compiled for a program or invoked by it.
+type NamedProcedures = Vec<(String, CodeBlock)>;
+
 // ASSEMBLY CONTEXT
 // ================================================================================================
 
@@ -55,10 +65,11 @@ impl AssemblyContext {
     }
 
     /// Returns the number of memory locals allocated for the procedure currently being compiled.
-    pub fn num_proc_locals(&self) -> u16 {
-        self.current_proc_context()
-            .expect("no procedures")
-            .num_locals
+    ///
+    /// # Errors
+    /// Returns an error if no procedure is currently being compiled.
+    pub fn num_proc_locals(&self) -> Result<u16, AssemblyError> {
+        Ok(self.current_proc_context()?.num_locals)
     }
 
     // STATE MUTATORS
@@ -101,8 +112,14 @@ impl AssemblyContext {
     ///
     /// This pops the module off the module stack and return all local procedures of the module
     /// (both exported and internal) together with the combined callset of module's procedures.
-    pub fn complete_module(&mut self) -> (Vec<Procedure>, CallSet) {
-        let module_ctx = self.module_stack.pop().expect("no modules");
+    ///
+    /// # Errors
+    /// Returns an error if no module is currently being compiled.
+    pub fn complete_module(&mut self) -> Result<(Vec<Procedure>, CallSet), AssemblyError> {
+        let module_ctx = self
+            .module_stack
+            .pop()
+            .ok_or_else(|| AssemblyError::invalid_context("no modules"))?;
         if self.is_kernel && self.module_stack.is_empty() {
             // if we are compiling a kernel and this is the last module on the module stack, then
             // it must be the Kernel module; thus, we build a Kernel struct from the procedures
@@ -117,7 +134,7 @@ impl AssemblyContext {
         }
 
         // return compiled procedures and callset from the module
-        (module_ctx.compiled_procs, module_ctx.callset)
+        Ok((module_ctx.compiled_procs, module_ctx.callset))
     }
 
     // PROCEDURE PROCESSORS
@@ -129,27 +146,54 @@ impl AssemblyContext {
     /// ensures that there are no procedures with identical name in the same module.
     ///
     /// # Errors
-    /// Returns an error if a procedure with the specified name already exists in the current
-    /// module.
+    /// Returns an error if no module is currently being compiled, or if a procedure with the
+    /// specified name already exists in the current module.
     pub fn begin_proc(
         &mut self,
         name: &str,
         is_export: bool,
         num_locals: u16,
     ) -> Result<(), AssemblyError> {
-        self.module_stack
-            .last_mut()
-            .expect("no modules")
+        self.current_module_context_mut()?
             .begin_proc(name, is_export, num_locals)
     }
 
     /// Completes compilation of the current procedure and adds the compiled procedure to the list
     /// of the current module's compiled procedures.
-    pub fn complete_proc(&mut self, code_root: CodeBlock) {
-        self.module_stack
-            .last_mut()
-            .expect("no modules")
-            .complete_proc(code_root);
+    ///
+    /// # Errors
+    /// Returns an error if no procedure is currently being compiled.
+    pub fn complete_proc(&mut self, code_root: CodeBlock) -> Result<(), AssemblyError> {
+        self.current_module_context_mut()?.complete_proc(code_root)
+    }
+
+    /// Sets the source locations of the instructions of the procedure currently being compiled.
+    ///
+    /// The locations must be listed in the order in which the instructions of the procedure are
+    /// compiled.
+    ///
+    /// # Errors
+    /// Returns an error if no procedure is currently being compiled.
+    pub fn set_source_locations(
+        &mut self,
+        locations: Vec<SourceLocation>,
+    ) -> Result<(), AssemblyError> {
+        let proc_context = self.current_proc_context_mut()?;
+        proc_context.source_locations = locations;
+        proc_context.next_location = 0;
+        Ok(())
+    }
+
+    /// Returns the source location of the next instruction of the procedure currently being
+    /// compiled, or None if the source locations of the procedure are not known.
+    pub fn next_source_location(&mut self) -> Option<SourceLocation> {
+        let proc_context = self.current_proc_context_mut().ok()?;
+        let location = proc_context
+            .source_locations
+            .get(proc_context.next_location)
+            .copied();
+        proc_context.next_location += 1;
+        location
     }
 
     // CALL PROCESSORS
@@ -164,6 +208,7 @@ impl AssemblyContext {
     ///
     /// # Errors
     /// Returns an error if:
+    /// - No procedure is currently being compiled.
     /// - A procedure at the specified index could not be found.
     /// - We are compiling a kernel and the procedure is not inlined.
     pub fn register_local_call(
@@ -173,13 +218,11 @@ impl AssemblyContext {
     ) -> Result<&Procedure, AssemblyError> {
         // non-inlined calls (i.e., `call` instructions) cannot be executed in a kernel
         if self.is_kernel && !inlined {
-            let proc_name = &self.current_proc_context().expect("no procedure").name;
+            let proc_name = &self.current_proc_context()?.name;
             return Err(AssemblyError::call_in_kernel(proc_name));
         }
 
-        self.module_stack
-            .last_mut()
-            .expect("no modules")
+        self.current_module_context_mut()?
             .register_local_call(proc_idx, inlined)
     }
 
@@ -192,7 +235,7 @@ impl AssemblyContext {
     ///
     /// # Errors
     /// Returns an error if:
-    /// - A procedure at the specified index could not be found.
+    /// - No procedure is currently being compiled.
     /// - We are compiling a kernel and the procedure is not inlined.
     pub fn register_external_call(
         &mut self,
@@ -201,16 +244,12 @@ impl AssemblyContext {
     ) -> Result<(), AssemblyError> {
         // non-inlined calls (i.e., `call` instructions) cannot be executed in a kernel
         if self.is_kernel && !inlined {
-            let proc_name = &self.current_proc_context().expect("no procedure").name;
+            let proc_name = &self.current_proc_context()?.name;
             return Err(AssemblyError::call_in_kernel(proc_name));
         }
 
-        self.module_stack
-            .last_mut()
-            .expect("no modules")
-            .register_external_call(proc, inlined);
-
-        Ok(())
+        self.current_module_context_mut()?
+            .register_external_call(proc, inlined)
     }
 
     // CONTEXT FINALIZERS
@@ -220,55 +259,113 @@ impl AssemblyContext {
     ///
     /// This method is invoked at the end of the compilation of a kernel module.
     ///
-    /// # Panics
-    /// Panics if this context was not used for kernel compilation (i.e., was not instantiated with
-    /// is_kernel == true) or if the kernel module has not been completed yet.
-    pub fn into_kernel(self) -> Kernel {
-        self.kernel.expect("no kernel")
+    /// # Errors
+    /// Returns an error if this context was not used for kernel compilation (i.e., was not
+    /// instantiated with is_kernel == true) or if the kernel module has not been completed yet.
+    pub fn into_kernel(self) -> Result<Kernel, AssemblyError> {
+        self.kernel
+            .ok_or_else(|| AssemblyError::invalid_context("no kernel"))
     }
 
-    /// Transforms this context into a [CodeBlockTable] for the compiled program.
+    /// Transforms this context into a [CodeBlockTable] for the compiled program. The table is
+    /// returned together with the names of all procedures in it, the bodies of all procedures
+    /// compiled for the program or invoked by it, and the paths of all library procedures which
+    /// may be executed by the program.
+    ///
+    /// Local procedures are named by their names (e.g., `foo`), while library procedures are
+    /// named by their fully qualified paths (e.g., `std::math::u64::checked_add`).
     ///
     /// This method is invoked at the end of the compilation of an executable program.
     ///
-    /// # Panics
-    /// Panics if:
+    /// # Errors
+    /// Returns an error if:
     /// - There is not exactly one module left on the module stack.
     /// - If this module is not an executable module.
     /// - If any of the procedures in the module's callset cannot be found in the specified
     ///   procedure cache or the local procedure set of the module.
-    pub fn into_cb_table(mut self, proc_cache: &ProcedureCache) -> CodeBlockTable {
+    pub fn into_cb_table(
+        mut self,
+        proc_cache: &ProcedureCache,
+    ) -> Result<
+        (
+            CodeBlockTable,
+            ProcedureNames,
+            NamedProcedures,
+            BTreeSet<String>,
+        ),
+        AssemblyError,
+    > {
         // get the last module off the module stack
-        let mut main_module_context = self.module_stack.pop().expect("no modules");
-        assert!(self.module_stack.is_empty(), "executable not last module");
+        let mut main_module_context = self
+            .module_stack
+            .pop()
+            .ok_or_else(|| AssemblyError::invalid_context("no modules"))?;
+        if !self.module_stack.is_empty() {
+            return Err(AssemblyError::invalid_context("executable not last module"));
+        }
 
         // complete compilation of the executable module; this appends the callset of the main
         // procedure to the callset of the executable module
-        main_module_context.complete_executable();
+        let library_procs = main_module_context.complete_executable()?;
 
         // build the code block table based on the callset of the executable module; called
         // procedures can be either in the specified procedure cache (for procedures imported from
         // other modules) or in the module's procedures (for procedures defined locally).
         let mut cb_table = CodeBlockTable::default();
+        let mut proc_names = Vec::new();
         for proc_id in main_module_context.callset.iter() {
             let proc = proc_cache
                 .get(proc_id)
                 .or_else(|| main_module_context.find_local_proc(proc_id))
-                .expect("callset procedure not found");
+                .ok_or_else(|| AssemblyError::callset_proc_not_found(proc_id))?;
 
             cb_table.insert(proc.code_root().clone());
+            proc_names.push((proc.code_root().hash(), proc.label().to_string()));
         }
 
-        cb_table
+        // record all local procedures (regardless of whether they are invoked), and all library
+        // procedures which may be executed by the program
+        let local_procs = main_module_context
+            .compiled_procs
+            .iter()
+            .map(|proc| (proc.label().to_string(), proc.code_root().clone()));
+        let library_procs_by_path = library_procs.iter().filter_map(|path| {
+            proc_cache
+                .get(&ProcedureId::new(path))
+                .map(|proc| (path.clone(), proc.code_root().clone()))
+        });
+        let named_procs = local_procs.chain(library_procs_by_path).collect();
+
+        Ok((cb_table, proc_names, named_procs, library_procs))
     }
 
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns the context of the procedure currently being complied, or None if module or
+    /// Returns a mutable reference to the context of the module currently being complied, or an
+    /// error if the module stack is empty.
+    fn current_module_context_mut(&mut self) -> Result<&mut ModuleContext, AssemblyError> {
+        self.module_stack
+            .last_mut()
+            .ok_or_else(|| AssemblyError::invalid_context("no modules"))
+    }
+
+    /// Returns the context of the procedure currently being complied, or an error if module or
     /// procedure stacks are empty.
-    fn current_proc_context(&self) -> Option<&ProcedureContext> {
-        self.module_stack.last().and_then(|m| m.proc_stack.last())
+    fn current_proc_context(&self) -> Result<&ProcedureContext, AssemblyError> {
+        self.module_stack
+            .last()
+            .and_then(|m| m.proc_stack.last())
+            .ok_or_else(|| AssemblyError::invalid_context("no procedures"))
+    }
+
+    /// Returns a mutable reference to the context of the procedure currently being complied, or
+    /// an error if module or procedure stacks are empty.
+    fn current_proc_context_mut(&mut self) -> Result<&mut ProcedureContext, AssemblyError> {
+        self.module_stack
+            .last_mut()
+            .and_then(|m| m.proc_stack.last_mut())
+            .ok_or_else(|| AssemblyError::invalid_context("no procedures"))
     }
 }
 
@@ -369,13 +466,24 @@ impl ModuleContext {
     /// compiled procedure, and adds it to the list of compiled procedures.
     ///
     /// This also updates module callset to include the callset of the newly compiled procedure.
-    pub fn complete_proc(&mut self, code_root: CodeBlock) {
-        let proc_context = self.proc_stack.pop().expect("no procedures");
+    ///
+    /// # Errors
+    /// Returns an error if the procedure stack is empty.
+    pub fn complete_proc(&mut self, code_root: CodeBlock) -> Result<(), AssemblyError> {
+        let mut proc_context = self
+            .proc_stack
+            .pop()
+            .ok_or_else(|| AssemblyError::invalid_context("no procedures"))?;
 
         // build an ID for the procedure as follows:
         // - for exported procedures: hash("module_path::proc_name")
         // - for internal procedures: hash("module_path::proc_index")
         let proc_id = if proc_context.is_export {
+            // procedures exported from library modules are library procedures themselves
+            if !self.is_executable() {
+                let path = ProcedureId::path(&proc_context.name, &self.path);
+                proc_context.library_procs.insert(path);
+            }
             ProcedureId::from_name(&proc_context.name, &self.path)
         } else {
             let proc_idx = self.compiled_procs.len() as u16;
@@ -385,6 +493,7 @@ impl ModuleContext {
         let proc = proc_context.into_procedure(proc_id, code_root);
         self.callset.append(proc.callset());
         self.compiled_procs.push(proc);
+        Ok(())
     }
 
     // CALL PROCESSORS
@@ -398,7 +507,8 @@ impl ModuleContext {
     /// the callset of the current procedure as well.
     ///
     /// # Errors
-    /// Returns an error if a procedure at the specified index could not be found.
+    /// Returns an error if a procedure at the specified index could not be found, or if the
+    /// procedure stack is empty.
     pub fn register_local_call(
         &mut self,
         proc_idx: u16,
@@ -411,11 +521,17 @@ impl ModuleContext {
             .ok_or_else(|| AssemblyError::local_proc_not_found(proc_idx, &self.path))?;
 
         // get the context of the procedure currently being compiled
-        let context = self.proc_stack.last_mut().expect("no proc context");
+        let context = self
+            .proc_stack
+            .last_mut()
+            .ok_or_else(|| AssemblyError::invalid_context("no procedures"))?;
 
         // append the callset of the called procedure to the current callset as all calls made as
         // the result of the called procedure may be made as a result of current procedure as well
         context.callset.append(called_proc.callset());
+        context
+            .library_procs
+            .extend(called_proc.library_procs().iter().cloned());
 
         // if the called procedure was not inlined, we include it in the current callset as well
         if !inlined {
@@ -430,18 +546,32 @@ impl ModuleContext {
     /// This also appends the callset of the called procedure to the callset of the current
     /// procedure at the top of procedure stack. If inlined == false, the called procedure itself
     /// is added to the callset of the current procedure as well.
-    pub fn register_external_call(&mut self, called_proc: &Procedure, inlined: bool) {
+    ///
+    /// # Errors
+    /// Returns an error if the procedure stack is empty.
+    pub fn register_external_call(
+        &mut self,
+        called_proc: &Procedure,
+        inlined: bool,
+    ) -> Result<(), AssemblyError> {
         // get the context of the procedure currently being compiled
-        let context = self.proc_stack.last_mut().expect("no proc context");
+        let context = self
+            .proc_stack
+            .last_mut()
+            .ok_or_else(|| AssemblyError::invalid_context("no procedures"))?;
 
         // append the callset of the called procedure to the current callset as all calls made as
         // the result of the called procedure may be made as a result of current procedure as well
         context.callset.append(called_proc.callset());
+        context
+            .library_procs
+            .extend(called_proc.library_procs().iter().cloned());
 
         // if the called procedure was not inlined, we include it in the current callset as well
         if !inlined {
             context.callset.insert(*called_proc.id());
         }
+        Ok(())
     }
 
     // EXECUTABLE FINALIZER
@@ -452,15 +582,30 @@ impl ModuleContext {
     /// compiling a program, the executable module will have the main procedure left on the
     /// procedure stack. To complete the module we need to pop the main procedure off the stack and
     /// append its callset to the callset of the module context.
-    pub fn complete_executable(&mut self) {
-        assert!(self.is_executable(), "module not executable");
-
-        let main_proc_context = self.proc_stack.pop().expect("no procedures");
+    ///
+    /// Returns the paths of all library procedures which may be executed by the main procedure.
+    ///
+    /// # Errors
+    /// Returns an error if this module is not an executable module, or if the main procedure is
+    /// not the only procedure left on the procedure stack.
+    pub fn complete_executable(&mut self) -> Result<BTreeSet<String>, AssemblyError> {
+        if !self.is_executable() {
+            return Err(AssemblyError::invalid_context("module not executable"));
+        }
 
-        assert!(main_proc_context.is_main(), "not main procedure");
-        assert!(self.proc_stack.is_empty(), "more procedures after main");
+        let main_proc_context = self
+            .proc_stack
+            .pop()
+            .ok_or_else(|| AssemblyError::invalid_context("no procedures"))?;
+        if !main_proc_context.is_main() {
+            return Err(AssemblyError::invalid_context("not main procedure"));
+        }
+        if !self.proc_stack.is_empty() {
+            return Err(AssemblyError::invalid_context("more procedures after main"));
+        }
 
         self.callset.append(&main_proc_context.callset);
+        Ok(main_proc_context.library_procs)
     }
 }
 
@@ -474,6 +619,9 @@ struct ProcedureContext {
     is_export: bool,
     num_locals: u16,
     callset: CallSet,
+    library_procs: BTreeSet<String>,
+    source_locations: Vec<SourceLocation>,
+    next_location: usize,
 }
 
 impl ProcedureContext {
@@ -483,6 +631,9 @@ impl ProcedureContext {
             is_export,
             num_locals,
             callset: CallSet::default(),
+            library_procs: BTreeSet::new(),
+            source_locations: Vec::new(),
+            next_location: 0,
         }
     }
 
@@ -496,8 +647,18 @@ impl ProcedureContext {
             is_export,
             num_locals,
             callset,
+            library_procs,
+            ..
         } = self;
 
-        Procedure::new(id, name, is_export, num_locals as u32, code_root, callset)
+        Procedure::new(
+            id,
+            name,
+            is_export,
+            num_locals as u32,
+            code_root,
+            callset,
+            library_procs,
+        )
     }
 }
@@ -18,6 +18,14 @@ pub fn adv_push(span: &mut SpanBuilder, n: u8) -> Result<Option<CodeBlock>, Asse
     Ok(None)
 }
 
+/// Appends a TAKE_MAP_VALUE advice injector followed by a READW operation to the span. This
+/// removes the word stored in the advice provider's key-value map under the key located at the
+/// top of the stack, and overwrites the key with this word.
+pub fn adv_loadw_key(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_decorator(Decorator::Advice(AdviceInjector::TakeMapValue));
+    span.add_op(ReadW)
+}
+
 // ADVICE INJECTORS
 // ================================================================================================
 
@@ -1,7 +1,8 @@
 use super::{
-    mem_ops::local_to_absolute_addr, push_felt, AssemblyContext, AssemblyError, CodeBlock, Felt,
-    Operation::*, SpanBuilder,
+    mem_ops::local_to_absolute_addr, push_felt, AdviceInjector, AssemblyContext, AssemblyError,
+    CodeBlock, Decorator, Felt, Operation::*, SpanBuilder,
 };
+use vm_core::EnvValue;
 
 // CONSTANT INPUTS
 // ================================================================================================
@@ -33,6 +34,21 @@ pub fn push(imms: &[Felt], span: &mut SpanBuilder) -> Result<Option<CodeBlock>,
 // ENVIRONMENT INPUTS
 // ================================================================================================
 
+/// Appends a sequence of operations to the span needed for executing push.env.name instruction.
+/// This consists of injecting the specified environment value supplied by the host into the
+/// advice tape and then moving it onto the stack via a READ operation.
+///
+/// Since the value is read from the advice tape, it is non-deterministic advice: it is not
+/// constrained by the VM, and the proof of the execution does not commit to it. Programs which
+/// rely on environment values should commit to them, e.g., by outputting them on the stack.
+pub fn push_env(
+    span: &mut SpanBuilder,
+    value: EnvValue,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_decorator(Decorator::Advice(AdviceInjector::Env(value)));
+    span.add_op(Read)
+}
+
 /// Appends a sequence of operations to the span needed for executing locaddr.i instruction. This
 /// consists of putting i onto the stack and then executing LOCADDR operation.
 ///
@@ -43,7 +59,7 @@ pub fn locaddr(
     index: u16,
     context: &AssemblyContext,
 ) -> Result<Option<CodeBlock>, AssemblyError> {
-    local_to_absolute_addr(span, index, context.num_proc_locals())?;
+    local_to_absolute_addr(span, index, context.num_proc_locals()?)?;
     Ok(None)
 }
 
@@ -3,6 +3,7 @@ use super::{
     StarkField, ONE, ZERO,
 };
 use crate::MAX_EXP_BITS;
+use vm_core::ASSERT_EQW_OPS;
 
 // BASIC ARITHMETIC OPERATIONS
 // ================================================================================================
@@ -222,6 +223,17 @@ pub fn neq_imm(span: &mut SpanBuilder, imm: Felt) -> Result<Option<CodeBlock>, A
     }
 }
 
+/// Appends a sequence of operations to assert that two words at the top of the stack are equal,
+/// and to remove both words from the stack.
+///
+/// The words are compared lane by lane as specified by [ASSERT_EQW_OPS]; the processor recognizes
+/// this sequence to report the lane which differed when the assertion fails.
+///
+/// This operation takes 11 VM cycles.
+pub fn assert_eqw(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops(ASSERT_EQW_OPS)
+}
+
 /// Appends a sequence of operations to check equality between two words at the top of the stack.
 ///
 /// This operation takes 15 VM cycles.
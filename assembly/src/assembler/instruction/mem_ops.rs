@@ -30,7 +30,7 @@ pub fn mem_read(
     // if the address was provided as an immediate value, put it onto the stack
     if let Some(addr) = addr {
         if is_local {
-            local_to_absolute_addr(span, addr as u16, context.num_proc_locals())?;
+            local_to_absolute_addr(span, addr as u16, context.num_proc_locals()?)?;
         } else {
             push_u32_value(span, addr);
         }
@@ -81,7 +81,7 @@ pub fn mem_write(
     // if the address was provided as an immediate value, put it onto the stack
     if let Some(addr) = addr {
         if is_local {
-            local_to_absolute_addr(span, addr as u16, context.num_proc_locals())?;
+            local_to_absolute_addr(span, addr as u16, context.num_proc_locals()?)?;
         } else {
             push_u32_value(span, addr);
         }
@@ -111,12 +111,16 @@ pub fn mem_write(
 /// - 2 VM cycles if index != 1
 ///
 /// # Errors
-/// Returns an error if index is greater than the number of procedure locals.
+/// Returns an error if index is greater than the number of procedure locals, or if no procedure
+/// locals were declared.
 pub fn local_to_absolute_addr(
     span: &mut SpanBuilder,
     index: u16,
     num_proc_locals: u16,
 ) -> Result<(), AssemblyError> {
+    if num_proc_locals == 0 {
+        return Err(AssemblyError::no_proc_locals(index));
+    }
     let max = num_proc_locals - 1;
     validate_param(index, 0, max)?;
 
@@ -2,6 +2,7 @@ use super::{
     Assembler, AssemblyContext, AssemblyError, CodeBlock, Decorator, Felt, Instruction, Operation,
     ProcedureId, SpanBuilder, ONE, ZERO,
 };
+use crate::versions;
 use vm_core::{AdviceInjector, FieldElement, StarkField};
 
 mod adv_ops;
@@ -27,16 +28,21 @@ impl Assembler {
         use AdviceInjector::*;
         use Operation::*;
 
+        // make sure the instruction is available in the targeted version of the instruction set
+        versions::check_instruction_version(instruction, self.target_version)?;
+
         // if the assembler is in debug mode, start tracking the instruction about to be executed;
         // this will allow us to map the instruction to the sequence of operations which were
         // executed as a part of this instruction.
         if self.in_debug_mode() {
-            span.track_instruction(instruction);
+            let location = ctx.next_source_location();
+            span.track_instruction(instruction, location);
         }
 
         let result = match instruction {
             Instruction::Assert => span.add_op(Assert),
             Instruction::AssertEq => span.add_ops([Eq, Assert]),
+            Instruction::AssertEqw => field_ops::assert_eqw(span),
             Instruction::Assertz => span.add_ops([Eqz, Assert]),
 
             Instruction::Add => span.add_op(Add),
@@ -235,11 +241,13 @@ impl Assembler {
             Instruction::CDropW => span.add_ops([CSwapW, Drop, Drop, Drop, Drop]),
 
             Instruction::PushConstants(imms) => env_ops::push(imms, span),
+            Instruction::PushEnv(value) => env_ops::push_env(span, *value),
             Instruction::Sdepth => span.add_op(SDepth),
             Instruction::Caller => env_ops::caller(span, ctx),
             Instruction::AdvPipe => span.add_ops([Pipe, RpPerm]),
             Instruction::AdvPush(n) => adv_ops::adv_push(span, *n),
             Instruction::AdvLoadW => span.add_op(ReadW),
+            Instruction::AdvLoadWKey => adv_ops::adv_loadw_key(span),
 
             Instruction::MemStream => span.add_ops([MStream, RpPerm]),
 
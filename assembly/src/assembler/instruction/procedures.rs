@@ -28,7 +28,6 @@ impl Assembler {
     ) -> Result<Option<CodeBlock>, AssemblyError> {
         // get the procedure from the assembler
         let proc = self.get_imported_proc(proc_id, context)?;
-        debug_assert!(proc.is_export(), "not imported procedure");
 
         // register and "inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
@@ -63,7 +62,6 @@ impl Assembler {
     ) -> Result<Option<CodeBlock>, AssemblyError> {
         // get the procedure from the assembler
         let proc = self.get_imported_proc(proc_id, context)?;
-        debug_assert!(proc.is_export(), "not imported procedure");
 
         // register and "non-inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
@@ -0,0 +1,167 @@
+use super::{
+    parsers::{Instruction, Node, ProcedureAst},
+    AssemblyError, InstructionSetVersion, ModuleProvider, ProcedureId,
+};
+use crate::{versions, BTreeSet};
+
+// PROGRAM METRICS
+// ================================================================================================
+
+/// Metrics of a program which can be determined statically from the program source (i.e., without
+/// executing the program).
+///
+/// Currently, the metrics describe memory addresses referenced by the program:
+/// - Addresses provided as immediate values to memory instructions (e.g., `mem_load.5`) and to
+///   the `adv.mem` injector are known statically, and the highest of these is reported by
+///   [ProgramMetrics::max_static_mem_addr()].
+/// - Addresses taken from the stack (e.g., `mem_load`, `mem_stream`, `adv_pipe`) are not known
+///   statically; whether the program contains such accesses is reported by
+///   [ProgramMetrics::has_dynamic_mem_access()]. Memory accessed by kernel procedures invoked via
+///   `syscall` is not known either, since the sources of the kernel are not analyzed.
+///
+/// Procedure locals are not included in the metrics, since these are allocated by the VM in a
+/// dedicated region of memory.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProgramMetrics {
+    max_static_mem_addr: Option<u32>,
+    has_dynamic_mem_access: bool,
+}
+
+impl ProgramMetrics {
+    /// Returns the highest memory address referenced by the program which is known statically, or
+    /// None if the program does not reference any statically known addresses.
+    ///
+    /// If [ProgramMetrics::has_dynamic_mem_access()] returns true, the program may access memory
+    /// beyond this address.
+    pub fn max_static_mem_addr(&self) -> Option<u32> {
+        self.max_static_mem_addr
+    }
+
+    /// Returns true if the program accesses memory at addresses which are not known statically; in
+    /// such a case, the highest memory address accessed by the program is unknown.
+    pub fn has_dynamic_mem_access(&self) -> bool {
+        self.has_dynamic_mem_access
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records a reference to the specified statically known memory address.
+    fn record_static_addr(&mut self, addr: u32) {
+        self.max_static_mem_addr = self.max_static_mem_addr.max(Some(addr));
+    }
+}
+
+// METRICS COLLECTOR
+// ================================================================================================
+
+/// Traverses the ASTs of a program and of all procedures invoked by it, and accumulates the
+/// metrics of the program.
+pub(super) struct MetricsCollector<'a> {
+    module_provider: &'a dyn ModuleProvider,
+    target_version: InstructionSetVersion,
+    visited_procs: BTreeSet<ProcedureId>,
+    metrics: ProgramMetrics,
+}
+
+impl<'a> MetricsCollector<'a> {
+    /// Returns a new collector which looks up imported procedures via the specified provider, and
+    /// checks the visited instructions against the specified version of the instruction set.
+    pub fn new(
+        module_provider: &'a dyn ModuleProvider,
+        target_version: InstructionSetVersion,
+    ) -> Self {
+        Self {
+            module_provider,
+            target_version,
+            visited_procs: BTreeSet::new(),
+            metrics: ProgramMetrics::default(),
+        }
+    }
+
+    /// Consumes this collector and returns the accumulated metrics.
+    pub fn into_metrics(self) -> ProgramMetrics {
+        self.metrics
+    }
+
+    /// Accumulates the metrics of the specified body, which is defined in the module with the
+    /// specified path and the specified local procedures.
+    ///
+    /// # Errors
+    /// Returns an error if any of the procedures invoked from the body could not be found, or if
+    /// any of the visited instructions is not available in the target version.
+    pub fn visit_body(
+        &mut self,
+        body: &[Node],
+        local_procs: &[ProcedureAst],
+        module_path: &str,
+    ) -> Result<(), AssemblyError> {
+        for node in body {
+            match node {
+                Node::Instruction(instruction) => {
+                    self.visit_instruction(instruction, local_procs, module_path)?
+                }
+                Node::IfElse(t, f) => {
+                    self.visit_body(t, local_procs, module_path)?;
+                    self.visit_body(f, local_procs, module_path)?;
+                }
+                Node::Repeat(_, nodes) | Node::While(nodes) => {
+                    self.visit_body(nodes, local_procs, module_path)?
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Accumulates the metrics of the specified instruction; for instructions which invoke
+    /// procedures, the bodies of the invoked procedures are visited (once per procedure).
+    fn visit_instruction(
+        &mut self,
+        instruction: &Instruction,
+        local_procs: &[ProcedureAst],
+        module_path: &str,
+    ) -> Result<(), AssemblyError> {
+        versions::check_instruction_version(instruction, self.target_version)?;
+        match instruction {
+            Instruction::MemLoadImm(addr)
+            | Instruction::MemLoadWImm(addr)
+            | Instruction::MemStoreImm(addr)
+            | Instruction::MemStoreWImm(addr) => self.metrics.record_static_addr(*addr),
+            Instruction::AdvMem(start_addr, num_words) if *num_words > 0 => {
+                let end_addr = start_addr.saturating_add(num_words - 1);
+                self.metrics.record_static_addr(end_addr);
+            }
+            Instruction::MemLoad
+            | Instruction::MemLoadW
+            | Instruction::MemStore
+            | Instruction::MemStoreW
+            | Instruction::MemStream
+            | Instruction::AdvPipe
+            | Instruction::SysCall(_) => self.metrics.has_dynamic_mem_access = true,
+            Instruction::ExecLocal(idx) | Instruction::CallLocal(idx) => {
+                let proc = local_procs
+                    .get(*idx as usize)
+                    .ok_or_else(|| AssemblyError::local_proc_not_found(*idx, module_path))?;
+                let proc_id = ProcedureId::from_name(&proc.name, module_path);
+                if self.visited_procs.insert(proc_id) {
+                    self.visit_body(&proc.body, local_procs, module_path)?;
+                }
+            }
+            Instruction::ExecImported(proc_id) | Instruction::CallImported(proc_id) => {
+                if !self.visited_procs.insert(*proc_id) {
+                    return Ok(());
+                }
+                let module_provider = self.module_provider;
+                let module = module_provider
+                    .get_module(proc_id)
+                    .ok_or_else(|| AssemblyError::imported_proc_module_not_found(proc_id))?;
+                let proc = module.get_procedure(proc_id).ok_or_else(|| {
+                    AssemblyError::imported_proc_not_found_in_module(proc_id, module.path())
+                })?;
+                self.visit_body(&proc.body, &module.local_procs, module.path())?;
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+}
@@ -1,13 +1,24 @@
 use super::{
-    parsers::{self, Instruction, Node, ProcedureAst, ProgramAst},
-    AssemblyError, BTreeMap, Box, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, ModuleAst,
-    ModuleProvider, Operation, Procedure, ProcedureId, Program, String, ToString, Vec, ONE, ZERO,
+    parsers::{self, ConstantMap, Instruction, Node, ProcedureAst, ProgramAst, SourceMap},
+    AssemblyError, BTreeMap, BTreeSet, Box, CallSet, CodeBlock, CodeBlockTable, Felt,
+    InstructionSetVersion, Kernel, ModuleAst, ModuleProvider, Operation, Procedure, ProcedureId,
+    Program, SourceLocation, String, ToString, Vec, MAX_BLOCK_NESTING_DEPTH, MODULE_PATH_DELIM,
+    ONE, ZERO,
 };
 use core::{borrow::Borrow, pin::Pin};
+use log::debug;
 use vm_core::{utils::group_vector_elements, Decorator, DecoratorList};
 
 mod instruction;
 
+mod peephole;
+
+mod constants;
+
+mod metrics;
+use metrics::MetricsCollector;
+pub use metrics::ProgramMetrics;
+
 mod span_builder;
 use span_builder::SpanBuilder;
 
@@ -38,6 +49,11 @@ pub struct Assembler {
     module_provider: Box<dyn ModuleProvider>,
     proc_cache: Pin<Box<ProcedureCache>>,
     in_debug_mode: bool,
+    with_debug_symbols: bool,
+    with_optimizations: bool,
+    target_version: InstructionSetVersion,
+    constants: ConstantMap,
+    required_library_hashes: BTreeMap<String, String>,
 }
 
 impl Assembler {
@@ -50,6 +66,11 @@ impl Assembler {
             module_provider: Box::new(()),
             proc_cache: Box::pin(BTreeMap::default()),
             in_debug_mode: false,
+            with_debug_symbols: false,
+            with_optimizations: false,
+            target_version: InstructionSetVersion::LATEST,
+            constants: ConstantMap::new(),
+            required_library_hashes: BTreeMap::new(),
         }
     }
 
@@ -59,6 +80,77 @@ impl Assembler {
         self
     }
 
+    /// Instructs the assembler to retain names of procedures in the compiled programs.
+    ///
+    /// Procedure names do not affect hashes of the compiled programs, and can be retrieved via
+    /// [Program::procedure_name()] (e.g., for profiling purposes). Only names of the procedures
+    /// in the code block table of a program (i.e., procedures invoked via `call` instructions)
+    /// are retained, since all other procedures are inlined into the program MAST.
+    pub fn with_debug_symbols(mut self, with_debug_symbols: bool) -> Self {
+        self.with_debug_symbols = with_debug_symbols;
+        self
+    }
+
+    /// Instructs the assembler to apply peephole optimizations to the compiled programs.
+    ///
+    /// Optimizations remove sequences of operations which do not affect the state of the VM
+    /// (e.g., `push.1 drop` or `swap swap`), and thus, optimized programs produce the same
+    /// results in fewer cycles. However, the MAST of an optimized program differs from the MAST
+    /// of the same program compiled without optimizations, and so does the program hash.
+    pub fn with_optimizations(mut self, with_optimizations: bool) -> Self {
+        self.with_optimizations = with_optimizations;
+        self
+    }
+
+    /// Sets the version of the instruction set targeted by the assembler.
+    ///
+    /// When a version older than [InstructionSetVersion::LATEST] is targeted, compiling a program
+    /// which uses instructions added after the target version results in an error. This applies
+    /// to the procedures imported from the module provider as well, and to the kernel if the
+    /// target version is set before the kernel.
+    pub fn with_target_version(mut self, version: InstructionSetVersion) -> Self {
+        self.target_version = version;
+        self
+    }
+
+    /// Adds the constants defined by the provided source to the assembler.
+    ///
+    /// The source consists of lines of the form `NAME=value`, where the value is a field element in
+    /// decimal or hexadecimal (prefixed with `0x`) representation; empty lines and lines starting
+    /// with `#` are ignored. The constants can then be referenced by name in `push` instructions
+    /// of compiled programs (e.g., `push.NAME`). Constants are not available to the kernel or to
+    /// the modules imported from the module provider.
+    ///
+    /// # Errors
+    /// Returns an error with the line number of the first invalid definition if any of the lines
+    /// is malformed, or if a constant with the same name has already been defined.
+    pub fn with_constants(mut self, source: &str) -> Result<Self, AssemblyError> {
+        constants::parse_constants(source, &mut self.constants)?;
+        Ok(self)
+    }
+
+    /// Adds the constants defined in the file at the specified path to the assembler.
+    ///
+    /// The file is expected to be in the format described in [Assembler::with_constants()].
+    ///
+    /// # Errors
+    /// Returns an error if the file could not be read, or if the contents of the file could not be
+    /// parsed.
+    #[cfg(feature = "std")]
+    pub fn with_constants_file<P>(self, path: P) -> Result<Self, AssemblyError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|err| {
+            AssemblyError::constants_file_not_readable(
+                &path.display().to_string(),
+                &err.to_string(),
+            )
+        })?;
+        self.with_constants(&source)
+    }
+
     /// Adds the specified [ModuleProvider] to the assembler.
     pub fn with_module_provider<P>(mut self, provider: P) -> Self
     where
@@ -68,6 +160,20 @@ impl Assembler {
         self
     }
 
+    /// Requires the library with the specified root namespace (e.g., `std`) provided by the module
+    /// provider of the assembler to have the specified content hash.
+    ///
+    /// Programs depend on the exact implementation of the libraries they import, and thus, a change
+    /// in a library changes the hashes of the programs compiled against it. Requiring a content
+    /// hash ensures that programs are not compiled against a modified library by accident. The
+    /// content hashes of the libraries used by a program are recorded in the compiled program
+    /// regardless (see [Program::library_hash()]).
+    pub fn with_required_library_hash(mut self, root_ns: &str, hash: &str) -> Self {
+        self.required_library_hashes
+            .insert(root_ns.to_string(), hash.to_string());
+        self
+    }
+
     /// Sets the kernel for the assembler to the kernel defined by the provided source.
     ///
     /// # Errors
@@ -91,7 +197,7 @@ impl Assembler {
 
         // convert the context into Kernel; this builds the kernel from hashes of procedures
         // exported form the kernel module
-        self.kernel = context.into_kernel();
+        self.kernel = context.into_kernel()?;
 
         Ok(self)
     }
@@ -116,37 +222,103 @@ impl Assembler {
     /// Compiles the provided source code into a [Program]. The resulting program can be executed
     /// on Miden VM.
     ///
+    /// This method never panics, regardless of the contents of the source code or of the modules
+    /// provided by the module provider: malformed or truncated sources result in parsing errors,
+    /// and control flow blocks nested more than 64 levels deep are rejected rather than
+    /// overflowing the stack. Thus, it can be used to compile programs from untrusted sources.
+    ///
     /// # Errors
-    /// Returns an error if parsing or compilation of the specified program fails.
+    /// Returns an error if parsing or compilation of the specified program fails, or if any of
+    /// the libraries of the module provider does not have the content hash required via
+    /// [Assembler::with_required_library_hash()].
     pub fn compile<S>(&self, source: S) -> Result<Program, AssemblyError>
     where
         S: AsRef<str>,
     {
+        // make sure the libraries have the required content hashes
+        let mut library_hashes = self.module_provider.library_hashes();
+        for (root_ns, required) in self.required_library_hashes.iter() {
+            let found = library_hashes
+                .iter()
+                .find(|(ns, _)| ns == root_ns)
+                .map(|(_, hash)| hash.as_str());
+            if found != Some(required.as_str()) {
+                return Err(AssemblyError::library_hash_mismatch(
+                    root_ns, required, found,
+                ));
+            }
+        }
+
         // parse the program into an AST
         let source = source.as_ref();
-        let ProgramAst { local_procs, body } = parsers::parse_program(source)?;
+        let (ProgramAst { local_procs, body }, source_map) =
+            parsers::parse_program_with_constants(source, &self.constants)?;
+        let SourceMap {
+            local_procs: proc_locations,
+            body: body_locations,
+        } = source_map;
 
         // compile all local procedures; this will add the procedures to the specified context
         let mut context = AssemblyContext::new(false);
-        for proc_ast in local_procs.iter() {
+        for (proc_ast, locations) in local_procs.iter().zip(proc_locations) {
             if proc_ast.is_export {
                 return Err(AssemblyError::exported_proc_in_program(&proc_ast.name));
             }
-            self.compile_procedure(proc_ast, &mut context)?;
+            self.compile_procedure(proc_ast, locations, &mut context)?;
         }
 
         // compile the program body
-        let program_root = self.compile_body(body.iter(), &mut context, None)?;
+        context.set_source_locations(body_locations)?;
+        let program_root = self.compile_body(body.iter(), &mut context, None, 0)?;
 
         // convert the context into a call block table for the program
-        let cb_table = context.into_cb_table(&self.proc_cache);
+        let (cb_table, proc_names, named_procs, library_procs) =
+            context.into_cb_table(&self.proc_cache)?;
+
+        // record the content hashes only for the libraries from which procedures are used
+        library_hashes.retain(|(root_ns, _)| {
+            library_procs
+                .iter()
+                .any(|path| path.split(MODULE_PATH_DELIM).next() == Some(root_ns.as_str()))
+        });
 
         // build and return the program
-        Ok(Program::with_kernel(
-            program_root,
-            self.kernel.clone(),
-            cb_table,
-        ))
+        let program = Program::with_kernel(program_root, self.kernel.clone(), cb_table)
+            .with_named_procedures(named_procs)
+            .with_library_hashes(library_hashes)
+            .with_library_procedures(library_procs);
+        let num_local_procs = local_procs.len();
+        debug!(num_local_procs; "Compiled program with {num_local_procs} local procedures");
+        if self.with_debug_symbols {
+            Ok(program.with_procedure_names(proc_names))
+        } else {
+            Ok(program)
+        }
+    }
+
+    // PROGRAM ANALYZER
+    // --------------------------------------------------------------------------------------------
+    /// Analyzes the provided source code without compiling it, and returns the [ProgramMetrics]
+    /// of the program.
+    ///
+    /// The analysis covers the program body as well as all procedures invoked from it, including
+    /// procedures imported via the module provider of this assembler.
+    ///
+    /// # Errors
+    /// Returns an error if parsing of the specified program fails, if any of the procedures
+    /// invoked by the program could not be found, or if any of the analyzed instructions is not
+    /// available in the target version of the instruction set.
+    pub fn analyze<S>(&self, source: S) -> Result<ProgramMetrics, AssemblyError>
+    where
+        S: AsRef<str>,
+    {
+        let (ProgramAst { local_procs, body }, _) =
+            parsers::parse_program_with_constants(source.as_ref(), &self.constants)?;
+
+        let mut collector =
+            MetricsCollector::new(self.module_provider.as_ref(), self.target_version);
+        collector.visit_body(&body, &local_procs, MODULE_PATH_DELIM)?;
+        Ok(collector.into_metrics())
     }
 
     // MODULE COMPILER
@@ -164,9 +336,9 @@ impl Assembler {
         // compiled procedures (and their combined callset) from the context
         context.begin_module(module_path)?;
         for proc_ast in module.local_procs.iter() {
-            self.compile_procedure(proc_ast, context)?;
+            self.compile_procedure(proc_ast, Vec::new(), context)?;
         }
-        let (module_procs, module_callset) = context.complete_module();
+        let (module_procs, module_callset) = context.complete_module()?;
 
         // add the compiled procedures to the assembler's cache. the procedures are added to the
         // cache only if:
@@ -193,9 +365,11 @@ impl Assembler {
     fn compile_procedure(
         &self,
         proc: &ProcedureAst,
+        source_locations: Vec<SourceLocation>,
         context: &mut AssemblyContext,
     ) -> Result<(), AssemblyError> {
         context.begin_proc(&proc.name, proc.is_export, proc.num_locals)?;
+        context.set_source_locations(source_locations)?;
 
         let code_root = if proc.num_locals > 0 {
             // for procedures with locals, we need to update fmp register before and after the
@@ -207,32 +381,42 @@ impl Assembler {
                 prologue: vec![Operation::Push(num_locals), Operation::FmpUpdate],
                 epilogue: vec![Operation::Push(-num_locals), Operation::FmpUpdate],
             };
-            self.compile_body(proc.body.iter(), context, Some(wrapper))?
+            self.compile_body(proc.body.iter(), context, Some(wrapper), 0)?
         } else {
-            self.compile_body(proc.body.iter(), context, None)?
+            self.compile_body(proc.body.iter(), context, None, 0)?
         };
 
-        context.complete_proc(code_root);
-
-        Ok(())
+        context.complete_proc(code_root)
     }
 
     // CODE BODY COMPILER
     // --------------------------------------------------------------------------------------------
 
-    /// TODO: add comments
+    /// Compiles the provided sequence of AST nodes into a single code block.
+    ///
+    /// The depth specifies the number of control flow blocks in which the body is nested. Bodies
+    /// of nested blocks are compiled recursively, and thus, an error is returned if the depth
+    /// exceeds [MAX_BLOCK_NESTING_DEPTH]; this also covers ASTs which were not produced by the
+    /// parser (e.g., deserialized module ASTs).
     fn compile_body<A, N>(
         &self,
         body: A,
         context: &mut AssemblyContext,
         wrapper: Option<BodyWrapper>,
+        depth: usize,
     ) -> Result<CodeBlock, AssemblyError>
     where
         A: Iterator<Item = N>,
         N: Borrow<Node>,
     {
+        if depth > MAX_BLOCK_NESTING_DEPTH {
+            return Err(AssemblyError::block_nesting_too_deep(
+                MAX_BLOCK_NESTING_DEPTH,
+            ));
+        }
+
         let mut blocks: Vec<CodeBlock> = Vec::new();
-        let mut span = SpanBuilder::new(wrapper);
+        let mut span = SpanBuilder::new(wrapper, self.with_optimizations);
 
         for node in body {
             match node.borrow() {
@@ -248,12 +432,12 @@ impl Assembler {
                 Node::IfElse(t, f) => {
                     span.extract_span_into(&mut blocks);
 
-                    let t = self.compile_body(t.iter(), context, None)?;
+                    let t = self.compile_body(t.iter(), context, None, depth + 1)?;
 
                     // else is an exception because it is optional; hence, will have to be replaced
                     // by noop span
                     let f = if !f.is_empty() {
-                        self.compile_body(f.iter(), context, None)?
+                        self.compile_body(f.iter(), context, None, depth + 1)?
                     } else {
                         CodeBlock::new_span(vec![Operation::Noop])
                     };
@@ -266,7 +450,7 @@ impl Assembler {
                 Node::Repeat(n, nodes) => {
                     span.extract_span_into(&mut blocks);
 
-                    let block = self.compile_body(nodes.iter(), context, None)?;
+                    let block = self.compile_body(nodes.iter(), context, None, depth + 1)?;
 
                     for _ in 0..*n {
                         blocks.push(block.clone());
@@ -276,7 +460,7 @@ impl Assembler {
                 Node::While(nodes) => {
                     span.extract_span_into(&mut blocks);
 
-                    let block = self.compile_body(nodes.iter(), context, None)?;
+                    let block = self.compile_body(nodes.iter(), context, None, depth + 1)?;
                     let block = CodeBlock::new_loop(block);
 
                     blocks.push(block);
@@ -286,6 +470,12 @@ impl Assembler {
 
         span.extract_final_span_into(&mut blocks);
 
+        // a body may compile into no blocks at all (e.g., if it consists only of loops with zero
+        // iterations); such a body is replaced by a noop span, same as an omitted else branch
+        if blocks.is_empty() {
+            blocks.push(CodeBlock::new_span(vec![Operation::Noop]));
+        }
+
         Ok(combine_blocks(blocks))
     }
 
@@ -301,9 +491,15 @@ impl Assembler {
         proc_id: &ProcedureId,
         context: &mut AssemblyContext,
     ) -> Result<&Procedure, AssemblyError> {
-        // if the procedure is already in the procedure cache, return it
+        // if the procedure is already in the procedure cache, return it; internal procedures are
+        // in the cache as well (if they are invoked via call instructions), but they cannot be
+        // imported
         if let Some(p) = self.proc_cache.get(proc_id) {
-            return Ok(p);
+            return if p.is_export() {
+                Ok(p)
+            } else {
+                Err(AssemblyError::imported_proc_not_exported(proc_id))
+            };
         }
 
         // otherwise, get the module to which the procedure belongs and compile the entire module;
@@ -320,6 +516,9 @@ impl Assembler {
         let proc = self.proc_cache.get(proc_id).ok_or_else(|| {
             AssemblyError::imported_proc_not_found_in_module(proc_id, module.path())
         })?;
+        if !proc.is_export() {
+            return Err(AssemblyError::imported_proc_not_exported(proc_id));
+        }
         Ok(proc)
     }
 }
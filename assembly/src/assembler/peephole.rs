@@ -0,0 +1,98 @@
+use super::{DecoratorList, Operation, Vec};
+
+// PEEPHOLE OPTIMIZER
+// ================================================================================================
+
+/// Removes pairs of adjacent operations which cancel each other out from the provided list of
+/// SPAN block operations, and returns the remaining operations together with the decorators
+/// adjusted to point to the remaining operations.
+///
+/// The following pairs of operations are removed:
+/// - An operation which pushes a value onto the stack without side effects (i.e., `Push`, `Pad`,
+///   and `Dup*`) followed by `Drop`.
+/// - Two identical stack permutations which are their own inverses (i.e., `Swap`, `SwapW`,
+///   `SwapW2`, `SwapW3`, and `SwapDW`), as well as `Neg` followed by `Neg`.
+/// - `MovUpN` followed by `MovDnN` and vice versa.
+///
+/// Removal of a pair may result in a new cancelling pair (e.g., `swap push.1 drop swap`); such
+/// pairs are removed as well. A pair of operations is never removed if any decorators are
+/// attached to either operation, since the decorators may depend on the state of the VM (e.g.,
+/// advice injectors), or describe assembly instructions which produced the operations.
+///
+/// The list of operations is never reduced to an empty list, since SPAN blocks must contain at
+/// least one operation; if all operations cancel out, a single `Noop` is returned instead.
+pub fn optimize_ops(
+    ops: Vec<Operation>,
+    decorators: DecoratorList,
+) -> (Vec<Operation>, DecoratorList) {
+    let num_ops = ops.len();
+    let mut result: Vec<Operation> = Vec::with_capacity(num_ops);
+    let mut result_decorators = Vec::with_capacity(decorators.len());
+
+    // if the operation at a given position of the result has decorators attached to it, the
+    // corresponding flag is set to true
+    let mut has_decorators: Vec<bool> = Vec::with_capacity(num_ops);
+
+    let mut decorators = decorators.into_iter().peekable();
+    for (op_idx, op) in ops.into_iter().enumerate() {
+        let mut decorated = false;
+        while let Some((_, decorator)) = decorators.next_if(|(pos, _)| *pos <= op_idx) {
+            result_decorators.push((result.len(), decorator));
+            decorated = true;
+        }
+
+        let cancels_previous = !decorated
+            && matches!(has_decorators.last(), Some(false))
+            && cancels_out(result[result.len() - 1], op);
+        if cancels_previous {
+            result.pop();
+            has_decorators.pop();
+        } else {
+            result.push(op);
+            has_decorators.push(decorated);
+        }
+    }
+
+    // decorators placed after the last operation remain after the last operation
+    for (_, decorator) in decorators {
+        result_decorators.push((result.len(), decorator));
+    }
+
+    if result.is_empty() && num_ops > 0 {
+        result.push(Operation::Noop);
+    }
+
+    (result, result_decorators)
+}
+
+/// Returns true if executing operation `second` immediately after operation `first` leaves the
+/// state of the VM unchanged.
+fn cancels_out(first: Operation, second: Operation) -> bool {
+    use Operation::*;
+    matches!(
+        (first, second),
+        (Push(_) | Pad, Drop)
+            | (Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7, Drop)
+            | (Dup9 | Dup11 | Dup13 | Dup15, Drop)
+            | (Swap, Swap)
+            | (SwapW, SwapW)
+            | (SwapW2, SwapW2)
+            | (SwapW3, SwapW3)
+            | (SwapDW, SwapDW)
+            | (Neg, Neg)
+            | (MovUp2, MovDn2)
+            | (MovDn2, MovUp2)
+            | (MovUp3, MovDn3)
+            | (MovDn3, MovUp3)
+            | (MovUp4, MovDn4)
+            | (MovDn4, MovUp4)
+            | (MovUp5, MovDn5)
+            | (MovDn5, MovUp5)
+            | (MovUp6, MovDn6)
+            | (MovDn6, MovUp6)
+            | (MovUp7, MovDn7)
+            | (MovDn7, MovUp7)
+            | (MovUp8, MovDn8)
+            | (MovDn8, MovUp8)
+    )
+}
@@ -1,8 +1,8 @@
 use super::{
-    AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList, Instruction,
+    peephole, AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList, Instruction,
     Operation, ToString, Vec,
 };
-use vm_core::AssemblyOp;
+use vm_core::{AssemblyOp, SourceLocation};
 
 // SPAN BUILDER
 // ================================================================================================
@@ -14,12 +14,16 @@ use vm_core::AssemblyOp;
 ///
 /// The same span builder can be used to construct many blocks. It is expected that when the last
 /// SPAN block in a procedure's body is constructed `extract_final_span_into()` will be used.
+///
+/// If the builder is instantiated with optimizations enabled, peephole optimizations are applied
+/// to the operations of every SPAN block when the block is extracted from the builder.
 #[derive(Default)]
 pub struct SpanBuilder {
     ops: Vec<Operation>,
     decorators: DecoratorList,
     epilogue: Vec<Operation>,
     last_asmop_pos: usize,
+    optimize: bool,
 }
 
 impl SpanBuilder {
@@ -30,15 +34,19 @@ impl SpanBuilder {
     /// If the wrapper is provided, the prologue of the wrapper is immediately appended to the
     /// vector of span operations. The epilogue of the wrapper is appended to the list of
     /// operations upon consumption of the builder via `extract_final_span_into()` method.
-    pub(super) fn new(wrapper: Option<BodyWrapper>) -> Self {
+    pub(super) fn new(wrapper: Option<BodyWrapper>, optimize: bool) -> Self {
         match wrapper {
             Some(wrapper) => Self {
                 ops: wrapper.prologue,
                 decorators: Vec::new(),
                 epilogue: wrapper.epilogue,
                 last_asmop_pos: 0,
+                optimize,
+            },
+            None => Self {
+                optimize,
+                ..Self::default()
             },
-            None => Self::default(),
         }
     }
 
@@ -102,8 +110,18 @@ impl SpanBuilder {
     ///
     /// This indicates that the provided instruction should be tracked and the cycle count for
     /// this instruction will be computed when the call to set_instruction_cycle_count() is made.
-    pub fn track_instruction(&mut self, instruction: &Instruction) {
+    /// If the location of the instruction in the source code is known, it is recorded in the
+    /// decorator as well.
+    pub fn track_instruction(
+        &mut self,
+        instruction: &Instruction,
+        location: Option<SourceLocation>,
+    ) {
         let op = AssemblyOp::new(instruction.to_string(), 0);
+        let op = match location {
+            Some(location) => op.with_location(location),
+            None => op,
+        };
         self.push_decorator(Decorator::AsmOp(op));
         self.last_asmop_pos = self.decorators.len() - 1;
     }
@@ -142,14 +160,18 @@ impl SpanBuilder {
     /// This consumes all operations and decorators in the builder, but does not touch the
     /// operations in the epilogue of the builder.
     pub fn extract_span_into(&mut self, target: &mut Vec<CodeBlock>) {
-        if !self.ops.is_empty() {
-            let ops = self.ops.drain(..).collect();
-            let decorators = self.decorators.drain(..).collect();
+        if !self.ops.is_empty() || !self.decorators.is_empty() {
+            let mut ops: Vec<Operation> = self.ops.drain(..).collect();
+            let mut decorators = self.decorators.drain(..).collect();
+            if self.optimize {
+                (ops, decorators) = peephole::optimize_ops(ops, decorators);
+            }
+            // decorators which are not followed by any operations (e.g., advice injectors at the
+            // end of a block) are attached to a NOOP, so that they are still executed
+            if matches!(decorators.last(), Some((pos, _)) if *pos >= ops.len()) {
+                ops.push(Operation::Noop);
+            }
             target.push(CodeBlock::new_span_with_decorators(ops, decorators));
-        } else if !self.decorators.is_empty() {
-            // this is a bug in the assembler. we shouldn't have decorators added without their
-            // associated operations
-            unreachable!()
         }
     }
 
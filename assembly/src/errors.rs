@@ -1,4 +1,4 @@
-use super::{ProcedureId, String, ToString, Token, Vec};
+use super::{InstructionSetVersion, ProcedureId, String, ToString, Token, Vec};
 use core::{fmt, array::TryFromSliceError};
 
 // ASSEMBLY ERROR
@@ -6,25 +6,39 @@ use core::{fmt, array::TryFromSliceError};
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum AssemblyError {
+    BlockNestingTooDeep(usize),
     CallInKernel(String),
     CallerOutOKernel,
+    CallsetProcNotFound(ProcedureId),
     CircularModuleDependency(Vec<String>),
+    ConstantsFileNotReadable(String, String),
     DivisionByZero,
     DuplicateProcName(String, String),
     ExportedProcInProgram(String),
     ImportedProcModuleNotFound(ProcedureId),
+    ImportedProcNotExported(ProcedureId),
     ImportedProcNotFoundInModule(ProcedureId, String),
+    InvalidConstant(usize, String),
+    InvalidContext(String),
     KernelProcNotFound(ProcedureId),
+    LibraryHashMismatch(String, String, Option<String>),
     LocalProcNotFound(u16, String),
+    NoProcLocals(u16),
     ParsingError(String),
     ParamOutOfBounds(u64, u64, u64),
     SysCallInKernel(String),
+    UnavailableInstruction(String, InstructionSetVersion, InstructionSetVersion),
+    UnknownInstruction(String, Option<String>),
 }
 
 impl AssemblyError {
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
+    pub fn block_nesting_too_deep(max_depth: usize) -> Self {
+        Self::BlockNestingTooDeep(max_depth)
+    }
+
     pub fn call_in_kernel(kernel_proc_name: &str) -> Self {
         Self::CallInKernel(kernel_proc_name.to_string())
     }
@@ -33,10 +47,18 @@ impl AssemblyError {
         Self::CallerOutOKernel
     }
 
+    pub fn callset_proc_not_found(proc_id: &ProcedureId) -> Self {
+        Self::CallsetProcNotFound(*proc_id)
+    }
+
     pub fn circular_module_dependency(dep_chain: &[String]) -> Self {
         Self::CircularModuleDependency(dep_chain.to_vec())
     }
 
+    pub fn constants_file_not_readable(path: &str, reason: &str) -> Self {
+        Self::ConstantsFileNotReadable(path.to_string(), reason.to_string())
+    }
+
     pub fn division_by_zero() -> Self {
         Self::DivisionByZero
     }
@@ -53,18 +75,42 @@ impl AssemblyError {
         Self::ImportedProcModuleNotFound(*proc_id)
     }
 
+    pub fn imported_proc_not_exported(proc_id: &ProcedureId) -> Self {
+        Self::ImportedProcNotExported(*proc_id)
+    }
+
     pub fn imported_proc_not_found_in_module(proc_id: &ProcedureId, module_path: &str) -> Self {
         Self::ImportedProcNotFoundInModule(*proc_id, module_path.to_string())
     }
 
+    pub fn invalid_constant(line: usize, reason: &str) -> Self {
+        Self::InvalidConstant(line, reason.to_string())
+    }
+
+    pub fn invalid_context(reason: &str) -> Self {
+        Self::InvalidContext(reason.to_string())
+    }
+
     pub fn kernel_proc_not_found(kernel_proc_id: &ProcedureId) -> Self {
         Self::KernelProcNotFound(*kernel_proc_id)
     }
 
+    pub fn library_hash_mismatch(root_ns: &str, required: &str, found: Option<&str>) -> Self {
+        Self::LibraryHashMismatch(
+            root_ns.to_string(),
+            required.to_string(),
+            found.map(|hash| hash.to_string()),
+        )
+    }
+
     pub fn local_proc_not_found(proc_idx: u16, module_path: &str) -> Self {
         Self::LocalProcNotFound(proc_idx, module_path.to_string())
     }
 
+    pub fn no_proc_locals(index: u16) -> Self {
+        Self::NoProcLocals(index)
+    }
+
     pub fn param_out_of_bounds(value: u64, min: u64, max: u64) -> Self {
         Self::ParamOutOfBounds(value, min, max)
     }
@@ -72,11 +118,28 @@ impl AssemblyError {
     pub fn syscall_in_kernel(kernel_proc_name: &str) -> Self {
         Self::SysCallInKernel(kernel_proc_name.to_string())
     }
+
+    pub fn unavailable_instruction(
+        name: &str,
+        version: InstructionSetVersion,
+        target_version: InstructionSetVersion,
+    ) -> Self {
+        Self::UnavailableInstruction(name.to_string(), version, target_version)
+    }
+
+    pub fn unknown_instruction(name: &str, suggestion: Option<&str>) -> Self {
+        Self::UnknownInstruction(name.to_string(), suggestion.map(|s| s.to_string()))
+    }
 }
 
 impl From<ParsingError> for AssemblyError {
     fn from(err: ParsingError) -> Self {
-        Self::ParsingError(err.message)
+        match err.kind {
+            ParsingErrorKind::UnknownInstruction(name, suggestion) => {
+                Self::UnknownInstruction(name, suggestion)
+            }
+            ParsingErrorKind::Other => Self::ParsingError(err.message),
+        }
     }
 }
 
@@ -84,19 +147,31 @@ impl fmt::Display for AssemblyError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         use AssemblyError::*;
         match self {
+            BlockNestingTooDeep(max_depth) => write!(f, "control flow blocks cannot be nested more than {max_depth} levels deep"),
             CallInKernel(proc_name) => write!(f, "call instruction used kernel procedure '{proc_name}'"),
             CallerOutOKernel => write!(f, "caller instruction used outside of kernel"),
+            CallsetProcNotFound(proc_id) => write!(f, "procedure {proc_id} invoked by the program not found"),
             CircularModuleDependency(dep_chain) => write!(f, "circular module dependency in the following chain: {dep_chain:?}"),
+            ConstantsFileNotReadable(path, reason) => write!(f, "failed to read constants file {path}: {reason}"),
             DivisionByZero => write!(f, "division by zero"),
             DuplicateProcName(proc_name, module_path) => write!(f, "duplicate proc name '{proc_name}' in module {module_path}"),
             ExportedProcInProgram(proc_name) => write!(f, "exported procedure '{proc_name}' in executable program"),
             ImportedProcModuleNotFound(proc_id) => write!(f, "module for imported procedure {proc_id} not found"),
+            ImportedProcNotExported(proc_id) => write!(f, "imported procedure {proc_id} is not exported from its module"),
             ImportedProcNotFoundInModule(proc_id, module_path) => write!(f, "imported procedure {proc_id} not found in module {module_path}"),
+            InvalidConstant(line, reason) => write!(f, "invalid constant definition at line {line}: {reason}"),
+            InvalidContext(reason) => write!(f, "invalid assembly context: {reason}"),
             KernelProcNotFound(proc_id) => write!(f, "procedure {proc_id} not found in kernel"),
+            LibraryHashMismatch(root_ns, required, Some(found)) => write!(f, "library '{root_ns}' has content hash {found}, but content hash {required} is required"),
+            LibraryHashMismatch(root_ns, required, None) => write!(f, "library '{root_ns}' is required with content hash {required}, but is not available"),
             LocalProcNotFound(proc_idx, module_path) => write!(f, "procedure at index {proc_idx} not found in module {module_path}"),
+            NoProcLocals(index) => write!(f, "local at index {index} accessed, but no procedure locals were declared"),
             ParsingError(err) => write!(f, "{err}"),
             ParamOutOfBounds(value, min, max) => write!(f, "parameter value must be greater than or equal to {min} and less than or equal to {max}, but was {value}"),
             SysCallInKernel(proc_name) => write!(f, "syscall instruction used in kernel procedure '{proc_name}'"),
+            UnavailableInstruction(name, version, target_version) => write!(f, "instruction '{name}' requires instruction set version {version}, but the target version is {target_version}"),
+            UnknownInstruction(name, Some(suggestion)) => write!(f, "unknown instruction '{name}'; did you mean '{suggestion}'?"),
+            UnknownInstruction(name, None) => write!(f, "unknown instruction '{name}'"),
         }
     }
 }
@@ -112,6 +187,14 @@ pub struct ParsingError {
     message: String,
     step: usize,
     op: String,
+    kind: ParsingErrorKind,
+}
+
+/// Kinds of parsing errors which are converted into dedicated [AssemblyError] variants.
+#[derive(Clone, Eq, PartialEq)]
+enum ParsingErrorKind {
+    UnknownInstruction(String, Option<String>),
+    Other,
 }
 
 impl ParsingError {
@@ -119,243 +202,184 @@ impl ParsingError {
     // --------------------------------------------------------------------------------------------
 
     pub fn empty_source() -> Self {
-        ParsingError {
-            message: "source code cannot be an empty string".to_string(),
-            step: 0,
-            op: "".to_string(),
-        }
+        Self::other_at(0, "source code cannot be an empty string".to_string())
     }
 
     pub fn unexpected_eof(step: usize) -> Self {
-        ParsingError {
-            message: "unexpected EOF".to_string(),
-            step,
-            op: "".to_string(),
-        }
+        Self::other_at(step, "unexpected EOF".to_string())
     }
 
     pub fn unexpected_token(token: &Token, expected: &str) -> Self {
-        ParsingError {
-            message: format!("unexpected token: expected '{expected}' but was '{token}'"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(
+            token,
+            format!("unexpected token: expected '{expected}' but was '{token}'"),
+        )
     }
 
     pub fn unexpected_body_end(token: &Token) -> Self {
-        ParsingError {
-            message: format!("unexpected body termination: invalid token '{token}'"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(
+            token,
+            format!("unexpected body termination: invalid token '{token}'"),
+        )
     }
 
     pub fn empty_block(token: &Token) -> Self {
-        ParsingError {
-            message: "a code block must contain at least one instruction".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(
+            token,
+            "a code block must contain at least one instruction".to_string(),
+        )
     }
 
     pub fn invalid_op(token: &Token) -> Self {
+        Self::other(token, format!("instruction '{token}' is invalid"))
+    }
+
+    pub fn unknown_instruction(token: &Token, suggestion: Option<&str>) -> Self {
+        let name = token.parts()[0];
+        let message = match suggestion {
+            Some(suggestion) => {
+                format!("unknown instruction '{name}'; did you mean '{suggestion}'?")
+            }
+            None => format!("unknown instruction '{name}'"),
+        };
         ParsingError {
-            message: format!("instruction '{token}' is invalid"),
+            message,
             step: token.pos(),
             op: token.to_string(),
+            kind: ParsingErrorKind::UnknownInstruction(
+                name.to_string(),
+                suggestion.map(|s| s.to_string()),
+            ),
         }
     }
 
     /// TODO: currently unused
     pub fn invalid_op_with_reason(token: &Token, reason: &str) -> Self {
-        ParsingError {
-            message: format!("instruction '{token}' is invalid: {reason}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, format!("instruction '{token}' is invalid: {reason}"))
     }
 
     pub fn missing_param(token: &Token) -> Self {
-        ParsingError {
-            message: format!("malformed instruction '{token}': missing required parameter"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(
+            token,
+            format!("malformed instruction '{token}': missing required parameter"),
+        )
     }
 
     pub fn extra_param(token: &Token) -> Self {
-        ParsingError {
-            message: format!("malformed instruction '{token}': too many parameters provided"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(
+            token,
+            format!("malformed instruction '{token}': too many parameters provided"),
+        )
     }
 
     pub fn invalid_param(token: &Token, part_idx: usize) -> Self {
-        ParsingError {
-            message: format!(
+        Self::other(
+            token,
+            format!(
                 "malformed instruction `{token}`: parameter '{}' is invalid",
                 token.parts()[part_idx]
             ),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        )
     }
 
     pub fn invalid_param_with_reason(token: &Token, part_idx: usize, reason: &str) -> Self {
-        ParsingError {
-            message: format!(
+        Self::other(
+            token,
+            format!(
                 "malformed instruction '{token}', parameter {} is invalid: {reason}",
                 token.parts()[part_idx],
             ),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        )
     }
 
     pub fn dangling_else(token: &Token) -> Self {
-        ParsingError {
-            message: "else without matching if".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "else without matching if".to_string())
     }
 
     pub fn unmatched_if(token: &Token) -> Self {
-        ParsingError {
-            message: "if without matching else/end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "if without matching else/end".to_string())
     }
 
     pub fn unmatched_while(token: &Token) -> Self {
-        ParsingError {
-            message: "while without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "while without matching end".to_string())
     }
 
     pub fn unmatched_repeat(token: &Token) -> Self {
-        ParsingError {
-            message: "repeat without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "repeat without matching end".to_string())
+    }
+
+    pub fn block_nesting_too_deep(token: &Token, max_depth: usize) -> Self {
+        Self::other(
+            token,
+            format!("control flow blocks cannot be nested more than {max_depth} levels deep"),
+        )
     }
 
     pub fn unmatched_else(token: &Token) -> Self {
-        ParsingError {
-            message: "else without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "else without matching end".to_string())
     }
 
     pub fn malformed_doc_comment(step: usize) -> Self {
-        ParsingError {
-            message: "doc comments separated by line break".to_string(),
-            step,
-            op: "".to_string(),
-        }
+        Self::other_at(step, "doc comments separated by line break".to_string())
     }
 
     // PROGRAM
     // --------------------------------------------------------------------------------------------
 
     pub fn unmatched_begin(token: &Token) -> Self {
-        ParsingError {
-            message: "begin without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "begin without matching end".to_string())
     }
 
     pub fn dangling_ops_after_program(token: &Token) -> Self {
-        ParsingError {
-            message: "dangling instructions after program end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "dangling instructions after program end".to_string())
     }
 
     // PROCEDURES
     // --------------------------------------------------------------------------------------------
 
     pub fn duplicate_proc_label(token: &Token, label: &str) -> Self {
-        ParsingError {
-            message: format!("duplicate procedure label: {label}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, format!("duplicate procedure label: {label}"))
     }
 
     pub fn invalid_proc_label(token: &Token, label: &str) -> Self {
-        ParsingError {
-            message: format!("invalid procedure label: {label}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, format!("invalid procedure label: {label}"))
     }
 
     pub fn invalid_proc_locals(token: &Token, locals: &str) -> Self {
-        ParsingError {
-            message: format!("invalid procedure locals: {locals}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, format!("invalid procedure locals: {locals}"))
     }
 
     pub fn unmatched_proc(token: &Token) -> Self {
-        ParsingError {
-            message: "proc without matching end".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "proc without matching end".to_string())
     }
 
     pub fn undefined_proc(token: &Token, label: &str) -> Self {
-        ParsingError {
-            message: format!("undefined procedure: {label}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, format!("undefined procedure: {label}"))
     }
 
     pub fn proc_export_not_allowed(token: &Token, label: &str) -> Self {
-        ParsingError {
-            message: format!("exported procedures not allowed in this context: {label}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(
+            token,
+            format!("exported procedures not allowed in this context: {label}"),
+        )
     }
 
     /// TODO: currently unused
     pub fn syscall_in_kernel(token: &Token) -> Self {
-        ParsingError {
-            message: "syscall inside kernel".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "syscall inside kernel".to_string())
     }
 
     /// TODO: currently unused
     pub fn call_in_kernel(token: &Token) -> Self {
-        ParsingError {
-            message: "call inside kernel".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, "call inside kernel".to_string())
     }
 
     /// TODO: currently unused
     pub fn caller_out_of_kernel(token: &Token) -> Self {
-        ParsingError {
-            message: "caller instruction executed outside of kernel context".to_string(),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(
+            token,
+            "caller instruction executed outside of kernel context".to_string(),
+        )
     }
 
     // IMPORTS AND MODULES
@@ -363,27 +387,29 @@ impl ParsingError {
 
     /// TODO: currently unused
     pub fn dangling_ops_after_module(token: &Token, module_path: &str) -> Self {
-        ParsingError {
-            message: format!("dangling instructions after module end at {module_path}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(
+            token,
+            format!("dangling instructions after module end at {module_path}"),
+        )
     }
 
     pub fn duplicate_module_import(token: &Token, module: &str) -> Self {
-        ParsingError {
-            message: format!("duplicate module import found: {module}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, format!("duplicate module import found: {module}"))
+    }
+
+    pub fn undefined_module(token: &Token, module: &str) -> Self {
+        Self::other(token, format!("undefined module: {module}"))
+    }
+
+    pub fn ambiguous_module_path(token: &Token, module_path: &str, import: &str) -> Self {
+        Self::other(
+            token,
+            format!("ambiguous module path {module_path}: conflicts with import {import}"),
+        )
     }
 
     pub fn invalid_module_path(token: &Token, module_path: &str) -> Self {
-        ParsingError {
-            message: format!("invalid module import path: {module_path}"),
-            step: token.pos(),
-            op: token.to_string(),
-        }
+        Self::other(token, format!("invalid module import path: {module_path}"))
     }
 
     // PUBLIC ACCESSORS
@@ -399,6 +425,31 @@ impl ParsingError {
     pub fn step(&self) -> usize {
         self.step
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an error with the specified message for the specified token; this error is
+    /// converted into [AssemblyError::ParsingError].
+    fn other(token: &Token, message: String) -> Self {
+        ParsingError {
+            message,
+            step: token.pos(),
+            op: token.to_string(),
+            kind: ParsingErrorKind::Other,
+        }
+    }
+
+    /// Returns an error with the specified message at the specified step, which is not associated
+    /// with any token; this error is converted into [AssemblyError::ParsingError].
+    fn other_at(step: usize, message: String) -> Self {
+        ParsingError {
+            message,
+            step,
+            op: "".to_string(),
+            kind: ParsingErrorKind::Other,
+        }
+    }
 }
 
 impl fmt::Debug for ParsingError {
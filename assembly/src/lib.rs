@@ -11,7 +11,7 @@ use vm_core::{
         string::{String, ToString},
         Box,
     },
-    CodeBlockTable, Felt, Kernel, Operation, Program, StarkField, ONE, ZERO,
+    CodeBlockTable, Felt, Kernel, Operation, Program, SourceLocation, StarkField, ONE, ZERO,
 };
 
 mod procedures;
@@ -30,7 +30,10 @@ mod errors;
 pub use errors::{AssemblyError, LibraryError, ParsingError};
 
 mod assembler;
-pub use assembler::Assembler;
+pub use assembler::{Assembler, ProgramMetrics};
+
+mod versions;
+pub use versions::InstructionSetVersion;
 
 #[cfg(test)]
 mod tests;
@@ -59,6 +62,10 @@ const MAX_EXP_BITS: u8 = 64;
 /// The maximum length of a procedure's name.
 const MAX_PROC_NAME_LEN: u8 = 100;
 
+/// The maximum depth to which control flow blocks (i.e., `if`, `while`, and `repeat` blocks) can
+/// be nested within a program body or a procedure.
+const MAX_BLOCK_NESTING_DEPTH: usize = 64;
+
 // MODULE PROVIDER
 // ================================================================================================
 
@@ -67,6 +74,12 @@ const MAX_PROC_NAME_LEN: u8 = 100;
 pub trait ModuleProvider {
     /// Fetch a module AST from its ID
     fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>>;
+
+    /// Returns the (root namespace, content hash) pairs of the libraries whose modules are provided
+    /// by this provider; these are recorded in the programs which use procedures of the libraries.
+    fn library_hashes(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
 }
 
 // A default provider that won't resolve modules
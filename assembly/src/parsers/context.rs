@@ -1,6 +1,7 @@
 use super::{
-    field_ops, io_ops, stack_ops, u32_ops, Instruction, LocalProcMap, Node, ParsingError,
-    ProcedureAst, ProcedureId, Token, TokenStream, MODULE_PATH_DELIM,
+    error_at, field_ops, io_ops, read_current, stack_ops, u32_ops, ConstantMap, Instruction,
+    LocalProcMap, Node, ParsingError, ProcedureAst, ProcedureId, SourceLocation, Token,
+    TokenStream, MAX_BLOCK_NESTING_DEPTH, MODULE_PATH_DELIM,
 };
 use vm_core::utils::{
     collections::{BTreeMap, Vec},
@@ -15,6 +16,10 @@ use vm_core::utils::{
 pub struct ParserContext {
     pub imports: BTreeMap<String, String>,
     pub local_procs: LocalProcMap,
+    pub constants: ConstantMap,
+    /// Source locations of the instructions of each local procedure, in the order in which the
+    /// instructions were parsed, indexed by procedure index.
+    pub proc_locations: Vec<Vec<SourceLocation>>,
 }
 
 impl ParserContext {
@@ -22,14 +27,14 @@ impl ParserContext {
     // --------------------------------------------------------------------------------------------
 
     // Parses an if-else statement from the provided token stream.
-    fn parse_if(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_if(&self, tokens: &mut TokenStream, depth: usize) -> Result<Node, ParsingError> {
         // record start of the if-else block and consume the 'if' token
         let if_start = tokens.pos();
         tokens.advance();
 
         let mut t_branch = Vec::<Node>::new();
         // read the `if` clause
-        self.parse_body(tokens, &mut t_branch, true)?;
+        self.parse_body(tokens, &mut t_branch, true, depth)?;
 
         // build the `else` clause; if the else clause is specified, then read it;
         // otherwise, set to a Span with a single noop
@@ -44,19 +49,15 @@ impl ParserContext {
 
                     let mut f_branch = Vec::<Node>::new();
                     // parse the `false` branch
-                    self.parse_body(tokens, &mut f_branch, false)?;
+                    self.parse_body(tokens, &mut f_branch, false, depth)?;
 
                     // consume the `end` token
                     match tokens.read() {
-                        None => Err(ParsingError::unmatched_else(
-                            tokens.read_at(else_start).expect("no else token"),
-                        )),
+                        None => Err(error_at(tokens, else_start, ParsingError::unmatched_else)),
                         Some(token) => match token.parts()[0] {
                             Token::END => token.validate_end(),
                             Token::ELSE => Err(ParsingError::dangling_else(token)),
-                            _ => Err(ParsingError::unmatched_else(
-                                tokens.read_at(else_start).expect("no else token"),
-                            )),
+                            _ => Err(error_at(tokens, else_start, ParsingError::unmatched_else)),
                         },
                     }?;
                     tokens.advance();
@@ -70,43 +71,31 @@ impl ParserContext {
                     tokens.advance();
                     Vec::new()
                 }
-                _ => {
-                    return Err(ParsingError::unmatched_if(
-                        tokens.read_at(if_start).expect("no if token"),
-                    ))
-                }
+                _ => return Err(error_at(tokens, if_start, ParsingError::unmatched_if)),
             },
-            None => {
-                return Err(ParsingError::unmatched_if(
-                    tokens.read_at(if_start).expect("no if token"),
-                ))
-            }
+            None => return Err(error_at(tokens, if_start, ParsingError::unmatched_if)),
         };
 
         Ok(Node::IfElse(t_branch, f_branch))
     }
 
     /// Parse while token into AST nodes.
-    fn parse_while(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_while(&self, tokens: &mut TokenStream, depth: usize) -> Result<Node, ParsingError> {
         // record start of the while block and consume the 'while' token
         let while_start = tokens.pos();
         tokens.advance();
 
         let mut loop_body = Vec::<Node>::new();
         // read the loop body
-        self.parse_body(tokens, &mut loop_body, false)?;
+        self.parse_body(tokens, &mut loop_body, false, depth)?;
 
         // consume the `end` token
         match tokens.read() {
-            None => Err(ParsingError::unmatched_while(
-                tokens.read_at(while_start).expect("no if token"),
-            )),
+            None => Err(error_at(tokens, while_start, ParsingError::unmatched_while)),
             Some(token) => match token.parts()[0] {
                 Token::END => token.validate_end(),
                 Token::ELSE => Err(ParsingError::dangling_else(token)),
-                _ => Err(ParsingError::unmatched_while(
-                    tokens.read_at(while_start).expect("no if token"),
-                )),
+                _ => Err(error_at(tokens, while_start, ParsingError::unmatched_while)),
             },
         }?;
         tokens.advance();
@@ -115,33 +104,33 @@ impl ParserContext {
     }
 
     /// Parse repeat token into AST nodes.
-    fn parse_repeat(&self, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
+    fn parse_repeat(&self, tokens: &mut TokenStream, depth: usize) -> Result<Node, ParsingError> {
         // record start of the repeat block and consume the 'repeat' token
         let repeat_start = tokens.pos();
         let count = match tokens.read() {
             Some(token) => token.parse_repeat()? as usize,
-            None => {
-                return Err(ParsingError::missing_param(
-                    tokens.read_at(repeat_start).expect("no repeat token"),
-                ))
-            }
+            None => return Err(error_at(tokens, repeat_start, ParsingError::missing_param)),
         };
         tokens.advance();
 
         let mut loop_body = Vec::<Node>::new();
         // read the loop body
-        self.parse_body(tokens, &mut loop_body, false)?;
+        self.parse_body(tokens, &mut loop_body, false, depth)?;
 
         // consume the `end` token
         match tokens.read() {
-            None => Err(ParsingError::unmatched_repeat(
-                tokens.read_at(repeat_start).expect("no repeat token"),
+            None => Err(error_at(
+                tokens,
+                repeat_start,
+                ParsingError::unmatched_repeat,
             )),
             Some(token) => match token.parts()[0] {
                 Token::END => token.validate_end(),
                 Token::ELSE => Err(ParsingError::dangling_else(token)),
-                _ => Err(ParsingError::unmatched_repeat(
-                    tokens.read_at(repeat_start).expect("no repeat token"),
+                _ => Err(error_at(
+                    tokens,
+                    repeat_start,
+                    ParsingError::unmatched_repeat,
                 )),
             },
         }?;
@@ -155,18 +144,20 @@ impl ParserContext {
 
     /// Parse exec token into AST nodes.
     fn parse_exec(&self, label: String, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
-        tokens.advance();
-
         if label.contains(MODULE_PATH_DELIM) {
-            let full_proc_name = self.get_full_imported_proc_name(label);
+            let token = read_current(tokens)?;
+            let full_proc_name = self.get_full_imported_proc_name(&label, token)?;
+            tokens.advance();
             let proc_id = ProcedureId::new(full_proc_name);
             Ok(Node::Instruction(Instruction::ExecImported(proc_id)))
         } else {
+            let token = read_current(tokens)?;
             let index = self
                 .local_procs
                 .get(&label)
-                .ok_or_else(|| ParsingError::undefined_proc(tokens.read().unwrap(), &label))?
+                .ok_or_else(|| ParsingError::undefined_proc(token, &label))?
                 .0;
+            tokens.advance();
 
             Ok(Node::Instruction(Instruction::ExecLocal(index)))
         }
@@ -174,17 +165,20 @@ impl ParserContext {
 
     /// Parse call token into AST nodes.
     fn parse_call(&self, label: String, tokens: &mut TokenStream) -> Result<Node, ParsingError> {
-        tokens.advance();
         if label.contains(MODULE_PATH_DELIM) {
-            let full_proc_name = self.get_full_imported_proc_name(label);
+            let token = read_current(tokens)?;
+            let full_proc_name = self.get_full_imported_proc_name(&label, token)?;
+            tokens.advance();
             let proc_id = ProcedureId::new(full_proc_name);
             Ok(Node::Instruction(Instruction::CallImported(proc_id)))
         } else {
+            let token = read_current(tokens)?;
             let index = self
                 .local_procs
                 .get(&label)
-                .ok_or_else(|| ParsingError::undefined_proc(tokens.read().unwrap(), &label))?
+                .ok_or_else(|| ParsingError::undefined_proc(token, &label))?
                 .0;
+            tokens.advance();
 
             Ok(Node::Instruction(Instruction::CallLocal(index)))
         }
@@ -221,6 +215,8 @@ impl ParserContext {
                     let proc = self.parse_procedure(tokens)?;
                     self.local_procs
                         .insert(label.to_string(), (self.local_procs.len() as u16, proc));
+                    self.proc_locations
+                        .push(tokens.take_instruction_locations());
                 }
                 _ => break,
             }
@@ -234,7 +230,7 @@ impl ParserContext {
         let proc_start = tokens.pos();
 
         // read procedure name and consume the procedure header token
-        let header = tokens.read().expect("missing procedure header");
+        let header = read_current(tokens)?;
         let (label, num_locals, is_export) = header.parse_proc()?;
         let docs = if is_export {
             tokens.take_doc_comment_at(proc_start)
@@ -246,18 +242,14 @@ impl ParserContext {
 
         let mut body = Vec::<Node>::new();
         // parse procedure body
-        self.parse_body(tokens, &mut body, false)?;
+        self.parse_body(tokens, &mut body, false, 0)?;
 
         // consume the 'end' token
         match tokens.read() {
-            None => Err(ParsingError::unmatched_proc(
-                tokens.read_at(proc_start).expect("no proc token"),
-            )),
+            None => Err(error_at(tokens, proc_start, ParsingError::unmatched_proc)),
             Some(token) => match token.parts()[0] {
                 Token::END => token.validate_end(),
-                _ => Err(ParsingError::unmatched_proc(
-                    tokens.read_at(proc_start).expect("no proc token"),
-                )),
+                _ => Err(error_at(tokens, proc_start, ParsingError::unmatched_proc)),
             },
         }?;
         tokens.advance();
@@ -277,13 +269,25 @@ impl ParserContext {
     // BODY PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses a token from the token stream in a body, which generates a series of AST nodes.
+    ///
+    /// The depth specifies the number of control flow blocks in which the body is nested; a
+    /// control flow block cannot be opened in a body nested in [MAX_BLOCK_NESTING_DEPTH] blocks.
     pub fn parse_body(
         &self,
         tokens: &mut TokenStream,
         nodes: &mut Vec<Node>,
         break_on_else: bool,
+        depth: usize,
     ) -> Result<(), ParsingError> {
         while let Some(token) = tokens.read() {
+            let is_block_start =
+                matches!(token.parts()[0], Token::IF | Token::WHILE | Token::REPEAT);
+            if is_block_start && depth == MAX_BLOCK_NESTING_DEPTH {
+                return Err(ParsingError::block_nesting_too_deep(
+                    token,
+                    MAX_BLOCK_NESTING_DEPTH,
+                ));
+            }
             match token.parts()[0] {
                 Token::ELSE => {
                     token.validate_else()?;
@@ -294,23 +298,26 @@ impl ParserContext {
                 }
                 Token::IF => {
                     token.validate_if()?;
-                    nodes.push(self.parse_if(tokens)?);
+                    nodes.push(self.parse_if(tokens, depth + 1)?);
                 }
                 Token::WHILE => {
                     token.validate_while()?;
-                    nodes.push(self.parse_while(tokens)?);
+                    nodes.push(self.parse_while(tokens, depth + 1)?);
                 }
-                Token::REPEAT => nodes.push(self.parse_repeat(tokens)?),
+                Token::REPEAT => nodes.push(self.parse_repeat(tokens, depth + 1)?),
                 Token::EXEC => {
                     let label = token.parse_exec()?;
+                    tokens.record_instruction();
                     nodes.push(self.parse_exec(label, tokens)?);
                 }
                 Token::CALL => {
                     let label = token.parse_call()?;
+                    tokens.record_instruction();
                     nodes.push(self.parse_call(label, tokens)?);
                 }
                 Token::SYSCALL => {
                     let label = token.parse_syscall()?;
+                    tokens.record_instruction();
                     nodes.push(self.parse_syscall(label, tokens)?);
                 }
                 Token::END => {
@@ -328,7 +335,9 @@ impl ParserContext {
                         if op.is_control_token() {
                             break;
                         }
-                        nodes.push(parse_op_token(op)?);
+                        let node = parse_op_token(op, &self.constants)?;
+                        tokens.record_instruction();
+                        nodes.push(node);
                         tokens.advance();
                     }
                 }
@@ -341,15 +350,91 @@ impl ParserContext {
     // HELPER FUNCTIONS
     // ================================================================================================
 
-    fn get_full_imported_proc_name(&self, short_name: String) -> String {
-        let (module_name, proc_name) = short_name.rsplit_once(MODULE_PATH_DELIM).unwrap();
-        let full_module_name = self.imports.get(module_name).unwrap();
-        ProcedureId::path(proc_name, full_module_name)
+    /// Returns the fully-qualified name of the procedure invoked via the specified label.
+    ///
+    /// If the module part of the label is a single name (e.g., `u64::add`), it must be the name
+    /// of a module imported via a `use` statement. Otherwise, the module part is treated as a
+    /// fully-qualified module path (e.g., `std::math::u64::add`) which is resolved by the
+    /// assembler on demand.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The module part is a single name which does not refer to any imported module.
+    /// - The module part is a fully-qualified path starting with the name of an imported module,
+    ///   since it is not clear whether the path is relative to the imported module or not.
+    fn get_full_imported_proc_name(
+        &self,
+        label: &str,
+        token: &Token,
+    ) -> Result<String, ParsingError> {
+        let (module_name, proc_name) = label
+            .rsplit_once(MODULE_PATH_DELIM)
+            .ok_or_else(|| ParsingError::invalid_proc_label(token, label))?;
+        match module_name.split_once(MODULE_PATH_DELIM) {
+            None => {
+                let full_module_name = self
+                    .imports
+                    .get(module_name)
+                    .ok_or_else(|| ParsingError::undefined_module(token, module_name))?;
+                Ok(ProcedureId::path(proc_name, full_module_name))
+            }
+            Some((root_name, _)) => {
+                if let Some(import_path) = self.imports.get(root_name) {
+                    return Err(ParsingError::ambiguous_module_path(
+                        token,
+                        module_name,
+                        import_path,
+                    ));
+                }
+                Ok(ProcedureId::path(proc_name, module_name))
+            }
+        }
     }
 }
 
-/// Parses a Token into a node instruction.
-fn parse_op_token(op: &Token) -> Result<Node, ParsingError> {
+// INSTRUCTION TABLE
+// ================================================================================================
+
+/// Names of all instructions recognized by [parse_op_token()], as well as of the control flow
+/// keywords. This table is used to suggest a valid instruction when parsing an unknown one, and
+/// is checked against [parse_op_token()] in tests.
+#[rustfmt::skip]
+pub(super) const INSTRUCTION_NAMES: [&str; 120] = [
+    // ----- control flow --------------------------------------------------------------------------
+    Token::IF, Token::ELSE, Token::WHILE, Token::REPEAT, Token::EXEC, Token::CALL, Token::SYSCALL,
+    Token::END,
+    // ----- field operations ----------------------------------------------------------------------
+    "assert", "assertz", "assert_eq", "assert_eqw", "add", "sub", "mul", "div", "neg", "inv", "pow2",
+    "exp", "not", "and", "or", "xor", "eq", "neq", "lt", "lte", "gt", "gte", "eqw",
+    // ----- u32 operations ------------------------------------------------------------------------
+    "u32test", "u32testw", "u32assert", "u32assertw", "u32cast", "u32split", "u32checked_add",
+    "u32wrapping_add", "u32overflowing_add", "u32overflowing_add3", "u32wrapping_add3",
+    "u32checked_sub", "u32wrapping_sub", "u32overflowing_sub", "u32checked_mul", "u32wrapping_mul",
+    "u32overflowing_mul", "u32overflowing_madd", "u32wrapping_madd", "u32checked_div",
+    "u32unchecked_div", "u32checked_mod", "u32unchecked_mod", "u32checked_divmod",
+    "u32unchecked_divmod", "u32checked_and", "u32checked_or", "u32checked_xor", "u32checked_not",
+    "u32checked_shr", "u32unchecked_shr", "u32checked_shl", "u32unchecked_shl", "u32checked_rotr",
+    "u32unchecked_rotr", "u32checked_rotl", "u32unchecked_rotl", "u32checked_eq", "u32checked_neq",
+    "u32checked_lt", "u32unchecked_lt", "u32checked_lte", "u32unchecked_lte", "u32checked_gt",
+    "u32unchecked_gt", "u32checked_gte", "u32unchecked_gte", "u32checked_min", "u32unchecked_min",
+    "u32checked_max", "u32unchecked_max",
+    // ----- stack manipulation --------------------------------------------------------------------
+    "drop", "dropw", "padw", "dup", "dupw", "swap", "swapw", "swapdw", "movup", "movupw", "movdn",
+    "movdnw", "cswap", "cswapw", "cdrop", "cdropw",
+    // ----- input / output operations -------------------------------------------------------------
+    "push", "sdepth", "locaddr", "caller", "mem_load", "loc_load", "mem_loadw", "loc_loadw",
+    "mem_store", "loc_store", "mem_storew", "loc_storew", "mem_stream", "adv_pipe", "adv_push",
+    "adv_loadw", "adv",
+    // ----- cryptographic operations --------------------------------------------------------------
+    "rphash", "rpperm", "mtree_get", "mtree_set", "mtree_cwm",
+];
+
+/// The maximum edit distance between an unknown instruction and a suggested instruction.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// Parses a Token into a node instruction; named constants referenced by `push` instructions are
+/// resolved via the provided constant map.
+fn parse_op_token(op: &Token, constants: &ConstantMap) -> Result<Node, ParsingError> {
     use Instruction::*;
 
     // based on the instruction, invoke the correct parser for the operation
@@ -358,6 +443,7 @@ fn parse_op_token(op: &Token) -> Result<Node, ParsingError> {
         "assert" => simple_instruction(op, Assert),
         "assertz" => simple_instruction(op, Assertz),
         "assert_eq" => simple_instruction(op, AssertEq),
+        "assert_eqw" => simple_instruction(op, AssertEqw),
 
         "add" => field_ops::parse_add(op),
         "sub" => field_ops::parse_sub(op),
@@ -475,7 +561,7 @@ fn parse_op_token(op: &Token) -> Result<Node, ParsingError> {
         "cdropw" => simple_instruction(op, CDropW),
 
         // ----- input / output operations --------------------------------------------------------
-        "push" => io_ops::parse_push(op),
+        "push" => io_ops::parse_push(op, constants),
 
         "sdepth" => simple_instruction(op, Sdepth),
         "locaddr" => io_ops::parse_locaddr(op),
@@ -497,7 +583,7 @@ fn parse_op_token(op: &Token) -> Result<Node, ParsingError> {
         "adv_pipe" => simple_instruction(op, AdvPipe),
 
         "adv_push" => io_ops::parse_adv_push(op),
-        "adv_loadw" => simple_instruction(op, AdvLoadW),
+        "adv_loadw" => io_ops::parse_adv_loadw(op),
 
         "adv" => io_ops::parse_adv_inject(op),
 
@@ -510,7 +596,10 @@ fn parse_op_token(op: &Token) -> Result<Node, ParsingError> {
         "mtree_cwm" => simple_instruction(op, MTreeCwm),
 
         // ----- catch all ------------------------------------------------------------------------
-        _ => Err(ParsingError::invalid_op(op)),
+        _ => Err(ParsingError::unknown_instruction(
+            op,
+            suggest_instruction(op.parts()[0]),
+        )),
     }
 }
 
@@ -527,3 +616,30 @@ fn simple_instruction(op: &Token, instruction: Instruction) -> Result<Node, Pars
         _ => Err(ParsingError::extra_param(op)),
     }
 }
+
+/// Returns the name of the known instruction closest to the specified name, if there is one
+/// within [MAX_SUGGESTION_DISTANCE] edits of it.
+fn suggest_instruction(name: &str) -> Option<&'static str> {
+    INSTRUCTION_NAMES
+        .iter()
+        .map(|&candidate| (edit_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= MAX_SUGGESTION_DISTANCE && distance < name.len())
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the Levenshtein distance between the two specified strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev_row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = Vec::with_capacity(b.len() + 1);
+        row.push(i + 1);
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = prev_row[j] + usize::from(a_char != b_char);
+            row.push(substitution.min(prev_row[j + 1] + 1).min(row[j] + 1));
+        }
+        prev_row = row;
+    }
+    prev_row[b.len()]
+}
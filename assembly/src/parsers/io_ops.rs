@@ -1,11 +1,12 @@
 use super::{
     parse_checked_param, parse_decimal_param, parse_element_param, parse_hex_param, parse_param,
-    Felt,
+    ConstantMap, Felt,
     Instruction::*,
     Node::{self, Instruction},
     ParsingError, Token, Vec,
 };
 use crate::{validate_operation, ADVICE_READ_LIMIT, MAX_PUSH_INPUTS};
+use vm_core::{AdviceSet, EnvValue, FieldElement};
 
 // CONSTANTS
 // ================================================================================================
@@ -14,19 +15,57 @@ use crate::{validate_operation, ADVICE_READ_LIMIT, MAX_PUSH_INPUTS};
 /// input is provided to `push` without period separators.
 const HEX_CHUNK_SIZE: usize = 16;
 
+/// The maximum total number of bits in a fixed-point format used with `push.fixed`. This ensures
+/// that any value in such a format is a valid field element.
+const MAX_FIXED_POINT_BITS: u32 = 63;
+
+/// The maximum number of fractional digits in a decimal value used with `push.fixed`.
+const MAX_FIXED_POINT_FRAC_DIGITS: usize = 19;
+
 // INSTRUCTION PARSERS
 // ================================================================================================
 
-/// Returns `PushConstants` instruction node.
+/// Returns `PushEnv` instruction node if the instruction is of the form `push.env.name`, or
+/// `PushConstants` instruction node otherwise.
+///
+/// For instructions of the form `push.mtree_root([a,b,...])`, a Merkle tree with leaves
+/// `[a, 0, 0, 0]`, `[b, 0, 0, 0]` etc. is built at parse time, and the `PushConstants` node
+/// pushes the root of this tree onto the stack.
+///
+/// For instructions of the form `push.fixed(value, Qm.n)`, the decimal value is converted at parse
+/// time into unsigned fixed-point representation with `m` integer and `n` fractional bits.
+///
+/// Any of the values pushed via `push.a.b.c` may be the name of a constant in the provided
+/// constant map (e.g., `push.NAME` or `push.1.NAME`), in which case the value of the constant is
+/// pushed.
 ///
 /// # Errors
 /// Returns an error if the instruction token has invalid values or inappropriate number of
-/// values.
-pub fn parse_push(op: &Token) -> Result<Node, ParsingError> {
+/// values, if the specified environment value does not exist, if a referenced constant is not
+/// defined, or if the number of Merkle tree leaves is not a power of two greater than one.
+pub fn parse_push(op: &Token, constants: &ConstantMap) -> Result<Node, ParsingError> {
     validate_operation!(op, "push", 1..MAX_PUSH_INPUTS);
 
-    let constants = parse_constants(op)?;
-    Ok(Instruction(PushConstants(constants)))
+    if op.parts()[1] == "env" {
+        validate_operation!(op, "push.env", 1);
+        let value =
+            EnvValue::from_name(op.parts()[2]).ok_or_else(|| ParsingError::invalid_param(op, 2))?;
+        return Ok(Instruction(PushEnv(value)));
+    }
+
+    if op.parts()[1].starts_with("fixed(") {
+        let value = parse_fixed(op)?;
+        return Ok(Instruction(PushConstants(vec![value])));
+    }
+
+    if op.parts()[1].starts_with("mtree_root(") {
+        validate_operation!(op, "push", 1);
+        let root = parse_mtree_root(op)?;
+        return Ok(Instruction(PushConstants(root)));
+    }
+
+    let values = parse_constants(op, constants)?;
+    Ok(Instruction(PushConstants(values)))
 }
 
 /// Returns `Locaddr` instruction node.
@@ -78,6 +117,23 @@ pub fn parse_adv_push(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `AdvLoadW` instruction node if no parameter is provided, or `AdvLoadWKey` instruction
+/// node if the instruction is of the form `adv_loadw.key`.
+///
+/// # Errors
+/// Returns an error if the instruction token has more than one parameter, or if the parameter is
+/// not `key`.
+pub fn parse_adv_loadw(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "adv_loadw");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Instruction(AdvLoadW)),
+        2 if op.parts()[1] == "key" => Ok(Instruction(AdvLoadWKey)),
+        2 => Err(ParsingError::invalid_param(op, 1)),
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `AdvU64Div`, `AdvKeyval`, or `AdvMem`  instruction node.
 ///
 /// # Errors
@@ -86,16 +142,16 @@ pub fn parse_adv_push(op: &Token) -> Result<Node, ParsingError> {
 /// - adv.mem.a.n has a + n > u32::MAX.
 pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
     debug_assert_eq!(op.parts()[0], "adv");
-    match op.parts()[1] {
-        "u64div" => {
+    match op.parts().get(1).copied() {
+        Some("u64div") => {
             validate_operation!(op, "adv.u64div", 0);
             Ok(Instruction(AdvU64Div))
         }
-        "keyval" => {
+        Some("keyval") => {
             validate_operation!(op, "adv.keyval", 0);
             Ok(Instruction(AdvKeyval))
         }
-        "mem" => {
+        Some("mem") => {
             validate_operation!(op, "adv.mem", 2);
             let start_addr = parse_param(op, 2)?;
             let num_words = parse_checked_param(op, 3, 1, u32::MAX - start_addr)?;
@@ -256,37 +312,151 @@ pub fn parse_loc_storew(op: &Token) -> Result<Node, ParsingError> {
 // HELPER FUNCTIONS
 // ================================================================================================
 
-fn parse_constants(op: &Token) -> Result<Vec<Felt>, ParsingError> {
-    let mut constants = Vec::new();
+fn parse_constants(op: &Token, constants: &ConstantMap) -> Result<Vec<Felt>, ParsingError> {
+    let mut values = Vec::new();
     let param_idx = 1;
     let param_count = op.num_parts() - param_idx;
 
     // for multiple input parameters, parse & push each one onto the stack in order, then return
     if param_count > 1 {
         for param_idx in param_idx..=param_count {
-            let value = parse_element_param(op, param_idx)?;
-            constants.push(value);
+            let value = match parse_named_constant(op, param_idx, constants)? {
+                Some(value) => value,
+                None => parse_element_param(op, param_idx)?,
+            };
+            values.push(value);
         }
-        return Ok(constants);
+        return Ok(values);
     }
 
-    // for a single input, there could be one value or there could be a series of many hexadecimal
-    // values without separators
+    // for a single input, there could be one value, a named constant, or a series of many
+    // hexadecimal values without separators
     let param_str = op.parts()[param_idx];
-    if let Some(param_str) = param_str.strip_prefix("0x") {
+    if let Some(value) = parse_named_constant(op, param_idx, constants)? {
+        values.push(value);
+    } else if let Some(param_str) = param_str.strip_prefix("0x") {
         // parse 1 or more hexadecimal values
-        let values = parse_hex_params(op, param_idx, param_str)?;
+        let hex_values = parse_hex_params(op, param_idx, param_str)?;
         // push each value onto the stack in order
-        for &value in values.iter() {
-            constants.push(value);
+        for &value in hex_values.iter() {
+            values.push(value);
         }
     } else {
         // parse 1 decimal value and push it onto the stack
         let value = parse_decimal_param(op, param_idx, param_str)?;
-        constants.push(value);
+        values.push(value);
+    }
+
+    Ok(values)
+}
+
+/// Returns the value of the named constant referenced by the specified parameter, or None if the
+/// parameter is not a constant name (i.e., it does not start with a letter or an underscore).
+///
+/// # Errors
+/// Returns an error if the parameter is a constant name but the constant is not defined.
+fn parse_named_constant(
+    op: &Token,
+    param_idx: usize,
+    constants: &ConstantMap,
+) -> Result<Option<Felt>, ParsingError> {
+    let name = op.parts()[param_idx];
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        return Ok(None);
+    }
+    match constants.get(name) {
+        Some(&value) => Ok(Some(value)),
+        None => Err(ParsingError::invalid_param_with_reason(
+            op,
+            param_idx,
+            &format!("constant '{name}' is not defined"),
+        )),
+    }
+}
+
+/// Parses the leaves of a `push.mtree_root([a,b,...])` instruction and returns the root of the
+/// Merkle tree built from these leaves.
+fn parse_mtree_root(op: &Token) -> Result<Vec<Felt>, ParsingError> {
+    let param_idx = 1;
+    let leaves_str = op.parts()[param_idx]
+        .strip_prefix("mtree_root([")
+        .and_then(|s| s.strip_suffix("])"))
+        .ok_or_else(|| ParsingError::invalid_param(op, param_idx))?;
+
+    let mut leaves = Vec::new();
+    for leaf_str in leaves_str.split(',') {
+        let value = parse_decimal_param(op, param_idx, leaf_str)?;
+        leaves.push([value, Felt::ZERO, Felt::ZERO, Felt::ZERO]);
     }
 
-    Ok(constants)
+    let tree = AdviceSet::new_merkle_tree(leaves)
+        .map_err(|_| ParsingError::invalid_param(op, param_idx))?;
+    Ok(tree.root().to_vec())
+}
+
+/// Parses a `push.fixed(value, Qm.n)` instruction and returns the specified decimal value in
+/// unsigned fixed-point representation with `m` integer bits and `n` fractional bits, rounded to
+/// the nearest representable value.
+///
+/// Since the value may contain periods, it is split into several token parts, and thus, all parts
+/// following the instruction name are parsed together.
+fn parse_fixed(op: &Token) -> Result<Felt, ParsingError> {
+    let param_idx = 1;
+    let param_str = op.parts()[param_idx..].join(".");
+    let (value_str, format_str) = param_str
+        .strip_prefix("fixed(")
+        .and_then(|s| s.strip_suffix(')'))
+        .and_then(|s| s.split_once(','))
+        .ok_or_else(|| ParsingError::invalid_param(op, param_idx))?;
+
+    // parse the format; the total number of bits must be small enough for all values to be valid
+    // field elements
+    let (int_bits, frac_bits) = format_str
+        .trim()
+        .strip_prefix('Q')
+        .and_then(|s| s.split_once('.'))
+        .and_then(|(m, n)| Some((parse_digits(m)?, parse_digits(n)?)))
+        .filter(|&(m, n)| {
+            m.checked_add(n)
+                .map_or(false, |bits| bits <= MAX_FIXED_POINT_BITS as u128)
+        })
+        .ok_or_else(|| {
+            ParsingError::invalid_param_with_reason(op, param_idx, "invalid fixed-point format")
+        })?;
+
+    // parse the integer and the fractional parts of the value
+    let value_str = value_str.trim();
+    let (int_str, frac_str) = value_str.split_once('.').unwrap_or((value_str, "0"));
+    let (int_value, frac_value) = parse_digits(int_str)
+        .zip(parse_digits(frac_str))
+        .filter(|_| frac_str.len() <= MAX_FIXED_POINT_FRAC_DIGITS)
+        .ok_or_else(|| {
+            ParsingError::invalid_param_with_reason(op, param_idx, "invalid decimal value")
+        })?;
+
+    // convert the value into the fixed-point representation; the fractional part is less than
+    // 10^19 < 2^64, and thus, this cannot overflow
+    let frac_scale = 10_u128.pow(frac_str.len() as u32);
+    let frac_value = ((frac_value << frac_bits) + frac_scale / 2) / frac_scale;
+    let value = int_value
+        .checked_shl(frac_bits as u32)
+        .filter(|_| int_value >> int_bits == 0)
+        .map(|value| value + frac_value)
+        .filter(|&value| value >> (int_bits + frac_bits) == 0)
+        .ok_or_else(|| {
+            ParsingError::invalid_param_with_reason(op, param_idx, "value overflows the format")
+        })?;
+
+    Ok(Felt::new(value as u64))
+}
+
+/// Parses a non-empty string of decimal digits, returning None if the string contains any other
+/// characters or if the value does not fit into u128.
+fn parse_digits(digits: &str) -> Option<u128> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
 }
 
 fn parse_hex_params(
@@ -1,6 +1,7 @@
 use super::{
-    errors::SerializationError, BTreeMap, Felt, ParsingError, ProcedureId, StarkField, String,
-    ToString, Token, TokenStream, Vec, MODULE_PATH_DELIM,
+    errors::SerializationError, BTreeMap, Felt, ParsingError, ProcedureId, SourceLocation,
+    StarkField, String, ToString, Token, TokenStream, Vec, MAX_BLOCK_NESTING_DEPTH,
+    MODULE_PATH_DELIM,
 };
 use core::{fmt::Display, ops::Deref};
 use serde::{ByteReader, ByteWriter, Deserializable, Serializable};
@@ -24,6 +25,9 @@ pub mod tests;
 // ================================================================================================
 type LocalProcMap = BTreeMap<String, (u16, ProcedureAst)>;
 
+/// A map of named constants which can be referenced by `push` instructions (e.g., `push.NAME`).
+pub(crate) type ConstantMap = BTreeMap<String, Felt>;
+
 // ABSTRACT SYNTAX TREE STRUCTS
 // ================================================================================================
 
@@ -70,6 +74,18 @@ impl ProgramAst {
     }
 }
 
+/// Source locations of the instructions of a Miden program.
+///
+/// Locations are listed in the order in which the instructions appear in the source code, which
+/// is also the order in which the instructions are compiled.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(crate) struct SourceMap {
+    /// Locations of the instructions of each local procedure, indexed by procedure index.
+    pub local_procs: Vec<Vec<SourceLocation>>,
+    /// Locations of the instructions of the program body.
+    pub body: Vec<SourceLocation>,
+}
+
 /// An abstract syntax tree (AST) of a Miden code module.
 ///
 /// A module AST consists of a list of procedure ASTs. These procedures could be local or exported.
@@ -230,11 +246,23 @@ impl Deserializable for ProcedureAst {
 /// Parses the provided source into a program AST. A program consist of a body and a set of
 /// internal (i.e., not exported) procedures.
 pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
+    parse_program_with_constants(source, &ConstantMap::new()).map(|(program, _)| program)
+}
+
+/// Parses the provided source into a program AST in the same way as [parse_program()], but also
+/// resolves references to the specified named constants in `push` instructions.
+///
+/// The AST is returned together with the source locations of the program's instructions.
+pub(crate) fn parse_program_with_constants(
+    source: &str,
+    constants: &ConstantMap,
+) -> Result<(ProgramAst, SourceMap), ParsingError> {
     let mut tokens = TokenStream::new(source)?;
     let imports = parse_imports(&mut tokens)?;
 
     let mut context = ParserContext {
         imports,
+        constants: constants.clone(),
         ..Default::default()
     };
 
@@ -250,7 +278,7 @@ pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
 
     let program_start = tokens.pos();
     // consume the 'begin' token
-    let header = tokens.read().expect("missing program header");
+    let header = read_current(&tokens)?;
     header.validate_begin()?;
     tokens.advance();
 
@@ -267,26 +295,29 @@ pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
     let beginning_node_count = body.len();
     while !end_of_nodes {
         let node_count = body.len();
-        context.parse_body(&mut tokens, &mut body, false)?;
+        context.parse_body(&mut tokens, &mut body, false, 0)?;
         end_of_nodes = body.len() == node_count;
     }
 
     // make sure at least one block has been read
     if body.len() == beginning_node_count {
-        let start_op = tokens.read_at(start_pos).expect("no start token");
-        return Err(ParsingError::empty_block(start_op));
+        return Err(error_at(&mut tokens, start_pos, ParsingError::empty_block));
     }
 
     // consume the 'end' token
     match tokens.read() {
-        None => Err(ParsingError::unmatched_begin(
-            tokens.read_at(program_start).expect("no begin token"),
+        None => Err(error_at(
+            &mut tokens,
+            program_start,
+            ParsingError::unmatched_begin,
         )),
         Some(token) => match token.parts()[0] {
             Token::END => token.validate_end(),
             Token::ELSE => Err(ParsingError::dangling_else(token)),
-            _ => Err(ParsingError::unmatched_begin(
-                tokens.read_at(program_start).expect("no begin token"),
+            _ => Err(error_at(
+                &mut tokens,
+                program_start,
+                ParsingError::unmatched_begin,
             )),
         },
     }?;
@@ -297,11 +328,15 @@ pub fn parse_program(source: &str) -> Result<ProgramAst, ParsingError> {
         return Err(ParsingError::dangling_ops_after_program(token));
     }
 
+    let source_map = SourceMap {
+        local_procs: context.proc_locations,
+        body: tokens.take_instruction_locations(),
+    };
     let local_procs = sort_procs_into_vec(context.local_procs);
 
     let program = ProgramAst { body, local_procs };
 
-    Ok(program)
+    Ok((program, source_map))
 }
 
 /// Parses the provided source into a module ST. A module consists of internal and exported
@@ -337,7 +372,10 @@ fn parse_imports(tokens: &mut TokenStream) -> Result<BTreeMap<String, String>, P
         match token.parts()[0] {
             Token::USE => {
                 let module_path = &token.parse_use()?;
-                let (_, short_name) = module_path.rsplit_once(MODULE_PATH_DELIM).unwrap();
+                let short_name = match module_path.rsplit_once(MODULE_PATH_DELIM) {
+                    Some((_, short_name)) if !short_name.is_empty() => short_name,
+                    _ => return Err(ParsingError::invalid_module_path(token, module_path)),
+                };
                 if imports.contains_key(short_name) {
                     return Err(ParsingError::duplicate_module_import(token, module_path));
                 }
@@ -357,6 +395,26 @@ fn parse_imports(tokens: &mut TokenStream) -> Result<BTreeMap<String, String>, P
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns the token at the current position of the token stream, or an error if all tokens of
+/// the stream have been read.
+fn read_current<'a>(tokens: &'a TokenStream) -> Result<&'a Token<'a>, ParsingError> {
+    tokens
+        .read()
+        .ok_or_else(|| ParsingError::unexpected_eof(tokens.pos()))
+}
+
+/// Builds an error for the token at the specified position of the token stream using the provided
+/// constructor; if there is no token at this position, an unexpected EOF error is returned.
+fn error_at<F>(tokens: &mut TokenStream, pos: usize, error: F) -> ParsingError
+where
+    F: FnOnce(&Token) -> ParsingError,
+{
+    match tokens.read_at(pos) {
+        Some(token) => error(token),
+        None => ParsingError::unexpected_eof(pos),
+    }
+}
+
 /// Sort a map of procedures into a vec, respecting the order set in the map
 fn sort_procs_into_vec(proc_map: LocalProcMap) -> Vec<ProcedureAst> {
     let mut procedures: Vec<_> = proc_map.into_values().collect();
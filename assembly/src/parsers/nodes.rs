@@ -1,5 +1,6 @@
 use super::{Felt, ProcedureId, String, ToString, Vec};
 use core::fmt;
+use vm_core::EnvValue;
 
 // NODES
 // ================================================================================================
@@ -20,6 +21,7 @@ pub enum Node {
 pub enum Instruction {
     Assert,
     AssertEq,
+    AssertEqw,
     Assertz,
     Add,
     AddImm(Felt),
@@ -210,6 +212,7 @@ pub enum Instruction {
 
     // ----- input / output operations --------------------------------------------------------
     PushConstants(Vec<Felt>),
+    PushEnv(EnvValue),
     Locaddr(u16),
     Sdepth,
     Caller,
@@ -233,6 +236,7 @@ pub enum Instruction {
 
     AdvPush(u8),
     AdvLoadW,
+    AdvLoadWKey,
 
     AdvU64Div,
     AdvKeyval,
@@ -258,6 +262,7 @@ impl fmt::Display for Instruction {
         match self {
             Self::Assert => write!(f, "assert"),
             Self::AssertEq => write!(f, "assert_eq"),
+            Self::AssertEqw => write!(f, "assert_eqw"),
             Self::Assertz => write!(f, "assertz"),
             Self::Add => write!(f, "add"),
             Self::AddImm(value) => write!(f, "add.{value}"),
@@ -455,6 +460,7 @@ impl fmt::Display for Instruction {
                 }
                 write!(f, "{values_string}")
             }
+            Self::PushEnv(value) => write!(f, "push.env.{value}"),
             Self::Locaddr(value) => write!(f, "locaddr.{value}"),
             Self::Sdepth => write!(f, "sdepth"),
             Self::Caller => write!(f, "caller"),
@@ -478,6 +484,7 @@ impl fmt::Display for Instruction {
 
             Self::AdvPush(value) => write!(f, "adv_push.{value}"),
             Self::AdvLoadW => write!(f, "adv_loadw"),
+            Self::AdvLoadWKey => write!(f, "adv_loadw.key"),
 
             Self::AdvU64Div => write!(f, "adv.u64div"),
             Self::AdvKeyval => write!(f, "adv.keyval"),
@@ -3,7 +3,7 @@ use super::{
     OpCode, IF_ELSE_OPCODE, REPEAT_OPCODE, WHILE_OPCODE,
 };
 use crate::{errors::SerializationError, ProcedureId};
-use vm_core::{utils::collections::Vec, utils::string::String, Felt};
+use vm_core::{utils::collections::Vec, utils::string::String, EnvValue, Felt};
 
 // BYTE READER IMPLEMENTATION
 // ================================================================================================
@@ -174,6 +174,7 @@ impl Deserializable for Instruction {
         match opcode {
             OpCode::Assert => Ok(Instruction::Assert),
             OpCode::AssertEq => Ok(Instruction::AssertEq),
+            OpCode::AssertEqw => Ok(Instruction::AssertEqw),
             OpCode::Assertz => Ok(Instruction::Assertz),
             OpCode::Add => Ok(Instruction::Add),
             OpCode::AddImm => Ok(Instruction::AddImm(bytes.read_felt()?)),
@@ -379,6 +380,11 @@ impl Deserializable for Instruction {
                 }
                 Ok(Instruction::PushConstants(constants))
             }
+            OpCode::PushEnv => match bytes.read_u8()? {
+                0 => Ok(Instruction::PushEnv(EnvValue::BlockNumber)),
+                1 => Ok(Instruction::PushEnv(EnvValue::Timestamp)),
+                _ => Err(SerializationError::InvalidOpCode),
+            },
             OpCode::Locaddr => Ok(Instruction::Locaddr(bytes.read_u16()?)),
             OpCode::Sdepth => Ok(Instruction::Sdepth),
             OpCode::Caller => Ok(Instruction::Caller),
@@ -408,6 +414,7 @@ impl Deserializable for Instruction {
             }
             OpCode::AdvPush => Ok(Instruction::AdvPush(bytes.read_u8()?)),
             OpCode::AdvLoadW => Ok(Instruction::AdvLoadW),
+            OpCode::AdvLoadWKey => Ok(Instruction::AdvLoadWKey),
 
             // ----- cryptographic operations ---------------------------------------------------------
             OpCode::RPHash => Ok(Instruction::RpHash),
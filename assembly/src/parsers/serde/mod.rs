@@ -249,4 +249,11 @@ pub enum OpCode {
     CallLocal = 215,
     CallImported = 216,
     SysCall = 217,
+
+    // ----- input / output operations (cont.) ------------------------------------------------
+    PushEnv = 218,
+    AdvLoadWKey = 219,
+
+    // ----- field operations (cont.) ---------------------------------------------------------
+    AssertEqw = 220,
 }
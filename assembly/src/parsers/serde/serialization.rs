@@ -5,6 +5,7 @@ use super::{
 use crate::{
     errors::SerializationError, Felt, ProcedureId, StarkField, String, Vec, MAX_PROC_NAME_LEN,
 };
+use vm_core::EnvValue;
 
 // BYTE WRITER IMPLEMENTATION
 // ================================================================================================
@@ -136,6 +137,7 @@ impl Serializable for Instruction {
         match self {
             Self::Assert => target.write_opcode(OpCode::Assert),
             Self::AssertEq => target.write_opcode(OpCode::AssertEq),
+            Self::AssertEqw => target.write_opcode(OpCode::AssertEqw),
             Self::Assertz => target.write_opcode(OpCode::Assertz),
             Self::Add => target.write_opcode(OpCode::Add),
             Self::AddImm(v) => {
@@ -429,6 +431,13 @@ impl Serializable for Instruction {
                 target.write_u8(values.len() as u8);
                 values.iter().for_each(|&v| target.write_felt(v));
             }
+            Self::PushEnv(value) => {
+                target.write_opcode(OpCode::PushEnv);
+                match value {
+                    EnvValue::BlockNumber => target.write_u8(0),
+                    EnvValue::Timestamp => target.write_u8(1),
+                }
+            }
             Self::Locaddr(v) => {
                 target.write_opcode(OpCode::Locaddr);
                 target.write_u16(*v);
@@ -488,6 +497,7 @@ impl Serializable for Instruction {
                 target.write_u8(*v);
             }
             Self::AdvLoadW => target.write_opcode(OpCode::AdvLoadW),
+            Self::AdvLoadWKey => target.write_opcode(OpCode::AdvLoadWKey),
 
             // ----- cryptographic operations ---------------------------------------------------------
             Self::RpHash => target.write_opcode(OpCode::RPHash),
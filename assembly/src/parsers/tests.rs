@@ -1,8 +1,9 @@
 use super::{
-    parse_module, parse_program, BTreeMap, Felt, Instruction, LocalProcMap, ModuleAst, Node,
-    ProcedureAst, ProcedureId, ProgramAst,
+    context::INSTRUCTION_NAMES, parse_module, parse_program, BTreeMap, Felt, Instruction,
+    LocalProcMap, ModuleAst, Node, ProcedureAst, ProcedureId, ProgramAst, StarkField,
 };
-use crate::{ONE, ZERO};
+use crate::{AssemblyError, ONE, ZERO};
+use vm_core::EnvValue;
 
 // UNIT TESTS
 // ================================================================================================
@@ -151,6 +152,17 @@ fn test_ast_parsing_adv_ops() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_adv_loadw_key() {
+    let source = "begin adv_loadw.key end";
+    let nodes: Vec<Node> = vec![Node::Instruction(Instruction::AdvLoadWKey)];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    assert!(parse_program("begin adv_loadw.value end").is_err());
+    assert!(parse_program("begin adv_loadw.key.1 end").is_err());
+}
+
 #[test]
 fn test_ast_parsing_adv_injection() {
     let source = "begin adv.u64div adv.keyval adv.mem.1.1 end";
@@ -163,6 +175,52 @@ fn test_ast_parsing_adv_injection() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_env_values() {
+    let source = "begin push.env.block_number push.env.timestamp end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Instruction::PushEnv(EnvValue::BlockNumber)),
+        Node::Instruction(Instruction::PushEnv(EnvValue::Timestamp)),
+    ];
+
+    assert_program_output(source, BTreeMap::new(), nodes);
+
+    // unknown environment values are rejected
+    assert!(parse_program("begin push.env.chain_id end").is_err());
+    assert!(parse_program("begin push.env end").is_err());
+}
+
+#[test]
+fn test_ast_parsing_immediates_over_modulus() {
+    // immediate values are never reduced modulo the field; values which are not smaller than the
+    // modulus are rejected instead
+    let sources = [
+        "begin push.18446744069414584321 end",
+        "begin push.0xffffffff00000001 end",
+        "begin push.1.18446744069414584321 end",
+        "begin push.0x0000000000000000ffffffff00000001 end",
+        "begin add.18446744069414584321 end",
+        "begin eq.0xffffffffffffffff end",
+    ];
+    for source in sources {
+        let err = parse_program(source).unwrap_err();
+        assert!(
+            err.message().ends_with(&format!(
+                "parameter value must be smaller than {}",
+                Felt::MODULUS
+            )),
+            "unexpected error for {source:?}: {err}"
+        );
+    }
+
+    // the largest field element is accepted as is
+    let source = "begin push.18446744069414584320 end";
+    let nodes = vec![Node::Instruction(Instruction::PushConstants(vec![
+        Felt::new(Felt::MODULUS - 1),
+    ]))];
+    assert_program_output(source, BTreeMap::new(), nodes);
+}
+
 #[test]
 fn test_ast_parsing_use() {
     let source = "\
@@ -430,6 +488,39 @@ fn test_ast_parsing_module_docs_fail() {
     parse_module(source).expect_err("comment message should not have empty lines");
 }
 
+#[test]
+fn test_instruction_names() {
+    // every listed name is recognized by the parser
+    for name in INSTRUCTION_NAMES {
+        if let Err(err) = parse_program(&format!("begin {name} end")) {
+            assert!(
+                !matches!(
+                    AssemblyError::from(err),
+                    AssemblyError::UnknownInstruction(..)
+                ),
+                "listed instruction '{name}' is not recognized by the parser"
+            );
+        }
+    }
+
+    // every instruction recognized by the parser is listed
+    let source = include_str!("context.rs");
+    let parser = &source[source.find("fn parse_op_token").unwrap()..];
+    for line in parser.lines() {
+        let name = line
+            .trim_start()
+            .strip_prefix('"')
+            .and_then(|line| line.split_once("\" =>"))
+            .map(|(name, _)| name);
+        if let Some(name) = name {
+            assert!(
+                INSTRUCTION_NAMES.contains(&name),
+                "instruction '{name}' recognized by the parser is not listed"
+            );
+        }
+    }
+}
+
 // SERIALIZATION AND DESERIALIZATION TESTS
 // ================================================================================================
 
@@ -443,6 +534,16 @@ fn test_ast_program_serde_simple() {
     assert_eq!(program, program_deserialized);
 }
 
+#[test]
+fn test_ast_program_serde_env_values() {
+    let source = "begin push.env.block_number push.env.timestamp end";
+    let program = parse_program(source).unwrap();
+    let program_serialized = program.to_bytes();
+    let program_deserialized = ProgramAst::from_bytes(program_serialized.as_slice()).unwrap();
+
+    assert_eq!(program, program_deserialized);
+}
+
 #[test]
 fn test_ast_program_serde_local_procs() {
     let source = "\
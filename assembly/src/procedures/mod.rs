@@ -14,6 +14,7 @@ pub struct Procedure {
     num_locals: u32,
     code_root: CodeBlock,
     callset: CallSet,
+    library_procs: BTreeSet<String>,
 }
 
 impl Procedure {
@@ -27,6 +28,7 @@ impl Procedure {
         num_locals: u32,
         code_root: CodeBlock,
         callset: CallSet,
+        library_procs: BTreeSet<String>,
     ) -> Self {
         Procedure {
             id,
@@ -35,6 +37,7 @@ impl Procedure {
             num_locals,
             code_root,
             callset,
+            library_procs,
         }
     }
 
@@ -72,6 +75,13 @@ impl Procedure {
     pub fn callset(&self) -> &CallSet {
         &self.callset
     }
+
+    /// Returns fully qualified paths of all library procedures (i.e., procedures exported from
+    /// library modules) which may be executed during the execution of this procedure. If this
+    /// procedure is exported from a library module, its own path is included as well.
+    pub fn library_procs(&self) -> &BTreeSet<String> {
+        &self.library_procs
+    }
 }
 
 // PROCEDURE ID
@@ -1,4 +1,11 @@
-use crate::{parse_module, Assembler, ModuleAst, ModuleProvider, NamedModuleAst, ProcedureId};
+use crate::{
+    parse_module,
+    parsers::{Instruction, Node},
+    Assembler, AssemblyError, CodeBlock, InstructionSetVersion, ModuleAst, ModuleProvider,
+    NamedModuleAst, ProcedureAst, ProcedureId, Program, SourceLocation, String, ToString, Vec,
+    MAX_BLOCK_NESTING_DEPTH, ONE,
+};
+use vm_core::Decorator;
 
 // SIMPLE PROGRAMS
 // ================================================================================================
@@ -173,6 +180,219 @@ fn program_with_exported_procedure() {
     assert!(assembler.compile(source).is_err());
 }
 
+#[test]
+fn program_procedures_ordering() {
+    let source = "\
+        proc.foo push.3 push.7 mul end \
+        proc.bar push.5 call.foo add end \
+        proc.baz push.9 call.bar end \
+        begin push.2 call.baz call.foo push.4 call.bar end";
+
+    let program1 = super::Assembler::default().compile(source).unwrap();
+    let program2 = super::Assembler::default().compile(source).unwrap();
+
+    let procedures = program1.procedures();
+    assert_eq!(3, procedures.len());
+    assert_eq!(procedures, program2.procedures());
+
+    // procedures must be sorted by hash
+    let proc_bytes = procedures
+        .iter()
+        .map(|&hash| hash.into())
+        .collect::<Vec<[u8; 32]>>();
+    let mut sorted_bytes = proc_bytes.clone();
+    sorted_bytes.sort();
+    assert_eq!(sorted_bytes, proc_bytes);
+
+    // call graph starts with the program root followed by procedures in the same order
+    let call_graph = program1.call_graph();
+    assert_eq!(call_graph, program2.call_graph());
+    assert_eq!(program1.hash(), call_graph[0].0);
+    assert_eq!(3, call_graph[0].1.len());
+    let callers = call_graph.iter().skip(1).map(|(caller, _)| *caller);
+    assert!(callers.eq(procedures.iter().copied()));
+}
+
+#[test]
+fn program_procedure_names() {
+    let source = "\
+        proc.foo push.3 push.7 mul end \
+        proc.bar push.5 call.foo add end \
+        proc.baz push.9 exec.foo end \
+        begin push.2 call.bar call.foo exec.baz end";
+
+    // names are retained with debug symbols
+    let program = super::Assembler::default()
+        .with_debug_symbols(true)
+        .compile(source)
+        .unwrap();
+    let names = program
+        .procedures()
+        .into_iter()
+        .map(|hash| program.procedure_name(hash))
+        .collect::<Vec<_>>();
+    assert_eq!(2, names.len());
+    assert!(names.contains(&Some("foo")));
+    assert!(names.contains(&Some("bar")));
+
+    // names are absent without debug symbols, but the hash of the program does not change
+    let program_no_symbols = super::Assembler::default().compile(source).unwrap();
+    assert_eq!(program.hash(), program_no_symbols.hash());
+    for hash in program_no_symbols.procedures() {
+        assert_eq!(None, program_no_symbols.procedure_name(hash));
+    }
+}
+
+#[test]
+fn program_procedure_hash() {
+    let source = "proc.foo push.3 push.7 mul end begin call.foo end";
+    let program = super::Assembler::default()
+        .with_debug_symbols(true)
+        .compile(source)
+        .unwrap();
+
+    // the hash of the procedure is the hash its callers reference
+    let foo_hash = program.procedure_hash("foo").unwrap();
+    match program.root() {
+        CodeBlock::Call(call) => assert_eq!(call.fn_hash(), foo_hash),
+        block => panic!("expected a call block, but got {}", block),
+    }
+    assert_eq!(Some("foo"), program.procedure_name(foo_hash));
+
+    // the hash of the procedure is the hash of its body
+    let body = super::Assembler::default()
+        .compile("begin push.3 push.7 mul end")
+        .unwrap();
+    assert_eq!(body.hash(), foo_hash);
+
+    // hashes are known without debug symbols as well, and unknown names yield no hash
+    assert_eq!(None, program.procedure_hash("bar"));
+    let program_no_symbols = super::Assembler::default().compile(source).unwrap();
+    assert_eq!(Some(foo_hash), program_no_symbols.procedure_hash("foo"));
+
+    // hashes are known for procedures invoked via `exec` and for procedures which are not invoked
+    let source = "proc.foo push.3 push.7 mul end proc.bar push.1 end begin exec.foo end";
+    let program = super::Assembler::default().compile(source).unwrap();
+    assert!(program.procedures().is_empty());
+    assert_eq!(Some(foo_hash), program.procedure_hash("foo"));
+    let bar_hash = super::Assembler::default()
+        .compile("begin push.1 end")
+        .unwrap()
+        .hash();
+    assert_eq!(Some(bar_hash), program.procedure_hash("bar"));
+}
+
+#[test]
+fn program_with_optimizations() {
+    let assembler = super::Assembler::default().with_optimizations(true);
+
+    // cancelling operations are removed, including pairs which cancel out after removal of the
+    // pairs between them
+    let source = "begin push.1 swap push.2 drop swap add if.true movup.3 movdn.3 end end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            join \
+                span pad incr add end \
+                if.true span noop end else span noop end end \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // the same program compiled without optimizations has a different hash
+    let unoptimized = super::Assembler::default().compile(source).unwrap();
+    assert_ne!(program.hash(), unoptimized.hash());
+
+    // operations with decorators attached to them are not removed
+    let source = "begin push.7 adv.u64div drop end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span push(7) drop end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // in debug mode, all operations have decorators attached to them
+    let program = super::Assembler::default()
+        .with_optimizations(true)
+        .with_debug_mode(true)
+        .compile("begin push.7 drop end")
+        .unwrap();
+    let expected = "\
+        begin \
+            span push(7) drop end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+#[test]
+fn program_source_locations() {
+    fn collect_locations(
+        block: &CodeBlock,
+        program: &Program,
+        locations: &mut Vec<(String, SourceLocation)>,
+    ) {
+        match block {
+            CodeBlock::Span(span) => {
+                for (_, decorator) in span.decorators() {
+                    if let Decorator::AsmOp(asmop) = decorator {
+                        locations.push((asmop.op().clone(), asmop.location().unwrap()));
+                    }
+                }
+            }
+            CodeBlock::Join(join) => {
+                collect_locations(join.first(), program, locations);
+                collect_locations(join.second(), program, locations);
+            }
+            CodeBlock::Split(split) => {
+                collect_locations(split.on_true(), program, locations);
+                collect_locations(split.on_false(), program, locations);
+            }
+            CodeBlock::Loop(block) => collect_locations(block.body(), program, locations),
+            CodeBlock::Call(call) => {
+                let callee = program.cb_table().get(call.fn_hash()).unwrap();
+                collect_locations(callee, program, locations);
+            }
+            CodeBlock::Proxy(_) => (),
+        }
+    }
+
+    let source = [
+        "proc.foo",
+        "    push.1 add",
+        "end",
+        "begin",
+        "    push.2",
+        "    if.true",
+        "        call.foo",
+        "    else",
+        "        repeat.2 push.3 drop end",
+        "    end",
+        "end",
+    ]
+    .join("\n");
+
+    // in debug mode, the location of every instruction is recorded in its decorator, including
+    // instructions of local procedures and of repeated blocks
+    let program = Assembler::default()
+        .with_debug_mode(true)
+        .compile(&source)
+        .unwrap();
+    let mut locations = Vec::new();
+    collect_locations(program.root(), &program, &mut locations);
+    let expected = [
+        ("push.2", 5, 5),
+        ("push.1", 2, 5),
+        ("add", 2, 12),
+        ("push.3", 9, 18),
+        ("drop", 9, 25),
+        ("push.3", 9, 18),
+        ("drop", 9, 25),
+    ]
+    .map(|(op, line, column)| (op.to_string(), SourceLocation::new(line, column)));
+    assert_eq!(expected.as_slice(), locations.as_slice());
+}
+
 // IMPORTS
 // ================================================================================================
 
@@ -263,6 +483,174 @@ fn program_with_import_errors() {
             exec.u256::foo \
         end";
     assert!(assembler.compile(source).is_err());
+
+    // --- module not imported --------------------------------------------------------------------
+    let assembler = super::Assembler::default();
+    let source = "\
+        begin \
+            push.4 push.3 \
+            exec.u256::iszero_unsafe \
+        end";
+    let err = assembler.compile(source).unwrap_err();
+    assert!(err.to_string().contains("undefined module: u256"));
+
+    // --- full path conflicting with an import ---------------------------------------------------
+    let assembler = super::Assembler::default();
+    let source = "\
+        use.std::math
+        begin \
+            push.4 push.3 \
+            exec.math::u64::wrapping_add \
+        end";
+    let err = assembler.compile(source).unwrap_err();
+    assert!(err.to_string().contains("ambiguous module path math::u64"));
+}
+
+// CONSTANTS
+// ================================================================================================
+
+#[test]
+fn program_with_constants_file() {
+    let path = std::env::temp_dir().join(format!("miden_constants_{}.txt", std::process::id()));
+    std::fs::write(
+        &path,
+        "# lookup table\nTABLE_BASE = 1000\n\nTABLE_MASK=0xff\n_SIZE=16\n",
+    )
+    .unwrap();
+    let assembler = Assembler::new().with_constants_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    let assembler = assembler.unwrap();
+
+    let source = "begin push.TABLE_BASE push.1.TABLE_MASK.3 push._SIZE end";
+    let program = assembler.compile(source).unwrap();
+    let expected = "\
+        begin \
+            span push(1000) pad incr push(255) push(3) push(16) end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // referencing an undefined constant is an error
+    let result = assembler.compile("begin push.TABLE_SIZE end");
+    assert!(result.is_err());
+
+    // reading a file which does not exist is an error
+    let result = Assembler::new().with_constants_file(path);
+    assert!(matches!(
+        result,
+        Err(AssemblyError::ConstantsFileNotReadable(..))
+    ));
+}
+
+#[test]
+fn invalid_constants() {
+    let test_cases = [
+        ("A=1\nB\n", 2, "expected definition of the form NAME=value"),
+        ("A=1\n\n1A=2", 3, "invalid constant name '1A'"),
+        ("A.B=1", 1, "invalid constant name 'A.B'"),
+        (
+            "# comment\nA=abc",
+            2,
+            "value 'abc' of constant 'A' is not a valid field element",
+        ),
+        (
+            "A=18446744069414584321",
+            1,
+            "value '18446744069414584321' of constant 'A' is not a valid field element",
+        ),
+        ("A=1\nA=2", 2, "constant 'A' is already defined"),
+    ];
+    for (source, line, reason) in test_cases {
+        let result = Assembler::new().with_constants(source);
+        assert!(
+            matches!(result, Err(ref err) if *err == AssemblyError::invalid_constant(line, reason)),
+            "unexpected result for {source:?}"
+        );
+    }
+
+    // constants defined via multiple sources must not clash
+    let result = Assembler::new()
+        .with_constants("A=1")
+        .and_then(|assembler| assembler.with_constants("B=2\nA=3"));
+    assert!(matches!(result, Err(AssemblyError::InvalidConstant(2, _))));
+}
+
+// PROGRAM METRICS
+// ================================================================================================
+
+#[test]
+fn program_metrics_static_addresses() {
+    const MODULE: &str = "dummy::mem";
+    const PROCEDURES: &str = r#"
+        export.store_high
+            mem_storew.40
+        end"#;
+
+    struct DummyProvider {
+        module: ModuleAst,
+    }
+
+    impl ModuleProvider for DummyProvider {
+        fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+            (id == &ProcedureId::from_name("store_high", MODULE))
+                .then_some(NamedModuleAst::new(MODULE, &self.module))
+        }
+    }
+
+    let module = parse_module(PROCEDURES).unwrap();
+    let assembler = Assembler::new().with_module_provider(DummyProvider { module });
+
+    // addresses referenced from the program body, local and imported procedures are included;
+    // procedure locals are not
+    let source = format!(
+        r#"
+        use.{MODULE}
+        proc.foo.2
+            loc_store.1
+            mem_load.17
+        end
+        begin
+            push.1 mem_store.3
+            if.true
+                mem_loadw.25
+            else
+                exec.foo
+            end
+            exec.mem::store_high
+        end"#
+    );
+    let metrics = assembler.analyze(&source).unwrap();
+    assert_eq!(Some(40), metrics.max_static_mem_addr());
+    assert!(!metrics.has_dynamic_mem_access());
+
+    // the last address read by the adv.mem injector is included
+    let metrics = assembler.analyze("begin adv.mem.50.10 end").unwrap();
+    assert_eq!(Some(59), metrics.max_static_mem_addr());
+
+    // programs which do not access memory have no static addresses
+    let metrics = assembler.analyze("begin push.1 push.2 add end").unwrap();
+    assert_eq!(None, metrics.max_static_mem_addr());
+    assert!(!metrics.has_dynamic_mem_access());
+}
+
+#[test]
+fn program_metrics_dynamic_addresses() {
+    let assembler = Assembler::new();
+    let metrics = assembler
+        .analyze("begin push.7 mem_store.12 push.3 mem_load end")
+        .unwrap();
+    assert_eq!(Some(12), metrics.max_static_mem_addr());
+    assert!(metrics.has_dynamic_mem_access());
+
+    // dynamic accesses within procedures are detected as well
+    let metrics = assembler
+        .analyze("proc.foo mem_stream end begin exec.foo end")
+        .unwrap();
+    assert_eq!(None, metrics.max_static_mem_addr());
+    assert!(metrics.has_dynamic_mem_access());
+
+    // programs which import unknown procedures cannot be analyzed
+    let result = assembler.analyze("use.dummy::mem begin exec.mem::foo end");
+    assert!(result.is_err());
 }
 
 // COMMENTS
@@ -388,6 +776,146 @@ fn invalid_program() {
     if let Err(error) = program {
         assert_eq!(error.to_string(), "dangling instructions after program end");
     }
+
+    let source = "begin adv end";
+    let program = assembler.compile(source);
+    assert!(program.is_err());
+    if let Err(error) = program {
+        assert_eq!(error.to_string(), "instruction 'adv' is invalid");
+    }
+}
+
+#[test]
+fn unknown_instruction() {
+    let assembler = super::Assembler::default();
+
+    // a near-miss typo produces a suggestion
+    let source = "begin pushh.1 end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error,
+        AssemblyError::unknown_instruction("pushh", Some("push"))
+    );
+    assert_eq!(
+        error.to_string(),
+        "unknown instruction 'pushh'; did you mean 'push'?"
+    );
+
+    let source = "begin push.1 push.2 u32checkd_add end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown instruction 'u32checkd_add'; did you mean 'u32checked_add'?"
+    );
+
+    // no suggestion is made for instructions which are not close to any known instruction
+    let source = "begin frobnicate end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(error.to_string(), "unknown instruction 'frobnicate'");
+}
+
+#[test]
+fn target_version() {
+    let source = "begin push.1 push.env.timestamp add end";
+
+    // instructions added after the target version are rejected
+    let assembler = Assembler::default().with_target_version(InstructionSetVersion::V0_3);
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "instruction 'push.env' requires instruction set version 0.4, but the target version \
+        is 0.3"
+    );
+    assert_eq!(
+        error.to_string(),
+        assembler.analyze(source).unwrap_err().to_string()
+    );
+
+    // all instructions added after the initial version are rejected when targeting it
+    for (instruction, name) in [
+        ("push.env.timestamp", "push.env"),
+        ("adv_loadw.key", "adv_loadw.key"),
+        ("assert_eqw", "assert_eqw"),
+    ] {
+        let source = format!("begin {instruction} end");
+        assert_eq!(
+            Some(name),
+            assembler
+                .compile(&source)
+                .unwrap_err()
+                .to_string()
+                .split('\'')
+                .nth(1)
+        );
+        assert!(Assembler::default().compile(&source).is_ok());
+    }
+
+    // instructions available in the target version are accepted; this includes the forms of the
+    // push instruction which are resolved into plain push instructions at parse time
+    let source_v0_3 = "begin push.1 push.98304 add end";
+    let program = assembler.compile(source_v0_3).unwrap();
+    let source_fixed = "begin push.1 push.fixed(1.5, Q16.16) add end";
+    assert_eq!(
+        program.hash(),
+        assembler.compile(source_fixed).unwrap().hash()
+    );
+    assert!(assembler
+        .compile("begin push.mtree_root([1,2]) end")
+        .is_ok());
+
+    // the latest version is targeted by default
+    assert!(Assembler::default().compile(source).is_ok());
+}
+
+#[test]
+fn target_version_modules() {
+    const MODULE: &str = "dummy::math";
+    const PROCEDURES: &str = r#"
+        export.foo
+            assert_eqw
+        end"#;
+
+    struct DummyProvider {
+        module: ModuleAst,
+    }
+
+    impl ModuleProvider for DummyProvider {
+        fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+            (id == &ProcedureId::from_name("foo", MODULE))
+                .then_some(NamedModuleAst::new(MODULE, &self.module))
+        }
+    }
+
+    let expected = "instruction 'assert_eqw' requires instruction set version 0.4, but the target \
+        version is 0.3";
+
+    // instructions of the procedures imported from the module provider are checked
+    let source = format!("use.{MODULE} begin exec.math::foo end");
+    let provider = DummyProvider {
+        module: parse_module(PROCEDURES).unwrap(),
+    };
+    let assembler = Assembler::default()
+        .with_target_version(InstructionSetVersion::V0_3)
+        .with_module_provider(provider);
+    let error = assembler.compile(&source).unwrap_err();
+    assert_eq!(expected, error.to_string());
+    let error = assembler.analyze(&source).unwrap_err();
+    assert_eq!(expected, error.to_string());
+
+    let provider = DummyProvider {
+        module: parse_module(PROCEDURES).unwrap(),
+    };
+    let assembler = Assembler::default().with_module_provider(provider);
+    assert!(assembler.compile(&source).is_ok());
+
+    // instructions of the kernel are checked
+    let error = Assembler::default()
+        .with_target_version(InstructionSetVersion::V0_3)
+        .with_kernel(PROCEDURES)
+        .err()
+        .unwrap();
+    assert_eq!(expected, error.to_string());
+    assert!(Assembler::default().with_kernel(PROCEDURES).is_ok());
 }
 
 #[test]
@@ -533,3 +1061,128 @@ fn invalid_while() {
         assert_eq!(error.to_string(), "while without matching end");
     }
 }
+
+#[test]
+fn pathological_programs() {
+    let assembler = super::Assembler::default();
+
+    let source = "use.std begin push.1 end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(error.to_string(), "invalid module import path: std");
+
+    let source = "begin loc_store.0 end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(error, AssemblyError::no_proc_locals(0));
+
+    let source = "begin exec.foo #! doc end";
+    assert!(assembler.compile(source).is_err());
+
+    let source = "proc.foo add end begin exec.bar end";
+    let error = assembler.compile(source).unwrap_err();
+    assert_eq!(error.to_string(), "undefined procedure: bar");
+
+    // empty blocks and decorators which are not followed by any operations are still compiled
+    assert!(assembler.compile("begin repeat.0 push.1 end end").is_ok());
+    assert!(assembler.compile("begin push.1 adv.u64div end").is_ok());
+    assert!(assembler.compile("begin adv.u64div end").is_ok());
+
+    // truncated sources result in errors rather than panics
+    let source = "use.std::math::u64 proc.foo.1 if.true exec.u64::add else loc_store.0 end end \
+        begin repeat.2 while.true call.foo end end exec.foo end";
+    let words: Vec<&str> = source.split_whitespace().collect();
+    for len in 0..words.len() {
+        assert!(assembler.compile(words[..len].join(" ")).is_err());
+    }
+
+    // control flow blocks can be nested up to the maximum depth
+    let nested = |depth: usize| {
+        let body = format!(
+            "{}push.1{}",
+            "while.true ".repeat(depth),
+            " end".repeat(depth)
+        );
+        format!("begin {body} end")
+    };
+    assert!(assembler.compile(nested(MAX_BLOCK_NESTING_DEPTH)).is_ok());
+    let error = assembler
+        .compile(nested(MAX_BLOCK_NESTING_DEPTH + 1))
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!(
+            "control flow blocks cannot be nested more than {MAX_BLOCK_NESTING_DEPTH} levels deep"
+        )
+    );
+
+    // the nesting depth is also limited for ASTs which were not produced by the parser
+    let mut body = vec![Node::Instruction(Instruction::PushConstants(vec![ONE]))];
+    for _ in 0..=MAX_BLOCK_NESTING_DEPTH {
+        body = vec![Node::While(body)];
+    }
+    let kernel = ModuleAst {
+        local_procs: vec![ProcedureAst {
+            name: "foo".to_string(),
+            docs: None,
+            num_locals: 0,
+            is_export: true,
+            body,
+        }],
+    };
+    let error = Assembler::new().with_kernel_module(&kernel).err().unwrap();
+    assert_eq!(
+        error,
+        AssemblyError::block_nesting_too_deep(MAX_BLOCK_NESTING_DEPTH)
+    );
+}
+
+#[test]
+fn malformed_modules() {
+    const LIB_PATH: &str = "dummy::lib";
+    const UTILS_PATH: &str = "dummy::utils";
+
+    struct DummyProvider {
+        lib: ModuleAst,
+        utils: ModuleAst,
+    }
+
+    impl ModuleProvider for DummyProvider {
+        fn get_module(&self, id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+            if id == &ProcedureId::from_name("foo", LIB_PATH) {
+                Some(NamedModuleAst::new(LIB_PATH, &self.lib))
+            } else if id == &ProcedureId::from_name("bar", UTILS_PATH) {
+                Some(NamedModuleAst::new(UTILS_PATH, &self.utils))
+            } else {
+                None
+            }
+        }
+    }
+
+    // the internal procedure of the utils module is added to the procedure cache because it is
+    // invoked via a call instruction; a malformed module which imports it must be rejected
+    let internal_proc_id = ProcedureId::from_index(0, UTILS_PATH);
+    let source = format!("use.{UTILS_PATH} use.{LIB_PATH} begin exec.utils::bar exec.lib::foo end");
+    for in_debug_mode in [false, true] {
+        let provider = DummyProvider {
+            lib: ModuleAst {
+                local_procs: vec![ProcedureAst {
+                    name: "foo".to_string(),
+                    docs: None,
+                    num_locals: 0,
+                    is_export: true,
+                    body: vec![Node::Instruction(Instruction::ExecImported(
+                        internal_proc_id,
+                    ))],
+                }],
+            },
+            utils: parse_module("proc.baz push.1 end export.bar call.baz end").unwrap(),
+        };
+        let assembler = Assembler::new()
+            .with_debug_mode(in_debug_mode)
+            .with_module_provider(provider);
+        let error = assembler.compile(&source).unwrap_err();
+        assert_eq!(
+            error,
+            AssemblyError::imported_proc_not_exported(&internal_proc_id)
+        );
+    }
+}
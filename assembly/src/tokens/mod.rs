@@ -1,4 +1,4 @@
-use super::{BTreeMap, ParsingError, String, ToString, Vec};
+use super::{BTreeMap, ParsingError, SourceLocation, String, ToString, Vec};
 use core::fmt;
 
 mod stream;
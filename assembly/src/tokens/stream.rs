@@ -1,4 +1,4 @@
-use super::{BTreeMap, ParsingError, String, Token, Vec};
+use super::{BTreeMap, ParsingError, SourceLocation, String, Token, Vec};
 use core::fmt;
 
 pub const DOC_COMMENT_PREFIX: &str = "#!";
@@ -10,6 +10,8 @@ pub const LINE_COMMENT_PREFIX: &str = "#";
 #[derive(Debug)]
 pub struct TokenStream<'a> {
     tokens: Vec<&'a str>,
+    locations: Vec<SourceLocation>,
+    instruction_locations: Vec<SourceLocation>,
     current: Token<'a>,
     pos: usize,
     temp: Token<'a>,
@@ -25,12 +27,13 @@ impl<'a> TokenStream<'a> {
             return Err(ParsingError::empty_source());
         }
         let mut tokens = Vec::new();
+        let mut locations = Vec::new();
         let mut doc_comments = BTreeMap::new();
 
         let mut comment = Comment(None);
 
-        for line in source.lines() {
-            let line = line.trim();
+        for (line_idx, source_line) in source.lines().enumerate() {
+            let line = source_line.trim();
             if line.starts_with(DOC_COMMENT_PREFIX) {
                 comment.append_line(line);
             } else if line.is_empty() && !comment.is_empty() {
@@ -39,10 +42,15 @@ impl<'a> TokenStream<'a> {
                 if !comment.is_empty() {
                     doc_comments.insert(tokens.len(), comment.take_content());
                 }
-                let mut line_tokens = line
-                    .split_whitespace()
+                let mut line_tokens = split_line(line)
+                    .into_iter()
                     .take_while(|&token| !token.starts_with(LINE_COMMENT_PREFIX))
                     .collect::<Vec<_>>();
+                locations.extend(
+                    line_tokens
+                        .iter()
+                        .map(|token| token_location(source_line, token, line_idx)),
+                );
                 tokens.append(&mut line_tokens);
             }
         }
@@ -53,6 +61,8 @@ impl<'a> TokenStream<'a> {
         let current = Token::new(tokens[0], 0);
         Ok(Self {
             tokens,
+            locations,
+            instruction_locations: Vec::new(),
             current,
             pos: 0,
             temp: Token::default(),
@@ -114,6 +124,23 @@ impl<'a> TokenStream<'a> {
     pub fn take_doc_comment_at(&mut self, pos: usize) -> Option<String> {
         self.doc_comments.remove(&pos)?
     }
+
+    // SOURCE LOCATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the location of the current token as the location of the next instruction parsed
+    /// from this stream. If all the tokens have been read, this is noop.
+    pub fn record_instruction(&mut self) {
+        if let Some(&location) = self.locations.get(self.pos) {
+            self.instruction_locations.push(location);
+        }
+    }
+
+    /// Returns the locations of all instructions recorded since the last invocation of this
+    /// method, in the order in which the instructions were parsed.
+    pub fn take_instruction_locations(&mut self) -> Vec<SourceLocation> {
+        core::mem::take(&mut self.instruction_locations)
+    }
 }
 
 impl<'a> fmt::Display for TokenStream<'a> {
@@ -159,3 +186,42 @@ pub fn prepare_line(line: &str) -> &str {
         .expect("Current line is not a doc comment")
         .trim()
 }
+
+/// Returns the location of the specified token, which must be a slice of the specified line.
+///
+/// Line indexes are 0-based, while the returned line and column numbers are 1-based.
+fn token_location(line: &str, token: &str, line_idx: usize) -> SourceLocation {
+    let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+    let column = line[..offset].chars().count() + 1;
+    SourceLocation::new(line_idx as u32 + 1, column as u32)
+}
+
+/// Splits the provided `line` into whitespace-separated tokens.
+///
+/// Whitespace inside parentheses does not separate tokens, so that parameters such as the ones
+/// in `push.fixed(1.5, Q16.16)` are read as a part of a single token.
+fn split_line(line: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+    let mut token_start = None;
+    let mut depth = 0_usize;
+    for (idx, c) in line.char_indices() {
+        if c.is_whitespace() && depth == 0 {
+            if let Some(start) = token_start.take() {
+                tokens.push(&line[start..idx]);
+            }
+            continue;
+        }
+
+        token_start.get_or_insert(idx);
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+    }
+    if let Some(start) = token_start {
+        tokens.push(&line[start..]);
+    }
+
+    tokens
+}
@@ -0,0 +1,85 @@
+use super::{parsers::Instruction, AssemblyError};
+use core::fmt;
+
+// INSTRUCTION SET VERSION
+// ================================================================================================
+
+/// Version of the Miden assembly instruction set.
+///
+/// Programs compiled for a given version use only the instructions which are available in this
+/// version, and thus, can be executed and verified by the VMs supporting this version.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InstructionSetVersion {
+    major: u16,
+    minor: u16,
+}
+
+impl InstructionSetVersion {
+    /// The initial version of the instruction set.
+    pub const V0_3: Self = Self::new(0, 3);
+
+    /// Adds `push.env`, `adv_loadw.key`, and `assert_eqw` instructions, as well as
+    /// `push.mtree_root` and `push.fixed` forms of the `push` instruction; the latter are resolved
+    /// into plain `push` instructions at parse time, and thus, are available when targeting older
+    /// versions too.
+    pub const V0_4: Self = Self::new(0, 4);
+
+    /// The latest version of the instruction set supported by the assembler.
+    pub const LATEST: Self = Self::V0_4;
+
+    /// Returns a new version with the specified major and minor version numbers.
+    pub const fn new(major: u16, minor: u16) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl Default for InstructionSetVersion {
+    fn default() -> Self {
+        Self::LATEST
+    }
+}
+
+impl fmt::Display for InstructionSetVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+// INSTRUCTION AVAILABILITY
+// ================================================================================================
+
+/// Checks that the provided instruction is available in the target version of the instruction
+/// set.
+///
+/// # Errors
+/// Returns an error if the instruction was added in a version newer than the target version.
+pub(crate) fn check_instruction_version(
+    instruction: &Instruction,
+    target: InstructionSetVersion,
+) -> Result<(), AssemblyError> {
+    match get_instruction_version(instruction) {
+        Some((name, version)) if version > target => Err(AssemblyError::unavailable_instruction(
+            name, version, target,
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the name of the provided instruction and the version in which it was added, if the
+/// instruction was added after the initial version of the instruction set; all other
+/// instructions are available in [InstructionSetVersion::V0_3].
+///
+/// Every instruction added to the instruction set must be listed here. Instructions which are
+/// resolved into other instructions at parse time (e.g., `push.fixed`) do not need to be listed,
+/// since programs using them compile into the same MAST as programs using the resolved
+/// instructions.
+fn get_instruction_version(
+    instruction: &Instruction,
+) -> Option<(&'static str, InstructionSetVersion)> {
+    match instruction {
+        Instruction::PushEnv(_) => Some(("push.env", InstructionSetVersion::V0_4)),
+        Instruction::AdvLoadWKey => Some(("adv_loadw.key", InstructionSetVersion::V0_4)),
+        Instruction::AssertEqw => Some(("assert_eqw", InstructionSetVersion::V0_4)),
+        _ => None,
+    }
+}
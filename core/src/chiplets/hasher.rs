@@ -137,6 +137,10 @@ pub fn merge(values: &[Digest; 2]) -> Digest {
 }
 
 /// Returns a hash of the provided list of field elements.
+///
+/// The hash is computed using the same permutation as the one used by the VM, and thus, it matches
+/// the hashes of the same elements computed by programs (e.g., via `std::mem::pipe_words_to_memory`
+/// procedure for an even number of words, or via `rphash` instruction for a single pair of words).
 #[inline(always)]
 pub fn hash_elements(elements: &[Felt]) -> Digest {
     Hasher::hash_elements(elements)
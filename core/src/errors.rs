@@ -1,10 +1,14 @@
 use super::Word;
-use crate::utils::collections::Vec;
+use crate::utils::{collections::Vec, string::String};
 
 #[derive(Clone, Debug)]
 pub enum InputError {
     NotFieldElement(u64, &'static str),
     DuplicateAdviceRoot([u8; 32]),
+    UnknownAdviceField(String),
+    MissingAdviceField(String),
+    AdviceFieldTypeMismatch(String),
+    InvalidMerklePathDepth(String, u32, usize),
 }
 
 #[derive(Clone, Debug)]
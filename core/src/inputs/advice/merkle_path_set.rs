@@ -89,6 +89,19 @@ impl MerklePathSet {
         self.total_depth
     }
 
+    /// Appends all paths stored in this set to the provided vector of elements. Each path is
+    /// prefixed with the position of its leaves and with its length.
+    pub(super) fn append_nodes(&self, target: &mut Vec<Felt>) {
+        target.push(Felt::new(self.paths.len() as u64));
+        for (&pos, path) in self.paths.iter() {
+            target.push(Felt::new(pos));
+            target.push(Felt::new(path.len() as u64));
+            for node in path.iter() {
+                target.extend_from_slice(node);
+            }
+        }
+    }
+
     /// Returns a node at the specified index.
     ///
     /// # Errors
@@ -66,6 +66,14 @@ impl MerkleTree {
         log2(self.nodes.len() / 2)
     }
 
+    /// Appends all nodes of this Merkle tree to the provided vector of elements, starting with the
+    /// root and ending with the last leaf.
+    pub(super) fn append_nodes(&self, target: &mut Vec<Felt>) {
+        for node in self.nodes[1..].iter() {
+            target.extend_from_slice(node);
+        }
+    }
+
     /// Returns a node at the specified depth and index.
     ///
     /// # Errors
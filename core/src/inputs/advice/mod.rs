@@ -8,6 +8,26 @@ use merkle_path_set::MerklePathSet;
 mod sparse_merkle_tree;
 use sparse_merkle_tree::SparseMerkleTree;
 
+// MERKLE PATH ORDER
+// ================================================================================================
+
+/// Defines the order of nodes in Merkle paths provided to an [AdviceSet].
+///
+/// By default, the VM expects Merkle paths to start with the sibling of the leaf node and end with
+/// a child of the root node (i.e., [MerklePathOrder::LeafToRoot]). Some external tools emit paths
+/// in the opposite (big-endian) order; such paths can be provided using
+/// [MerklePathOrder::RootToLeaf].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MerklePathOrder {
+    /// The first node of the path is the sibling of the leaf, and the last node is a child of the
+    /// root.
+    #[default]
+    LeafToRoot,
+    /// The first node of the path is a child of the root, and the last node is the sibling of the
+    /// leaf.
+    RootToLeaf,
+}
+
 // ADVICE SET
 // ================================================================================================
 
@@ -46,6 +66,31 @@ impl AdviceSet {
         )?))
     }
 
+    /// Returns a new [AdviceSet] instantiated as a set of Merkle paths of the specified depth.
+    ///
+    /// Each path is described by a tuple (index, value, path), where `index` and `value` specify
+    /// the leaf node at which the path starts. Nodes of each path are expected to be in the
+    /// specified `order`.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the paths is not consistent with the specified depth.
+    /// - Any of the paths resolves to a root which is different from the root of other paths.
+    pub fn new_merkle_path_set(
+        depth: u32,
+        paths: Vec<(u64, Word, Vec<Word>)>,
+        order: MerklePathOrder,
+    ) -> Result<Self, AdviceSetError> {
+        let mut set = MerklePathSet::new(depth)?;
+        for (index, value, mut path) in paths {
+            if order == MerklePathOrder::RootToLeaf {
+                path.reverse();
+            }
+            set.add_path(index, value, path)?;
+        }
+        Ok(Self::MerklePathSet(set))
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -98,6 +143,25 @@ impl AdviceSet {
         }
     }
 
+    /// Appends the type, the depth, the root, and all nodes stored in this advice set to the
+    /// provided vector of elements.
+    pub(crate) fn append_elements(&self, target: &mut Vec<Felt>) {
+        let variant = match self {
+            Self::MerkleTree(_) => 0_u32,
+            Self::SparseMerkleTree(_) => 1,
+            Self::MerklePathSet(_) => 2,
+        };
+        target.push(Felt::from(variant));
+        target.push(Felt::from(self.depth()));
+        target.extend_from_slice(&self.root());
+
+        match self {
+            Self::MerkleTree(tree) => tree.append_nodes(target),
+            Self::SparseMerkleTree(tree) => tree.append_nodes(target),
+            Self::MerklePathSet(set) => set.append_nodes(target),
+        }
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -115,3 +179,45 @@ impl AdviceSet {
         }
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AdviceSet, Felt, FieldElement, MerklePathOrder, Word};
+
+    #[test]
+    fn merkle_path_set_ordering() {
+        let leaves = (0..8).map(int_to_node).collect();
+        let tree = AdviceSet::new_merkle_tree(leaves).unwrap();
+        let path = tree.get_path(3, 6).unwrap();
+        let mut reversed_path = path.clone();
+        reversed_path.reverse();
+
+        // paths in the matching order resolve to the root of the tree
+        let set = build_path_set(path.clone(), MerklePathOrder::LeafToRoot);
+        assert_eq!(tree.root(), set.root());
+
+        let set = build_path_set(reversed_path.clone(), MerklePathOrder::RootToLeaf);
+        assert_eq!(tree.root(), set.root());
+
+        // paths in the mismatched order resolve to a different root
+        let set = build_path_set(reversed_path, MerklePathOrder::LeafToRoot);
+        assert_ne!(tree.root(), set.root());
+
+        let set = build_path_set(path, MerklePathOrder::RootToLeaf);
+        assert_ne!(tree.root(), set.root());
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn build_path_set(path: Vec<Word>, order: MerklePathOrder) -> AdviceSet {
+        AdviceSet::new_merkle_path_set(4, vec![(6, int_to_node(6), path)], order).unwrap()
+    }
+
+    fn int_to_node(value: u64) -> Word {
+        [Felt::new(value), Felt::ZERO, Felt::ZERO, Felt::ZERO]
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
     hasher::{self, Digest},
-    AdviceSetError, Word,
+    AdviceSetError, Felt, Word,
 };
 use crate::utils::collections::BTreeMap;
 use crate::utils::collections::Vec;
@@ -39,6 +39,16 @@ impl SparseMerkleTree {
         self.root
     }
 
+    /// Appends all leaves of this Merkle tree to the provided vector of elements. Each leaf is
+    /// prefixed with its key; branch nodes are not included as they are defined by the leaves.
+    pub(super) fn append_nodes(&self, target: &mut Vec<Felt>) {
+        target.push(Felt::new(self.store.leaves.len() as u64));
+        for (&key, leaf) in self.store.leaves.iter() {
+            target.push(Felt::new(key));
+            target.extend_from_slice(leaf);
+        }
+    }
+
     /// Returns the depth of this Merkle tree.
     pub fn depth(&self) -> u32 {
         self.depth
@@ -46,20 +56,27 @@ impl SparseMerkleTree {
 
     /// Returns a node at the specified key
     ///
+    /// Nodes which have not been explicitly set are treated as roots of empty subtrees; in
+    /// particular, a leaf which was never inserted has the value of [ZERO; 4].
+    ///
     /// # Errors
     /// Returns an error if:
     /// * The specified depth is greater than the depth of the tree.
-    /// * The specified key does not exist
+    /// * The specified key is not valid for the specified depth.
     pub fn get_node(&self, depth: u32, key: u64) -> Result<Word, AdviceSetError> {
         if depth == 0 {
             Err(AdviceSetError::DepthTooSmall)
         } else if depth > self.depth() {
             Err(AdviceSetError::DepthTooBig(depth))
+        } else if !is_valid_key(depth, key) {
+            Err(AdviceSetError::InvalidIndex(depth, key))
         } else if depth == self.depth() {
-            self.store.get_leaf_node(key)
+            Ok(self.store.get_leaf_node(key).unwrap_or_default())
         } else {
-            let branch_node = self.store.get_branch_node(key, depth)?;
-            Ok(hasher::merge(&[branch_node.left, branch_node.right]).into())
+            match self.store.get_branch_node(key, depth) {
+                Ok(node) => Ok(hasher::merge(&[node.left, node.right]).into()),
+                Err(_) => Ok(self.store.empty_hashes[depth as usize].into()),
+            }
         }
     }
 
@@ -68,23 +85,25 @@ impl SparseMerkleTree {
     ///
     /// # Errors
     /// Returns an error if:
-    /// * The specified key does not exist as a branch or leaf node
+    /// * The specified key is not valid for the specified depth.
     /// * The specified depth is greater than the depth of the tree.
     pub fn get_path(&self, depth: u32, key: u64) -> Result<Vec<Word>, AdviceSetError> {
-        if self.store.get_leaf_node(key).is_err() {
-            return Err(AdviceSetError::InvalidKey(key));
-        }
         if depth == 0 {
             return Err(AdviceSetError::DepthTooSmall);
         } else if depth > self.depth() {
             return Err(AdviceSetError::DepthTooBig(depth));
+        } else if !is_valid_key(depth, key) {
+            return Err(AdviceSetError::InvalidIndex(depth, key));
         }
 
         let mut path = Vec::with_capacity(depth as usize);
         let mut curr_key = key;
         for n in (0..depth).rev() {
             let parent_key = curr_key >> 1;
-            let parent_node = self.store.get_branch_node(parent_key, n)?;
+            let parent_node = self
+                .store
+                .get_branch_node(parent_key, n)
+                .unwrap_or_else(|_| self.store.get_empty_node((n + 1) as usize));
             let sibling_node = if curr_key & 1 == 1 {
                 parent_node.left
             } else {
@@ -98,10 +117,12 @@ impl SparseMerkleTree {
 
     /// Replaces the leaf located at the specified key, and recomputes hashes by walking up the tree
     ///
+    /// Since the tree is sparse, the leaf does not need to have been inserted previously.
+    ///
     /// # Errors
     /// Returns an error if the specified key is not a valid leaf index for this tree.
     pub fn update_leaf(&mut self, key: u64, value: Word) -> Result<(), AdviceSetError> {
-        if self.store.get_leaf_node(key).is_err() {
+        if !is_valid_key(self.depth(), key) {
             return Err(AdviceSetError::InvalidKey(key));
         }
         self.insert_leaf(key, value)?;
@@ -138,6 +159,11 @@ impl SparseMerkleTree {
     }
 }
 
+/// Returns true if the specified key addresses a node at the specified depth.
+fn is_valid_key(depth: u32, key: u64) -> bool {
+    key < 1u64 << depth
+}
+
 // STORE
 // ================================================================================================
 
@@ -349,6 +375,27 @@ mod tests {
         assert_eq!(expected_tree.root, tree.root);
     }
 
+    #[test]
+    fn access_empty_leaves() {
+        let mut smt = SparseMerkleTree::new(vec![6], vec![int_to_node(7)], 3).unwrap();
+        let mut values = ZERO_VALUES8.to_vec();
+        values[6] = int_to_node(7);
+        let mt = MerkleTree::new(values.clone()).unwrap();
+
+        // leaves and internal nodes which were never set are read as empty
+        assert_eq!(int_to_node(0), smt.get_node(3, 1).unwrap());
+        assert_eq!(mt.get_node(2, 0).unwrap(), smt.get_node(2, 0).unwrap());
+        assert_eq!(mt.get_path(3, 1).unwrap(), smt.get_path(3, 1).unwrap());
+        assert!(smt.get_node(3, 8).is_err());
+
+        // a leaf which was never set can be updated
+        smt.update_leaf(1, int_to_node(2)).unwrap();
+        values[1] = int_to_node(2);
+        let mt = MerkleTree::new(values).unwrap();
+        assert_eq!(mt.root(), smt.root());
+        assert!(smt.update_leaf(8, int_to_node(2)).is_err());
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -1,14 +1,17 @@
 use super::{
-    chiplets::hasher,
+    chiplets::hasher::{self, Digest},
     errors::{AdviceSetError, InputError},
     utils::IntoBytes,
-    Felt, FieldElement, Word,
+    Felt, FieldElement, ProgramOutputs, StarkField, Word,
 };
 use core::convert::TryInto;
 use winter_utils::collections::{BTreeMap, Vec};
 
 mod advice;
-pub use advice::AdviceSet;
+pub use advice::{AdviceSet, MerklePathOrder};
+
+mod schema;
+pub use schema::{AdviceFieldType, AdviceSchema, StructuredAdvice};
 
 // PROGRAM INPUTS
 // ================================================================================================
@@ -126,6 +129,33 @@ impl ProgramInputs {
         Self::new(stack_init, &[], vec![])
     }
 
+    /// Returns [ProgramInputs] with the stack initialized to the state of the stack at the end of
+    /// the execution described by the provided [ProgramOutputs].
+    ///
+    /// All of the output stack values, including the values which were in the stack overflow
+    /// table, are placed onto the stack in the same order. Thus, a program executed against the
+    /// returned inputs starts with the stack left by the previous program, which allows splitting
+    /// a computation across multiple executions (and proofs). The overflow table addresses are not
+    /// carried over, since the overflow table is rebuilt from the stack values at the start of the
+    /// next execution.
+    ///
+    /// Advice tape and advice sets for the returned inputs are blank.
+    pub fn from_outputs(outputs: &ProgramOutputs) -> Self {
+        let mut result = Self::none();
+        result.stack_init = outputs
+            .stack()
+            .iter()
+            .map(|&value| Felt::new(value))
+            .collect();
+        result
+    }
+
+    /// Returns a [ProgramInputsBuilder] which can be used to construct [ProgramInputs]
+    /// incrementally.
+    pub fn builder() -> ProgramInputsBuilder {
+        ProgramInputsBuilder::default()
+    }
+
     /// Returns [ProgramInputs] with no input values.
     pub fn none() -> Self {
         Self {
@@ -149,6 +179,105 @@ impl ProgramInputs {
         &self.advice_tape
     }
 
+    /// Returns a commitment to these [ProgramInputs].
+    ///
+    /// The commitment is computed by hashing the initial stack values, the advice tape, the
+    /// entries of the advice map, and the full contents of the advice sets (i.e., all nodes
+    /// stored in them). Thus, equal inputs always have the same commitment, and changing any of
+    /// the values changes the commitment. This is intended for caching the results of executing a
+    /// given program with given inputs.
+    pub fn commitment(&self) -> Digest {
+        let mut elements = Vec::new();
+
+        // every component is prefixed with its length so that values cannot move between
+        // components without changing the commitment
+        elements.push(Felt::new(self.stack_init.len() as u64));
+        elements.extend_from_slice(&self.stack_init);
+
+        elements.push(Felt::new(self.advice_tape.len() as u64));
+        elements.extend_from_slice(&self.advice_tape);
+
+        elements.push(Felt::new(self.advice_map.len() as u64));
+        for (key, values) in self.advice_map.iter() {
+            elements.extend(key_to_elements(key));
+            elements.push(Felt::new(values.len() as u64));
+            elements.extend_from_slice(values);
+        }
+
+        elements.push(Felt::new(self.advice_sets.len() as u64));
+        for advice_set in self.advice_sets.values() {
+            advice_set.append_elements(&mut elements);
+        }
+
+        hasher::hash_elements(&elements)
+    }
+
+    // MINIMIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a minimized version of these [ProgramInputs] for which the `predicate` still holds.
+    ///
+    /// This is intended for minimizing failing inputs found via fuzzing or property testing: the
+    /// `predicate` should return true as long as the provided inputs still reproduce the failure.
+    /// Inputs are minimized as follows:
+    /// 1. Chunks of values are removed from the advice tape, starting with a chunk covering the
+    ///    whole tape and halving the chunk size until single values are removed.
+    /// 2. Values are removed from the initial stack in the same way.
+    /// 3. Each of the remaining initial stack values is reduced towards zero.
+    ///
+    /// The advice map and advice sets are left unchanged. If the predicate does not hold for
+    /// these inputs, a copy of the inputs is returned without any changes.
+    pub fn shrink<P>(&self, mut predicate: P) -> Self
+    where
+        P: FnMut(&Self) -> bool,
+    {
+        let mut result = self.clone();
+        if !predicate(&result) {
+            return result;
+        }
+
+        // trim the advice tape
+        let mut candidate = result.clone();
+        result.advice_tape = remove_chunks(&result.advice_tape, |tape| {
+            candidate.advice_tape = tape.to_vec();
+            predicate(&candidate)
+        });
+
+        // trim the initial stack
+        let mut candidate = result.clone();
+        result.stack_init = remove_chunks(&result.stack_init, |stack| {
+            candidate.stack_init = stack.to_vec();
+            predicate(&candidate)
+        });
+
+        // reduce the remaining stack values; we first try to set each value to zero, and if this
+        // doesn't preserve the failure, we keep halving the value for as long as it does. every
+        // accepted candidate is strictly smaller than the current value, and thus, the reduction
+        // of each value always terminates.
+        let mut candidate = result.clone();
+        for i in 0..candidate.stack_init.len() {
+            let mut value = candidate.stack_init[i].as_int();
+            candidate.stack_init[i] = Felt::ZERO;
+            if value > 0 && predicate(&candidate) {
+                value = 0;
+            }
+
+            let mut reduced = value / 2;
+            while reduced > 0 {
+                candidate.stack_init[i] = Felt::new(reduced);
+                if !predicate(&candidate) {
+                    break;
+                }
+                value = reduced;
+                reduced = value / 2;
+            }
+            candidate.stack_init[i] = Felt::new(value);
+        }
+        result.stack_init = candidate.stack_init;
+
+        result
+    }
+
     // DESTRUCTURING
     // --------------------------------------------------------------------------------------------
 
@@ -172,3 +301,231 @@ impl ProgramInputs {
         (stack_init, advice_tape, advice_map, advice_sets)
     }
 }
+
+// PROGRAM INPUTS BUILDER
+// ================================================================================================
+
+/// A builder for [ProgramInputs].
+///
+/// Inputs are accumulated as raw values and are validated only when [ProgramInputsBuilder::build]
+/// is invoked. Thus, the builder methods can be chained without handling errors at every step.
+#[derive(Clone, Debug, Default)]
+pub struct ProgramInputsBuilder {
+    stack_init: Vec<u64>,
+    advice_tape: Vec<u64>,
+    advice_map: BTreeMap<[u8; 32], Vec<Felt>>,
+    advice_sets: Vec<AdviceSet>,
+}
+
+impl ProgramInputsBuilder {
+    /// Sets the initial stack values, replacing any previously specified values.
+    ///
+    /// The last value in the `stack_init` slice will end up at the top of the stack.
+    pub fn with_stack(mut self, stack_init: &[u64]) -> Self {
+        self.stack_init = stack_init.to_vec();
+        self
+    }
+
+    /// Appends the specified values to the advice tape.
+    pub fn with_advice_tape(mut self, advice_tape: &[u64]) -> Self {
+        self.advice_tape.extend_from_slice(advice_tape);
+        self
+    }
+
+    /// Adds the specified key-value pairs to the advice map. If a key is already present in the
+    /// map, its values are replaced.
+    pub fn with_advice_map<I>(mut self, advice_map: I) -> Self
+    where
+        I: IntoIterator<Item = ([u8; 32], Vec<Felt>)>,
+    {
+        self.advice_map.extend(advice_map);
+        self
+    }
+
+    /// Adds the specified advice sets to the set of Merkle structures available to the program.
+    pub fn with_merkle_store<I>(mut self, advice_sets: I) -> Self
+    where
+        I: IntoIterator<Item = AdviceSet>,
+    {
+        self.advice_sets.extend(advice_sets);
+        self
+    }
+
+    /// Returns [ProgramInputs] built from the values accumulated by this builder.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the initial stack values or the advice tape values are not valid field elements.
+    /// - Any of the advice sets have the same root.
+    pub fn build(self) -> Result<ProgramInputs, InputError> {
+        ProgramInputs::with_advice_map(
+            &self.stack_init,
+            &self.advice_tape,
+            self.advice_map,
+            self.advice_sets,
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Removes as many chunks of values from the provided slice as possible while `still_fails`
+/// returns true for the remaining values, and returns the remaining values.
+///
+/// The first chunk covers all values, and the chunk size is halved on every iteration until
+/// individual values are being removed.
+fn remove_chunks<P>(values: &[Felt], mut still_fails: P) -> Vec<Felt>
+where
+    P: FnMut(&[Felt]) -> bool,
+{
+    let mut values = values.to_vec();
+    let mut chunk_size = values.len();
+    while chunk_size > 0 {
+        let mut start = 0;
+        while start < values.len() {
+            let end = core::cmp::min(start + chunk_size, values.len());
+            let mut candidate = values.clone();
+            candidate.drain(start..end);
+            if still_fails(&candidate) {
+                values = candidate;
+            } else {
+                start = end;
+            }
+        }
+        chunk_size /= 2;
+    }
+
+    values
+}
+
+/// Converts a 32-byte map key into 8 field elements, each containing 4 bytes of the key.
+fn key_to_elements(key: &[u8; 32]) -> impl Iterator<Item = Felt> + '_ {
+    key.chunks(4).map(|chunk| {
+        let chunk: [u8; 4] = chunk.try_into().expect("invalid chunk size");
+        Felt::from(u32::from_le_bytes(chunk))
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AdviceSet, BTreeMap, Felt, ProgramInputs, ProgramOutputs, StarkField};
+    use crate::FieldElement;
+
+    #[test]
+    fn commitment() {
+        let advice_set = AdviceSet::new_merkle_tree(vec![[Felt::ONE; 4], [Felt::ZERO; 4]]).unwrap();
+        let advice_map = BTreeMap::from([([1; 32], vec![Felt::new(5)])]);
+        let build = |advice_tape: &[u64]| {
+            ProgramInputs::with_advice_map(
+                &[1, 2, 3],
+                advice_tape,
+                advice_map.clone(),
+                vec![advice_set.clone()],
+            )
+            .unwrap()
+        };
+
+        // equal inputs have equal commitments
+        let inputs = build(&[4, 5, 6]);
+        assert_eq!(inputs.commitment(), build(&[4, 5, 6]).commitment());
+
+        // changing an advice value changes the commitment
+        assert_ne!(inputs.commitment(), build(&[4, 5, 7]).commitment());
+
+        // moving a value from the advice tape onto the stack changes the commitment
+        let moved = ProgramInputs::new(&[1, 2, 3, 4], &[5, 6], vec![advice_set.clone()]).unwrap();
+        assert_ne!(inputs.commitment(), moved.commitment());
+
+        // changing the advice map changes the commitment
+        let advice_map = BTreeMap::from([([1; 32], vec![Felt::new(6)])]);
+        let inputs_map =
+            ProgramInputs::with_advice_map(&[1, 2, 3], &[4, 5, 6], advice_map, vec![advice_set])
+                .unwrap();
+        assert_ne!(inputs.commitment(), inputs_map.commitment());
+
+        // Merkle path sets with the same root but different paths have different commitments
+        let tree = AdviceSet::new_merkle_tree(
+            (0..4)
+                .map(|i| [Felt::new(i), Felt::ZERO, Felt::ZERO, Felt::ZERO])
+                .collect(),
+        )
+        .unwrap();
+        let build_path_set = |index: u64| {
+            let value = tree.get_node(2, index).unwrap();
+            let path = tree.get_path(2, index).unwrap();
+            let set =
+                AdviceSet::new_merkle_path_set(3, vec![(index, value, path)], Default::default())
+                    .unwrap();
+            assert_eq!(tree.root(), set.root());
+            ProgramInputs::new(&[], &[], vec![set]).unwrap()
+        };
+        assert_ne!(
+            build_path_set(0).commitment(),
+            build_path_set(3).commitment()
+        );
+    }
+
+    #[test]
+    fn shrink_advice_tape() {
+        // the failure is triggered whenever the advice tape contains 42 and the value at the top
+        // of the stack is greater than 5
+        let advice_tape = (0..1000).collect::<Vec<u64>>();
+        let inputs = ProgramInputs::new(&[1, 2, 1000], &advice_tape, vec![]).unwrap();
+        let fails = |inputs: &ProgramInputs| {
+            inputs.advice_tape().contains(&Felt::new(42))
+                && inputs
+                    .stack_init()
+                    .first()
+                    .map_or(false, |v| v.as_int() > 5)
+        };
+        assert!(fails(&inputs));
+
+        let shrunk = inputs.shrink(fails);
+        assert!(fails(&shrunk));
+        assert_eq!(&[Felt::new(42)], shrunk.advice_tape());
+        assert_eq!(1, shrunk.stack_init().len());
+        assert!(shrunk.stack_init()[0].as_int() <= 11);
+    }
+
+    #[test]
+    fn shrink_rejecting_zero() {
+        // the failure is triggered whenever the value at the top of the stack is not zero; thus,
+        // reducing the value to zero never preserves the failure
+        let fails = |inputs: &ProgramInputs| {
+            inputs
+                .stack_init()
+                .first()
+                .map_or(false, |&v| v != Felt::ZERO)
+        };
+
+        let inputs = ProgramInputs::new(&[1], &[], vec![]).unwrap();
+        assert_eq!(&[Felt::new(1)], inputs.shrink(fails).stack_init());
+
+        let inputs = ProgramInputs::new(&[1000, 7], &[], vec![]).unwrap();
+        assert_eq!(&[Felt::new(1)], inputs.shrink(fails).stack_init());
+    }
+
+    #[test]
+    fn shrink_passing_inputs() {
+        let inputs = ProgramInputs::new(&[1, 2, 3], &[4, 5, 6], vec![]).unwrap();
+        let shrunk = inputs.shrink(|_| false);
+        assert_eq!(inputs.stack_init(), shrunk.stack_init());
+        assert_eq!(inputs.advice_tape(), shrunk.advice_tape());
+    }
+
+    #[test]
+    fn from_outputs() {
+        let stack = (1..=18).collect::<Vec<u64>>();
+        let outputs = ProgramOutputs::new(stack.clone(), vec![0, 3, 5]);
+        let inputs = ProgramInputs::from_outputs(&outputs);
+
+        // the stack is initialized in stack order, overflow values included
+        let expected = stack.into_iter().map(Felt::new).collect::<Vec<_>>();
+        assert_eq!(expected, inputs.stack_init());
+        assert!(inputs.advice_tape().is_empty());
+    }
+}
@@ -0,0 +1,240 @@
+use super::{BTreeMap, InputError, StarkField, Vec, Word};
+use crate::{utils::string::String, Felt};
+
+// ADVICE SCHEMA
+// ================================================================================================
+
+/// Type of a single field of structured advice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdviceFieldType {
+    /// A single field element; it occupies one value on the advice tape.
+    Int,
+    /// A word (4 field elements); it occupies four values on the advice tape.
+    Word,
+    /// A Merkle path of the specified depth; it occupies one value for the depth of the path
+    /// followed by four values for each node of the path.
+    MerklePath(u32),
+}
+
+/// Describes the layout of structured advice as an ordered list of named fields.
+///
+/// Values of the fields can be provided in any order via [StructuredAdvice], and are written onto
+/// the advice tape in the order in which the fields were declared. Advice written this way can be
+/// read by a program using procedures from `std::advice` module invoked in the same order:
+/// - [AdviceFieldType::Int] fields are read using `read_int` procedure.
+/// - [AdviceFieldType::Word] fields are read using `read_word` procedure.
+/// - [AdviceFieldType::MerklePath] fields are read using `read_merkle_path` procedure.
+#[derive(Clone, Debug, Default)]
+pub struct AdviceSchema {
+    fields: Vec<(String, AdviceFieldType)>,
+}
+
+impl AdviceSchema {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new schema without any fields.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a field element field with the specified name to this schema.
+    ///
+    /// # Panics
+    /// Panics if a field with the same name has already been declared.
+    pub fn with_int(self, name: &str) -> Self {
+        self.with_field(name, AdviceFieldType::Int)
+    }
+
+    /// Appends a word field with the specified name to this schema.
+    ///
+    /// # Panics
+    /// Panics if a field with the same name has already been declared.
+    pub fn with_word(self, name: &str) -> Self {
+        self.with_field(name, AdviceFieldType::Word)
+    }
+
+    /// Appends a Merkle path field with the specified name and depth to this schema.
+    ///
+    /// # Panics
+    /// Panics if a field with the same name has already been declared.
+    pub fn with_merkle_path(self, name: &str, depth: u32) -> Self {
+        self.with_field(name, AdviceFieldType::MerklePath(depth))
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the fields of this schema in the order in which they were declared.
+    pub fn fields(&self) -> &[(String, AdviceFieldType)] {
+        &self.fields
+    }
+
+    /// Returns an empty set of values for the fields of this schema.
+    pub fn values(&self) -> StructuredAdvice<'_> {
+        StructuredAdvice {
+            schema: self,
+            values: BTreeMap::new(),
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn with_field(mut self, name: &str, field_type: AdviceFieldType) -> Self {
+        assert!(
+            !self.has_field(name),
+            "advice field {name} declared more than once"
+        );
+        self.fields.push((name.into(), field_type));
+        self
+    }
+
+    fn has_field(&self, name: &str) -> bool {
+        self.fields.iter().any(|(field_name, _)| field_name == name)
+    }
+}
+
+// STRUCTURED ADVICE
+// ================================================================================================
+
+/// Values of the fields described by an [AdviceSchema].
+///
+/// Values are accumulated as provided and are validated against the schema only when
+/// [StructuredAdvice::to_advice_tape] is invoked.
+#[derive(Clone, Debug)]
+pub struct StructuredAdvice<'a> {
+    schema: &'a AdviceSchema,
+    values: BTreeMap<String, AdviceValue>,
+}
+
+impl<'a> StructuredAdvice<'a> {
+    /// Sets the value of the field element field with the specified name.
+    pub fn with_int(mut self, name: &str, value: u64) -> Self {
+        self.values.insert(name.into(), AdviceValue::Int(value));
+        self
+    }
+
+    /// Sets the value of the word field with the specified name.
+    pub fn with_word(mut self, name: &str, value: Word) -> Self {
+        self.values.insert(name.into(), AdviceValue::Word(value));
+        self
+    }
+
+    /// Sets the value of the Merkle path field with the specified name.
+    ///
+    /// The path is expected to start with the sibling of the leaf node and end with a child of
+    /// the root node.
+    pub fn with_merkle_path(mut self, name: &str, path: &[Word]) -> Self {
+        self.values
+            .insert(name.into(), AdviceValue::MerklePath(path.to_vec()));
+        self
+    }
+
+    /// Returns the values of all fields serialized into advice tape values in the order in which
+    /// the fields were declared in the schema.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A value was provided for a field which is not declared in the schema.
+    /// - A value was not provided for one of the fields declared in the schema.
+    /// - A value does not match the type of its field, or the length of a Merkle path does not
+    ///   match the depth of its field.
+    /// - A field element value is not a valid field element.
+    pub fn to_advice_tape(&self) -> Result<Vec<u64>, InputError> {
+        if let Some(name) = self
+            .values
+            .keys()
+            .find(|&name| !self.schema.has_field(name))
+        {
+            return Err(InputError::UnknownAdviceField(name.clone()));
+        }
+
+        let mut tape = Vec::new();
+        for (name, field_type) in self.schema.fields.iter() {
+            let value = self
+                .values
+                .get(name)
+                .ok_or_else(|| InputError::MissingAdviceField(name.clone()))?;
+
+            match (field_type, value) {
+                (AdviceFieldType::Int, AdviceValue::Int(value)) => {
+                    if *value >= Felt::MODULUS {
+                        return Err(InputError::NotFieldElement(*value, "advice field value"));
+                    }
+                    tape.push(*value);
+                }
+                (AdviceFieldType::Word, AdviceValue::Word(word)) => {
+                    tape.extend(word.iter().map(|v| v.as_int()));
+                }
+                (AdviceFieldType::MerklePath(depth), AdviceValue::MerklePath(path)) => {
+                    if path.len() != *depth as usize {
+                        return Err(InputError::InvalidMerklePathDepth(
+                            name.clone(),
+                            *depth,
+                            path.len(),
+                        ));
+                    }
+                    tape.push(*depth as u64);
+                    tape.extend(path.iter().flatten().map(|v| v.as_int()));
+                }
+                _ => return Err(InputError::AdviceFieldTypeMismatch(name.clone())),
+            }
+        }
+
+        Ok(tape)
+    }
+}
+
+/// A value of a single field of structured advice.
+#[derive(Clone, Debug)]
+enum AdviceValue {
+    Int(u64),
+    Word(Word),
+    MerklePath(Vec<Word>),
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AdviceSchema, Felt, InputError};
+    use crate::FieldElement;
+
+    #[test]
+    fn to_advice_tape() {
+        let schema = AdviceSchema::new()
+            .with_int("a")
+            .with_merkle_path("path", 1)
+            .with_word("b");
+
+        // values are serialized in the declared order regardless of the order they were set in
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let tape = schema
+            .values()
+            .with_word("b", word)
+            .with_merkle_path("path", &[[Felt::ONE; 4]])
+            .with_int("a", 7)
+            .to_advice_tape()
+            .unwrap();
+        assert_eq!(vec![7, 1, 1, 1, 1, 1, 1, 2, 3, 4], tape);
+
+        // all fields must have values of the declared types
+        let result = schema.values().with_int("a", 7).to_advice_tape();
+        assert!(matches!(result, Err(InputError::MissingAdviceField(name)) if name == "path"));
+
+        let result = schema.values().with_int("c", 7).to_advice_tape();
+        assert!(matches!(result, Err(InputError::UnknownAdviceField(name)) if name == "c"));
+
+        let values = schema.values().with_int("a", 7).with_word("b", word);
+        let result = values.clone().with_int("path", 1).to_advice_tape();
+        assert!(matches!(result, Err(InputError::AdviceFieldTypeMismatch(name)) if name == "path"));
+
+        let result = values.with_merkle_path("path", &[]).to_advice_tape();
+        assert!(matches!(
+            result,
+            Err(InputError::InvalidMerklePathDepth(name, 1, 0)) if name == "path"
+        ));
+    }
+}
@@ -7,6 +7,7 @@ extern crate alloc;
 use core::ops::Range;
 
 pub mod chiplets;
+pub use chiplets::hasher::{hash_elements, Digest};
 pub mod decoder;
 pub mod errors;
 pub mod range;
@@ -22,14 +23,18 @@ pub use program::{blocks as code_blocks, CodeBlockTable, Kernel, Program};
 
 mod operations;
 pub use operations::{
-    AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList, Operation,
+    AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList, EnvValue, Operation,
+    SourceLocation, ALL_OPERATIONS, ASSERT_EQW_OPS,
 };
 
 mod inputs;
-pub use inputs::{AdviceSet, ProgramInputs};
+pub use inputs::{
+    AdviceFieldType, AdviceSchema, AdviceSet, MerklePathOrder, ProgramInputs, ProgramInputsBuilder,
+    StructuredAdvice,
+};
 
 mod outputs;
-pub use outputs::ProgramOutputs;
+pub use outputs::{OutputInterp, ProgramOutputs};
 
 pub mod utils;
 use utils::range;
@@ -24,8 +24,17 @@ pub enum AdviceInjector {
     /// as the key.
     MapValue,
 
+    /// Removes a word from the key-value map maintained by the advice provider and injects it at
+    /// the front of the advice tape. The word is looked up using the top 4 elements on the stack
+    /// as the key.
+    TakeMapValue,
+
     /// Injects a list of words from the memory starting from the specified start address.
     Memory(u32, u32),
+
+    /// Injects the specified environment value supplied by the host at the head of the advice
+    /// tape.
+    Env(EnvValue),
 }
 
 impl fmt::Display for AdviceInjector {
@@ -34,7 +43,47 @@ impl fmt::Display for AdviceInjector {
             Self::MerkleNode => write!(f, "merkle_node"),
             Self::DivResultU64 => write!(f, "div_result_u64"),
             Self::MapValue => write!(f, "map_value"),
+            Self::TakeMapValue => write!(f, "take_map_value"),
             Self::Memory(start_addr, num_words) => write!(f, "mem({start_addr}, {num_words})"),
+            Self::Env(value) => write!(f, "env({value})"),
         }
     }
 }
+
+// ENVIRONMENT VALUES
+// ================================================================================================
+
+/// Values describing the environment in which a program is executed (e.g., the block in which
+/// a transaction is included). These values are supplied by the host at execution time.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EnvValue {
+    /// Number of the block in the context of which the program is executed.
+    BlockNumber,
+    /// Timestamp of the block in the context of which the program is executed.
+    Timestamp,
+}
+
+impl EnvValue {
+    /// Returns the name of this value as used in assembly instructions.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BlockNumber => "block_number",
+            Self::Timestamp => "timestamp",
+        }
+    }
+
+    /// Returns the environment value with the specified name, or None if no such value exists.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "block_number" => Some(Self::BlockNumber),
+            "timestamp" => Some(Self::Timestamp),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for EnvValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
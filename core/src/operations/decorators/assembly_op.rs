@@ -1,4 +1,5 @@
 use crate::utils::string::String;
+use core::fmt;
 
 // ASSEMBLY OP
 // ================================================================================================
@@ -8,13 +9,25 @@ use crate::utils::string::String;
 pub struct AssemblyOp {
     op: String,
     num_cycles: u8,
+    location: Option<SourceLocation>,
 }
 
 impl AssemblyOp {
     /// Returns [AssemblyOp] instantiated with the specified assembly instruction string and number
     /// of cycles it takes to execute the assembly instruction.
     pub fn new(op: String, num_cycles: u8) -> Self {
-        Self { op, num_cycles }
+        Self {
+            op,
+            num_cycles,
+            location: None,
+        }
+    }
+
+    /// Returns this [AssemblyOp] with the location of the assembly instruction in the source code
+    /// set to the specified location.
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(location);
+        self
     }
 
     /// Returns the assembly instruction corresponding to this decorator.
@@ -27,6 +40,12 @@ impl AssemblyOp {
         self.num_cycles
     }
 
+    /// Returns the location of the assembly instruction in the source code, or None if the
+    /// location is not known.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.location
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -35,3 +54,38 @@ impl AssemblyOp {
         self.num_cycles = num_cycles;
     }
 }
+
+// SOURCE LOCATION
+// ================================================================================================
+
+/// Location of an assembly instruction in the source code it was compiled from.
+///
+/// Both the line and the column are 1-based, and the column is measured in characters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SourceLocation {
+    line: u32,
+    column: u32,
+}
+
+impl SourceLocation {
+    /// Returns a new [SourceLocation] instantiated with the specified line and column.
+    pub fn new(line: u32, column: u32) -> Self {
+        Self { line, column }
+    }
+
+    /// Returns the line of this location.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Returns the column of this location.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
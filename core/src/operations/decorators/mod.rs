@@ -1,8 +1,8 @@
 mod advice;
 mod assembly_op;
 use crate::utils::collections::Vec;
-pub use advice::AdviceInjector;
-pub use assembly_op::AssemblyOp;
+pub use advice::{AdviceInjector, EnvValue};
+pub use assembly_op::{AssemblyOp, SourceLocation};
 use core::fmt;
 
 // DECORATORS
@@ -1,7 +1,10 @@
-use super::Felt;
+use super::{Felt, ZERO};
 use core::fmt;
 mod decorators;
-pub use decorators::{AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList};
+pub use decorators::{
+    AdviceInjector, AssemblyOp, Decorator, DecoratorIterator, DecoratorList, EnvValue,
+    SourceLocation,
+};
 
 // OPERATIONS
 // ================================================================================================
@@ -659,3 +662,120 @@ impl fmt::Display for Operation {
         }
     }
 }
+
+// OPERATION LIST
+// ================================================================================================
+
+/// All VM operations; operations which carry a value are listed with an arbitrary value.
+///
+/// Every operation of the VM must be listed here: the list is used to look up operations by
+/// opcode (e.g., when deserializing programs).
+pub const ALL_OPERATIONS: [Operation; 84] = [
+    Operation::Noop,
+    Operation::Assert,
+    Operation::FmpAdd,
+    Operation::FmpUpdate,
+    Operation::SDepth,
+    Operation::Caller,
+    Operation::Join,
+    Operation::Split,
+    Operation::Loop,
+    Operation::Call,
+    Operation::SysCall,
+    Operation::Span,
+    Operation::End,
+    Operation::Repeat,
+    Operation::Respan,
+    Operation::Halt,
+    Operation::Add,
+    Operation::Neg,
+    Operation::Mul,
+    Operation::Inv,
+    Operation::Incr,
+    Operation::And,
+    Operation::Or,
+    Operation::Not,
+    Operation::Eq,
+    Operation::Eqz,
+    Operation::Expacc,
+    Operation::U32split,
+    Operation::U32add,
+    Operation::U32assert2,
+    Operation::U32add3,
+    Operation::U32sub,
+    Operation::U32mul,
+    Operation::U32madd,
+    Operation::U32div,
+    Operation::U32and,
+    Operation::U32xor,
+    Operation::Pad,
+    Operation::Drop,
+    Operation::Dup0,
+    Operation::Dup1,
+    Operation::Dup2,
+    Operation::Dup3,
+    Operation::Dup4,
+    Operation::Dup5,
+    Operation::Dup6,
+    Operation::Dup7,
+    Operation::Dup9,
+    Operation::Dup11,
+    Operation::Dup13,
+    Operation::Dup15,
+    Operation::Swap,
+    Operation::SwapW,
+    Operation::SwapW2,
+    Operation::SwapW3,
+    Operation::SwapDW,
+    Operation::MovUp2,
+    Operation::MovUp3,
+    Operation::MovUp4,
+    Operation::MovUp5,
+    Operation::MovUp6,
+    Operation::MovUp7,
+    Operation::MovUp8,
+    Operation::MovDn2,
+    Operation::MovDn3,
+    Operation::MovDn4,
+    Operation::MovDn5,
+    Operation::MovDn6,
+    Operation::MovDn7,
+    Operation::MovDn8,
+    Operation::CSwap,
+    Operation::CSwapW,
+    Operation::Push(ZERO),
+    Operation::Read,
+    Operation::ReadW,
+    Operation::MLoadW,
+    Operation::MStoreW,
+    Operation::MLoad,
+    Operation::MStore,
+    Operation::MStream,
+    Operation::Pipe,
+    Operation::RpPerm,
+    Operation::MpVerify,
+    Operation::MrUpdate(false),
+];
+
+// OPERATION SEQUENCES
+// ================================================================================================
+
+/// Operations into which the assembler compiles an `assert_eqw` instruction.
+///
+/// The words are compared lane by lane, starting with the elements at the top of each word: for
+/// the first three lanes, the element of the deeper word is moved to the top of the stack before
+/// EQ, while the last lane is compared in place. The processor recognizes this sequence to report
+/// the lane which differed when the assertion fails.
+pub const ASSERT_EQW_OPS: [Operation; 11] = [
+    Operation::MovUp4,
+    Operation::Eq,
+    Operation::Assert,
+    Operation::MovUp3,
+    Operation::Eq,
+    Operation::Assert,
+    Operation::MovUp2,
+    Operation::Eq,
+    Operation::Assert,
+    Operation::Eq,
+    Operation::Assert,
+];
@@ -1,6 +1,10 @@
 use crate::{stack::STACK_TOP_SIZE, StackTopState};
 
-use super::{Felt, StarkField};
+use super::{
+    utils::string::{String, ToString},
+    Felt, StarkField,
+};
+use core::str::FromStr;
 use winter_utils::collections::Vec;
 
 // PROGRAM OUTPUTS
@@ -122,6 +126,41 @@ impl ProgramOutputs {
         overflow
     }
 
+    /// Returns a list of stack positions at which the stack outputs of `self` and `other` differ.
+    ///
+    /// Each entry is a tuple of (position, value in `self`, value in `other`), where position 0
+    /// is the top of the stack. If one of the outputs contains fewer stack values than the other,
+    /// the missing values are treated as ZEROs, since this is how the VM populates the stack
+    /// beyond its current depth.
+    pub fn diff(&self, other: &Self) -> Vec<(usize, u64, u64)> {
+        let len = self.stack.len().max(other.stack.len());
+        (0..len)
+            .filter_map(|i| {
+                let expected = self.stack.get(i).copied().unwrap_or(0);
+                let actual = other.stack.get(i).copied().unwrap_or(0);
+                (expected != actual).then_some((i, expected, actual))
+            })
+            .collect()
+    }
+
+    /// Returns the stack outputs rendered as a table with one row per stack position, where each
+    /// value is formatted according to the specified interpretation. Position 0 (i.e., the first
+    /// row) is the top of the stack.
+    ///
+    /// For example, a stack with values 3 and p - 1 rendered as [OutputInterp::I32] yields:
+    /// ```text
+    ///    0 | 3
+    ///    1 | -1
+    /// ```
+    pub fn display_as(&self, interp: OutputInterp) -> String {
+        self.stack
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| format!("{i:>4} | {}", interp.format(value)))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -133,6 +172,53 @@ impl ProgramOutputs {
     }
 }
 
+// OUTPUT INTERPRETATION
+// ================================================================================================
+
+/// Interpretation of the field elements output by a program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputInterp {
+    /// Unsigned 32-bit integers.
+    U32,
+    /// Signed 32-bit integers, where a negative value -x is represented by the field element p - x.
+    I32,
+    /// Field elements in hexadecimal representation.
+    Hex,
+    /// Field elements in decimal representation.
+    Felt,
+}
+
+impl OutputInterp {
+    /// Returns the provided field element formatted according to this interpretation; values
+    /// which cannot be interpreted as such are formatted as raw field elements in parentheses.
+    pub fn format(&self, value: u64) -> String {
+        match self {
+            Self::U32 if value <= u32::MAX as u64 => value.to_string(),
+            Self::I32 if value <= i32::MAX as u64 => value.to_string(),
+            Self::I32 if Felt::MODULUS - value <= 1 << 31 => {
+                format!("-{}", Felt::MODULUS - value)
+            }
+            Self::U32 | Self::I32 => format!("({value})"),
+            Self::Hex => format!("{value:#x}"),
+            Self::Felt => value.to_string(),
+        }
+    }
+}
+
+impl FromStr for OutputInterp {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "u32" => Ok(Self::U32),
+            "i32" => Ok(Self::I32),
+            "hex" => Ok(Self::Hex),
+            "felt" => Ok(Self::Felt),
+            _ => Err(format!("invalid output interpretation '{s}'")),
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -145,3 +231,48 @@ fn are_valid_elements(outputs: &[u64]) -> bool {
     }
     true
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, OutputInterp, ProgramOutputs, StarkField};
+
+    #[test]
+    fn diff() {
+        let expected = ProgramOutputs::new(vec![1, 2, 3, 4, 5], vec![]);
+        assert!(expected.diff(&expected).is_empty());
+
+        let actual = ProgramOutputs::new(vec![1, 7, 3, 4, 9], vec![]);
+        assert_eq!(vec![(1, 2, 7), (4, 5, 9)], expected.diff(&actual));
+
+        // missing stack values are treated as ZEROs
+        let actual = ProgramOutputs::new(vec![1, 2, 3, 0, 0, 0, 6], vec![]);
+        assert_eq!(
+            vec![(3, 4, 0), (4, 5, 0), (6, 0, 6)],
+            expected.diff(&actual)
+        );
+    }
+
+    #[test]
+    fn display_as() {
+        let outputs = ProgramOutputs::new(vec![3, Felt::MODULUS - 1, 1 << 32, 255], vec![]);
+
+        let expected = "   0 | 3\n   1 | (18446744069414584320)\n   2 | (4294967296)\n   3 | 255";
+        assert_eq!(expected, outputs.display_as(OutputInterp::U32));
+
+        let expected = "   0 | 3\n   1 | -1\n   2 | (4294967296)\n   3 | 255";
+        assert_eq!(expected, outputs.display_as(OutputInterp::I32));
+
+        let expected = "   0 | 0x3\n   1 | 0xffffffff00000000\n   2 | 0x100000000\n   3 | 0xff";
+        assert_eq!(expected, outputs.display_as(OutputInterp::Hex));
+
+        let expected = "   0 | 3\n   1 | 18446744069414584320\n   2 | 4294967296\n   3 | 255";
+        assert_eq!(expected, outputs.display_as(OutputInterp::Felt));
+
+        // the lowest representable i32 value
+        let outputs = ProgramOutputs::new(vec![Felt::MODULUS - (1 << 31)], vec![]);
+        assert_eq!("   0 | -2147483648", outputs.display_as(OutputInterp::I32));
+    }
+}
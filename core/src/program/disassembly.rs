@@ -0,0 +1,287 @@
+use super::{blocks::CodeBlock, Digest, Operation, Program, String, Vec};
+use core::fmt::{self, Write};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of spaces by which the body of a block is indented relative to the block itself.
+const INDENT_SIZE: usize = 4;
+
+// PROGRAM DISASSEMBLY
+// ================================================================================================
+
+impl Program {
+    /// Returns a textual representation of this program which resembles the Miden assembly source
+    /// the program was compiled from.
+    ///
+    /// Procedures stored in the code block table are rendered first, followed by the program root
+    /// enclosed in a `begin ... end` block. Code blocks are rendered as follows:
+    /// - SPAN blocks are rendered as sequences of VM operations, one operation per line. NOOPs
+    ///   are omitted, as these are inserted by the assembler only to pad operation batches.
+    /// - SPLIT blocks are rendered as `if.true ... else ... end` statements.
+    /// - LOOP blocks are rendered as `while.true ... end` statements.
+    /// - CALL blocks are rendered as `call` or `syscall` instructions; the callee is identified by
+    ///   its name if the program contains debug symbols, and by its hash otherwise.
+    /// - JOIN blocks are not rendered explicitly; instead, the blocks they combine are rendered one
+    ///   after another.
+    ///
+    /// Consecutive identical sequences of blocks combined via JOIN blocks are rendered as
+    /// `repeat.N ... end` statements. The assembler merges consecutive SPAN blocks into a single
+    /// SPAN block, and thus, repetitions of bodies which contain no control flow cannot be
+    /// identified and are rendered as flat sequences of operations.
+    pub fn disassemble(&self) -> String {
+        let mut disassembler = Disassembler {
+            program: self,
+            output: String::new(),
+        };
+
+        for procedure in self.cb_table.0.values() {
+            disassembler.write_line(0, format_args!("proc.{}", self.callee(procedure.hash())));
+            disassembler.write_block(procedure, 1);
+            disassembler.write_line(0, "end\n");
+        }
+
+        disassembler.write_line(0, "begin");
+        disassembler.write_block(&self.root, 1);
+        disassembler.write_line(0, "end");
+
+        disassembler.output
+    }
+
+    /// Returns the name of the procedure with the specified hash if it is known, or the hash of
+    /// the procedure in hex form otherwise.
+    fn callee(&self, hash: Digest) -> String {
+        match self.procedure_name(hash) {
+            Some(name) => String::from(name),
+            None => hex(hash),
+        }
+    }
+}
+
+// DISASSEMBLER
+// ================================================================================================
+
+/// Accumulates the textual representation of code blocks of a program.
+struct Disassembler<'a> {
+    program: &'a Program,
+    output: String,
+}
+
+impl Disassembler<'_> {
+    /// Appends the representation of the specified block indented to the specified depth.
+    fn write_block(&mut self, block: &CodeBlock, depth: usize) {
+        match block {
+            CodeBlock::Join(_) => {
+                let mut blocks = Vec::new();
+                flatten_joins(block, &mut blocks);
+                self.write_sequence(&blocks, depth);
+            }
+            CodeBlock::Split(split) => {
+                self.write_line(depth, "if.true");
+                self.write_block(split.on_true(), depth + 1);
+                self.write_line(depth, "else");
+                self.write_block(split.on_false(), depth + 1);
+                self.write_line(depth, "end");
+            }
+            CodeBlock::Loop(block) => {
+                self.write_line(depth, "while.true");
+                self.write_block(block.body(), depth + 1);
+                self.write_line(depth, "end");
+            }
+            CodeBlock::Call(call) => {
+                let instruction = if call.is_syscall() { "syscall" } else { "call" };
+                let callee = self.program.callee(call.fn_hash());
+                self.write_line(depth, format_args!("{instruction}.{callee}"));
+            }
+            CodeBlock::Span(span) => {
+                let mut ops = span
+                    .op_batches()
+                    .iter()
+                    .flat_map(|batch| batch.ops())
+                    .filter(|&&op| op != Operation::Noop)
+                    .peekable();
+
+                // a span block consisting of NOOPs only is rendered as a single NOOP
+                if ops.peek().is_none() {
+                    self.write_line(depth, Operation::Noop);
+                }
+                for op in ops {
+                    self.write_line(depth, op);
+                }
+            }
+            CodeBlock::Proxy(proxy) => {
+                self.write_line(depth, format_args!("proxy.{}", hex(proxy.hash())));
+            }
+        }
+    }
+
+    /// Appends the representation of the specified sequence of blocks indented to the specified
+    /// depth, rendering consecutive repetitions of blocks as `repeat.N` statements.
+    fn write_sequence(&mut self, blocks: &[&CodeBlock], depth: usize) {
+        let mut start = 0;
+        while start < blocks.len() {
+            let (period, num_repetitions) = find_repetition(&blocks[start..]);
+            if num_repetitions > 1 {
+                self.write_line(depth, format_args!("repeat.{num_repetitions}"));
+                self.write_sequence(&blocks[start..start + period], depth + 1);
+                self.write_line(depth, "end");
+            } else {
+                self.write_block(blocks[start], depth);
+            }
+            start += period * num_repetitions;
+        }
+    }
+
+    /// Appends the specified line indented to the specified depth.
+    fn write_line<T: fmt::Display>(&mut self, depth: usize, line: T) {
+        let indent = depth * INDENT_SIZE;
+        writeln!(self.output, "{:indent$}{line}", "").expect("failed to write to a string");
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Appends the blocks combined by the specified block via (possibly nested) JOIN blocks to the
+/// provided list, in the order in which the blocks are executed.
+fn flatten_joins<'a>(block: &'a CodeBlock, blocks: &mut Vec<&'a CodeBlock>) {
+    match block {
+        CodeBlock::Join(join) => {
+            flatten_joins(join.first(), blocks);
+            flatten_joins(join.second(), blocks);
+        }
+        _ => blocks.push(block),
+    }
+}
+
+/// Returns the length of a sequence of blocks at the start of the provided list, and the number
+/// of times this sequence is consecutively repeated, such that the repetitions cover as many
+/// blocks as possible. If no sequence is repeated, (1, 1) is returned.
+///
+/// Among the sequences covering the same number of blocks, the shortest sequence is selected.
+fn find_repetition(blocks: &[&CodeBlock]) -> (usize, usize) {
+    let mut result = (1, 1);
+    for period in 1..=blocks.len() / 2 {
+        let mut num_repetitions = 1;
+        while (num_repetitions + 1) * period <= blocks.len()
+            && (0..period).all(|i| is_same_block(blocks[i], blocks[num_repetitions * period + i]))
+        {
+            num_repetitions += 1;
+        }
+
+        if num_repetitions > 1 && period * num_repetitions > result.0 * result.1 {
+            result = (period, num_repetitions);
+        }
+    }
+    result
+}
+
+/// Returns true if the specified blocks are identical.
+///
+/// Comparing hashes of the blocks is not sufficient, since hashes of different types of blocks
+/// (e.g., JOIN and SPLIT blocks, or CALL and SYSCALL blocks) with the same children are equal.
+fn is_same_block(a: &CodeBlock, b: &CodeBlock) -> bool {
+    if a.hash() != b.hash() {
+        return false;
+    }
+
+    match (a, b) {
+        (CodeBlock::Join(a), CodeBlock::Join(b)) => {
+            is_same_block(a.first(), b.first()) && is_same_block(a.second(), b.second())
+        }
+        (CodeBlock::Split(a), CodeBlock::Split(b)) => {
+            is_same_block(a.on_true(), b.on_true()) && is_same_block(a.on_false(), b.on_false())
+        }
+        (CodeBlock::Loop(a), CodeBlock::Loop(b)) => is_same_block(a.body(), b.body()),
+        (CodeBlock::Call(a), CodeBlock::Call(b)) => a.is_syscall() == b.is_syscall(),
+        (CodeBlock::Span(_), CodeBlock::Span(_)) | (CodeBlock::Proxy(_), CodeBlock::Proxy(_)) => {
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Formats the specified hash as a hex string prefixed with `0x`.
+fn hex(hash: Digest) -> String {
+    let bytes = <[u8; 32]>::from(hash);
+    let mut result = String::from("0x");
+    for byte in bytes {
+        write!(result, "{byte:02x}").expect("failed to write to a string");
+    }
+    result
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{CodeBlock, Operation, Program};
+    use crate::{CodeBlockTable, Felt, Kernel};
+
+    #[test]
+    fn disassemble_loops() {
+        let branch = CodeBlock::new_split(
+            CodeBlock::new_span(vec![Operation::Add, Operation::Noop]),
+            CodeBlock::new_span(vec![Operation::Mul]),
+        );
+        let body = CodeBlock::new_span(vec![Operation::Pad, Operation::Incr, Operation::Swap]);
+        let root = CodeBlock::new_join([
+            CodeBlock::new_join([
+                CodeBlock::new_span(vec![Operation::Push(Felt::new(7))]),
+                branch.clone(),
+            ]),
+            CodeBlock::new_join([branch, CodeBlock::new_loop(body)]),
+        ]);
+
+        let program = Program::new(root);
+        let expected = "\
+begin
+    push(7)
+    repeat.2
+        if.true
+            add
+        else
+            mul
+        end
+    end
+    while.true
+        pad
+        incr
+        swap
+    end
+end
+";
+        assert_eq!(expected, program.disassemble());
+    }
+
+    #[test]
+    fn disassemble_procedures() {
+        let foo = CodeBlock::new_span(vec![Operation::Noop]);
+        let root = CodeBlock::new_join([
+            CodeBlock::new_call(foo.hash()),
+            CodeBlock::new_syscall(foo.hash()),
+        ]);
+
+        let mut cb_table = CodeBlockTable::default();
+        cb_table.insert(foo.clone());
+        let program = Program::with_kernel(root, Kernel::new(&[foo.hash()]), cb_table);
+        let disassembly = program.disassemble();
+        assert!(disassembly.starts_with("proc.0x"));
+        assert!(disassembly.contains("\n    call.0x"));
+
+        // procedures are identified by name when the program contains debug symbols
+        let program = program.with_procedure_names([(foo.hash(), "foo".to_string())]);
+        let expected = "\
+proc.foo
+    noop
+end
+
+begin
+    call.foo
+    syscall.foo
+end
+";
+        assert_eq!(expected, program.disassemble());
+    }
+}
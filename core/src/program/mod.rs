@@ -1,8 +1,9 @@
 use super::{
     chiplets::hasher::{self, Digest},
     utils::{
-        collections::{BTreeMap, Vec},
-        Box,
+        collections::{BTreeMap, BTreeSet, Vec},
+        string::String,
+        Box, ByteReader, Deserializable, DeserializationError, Serializable, SliceReader,
     },
     Felt, FieldElement, Operation,
 };
@@ -11,6 +12,9 @@ use core::fmt;
 pub mod blocks;
 use blocks::CodeBlock;
 
+mod disassembly;
+mod serialization;
+
 // PROGRAM
 // ================================================================================================
 /// A program which can be executed by the VM.
@@ -18,11 +22,19 @@ use blocks::CodeBlock;
 /// A program is described by a Merkelized Abstract Syntax Tree (MAST), where each node is a
 /// [CodeBlock]. Internal nodes describe control flow semantics of the program, while leaf nodes
 /// contain linear sequences of instructions which contain no control flow.
+///
+/// A program may also carry metadata: procedure names, named procedures, library hashes, and
+/// library procedures. Metadata does not affect the hash of the program and is not included in
+/// the serialized form of the program.
 #[derive(Clone, Debug)]
 pub struct Program {
     root: CodeBlock,
     kernel: Kernel,
     cb_table: CodeBlockTable,
+    proc_names: BTreeMap<[u8; 32], String>,
+    named_procs: BTreeMap<String, CodeBlock>,
+    library_hashes: BTreeMap<String, String>,
+    library_procs: BTreeSet<String>,
 }
 
 impl Program {
@@ -39,9 +51,97 @@ impl Program {
             root,
             kernel,
             cb_table,
+            proc_names: BTreeMap::new(),
+            named_procs: BTreeMap::new(),
+            library_hashes: BTreeMap::new(),
+            library_procs: BTreeSet::new(),
         }
     }
 
+    /// Returns a program which executes the specified programs one after another.
+    ///
+    /// The root of the resulting program is a sequence of JOIN blocks combining the roots of the
+    /// specified programs, and its code block table (as well as its procedure names and library
+    /// metadata) is the union of the code block tables of the specified programs. No operations
+    /// are executed between the programs: each program starts with the stack, memory, and advice
+    /// inputs left by the previous program. The hash of the resulting program is different from
+    /// the hashes of the chained programs, and is fully determined by them.
+    ///
+    /// # Panics
+    /// Panics if no programs are specified, or if the programs use different kernels.
+    pub fn chain(programs: &[&Program]) -> Self {
+        let (first, rest) = programs.split_first().expect("no programs to chain");
+
+        let mut root = first.root.clone();
+        let mut cb_table = first.cb_table.clone();
+        let mut proc_names = first.proc_names.clone();
+        let mut named_procs = first.named_procs.clone();
+        let mut library_hashes = first.library_hashes.clone();
+        let mut library_procs = first.library_procs.clone();
+        for program in rest {
+            assert_eq!(
+                first.kernel, program.kernel,
+                "chained programs must use the same kernel"
+            );
+            root = CodeBlock::new_join([root, program.root.clone()]);
+            cb_table.0.extend(program.cb_table.0.clone());
+            proc_names.extend(program.proc_names.clone());
+            named_procs.extend(program.named_procs.clone());
+            library_hashes.extend(program.library_hashes.clone());
+            library_procs.extend(program.library_procs.clone());
+        }
+
+        Self {
+            root,
+            kernel: first.kernel.clone(),
+            cb_table,
+            proc_names,
+            named_procs,
+            library_hashes,
+            library_procs,
+        }
+    }
+
+    /// Attaches the specified procedure names to this program as debug symbols.
+    pub fn with_procedure_names<I>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = (Digest, String)>,
+    {
+        self.proc_names
+            .extend(names.into_iter().map(|(hash, name)| (hash.into(), name)));
+        self
+    }
+
+    /// Records the procedures compiled for this program or invoked by it as (name, procedure
+    /// body) pairs.
+    pub fn with_named_procedures<I>(mut self, procs: I) -> Self
+    where
+        I: IntoIterator<Item = (String, CodeBlock)>,
+    {
+        self.named_procs.extend(procs);
+        self
+    }
+
+    /// Records the content hashes of the libraries used by this program as (root namespace,
+    /// content hash) pairs.
+    pub fn with_library_hashes<I>(mut self, hashes: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        self.library_hashes.extend(hashes);
+        self
+    }
+
+    /// Records the fully qualified paths (e.g., `std::math::u64::checked_add`) of the library
+    /// procedures which may be executed by this program.
+    pub fn with_library_procedures<I>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        self.library_procs.extend(paths);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -64,6 +164,109 @@ impl Program {
     pub fn cb_table(&self) -> &CodeBlockTable {
         &self.cb_table
     }
+
+    /// Returns hashes of all procedures stored in the code block table of this program (i.e.,
+    /// procedures invoked via `call` instructions).
+    ///
+    /// The returned hashes are always sorted by their byte representation. Thus, compiling the
+    /// same source code multiple times yields procedure lists with identical ordering.
+    pub fn procedures(&self) -> Vec<Digest> {
+        self.cb_table.0.values().map(|block| block.hash()).collect()
+    }
+
+    /// Returns the name of the procedure with the specified hash, or None if the name of the
+    /// procedure is not known.
+    ///
+    /// Procedure names are available only for programs compiled with debug symbols.
+    pub fn procedure_name(&self, hash: Digest) -> Option<&str> {
+        self.proc_names
+            .get(&<[u8; 32]>::from(hash))
+            .map(|name| name.as_str())
+    }
+
+    /// Returns the hash of the procedure with the specified name, or None if no procedure with
+    /// such name is known.
+    ///
+    /// Hashes are known for all local procedures of the program (named by their names, e.g.,
+    /// `foo`), and for all library procedures which may be executed by the program (named by their
+    /// fully qualified paths, e.g., `std::math::u64::checked_add`), regardless of whether they are
+    /// invoked via `exec` or `call` instructions, and regardless of debug symbols.
+    pub fn procedure_hash(&self, name: &str) -> Option<Digest> {
+        self.named_procedure(name).map(|body| body.hash())
+    }
+
+    /// Returns the body of the procedure with the specified name, or None if no procedure with
+    /// such name is known; see [Program::procedure_hash()] for the procedures which are known.
+    pub fn named_procedure(&self, name: &str) -> Option<&CodeBlock> {
+        self.named_procs.get(name)
+    }
+
+    /// Returns the content hash of the library with the specified root namespace (e.g., `std`)
+    /// against which this program was compiled, or None if the program does not use any
+    /// procedures of the library or the content hash of the library is not known.
+    pub fn library_hash(&self, root_ns: &str) -> Option<&str> {
+        self.library_hashes.get(root_ns).map(|hash| hash.as_str())
+    }
+
+    /// Returns the fully qualified paths of the standard library procedures which may be executed
+    /// by this program, sorted alphabetically.
+    ///
+    /// The list includes procedures invoked via both `exec` and `call` instructions, as well as
+    /// procedures invoked indirectly (i.e., by other library procedures).
+    pub fn stdlib_dependencies(&self) -> Vec<String> {
+        self.library_procs
+            .iter()
+            .filter(|path| path.starts_with("std::"))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the call graph of this program.
+    ///
+    /// The call graph contains an entry for the program root and for each procedure in the code
+    /// block table. Each entry consists of the hash of the caller and a list of hashes of
+    /// procedures invoked by the caller via `call` or `syscall` instructions.
+    ///
+    /// The ordering of the call graph is deterministic: the first entry is always the program
+    /// root, the remaining entries are sorted by hashes of the callers (same as in
+    /// [Program::procedures()]), and the list of callees in each entry is deduplicated and
+    /// sorted by callee hashes.
+    pub fn call_graph(&self) -> Vec<(Digest, Vec<Digest>)> {
+        let mut result = Vec::with_capacity(self.cb_table.0.len() + 1);
+        for block in core::iter::once(&self.root).chain(self.cb_table.0.values()) {
+            let mut callees = BTreeMap::new();
+            collect_callees(block, &mut callees);
+            result.push((block.hash(), callees.into_values().collect()));
+        }
+        result
+    }
+
+    // SERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this program into a vector of bytes.
+    ///
+    /// The resulting bytes contain the full MAST of the program (including the code block table
+    /// and the kernel), and thus, the program can be executed after being deserialized via
+    /// [Program::from_bytes()] without having to be recompiled.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        Serializable::to_bytes(self)
+    }
+
+    /// Deserializes a program from the provided bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes do not represent a valid program, if the hash of the
+    /// deserialized program does not match the hash recorded in the bytes, or if not all of the
+    /// provided bytes were consumed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let program = Self::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(program)
+    }
 }
 
 impl fmt::Display for Program {
@@ -147,3 +350,26 @@ impl Kernel {
         &self.0
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Adds hashes of all functions invoked via call blocks reachable from the provided block to the
+/// specified map. Blocks in the code block table referenced by the call blocks are not traversed.
+fn collect_callees(block: &CodeBlock, callees: &mut BTreeMap<[u8; 32], Digest>) {
+    match block {
+        CodeBlock::Join(block) => {
+            collect_callees(block.first(), callees);
+            collect_callees(block.second(), callees);
+        }
+        CodeBlock::Split(block) => {
+            collect_callees(block.on_true(), callees);
+            collect_callees(block.on_false(), callees);
+        }
+        CodeBlock::Loop(block) => collect_callees(block.body(), callees),
+        CodeBlock::Call(block) => {
+            callees.insert(block.fn_hash().into(), block.fn_hash());
+        }
+        CodeBlock::Span(_) | CodeBlock::Proxy(_) => (),
+    }
+}
@@ -0,0 +1,492 @@
+use super::{
+    blocks::CodeBlock, hasher::Digest, BTreeMap, CodeBlockTable, Felt, Kernel, Operation, Program,
+    Vec,
+};
+use crate::{
+    utils::{
+        string::{String, ToString},
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+    },
+    AdviceInjector, AssemblyOp, Decorator, DecoratorList, EnvValue, SourceLocation, ALL_OPERATIONS,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Bytes at the start of every serialized program.
+const MAGIC: &[u8; 4] = b"MASB";
+
+/// Version of the serialization format.
+const VERSION: u8 = 1;
+
+const SPAN: u8 = 0;
+const JOIN: u8 = 1;
+const SPLIT: u8 = 2;
+const LOOP: u8 = 3;
+const CALL: u8 = 4;
+const SYSCALL: u8 = 5;
+const PROXY: u8 = 6;
+
+/// The maximum depth of the code block tree of a deserialized code block; code blocks are
+/// deserialized recursively, and thus, the depth is limited to make sure that crafted inputs
+/// cannot overflow the stack.
+const MAX_BLOCK_DEPTH: usize = 256;
+
+const ADVICE_DECORATOR: u8 = 0;
+const ASMOP_DECORATOR: u8 = 1;
+
+const MERKLE_NODE_INJECTOR: u8 = 0;
+const DIV_RESULT_U64_INJECTOR: u8 = 1;
+const MAP_VALUE_INJECTOR: u8 = 2;
+const MEMORY_INJECTOR: u8 = 3;
+const ENV_INJECTOR: u8 = 4;
+const TAKE_MAP_VALUE_INJECTOR: u8 = 5;
+
+const BLOCK_NUMBER_ENV: u8 = 0;
+const TIMESTAMP_ENV: u8 = 1;
+
+// PROGRAM SERIALIZATION
+// ================================================================================================
+
+/// Programs are serialized as follows:
+/// - The `MASB` magic bytes followed by the format version.
+/// - Hash of the program; this is used to check the integrity of the program on deserialization.
+/// - Procedure hashes of the kernel.
+/// - Code blocks of the code block table.
+/// - The root code block of the program.
+///
+/// Hashes of code blocks are not serialized; instead, they are recomputed from the contents of
+/// the blocks on deserialization.
+impl Serializable for Program {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8_slice(MAGIC);
+        target.write_u8(VERSION);
+        write_digest(target, self.hash());
+
+        let proc_hashes = self.kernel.proc_hashes();
+        target.write_u32(proc_hashes.len() as u32);
+        proc_hashes
+            .iter()
+            .for_each(|&hash| write_digest(target, hash));
+
+        target.write_u32(self.cb_table.0.len() as u32);
+        self.cb_table
+            .0
+            .values()
+            .for_each(|block| write_block(target, block));
+
+        write_block(target, &self.root);
+    }
+}
+
+impl Deserializable for Program {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        if &source.read_u8_array::<4>()? != MAGIC {
+            return Err(invalid_value("not a serialized Miden program"));
+        }
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(invalid_value(format!(
+                "unsupported program format version {version}"
+            )));
+        }
+        let hash = read_digest(source)?;
+
+        let num_procs = source.read_u32()? as usize;
+        let proc_hashes = (0..num_procs)
+            .map(|_| read_digest(source))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let num_blocks = source.read_u32()? as usize;
+        let mut cb_table = CodeBlockTable(BTreeMap::new());
+        for _ in 0..num_blocks {
+            cb_table.insert(read_block(source, 0)?);
+        }
+
+        let root = read_block(source, 0)?;
+        if root.hash() != hash {
+            return Err(invalid_value(
+                "program hash does not match the program contents",
+            ));
+        }
+
+        Ok(Program::with_kernel(
+            root,
+            Kernel::new(&proc_hashes),
+            cb_table,
+        ))
+    }
+}
+
+// CODE BLOCKS
+// ================================================================================================
+
+fn write_block<W: ByteWriter>(target: &mut W, block: &CodeBlock) {
+    match block {
+        CodeBlock::Span(span) => {
+            target.write_u8(SPAN);
+            let ops = span
+                .op_batches()
+                .iter()
+                .flat_map(|batch| batch.ops())
+                .collect::<Vec<_>>();
+            target.write_u32(ops.len() as u32);
+            ops.into_iter().for_each(|op| write_op(target, op));
+
+            target.write_u32(span.decorators().len() as u32);
+            for (op_idx, decorator) in span.decorators() {
+                target.write_u32(*op_idx as u32);
+                write_decorator(target, decorator);
+            }
+        }
+        CodeBlock::Join(block) => {
+            target.write_u8(JOIN);
+            write_block(target, block.first());
+            write_block(target, block.second());
+        }
+        CodeBlock::Split(block) => {
+            target.write_u8(SPLIT);
+            write_block(target, block.on_true());
+            write_block(target, block.on_false());
+        }
+        CodeBlock::Loop(block) => {
+            target.write_u8(LOOP);
+            write_block(target, block.body());
+        }
+        CodeBlock::Call(block) => {
+            target.write_u8(if block.is_syscall() { SYSCALL } else { CALL });
+            write_digest(target, block.fn_hash());
+        }
+        CodeBlock::Proxy(block) => {
+            target.write_u8(PROXY);
+            write_digest(target, block.hash());
+        }
+    }
+}
+
+/// Reads a code block located at the specified depth of the code block tree.
+fn read_block<R: ByteReader>(
+    source: &mut R,
+    depth: usize,
+) -> Result<CodeBlock, DeserializationError> {
+    if depth == MAX_BLOCK_DEPTH {
+        return Err(invalid_value(format!(
+            "code blocks cannot be nested more than {MAX_BLOCK_DEPTH} levels deep"
+        )));
+    }
+
+    match source.read_u8()? {
+        SPAN => {
+            let num_ops = source.read_u32()? as usize;
+            if num_ops == 0 {
+                return Err(invalid_value("span block contains no operations"));
+            }
+            let ops = (0..num_ops)
+                .map(|_| read_op(source))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let num_decorators = source.read_u32()? as usize;
+            let mut decorators = DecoratorList::new();
+            for _ in 0..num_decorators {
+                let op_idx = source.read_u32()? as usize;
+                if op_idx >= num_ops || decorators.last().map_or(false, |(i, _)| op_idx < *i) {
+                    return Err(invalid_value("invalid decorator position in a span block"));
+                }
+                decorators.push((op_idx, read_decorator(source)?));
+            }
+
+            Ok(CodeBlock::new_span_with_decorators(ops, decorators))
+        }
+        JOIN => {
+            let first = read_block(source, depth + 1)?;
+            let second = read_block(source, depth + 1)?;
+            Ok(CodeBlock::new_join([first, second]))
+        }
+        SPLIT => {
+            let on_true = read_block(source, depth + 1)?;
+            let on_false = read_block(source, depth + 1)?;
+            Ok(CodeBlock::new_split(on_true, on_false))
+        }
+        LOOP => Ok(CodeBlock::new_loop(read_block(source, depth + 1)?)),
+        CALL => Ok(CodeBlock::new_call(read_digest(source)?)),
+        SYSCALL => Ok(CodeBlock::new_syscall(read_digest(source)?)),
+        PROXY => Ok(CodeBlock::new_proxy(read_digest(source)?)),
+        tag => Err(invalid_value(format!("invalid code block tag {tag}"))),
+    }
+}
+
+// OPERATIONS AND DECORATORS
+// ================================================================================================
+
+fn write_op<W: ByteWriter>(target: &mut W, op: &Operation) {
+    target.write_u8(op.op_code());
+    match op {
+        Operation::Push(value) => value.write_into(target),
+        Operation::MrUpdate(copy) => target.write_u8(*copy as u8),
+        _ => (),
+    }
+}
+
+fn read_op<R: ByteReader>(source: &mut R) -> Result<Operation, DeserializationError> {
+    let op_code = source.read_u8()?;
+    if op_code == Operation::Push(Felt::default()).op_code() {
+        Ok(Operation::Push(Felt::read_from(source)?))
+    } else if op_code == Operation::MrUpdate(false).op_code() {
+        Ok(Operation::MrUpdate(read_bool(source)?))
+    } else {
+        ALL_OPERATIONS
+            .iter()
+            .find(|op| op.op_code() == op_code)
+            .copied()
+            .ok_or_else(|| invalid_value(format!("invalid opcode {op_code}")))
+    }
+}
+
+fn write_decorator<W: ByteWriter>(target: &mut W, decorator: &Decorator) {
+    match decorator {
+        Decorator::Advice(injector) => {
+            target.write_u8(ADVICE_DECORATOR);
+            match injector {
+                AdviceInjector::MerkleNode => target.write_u8(MERKLE_NODE_INJECTOR),
+                AdviceInjector::DivResultU64 => target.write_u8(DIV_RESULT_U64_INJECTOR),
+                AdviceInjector::MapValue => target.write_u8(MAP_VALUE_INJECTOR),
+                AdviceInjector::TakeMapValue => target.write_u8(TAKE_MAP_VALUE_INJECTOR),
+                AdviceInjector::Memory(start_addr, num_words) => {
+                    target.write_u8(MEMORY_INJECTOR);
+                    target.write_u32(*start_addr);
+                    target.write_u32(*num_words);
+                }
+                AdviceInjector::Env(value) => {
+                    target.write_u8(ENV_INJECTOR);
+                    match value {
+                        EnvValue::BlockNumber => target.write_u8(BLOCK_NUMBER_ENV),
+                        EnvValue::Timestamp => target.write_u8(TIMESTAMP_ENV),
+                    }
+                }
+            }
+        }
+        Decorator::AsmOp(assembly_op) => {
+            target.write_u8(ASMOP_DECORATOR);
+            let op = assembly_op.op().as_bytes();
+            target.write_u32(op.len() as u32);
+            target.write_u8_slice(op);
+            target.write_u8(assembly_op.num_cycles());
+            match assembly_op.location() {
+                Some(location) => {
+                    target.write_u8(1);
+                    target.write_u32(location.line());
+                    target.write_u32(location.column());
+                }
+                None => target.write_u8(0),
+            }
+        }
+    }
+}
+
+fn read_decorator<R: ByteReader>(source: &mut R) -> Result<Decorator, DeserializationError> {
+    match source.read_u8()? {
+        ADVICE_DECORATOR => {
+            let injector = match source.read_u8()? {
+                MERKLE_NODE_INJECTOR => AdviceInjector::MerkleNode,
+                DIV_RESULT_U64_INJECTOR => AdviceInjector::DivResultU64,
+                MAP_VALUE_INJECTOR => AdviceInjector::MapValue,
+                TAKE_MAP_VALUE_INJECTOR => AdviceInjector::TakeMapValue,
+                MEMORY_INJECTOR => {
+                    let start_addr = source.read_u32()?;
+                    let num_words = source.read_u32()?;
+                    AdviceInjector::Memory(start_addr, num_words)
+                }
+                ENV_INJECTOR => match source.read_u8()? {
+                    BLOCK_NUMBER_ENV => AdviceInjector::Env(EnvValue::BlockNumber),
+                    TIMESTAMP_ENV => AdviceInjector::Env(EnvValue::Timestamp),
+                    tag => {
+                        return Err(invalid_value(format!(
+                            "invalid environment value tag {tag}"
+                        )))
+                    }
+                },
+                tag => return Err(invalid_value(format!("invalid advice injector tag {tag}"))),
+            };
+            Ok(Decorator::Advice(injector))
+        }
+        ASMOP_DECORATOR => {
+            let len = source.read_u32()? as usize;
+            let op = String::from_utf8(source.read_u8_vec(len)?)
+                .map_err(|_| invalid_value("assembly instruction is not valid UTF-8"))?;
+            let num_cycles = source.read_u8()?;
+            let assembly_op = AssemblyOp::new(op, num_cycles);
+            match source.read_u8()? {
+                0 => Ok(Decorator::AsmOp(assembly_op)),
+                1 => {
+                    let line = source.read_u32()?;
+                    let column = source.read_u32()?;
+                    let location = SourceLocation::new(line, column);
+                    Ok(Decorator::AsmOp(assembly_op.with_location(location)))
+                }
+                flag => Err(invalid_value(format!(
+                    "invalid source location flag {flag}"
+                ))),
+            }
+        }
+        tag => Err(invalid_value(format!("invalid decorator tag {tag}"))),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the digest as a sequence of 4 field elements in their canonical representation.
+fn write_digest<W: ByteWriter>(target: &mut W, digest: Digest) {
+    digest
+        .as_elements()
+        .iter()
+        .for_each(|element| element.write_into(target));
+}
+
+fn read_digest<R: ByteReader>(source: &mut R) -> Result<Digest, DeserializationError> {
+    let mut elements = [Felt::default(); 4];
+    for element in elements.iter_mut() {
+        *element = Felt::read_from(source)?;
+    }
+    Ok(Digest::new(elements))
+}
+
+fn read_bool<R: ByteReader>(source: &mut R) -> Result<bool, DeserializationError> {
+    match source.read_u8()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        value => Err(invalid_value(format!("invalid boolean value {value}"))),
+    }
+}
+
+fn invalid_value<S: ToString>(msg: S) -> DeserializationError {
+    DeserializationError::InvalidValue(msg.to_string())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AdviceInjector, AssemblyOp, CodeBlock, CodeBlockTable, Decorator, DeserializationError,
+        EnvValue, Felt, Kernel, Operation, Program, SourceLocation, ALL_OPERATIONS, LOOP,
+        MAX_BLOCK_DEPTH,
+    };
+
+    #[test]
+    fn program_round_trip() {
+        let decorators = vec![
+            (0, Decorator::Advice(AdviceInjector::Memory(7, 2))),
+            (
+                1,
+                Decorator::Advice(AdviceInjector::Env(EnvValue::Timestamp)),
+            ),
+            (
+                1,
+                Decorator::AsmOp(AssemblyOp::new("push.3".to_string(), 1)),
+            ),
+            (
+                2,
+                Decorator::AsmOp(
+                    AssemblyOp::new("mtree_set".to_string(), 1)
+                        .with_location(SourceLocation::new(3, 9)),
+                ),
+            ),
+        ];
+        let span = CodeBlock::new_span_with_decorators(
+            vec![
+                Operation::Pad,
+                Operation::Push(Felt::new(3)),
+                Operation::MrUpdate(true),
+            ],
+            decorators,
+        );
+        let callee = CodeBlock::new_span(vec![Operation::Add]);
+        let body = CodeBlock::new_join([
+            CodeBlock::new_split(span, CodeBlock::new_call(callee.hash())),
+            CodeBlock::new_loop(CodeBlock::new_syscall(callee.hash())),
+        ]);
+        let root = CodeBlock::new_join([body, CodeBlock::new_proxy(callee.hash())]);
+
+        let mut cb_table = CodeBlockTable::default();
+        cb_table.insert(callee.clone());
+        let program = Program::with_kernel(root, Kernel::new(&[callee.hash()]), cb_table);
+
+        let bytes = program.to_bytes();
+        let deserialized = Program::from_bytes(&bytes).unwrap();
+        assert_eq!(program.hash(), deserialized.hash());
+        assert_eq!(program.kernel(), deserialized.kernel());
+        assert!(deserialized.cb_table().has(callee.hash()));
+        assert_eq!(bytes, deserialized.to_bytes());
+
+        // trailing bytes are rejected
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert_eq!(
+            Err(DeserializationError::UnconsumedBytes),
+            Program::from_bytes(&extended).map(|_| ())
+        );
+
+        // tampering with the program is detected
+        let mut tampered = bytes;
+        let last = tampered.len() - 1;
+        tampered[last] ^= 1;
+        assert!(Program::from_bytes(&tampered).is_err());
+    }
+
+    #[test]
+    fn operations_round_trip() {
+        // operations are identified by their opcodes, and thus, every operation must have a
+        // distinct opcode
+        for (i, op) in ALL_OPERATIONS.iter().enumerate() {
+            assert!(
+                ALL_OPERATIONS[..i]
+                    .iter()
+                    .all(|other| other.op_code() != op.op_code()),
+                "opcode of {op} is not unique"
+            );
+        }
+
+        // control flow operations cannot be placed into spans, and thus, are not included
+        let ops = ALL_OPERATIONS
+            .into_iter()
+            .filter(|op| !op.is_control_op())
+            .collect::<Vec<_>>();
+        let program = Program::new(CodeBlock::new_span(ops));
+        let deserialized = Program::from_bytes(&program.to_bytes()).unwrap();
+        assert_eq!(program.hash(), deserialized.hash());
+    }
+
+    #[test]
+    fn program_nesting_depth() {
+        let nested = |depth: usize| {
+            let mut root = CodeBlock::new_span(vec![Operation::Noop]);
+            for _ in 1..depth {
+                root = CodeBlock::new_loop(root);
+            }
+            Program::new(root)
+        };
+
+        // programs with code block trees up to the maximum depth can be deserialized
+        let program = nested(MAX_BLOCK_DEPTH);
+        let deserialized = Program::from_bytes(&program.to_bytes()).unwrap();
+        assert_eq!(program.hash(), deserialized.hash());
+
+        // but deeper trees are rejected
+        let bytes = nested(MAX_BLOCK_DEPTH + 1).to_bytes();
+        assert!(matches!(
+            Program::from_bytes(&bytes),
+            Err(DeserializationError::InvalidValue(_))
+        ));
+
+        // including crafted inputs which would otherwise be read until the end of the input
+        let root_pos = bytes.windows(8).position(|w| w == [LOOP; 8]).unwrap();
+        let mut crafted = bytes[..root_pos].to_vec();
+        crafted.resize(crafted.len() + 1_000_000, LOOP);
+        assert!(matches!(
+            Program::from_bytes(&crafted),
+            Err(DeserializationError::InvalidValue(_))
+        ));
+    }
+}
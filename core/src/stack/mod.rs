@@ -8,6 +8,11 @@ pub const STACK_TOP_OFFSET: usize = 0;
 
 /// The number of stack registers which can be accessed by the VM directly. This is also the
 /// minimum stack depth enforced by the VM.
+///
+/// This value is not configurable: instruction encodings (e.g., `movup.15`, `swapw.3`,
+/// `swapdw`), the layout of the stack trace, the hasher and memory stream operations, and the
+/// AIR constraints for shifting the stack all assume exactly 16 directly accessible registers.
+/// Items beyond the top 16 are kept in the stack overflow table.
 pub const STACK_TOP_SIZE: usize = 16;
 
 /// Location of stack top items in the stack trace.
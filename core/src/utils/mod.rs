@@ -1,4 +1,4 @@
-use super::{Felt, StarkField};
+use super::{errors::InputError, Felt, FieldElement, StarkField};
 use core::{fmt::Debug, ops::Range};
 use winter_utils::collections::Vec;
 
@@ -11,6 +11,12 @@ pub use alloc::boxed::Box;
 #[cfg(feature = "std")]
 pub use std::boxed::Box;
 
+#[cfg(not(feature = "std"))]
+pub use alloc::sync::Arc;
+
+#[cfg(feature = "std")]
+pub use std::sync::Arc;
+
 // RE-EXPORTS
 // ================================================================================================
 
@@ -64,6 +70,106 @@ impl IntoBytes<32> for [Felt; 4] {
     }
 }
 
+// EXPONENTIATION
+// ================================================================================================
+
+/// Defines exponentiation of a field element by another field element.
+///
+/// [FieldElement::exp()] accepts only integer exponents; this trait allows the exponent to be
+/// specified as a field element, in which case the canonical integer value of the exponent is
+/// used.
+pub trait ExpByElement {
+    fn exp_by_element(self, exp: Felt) -> Self;
+}
+
+impl ExpByElement for Felt {
+    fn exp_by_element(self, exp: Felt) -> Self {
+        self.exp(exp.as_int())
+    }
+}
+
+// CHECKED DIVISION
+// ================================================================================================
+
+/// Defines division of a field element by another field element which detects division by ZERO.
+///
+/// Dividing a field element by ZERO via the `/` operator does not fail; instead, the result is
+/// ZERO, since inverse of ZERO is defined to be ZERO. This trait allows callers to handle such
+/// division explicitly.
+pub trait CheckedDiv: Sized {
+    /// Returns the result of dividing `self` by `rhs`, or None if `rhs` is ZERO.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+impl CheckedDiv for Felt {
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs == Felt::ZERO {
+            None
+        } else {
+            Some(self / rhs)
+        }
+    }
+}
+
+// FELT BITS
+// ================================================================================================
+
+/// A field element viewed as a vector of the 64 bits of its canonical integer representation.
+///
+/// This is the representation in which the VM exposes bits of field elements (e.g., via the
+/// `u32split` instruction); bit 0 is the least significant bit. Since the field modulus is smaller
+/// than 2^64, not every combination of bits is a valid field element; modifications which would
+/// result in such a combination are rejected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeltBits(Felt);
+
+impl FeltBits {
+    /// Number of bits in a field element.
+    pub const NUM_BITS: usize = 64;
+
+    /// Returns a bit vector view of the specified field element.
+    pub fn new(value: Felt) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value of the bit at the specified index.
+    ///
+    /// # Panics
+    /// Panics if the index is greater than or equal to 64.
+    pub fn get_bit(&self, idx: usize) -> bool {
+        assert!(idx < Self::NUM_BITS, "bit index {idx} is out of bounds");
+        (self.0.as_int() >> idx) & 1 == 1
+    }
+
+    /// Sets the bit at the specified index to the specified value.
+    ///
+    /// # Errors
+    /// Returns an error if the resulting integer would not be smaller than the field modulus; in
+    /// such a case, this bit vector is left unchanged.
+    ///
+    /// # Panics
+    /// Panics if the index is greater than or equal to 64.
+    pub fn set_bit(&mut self, idx: usize, value: bool) -> Result<(), InputError> {
+        assert!(idx < Self::NUM_BITS, "bit index {idx} is out of bounds");
+        let bits = if value {
+            self.0.as_int() | (1 << idx)
+        } else {
+            self.0.as_int() & !(1 << idx)
+        };
+
+        if bits >= Felt::MODULUS {
+            return Err(InputError::NotFieldElement(bits, "bit vector value"));
+        }
+        self.0 = Felt::new(bits);
+        Ok(())
+    }
+
+    /// Returns the field element represented by this bit vector.
+    pub fn as_felt(&self) -> Felt {
+        self.0
+    }
+}
+
 // PUSH MANY
 // ================================================================================================
 
@@ -101,6 +207,66 @@ pub fn new_array_vec<T: Debug, const N: usize>(capacity: usize) -> [Vec<T>; N] {
         .expect("failed to convert vector to array")
 }
 
+#[test]
+fn exp_by_element() {
+    // small exponents should match repeated multiplication
+    let base = Felt::new(7);
+    let mut expected = Felt::ONE;
+    for i in 0..20_u64 {
+        assert_eq!(expected, base.exp_by_element(Felt::new(i)));
+        expected *= base;
+    }
+
+    // raising a non-zero element to p - 1 should result in one
+    let exp = Felt::new(Felt::MODULUS - 1);
+    for value in [1, 2, 3, 7, u32::MAX as u64, Felt::MODULUS - 1] {
+        assert_eq!(Felt::ONE, Felt::new(value).exp_by_element(exp));
+    }
+    assert_eq!(Felt::ZERO, Felt::ZERO.exp_by_element(exp));
+}
+
+#[test]
+fn checked_div() {
+    let a = Felt::new(42);
+    let b = Felt::new(7);
+    assert_eq!(Some(Felt::new(6)), a.checked_div(b));
+
+    // the quotient of non-divisible values satisfies q * b = a
+    let q = Felt::new(43).checked_div(b).unwrap();
+    assert_eq!(Felt::new(43), q * b);
+
+    // dividing by ZERO returns None, while dividing ZERO returns ZERO
+    assert_eq!(None, a.checked_div(Felt::ZERO));
+    assert_eq!(None, Felt::ZERO.checked_div(Felt::ZERO));
+    assert_eq!(Some(Felt::ZERO), Felt::ZERO.checked_div(b));
+}
+
+#[test]
+fn felt_bits() {
+    let mut bits = FeltBits::new(Felt::new(0b1010));
+    assert!(!bits.get_bit(0));
+    assert!(bits.get_bit(1));
+    assert!(bits.get_bit(3));
+    assert!(!bits.get_bit(63));
+
+    bits.set_bit(0, true).unwrap();
+    bits.set_bit(3, false).unwrap();
+    bits.set_bit(40, true).unwrap();
+    assert_eq!(Felt::new((1 << 40) | 0b0011), bits.as_felt());
+    assert!(bits.get_bit(40));
+
+    // the modulus is 2^64 - 2^32 + 1; thus, setting all of the upper 32 bits is possible only if
+    // all of the lower 32 bits are zeros
+    let mut bits = FeltBits::new(Felt::new(Felt::MODULUS - 1));
+    assert_eq!(0xffffffff00000000, bits.as_felt().as_int());
+    assert!(bits.set_bit(0, true).is_err());
+    assert_eq!(Felt::new(Felt::MODULUS - 1), bits.as_felt());
+
+    bits.set_bit(32, false).unwrap();
+    bits.set_bit(0, true).unwrap();
+    assert_eq!(0xfffffffe00000001, bits.as_felt().as_int());
+}
+
 #[test]
 #[should_panic]
 fn debug_assert_is_checked() {
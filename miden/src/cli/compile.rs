@@ -1,7 +1,8 @@
-use super::data::ProgramFile;
+use super::data::{InputFile, ProgramFile};
 use crypto::Digest;
 use std::path::PathBuf;
 use structopt::StructOpt;
+use vm_core::{Program, ProgramInputs};
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Compile", about = "Compile a miden program")]
@@ -9,6 +10,16 @@ pub struct CompileCmd {
     /// Path to .masm assembly file
     #[structopt(short = "a", long = "assembly", parse(from_os_str))]
     assembly_file: PathBuf,
+    /// Path to the output file for the compiled program; the program is not saved if omitted
+    #[structopt(short = "o", long = "output", parse(from_os_str))]
+    output_file: Option<PathBuf>,
+    /// Apply peephole optimizations to the compiled program; this changes the program hash
+    #[structopt(long = "optimize")]
+    optimize: bool,
+    /// Path to the input file used to execute the program when reporting the cycle reduction
+    /// achieved by optimizations
+    #[structopt(short = "i", long = "input", parse(from_os_str))]
+    input_file: Option<PathBuf>,
 }
 
 impl CompileCmd {
@@ -18,11 +29,47 @@ impl CompileCmd {
         println!("============================================================");
 
         // load and compile program file
-        let program = ProgramFile::read(&self.assembly_file)?;
+        let program = if self.optimize {
+            if ProgramFile::is_binary(&self.assembly_file) {
+                return Err("Optimizations can be applied only to .masm assembly files".into());
+            }
+
+            // compile the program with and without optimizations, and execute both versions to
+            // report the reduction in the number of cycles
+            let source = ProgramFile::read_source(&self.assembly_file)?;
+            let inputs = InputFile::read(&self.input_file, &self.assembly_file)?;
+            let inputs = inputs.get_program_inputs();
+            let num_cycles = count_cycles(&ProgramFile::compile(&source, false)?, &inputs)?;
+            let program = ProgramFile::compile(&source, true)?;
+            let num_optimized_cycles = count_cycles(&program, &inputs)?;
+            println!(
+                "optimizations reduced execution from {} to {} cycles",
+                num_cycles, num_optimized_cycles
+            );
+            program
+        } else {
+            ProgramFile::read(&self.assembly_file)?
+        };
 
         // report program hash to user
         println!("program hash is {}", hex::encode(program.hash().as_bytes()));
 
+        // save the compiled program if an output file was specified
+        if let Some(output_path) = &self.output_file {
+            ProgramFile::write_binary(&program, output_path)?;
+        }
+
         Ok(())
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of cycles it takes to execute the specified program against the specified
+/// inputs.
+fn count_cycles(program: &Program, inputs: &ProgramInputs) -> Result<usize, String> {
+    processor::execute(program, inputs)
+        .map(|trace| trace.num_cycles())
+        .map_err(|err| format!("Failed to execute program to count cycles = {:?}", err))
+}
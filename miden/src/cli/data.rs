@@ -1,4 +1,4 @@
-use miden::Assembler;
+use miden::{proof_from_bytes, proof_to_bytes, Assembler};
 use prover::StarkProof;
 use serde_derive::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -8,6 +8,12 @@ use vm_core::ProgramOutputs;
 use vm_core::{chiplets::hasher::Digest, Program, ProgramInputs};
 use winter_utils::{Deserializable, SliceReader};
 
+// CONSTANTS
+// ================================================================================================
+
+/// Extension of files containing compiled programs.
+const BINARY_EXTENSION: &str = "masb";
+
 // INPUT FILE
 // ================================================================================================
 
@@ -169,26 +175,99 @@ pub struct ProgramFile;
 
 /// Helper methods to interact with masm program file
 impl ProgramFile {
+    /// Reads a program from the specified file.
+    ///
+    /// If the file has a `.masb` extension, it is expected to contain a compiled program
+    /// serialized via [Program::to_bytes()]; otherwise, the file is expected to contain Miden
+    /// assembly source code, which is compiled into a program.
     pub fn read(path: &PathBuf) -> Result<Program, String> {
+        if Self::is_binary(path) {
+            return Self::read_binary(path);
+        }
+
+        let source = Self::read_source(path)?;
+        Self::compile(&source, false)
+    }
+
+    /// Returns true if the specified file is expected to contain a compiled program.
+    pub fn is_binary(path: &Path) -> bool {
+        path.extension()
+            .map_or(false, |ext| ext == BINARY_EXTENSION)
+    }
+
+    /// Reads Miden assembly source code from the specified file.
+    pub fn read_source(path: &PathBuf) -> Result<String, String> {
         println!("Reading program file `{}`", path.display());
 
-        // read program file to string
-        let program_file = fs::read_to_string(&path)
-            .map_err(|err| format!("Failed to open program file `{}` - {}", path.display(), err))?;
+        fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to open program file `{}` - {}", path.display(), err))
+    }
+
+    /// Compiles the provided Miden assembly source code into a program, applying peephole
+    /// optimizations if `with_optimizations` is set to true.
+    pub fn compile(source: &str, with_optimizations: bool) -> Result<Program, String> {
+        if with_optimizations {
+            print!("Compiling program with optimizations... ");
+        } else {
+            print!("Compiling program... ");
+        }
+        let now = Instant::now();
+
+        // compile program
+        let program = Assembler::new()
+            .with_module_provider(StdLibrary::default())
+            .with_optimizations(with_optimizations)
+            .compile(source)
+            .map_err(|err| format!("Failed to compile program - {}", err))?;
+
+        println!("done ({} ms)", now.elapsed().as_millis());
 
-        print!("Compiling program... ");
+        Ok(program)
+    }
+
+    /// Compiles the provided Miden assembly source code into a program in debug mode and with debug
+    /// symbols, so that failures of the program can be traced back to the assembly instructions
+    /// and procedures they occurred in.
+    pub fn compile_with_debug_info(source: &str) -> Result<Program, String> {
+        print!("Compiling program with debug info... ");
         let now = Instant::now();
 
         // compile program
         let program = Assembler::new()
             .with_module_provider(StdLibrary::default())
-            .compile(&program_file)
+            .with_debug_mode(true)
+            .with_debug_symbols(true)
+            .compile(source)
             .map_err(|err| format!("Failed to compile program - {}", err))?;
 
         println!("done ({} ms)", now.elapsed().as_millis());
 
         Ok(program)
     }
+
+    /// Writes the compiled program to the specified file in binary format.
+    pub fn write_binary(program: &Program, path: &PathBuf) -> Result<(), String> {
+        println!("Writing compiled program to `{}`", path.display());
+
+        fs::write(path, program.to_bytes()).map_err(|err| {
+            format!(
+                "Failed to write compiled program to `{}` - {}",
+                path.display(),
+                err
+            )
+        })
+    }
+
+    /// Reads a compiled program from the specified binary file.
+    fn read_binary(path: &PathBuf) -> Result<Program, String> {
+        println!("Reading compiled program file `{}`", path.display());
+
+        let bytes = fs::read(path)
+            .map_err(|err| format!("Failed to open program file `{}` - {}", path.display(), err))?;
+
+        Program::from_bytes(&bytes)
+            .map_err(|err| format!("Failed to deserialize compiled program - {}", err))
+    }
 }
 
 // PROOF FILE
@@ -214,8 +293,7 @@ impl ProofFile {
             .map_err(|err| format!("Failed to open proof file `{}` - {}", path.display(), err))?;
 
         // deserialize bytes into a stark proof
-        StarkProof::from_bytes(&file)
-            .map_err(|err| format!("Failed to decode proof data - {}", err))
+        proof_from_bytes(&file).map_err(|err| format!("Failed to decode proof data - {}", err))
     }
 
     /// Write stark proof to file
@@ -237,7 +315,7 @@ impl ProofFile {
         let mut file = fs::File::create(&path)
             .map_err(|err| format!("Failed to create proof file `{}` - {}", path.display(), err))?;
 
-        let proof_bytes = proof.to_bytes();
+        let proof_bytes = proof_to_bytes(&proof);
 
         println!(
             "Writing data to proof file - size {} KB",
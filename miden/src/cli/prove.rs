@@ -9,7 +9,7 @@ use structopt::StructOpt;
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Prove", about = "Prove a miden program")]
 pub struct ProveCmd {
-    /// Path to .masm assembly file
+    /// Path to .masm assembly file or .masb compiled program file
     #[structopt(short = "a", long = "assembly", parse(from_os_str))]
     assembly_file: PathBuf,
     /// Path to input file
@@ -1,13 +1,15 @@
 use super::data::{InputFile, OutputFile, ProgramFile};
 use crypto::Digest;
+use processor::Process;
 use std::path::PathBuf;
 use std::time::Instant;
 use structopt::StructOpt;
+use vm_core::{OutputInterp, Program, ProgramInputs};
 
 #[derive(StructOpt, Debug)]
 #[structopt(name = "Run", about = "Run a miden program")]
 pub struct RunCmd {
-    /// Path to .masm assembly file
+    /// Path to .masm assembly file or .masb compiled program file
     #[structopt(short = "a", long = "assembly", parse(from_os_str))]
     assembly_file: PathBuf,
     /// Path to input file
@@ -19,6 +21,18 @@ pub struct RunCmd {
     /// Path to output file
     #[structopt(short = "o", long = "output", parse(from_os_str))]
     output_file: Option<PathBuf>,
+    /// Interpretation of the displayed outputs; values which cannot be interpreted as such are
+    /// displayed as raw field elements in parentheses
+    #[structopt(
+        long = "output-as",
+        default_value = "felt",
+        possible_values = &["u32", "i32", "hex", "felt"]
+    )]
+    output_as: OutputInterp,
+    /// Explain execution failures: report the failing instruction and its location in the
+    /// source code, the call stack, and the state of the stack at the time of failure
+    #[structopt(long = "explain-error")]
+    explain_error: bool,
 }
 
 impl RunCmd {
@@ -27,8 +41,15 @@ impl RunCmd {
         println!("Run program");
         println!("============================================================");
 
-        // load program from file and compile
-        let program = ProgramFile::read(&self.assembly_file)?;
+        // load program from file and compile; to explain failures, source programs are compiled
+        // with debug info, which does not affect the hash of the program
+        let (program, source) =
+            if self.explain_error && !ProgramFile::is_binary(&self.assembly_file) {
+                let source = ProgramFile::read_source(&self.assembly_file)?;
+                (ProgramFile::compile_with_debug_info(&source)?, Some(source))
+            } else {
+                (ProgramFile::read(&self.assembly_file)?, None)
+            };
 
         // load input data from file
         let input_data = InputFile::read(&self.input_file, &self.assembly_file)?;
@@ -40,22 +61,59 @@ impl RunCmd {
         let now = Instant::now();
 
         // execute program and generate outputs
-        let trace = processor::execute(&program, &input_data.get_program_inputs())
-            .map_err(|err| format!("Failed to generate exection trace = {:?}", err))?;
+        let inputs = input_data.get_program_inputs();
+        let trace = match processor::execute(&program, &inputs) {
+            Ok(trace) => trace,
+            Err(_) if self.explain_error => {
+                return Err(explain_error(&program, inputs, source.as_deref()))
+            }
+            Err(err) => return Err(format!("Failed to generate exection trace = {:?}", err)),
+        };
 
-        println!("done ({} ms)", now.elapsed().as_millis());
+        println!(
+            "done ({} cycles in {} ms)",
+            trace.num_cycles(),
+            now.elapsed().as_millis()
+        );
 
         if let Some(output_path) = &self.output_file {
             // write outputs to file if one was specified
             OutputFile::write(trace.program_outputs(), output_path)?;
         } else {
             // write the stack outputs to the screen.
-            println!(
-                "Output: {:?}",
-                trace.program_outputs().stack_outputs(self.num_outputs)
-            );
+            let outputs = trace
+                .program_outputs()
+                .stack_outputs(self.num_outputs)
+                .iter()
+                .map(|&value| self.output_as.format(value))
+                .collect::<Vec<_>>();
+            println!("Output: [{}]", outputs.join(", "));
         }
 
         Ok(())
     }
 }
+
+// ERROR EXPLANATION
+// ================================================================================================
+
+/// Returns a report explaining why executing the provided program against the provided inputs
+/// fails.
+///
+/// The program is re-executed in debug mode to capture the state of the VM at the failing cycle.
+/// If the source code of the program is available, the report also includes an excerpt of the
+/// source code pointing at the failing instruction.
+fn explain_error(program: &Program, inputs: ProgramInputs, source: Option<&str>) -> String {
+    let mut process = Process::new_debug(program.kernel(), inputs);
+    let err = match process.execute(program) {
+        Ok(_) => return "Failed to generate exection trace".to_string(),
+        Err(err) => err,
+    };
+    let context = process.failure_context();
+    let diagnostic = match source {
+        Some(source) => err.as_diagnostic_with_source(program, context, source),
+        None => err.as_diagnostic(program, context),
+    };
+
+    format!("Failed to execute program\n{diagnostic}")
+}
@@ -7,10 +7,10 @@ use structopt::StructOpt;
 #[structopt(name = "Verify", about = "Verify a miden program")]
 pub struct VerifyCmd {
     /// Path to input file
-    #[structopt(short = "i", long = "input", parse(from_os_str))]
+    #[structopt(short = "i", long = "input", alias = "inputs", parse(from_os_str))]
     input_file: Option<PathBuf>,
     /// Path to output file
-    #[structopt(short = "o", long = "output", parse(from_os_str))]
+    #[structopt(short = "o", long = "output", alias = "outputs", parse(from_os_str))]
     output_file: Option<PathBuf>,
     /// Path to proof file
     #[structopt(short = "p", long = "proof", parse(from_os_str))]
@@ -50,7 +50,11 @@ impl VerifyCmd {
         )
         .map_err(|err| format!("Program failed verification! - {}", err))?;
 
-        println!("Verification complete in {} ms", now.elapsed().as_millis());
+        println!(
+            "Program with hash {} verified successfully in {} ms",
+            self.program_hash,
+            now.elapsed().as_millis()
+        );
 
         Ok(())
     }
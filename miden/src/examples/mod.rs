@@ -1,4 +1,4 @@
-use miden::{Program, ProgramInputs, ProofOptions, StarkProof};
+use miden::{proof_from_bytes, proof_to_bytes, Program, ProgramInputs, ProofOptions};
 use std::io::Write;
 use std::time::Instant;
 use structopt::StructOpt;
@@ -93,7 +93,7 @@ impl ExampleOptions {
         );
 
         // serialize the proof to see how big it is
-        let proof_bytes = proof.to_bytes();
+        let proof_bytes = proof_to_bytes(&proof);
         println!("Execution proof size: {} KB", proof_bytes.len() / 1024);
         println!(
             "Execution proof security: {} bits",
@@ -103,7 +103,7 @@ impl ExampleOptions {
 
         // verify that executing a program with a given hash and given inputs
         // results in the expected output
-        let proof = StarkProof::from_bytes(&proof_bytes).unwrap();
+        let proof = proof_from_bytes(&proof_bytes).unwrap();
         let now = Instant::now();
         match miden::verify(program.hash(), &pub_inputs, &outputs, proof) {
             Ok(_) => println!("Execution verified in {} ms", now.elapsed().as_millis()),
@@ -4,14 +4,24 @@
 // ================================================================================================
 
 pub use air::{FieldExtension, HashFunction, ProofOptions};
-pub use assembly::{Assembler, AssemblyError, ParsingError};
+pub use assembly::{Assembler, AssemblyError, InstructionSetVersion, ParsingError, ProgramMetrics};
 pub use processor::{
-    execute, execute_iter, AsmOpInfo, ExecutionError, ExecutionTrace, VmState, VmStateIterator,
+    execute, execute_chain, execute_iter, execute_proc, execute_with_advice,
+    execute_with_host_context, execute_with_memory_access_log, execute_with_warnings,
+    AdviceProvider, AsmOpInfo, ExecutionError, ExecutionTrace, ExecutionWarning, HashOp,
+    HostContext, MemAdviceProvider, MemoryAccess, SharedAdviceProvider, SharedAdviceTape, VmState,
+    VmStateIterator,
+};
+pub use prover::{
+    estimate_proof_size, prove, prove_trace, prove_with_host_context, prove_with_progress,
+    ProvingPhase, StarkProof,
+};
+pub use verifier::{
+    proof_from_bytes, proof_to_bytes, verify, VerificationError, Verifier, PROOF_VERSION,
 };
-pub use prover::{prove, StarkProof};
-pub use verifier::{verify, VerificationError};
 pub use vm_core::{
     chiplets::hasher::Digest,
     errors::{AdviceSetError, InputError},
-    AdviceSet, Program, ProgramInputs,
+    hash_elements, AdviceFieldType, AdviceSchema, AdviceSet, MerklePathOrder, OutputInterp,
+    Program, ProgramInputs, ProgramInputsBuilder, StructuredAdvice,
 };
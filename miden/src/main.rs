@@ -49,9 +49,10 @@ pub fn main() {
     // read command-line args
     let cli = Cli::from_args();
 
-    // execute cli action
+    // execute cli action; exit with a non-zero status code if the action failed
     if let Err(error) = cli.execute() {
         println!("{}", error);
+        std::process::exit(1);
     }
 }
 
@@ -0,0 +1,335 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+// HELPERS
+// ================================================================================================
+
+/// Returns a fresh directory for the artifacts of the specified test.
+fn test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("miden-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Runs the miden binary with the specified arguments.
+fn miden(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_miden"))
+        .args(args)
+        .output()
+        .expect("failed to run miden binary")
+}
+
+/// Proves the program in `dir` via `miden prove` and returns the hash of the program.
+fn prove(dir: &Path) -> String {
+    fs::write(dir.join("program.masm"), "begin push.3 add mul end").unwrap();
+    fs::write(dir.join("in.json"), r#"{ "stack_init": ["5", "2"] }"#).unwrap();
+
+    let output = miden(&[
+        "prove",
+        "--assembly",
+        dir.join("program.masm").to_str().unwrap(),
+        "--input",
+        dir.join("in.json").to_str().unwrap(),
+        "--output",
+        dir.join("out.json").to_str().unwrap(),
+        "--proof",
+        dir.join("proof.bin").to_str().unwrap(),
+    ]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+
+    // extract the program hash from the prover output
+    let line = stdout
+        .lines()
+        .find(|line| line.starts_with("Program with hash"))
+        .unwrap();
+    line.split_whitespace().nth(3).unwrap().to_string()
+}
+
+/// Runs the specified program via `miden run` and returns the reported stack outputs.
+fn run(program: &Path) -> String {
+    run_with_cycles(program).0
+}
+
+/// Runs the specified program via `miden run` and returns the reported stack outputs together
+/// with the reported number of cycles.
+fn run_with_cycles(program: &Path) -> (String, usize) {
+    let output = miden(&["run", "--assembly", program.to_str().unwrap()]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+
+    let outputs = stdout
+        .lines()
+        .find(|line| line.starts_with("Output:"))
+        .unwrap()
+        .to_string();
+
+    // the number of cycles is reported as "done (<cycles> cycles in <time> ms)"
+    let (_, cycles) = stdout.split_once("done (").unwrap();
+    let cycles = cycles.split_whitespace().next().unwrap().parse().unwrap();
+
+    (outputs, cycles)
+}
+
+/// Runs the specified program via `miden run` with the outputs interpreted according to the
+/// specified format, and returns the reported stack outputs.
+fn run_with_format(program: &Path, format: &str) -> String {
+    let output = miden(&[
+        "run",
+        "--assembly",
+        program.to_str().unwrap(),
+        "--num-outputs",
+        "3",
+        "--output-as",
+        format,
+    ]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+
+    stdout
+        .lines()
+        .find(|line| line.starts_with("Output:"))
+        .unwrap()
+        .to_string()
+}
+
+/// Verifies the proof in `dir` via `miden verify`.
+fn verify(dir: &Path, program_hash: &str, outputs: &str) -> Output {
+    miden(&[
+        "verify",
+        "--program-hash",
+        program_hash,
+        "--inputs",
+        dir.join("in.json").to_str().unwrap(),
+        "--outputs",
+        dir.join(outputs).to_str().unwrap(),
+        "--proof",
+        dir.join("proof.bin").to_str().unwrap(),
+    ])
+}
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn prove_and_verify() {
+    let dir = test_dir("prove-and-verify");
+    let program_hash = prove(&dir);
+
+    let output = verify(&dir, &program_hash, "out.json");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("verified successfully"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn verify_wrong_outputs() {
+    let dir = test_dir("verify-wrong-outputs");
+    let program_hash = prove(&dir);
+
+    // tamper with the first stack output
+    let outputs = fs::read_to_string(dir.join("out.json")).unwrap();
+    let tampered = outputs.replacen("\"25\"", "\"26\"", 1);
+    assert_ne!(outputs, tampered);
+    fs::write(dir.join("bad_out.json"), tampered).unwrap();
+
+    let output = verify(&dir, &program_hash, "bad_out.json");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(Some(1), output.status.code(), "{}", stdout);
+    assert!(stdout.contains("Program failed verification!"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn compile_and_run_binary() {
+    let dir = test_dir("compile-and-run-binary");
+    let source = "
+        use.std::math::u64
+        proc.foo.1
+            loc_store.0 push.7 loc_load.0 mul
+        end
+        begin
+            push.5 exec.foo
+            push.1.2 push.3.4 exec.u64::checked_add
+            repeat.3 push.1 add end
+            dup.5 push.0 eq if.true push.9 else push.10 end
+        end";
+    fs::write(dir.join("program.masm"), source).unwrap();
+
+    let output = miden(&[
+        "compile",
+        "--assembly",
+        dir.join("program.masm").to_str().unwrap(),
+        "--output",
+        dir.join("program.masb").to_str().unwrap(),
+    ]);
+    assert!(output.status.success());
+
+    // running the compiled program produces the same outputs as running the source
+    let expected = run(&dir.join("program.masm"));
+    assert_eq!(expected, run(&dir.join("program.masb")));
+
+    // a corrupted binary is rejected
+    let mut bytes = fs::read(dir.join("program.masb")).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 1;
+    fs::write(dir.join("corrupted.masb"), bytes).unwrap();
+    let output = miden(&[
+        "run",
+        "--assembly",
+        dir.join("corrupted.masb").to_str().unwrap(),
+    ]);
+    assert_eq!(Some(1), output.status.code());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn compile_with_optimizations() {
+    let dir = test_dir("compile-with-optimizations");
+    let source = "
+        proc.foo.2
+            swap swap loc_store.1 push.3 loc_load.1 mul
+        end
+        begin
+            push.5 push.7 dup drop exec.foo
+            repeat.10 push.9 drop movup.2 movdn.2 add.1 end
+        end";
+    fs::write(dir.join("program.masm"), source).unwrap();
+
+    let compile = |output: &str, optimize: bool| {
+        let program = dir.join("program.masm");
+        let output = dir.join(output);
+        let mut args = vec![
+            "compile",
+            "--assembly",
+            program.to_str().unwrap(),
+            "--output",
+            output.to_str().unwrap(),
+        ];
+        if optimize {
+            args.push("--optimize");
+        }
+        let output = miden(&args);
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(output.status.success(), "{}", stdout);
+        stdout
+    };
+
+    let stdout = compile("program.masb", false);
+    assert!(!stdout.contains("optimizations reduced"));
+    let optimized_stdout = compile("optimized.masb", true);
+
+    // the optimized program produces the same outputs in fewer cycles
+    let (outputs, num_cycles) = run_with_cycles(&dir.join("program.masb"));
+    let (optimized_outputs, num_optimized_cycles) = run_with_cycles(&dir.join("optimized.masb"));
+    assert_eq!(outputs, optimized_outputs);
+    assert!(num_optimized_cycles < num_cycles);
+
+    // the reported cycle reduction matches the executed programs
+    let expected = format!(
+        "optimizations reduced execution from {} to {} cycles",
+        num_cycles, num_optimized_cycles
+    );
+    assert!(optimized_stdout.contains(&expected), "{}", optimized_stdout);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn run_with_output_format() {
+    let dir = test_dir("run-with-output-format");
+    let program = dir.join("program.masm");
+    fs::write(
+        &program,
+        "begin push.4294967296 push.255 push.0 push.5 sub end",
+    )
+    .unwrap();
+
+    // 0 - 5 is the field element p - 5, which is interpreted as -5 by the i32 format
+    assert_eq!(
+        "Output: [18446744069414584316, 255, 4294967296]",
+        run_with_format(&program, "felt")
+    );
+    assert_eq!(
+        "Output: [-5, 255, (4294967296)]",
+        run_with_format(&program, "i32")
+    );
+    assert_eq!(
+        "Output: [(18446744069414584316), 255, (4294967296)]",
+        run_with_format(&program, "u32")
+    );
+    assert_eq!(
+        "Output: [0xfffffffefffffffc, 0xff, 0x100000000]",
+        run_with_format(&program, "hex")
+    );
+
+    // an unknown format is rejected
+    let output = miden(&[
+        "run",
+        "--assembly",
+        program.to_str().unwrap(),
+        "--output-as",
+        "u64",
+    ]);
+    assert!(!output.status.success());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn run_with_explain_error() {
+    let dir = test_dir("run-with-explain-error");
+    let program = dir.join("program.masm");
+    fs::write(
+        &program,
+        "proc.divide
+    push.0
+    div
+end
+
+begin
+    push.6 push.2 div
+    push.5
+    call.divide
+end",
+    )
+    .unwrap();
+
+    let output = miden(&[
+        "run",
+        "--assembly",
+        program.to_str().unwrap(),
+        "--explain-error",
+    ]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success(), "{}", stdout);
+
+    // the report points at the failing instruction in the source code, and includes the call
+    // stack and the state of the stack
+    assert!(stdout.contains("error: division by zero"), "{}", stdout);
+    assert!(stdout.contains("--> 3:5, cycle "), "{}", stdout);
+    assert!(stdout.contains("instruction `div`"), "{}", stdout);
+    assert!(
+        stdout.contains("\n 3 |     div\n   |     ^^^\n"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("1: procedure divide ("), "{}", stdout);
+    assert!(stdout.contains("stack: [0, 5, "), "{}", stdout);
+
+    // without the flag, only a terse error is reported
+    let output = miden(&["run", "--assembly", program.to_str().unwrap()]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(!output.status.success());
+    assert!(!stdout.contains("instruction `div`"), "{}", stdout);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -1,4 +1,6 @@
 use crate::{build_op_test, build_test};
+use std::collections::BTreeMap;
+use vm_core::{StarkField, ONE, RANGE_CHECK_TRACE_OFFSET};
 
 /// Range checks the result of 1 + 1. This results in 2 range checks, one for each 16-bit limb of
 /// the 32-bit result (2 and 0).
@@ -28,3 +30,98 @@ fn range_check_u16max() {
 
     build_op_test!(asm_op, &stack).prove_and_verify(stack, false);
 }
+
+/// Range checks values 1..=250 followed by 200 values which are 300 apart. Placing the bridge rows
+/// of the 16-bit segment so that they reuse the lookups of the 8-bit segment makes the range
+/// checker segment of the trace strictly shorter than placing them at increments of 255 would.
+#[test]
+fn range_check_bridges() {
+    let source = "
+        begin
+            push.0
+            repeat.250
+                add.1 u32assert
+            end
+            dup
+            repeat.200
+                add.300 u32assert
+            end
+        end";
+    let test = build_test!(source);
+    test.expect_stack(&[60250, 250]);
+
+    // read the lookups performed against the 16-bit segment of the range checker from the trace
+    let air = test.execute().unwrap().to_air_description();
+    let columns = &air.main_columns[RANGE_CHECK_TRACE_OFFSET..RANGE_CHECK_TRACE_OFFSET + 4];
+    let num_rows = air.trace_len - air.num_rand_rows;
+    let mut lookups = BTreeMap::new();
+    let mut first_row = None;
+    let rows = columns[0]
+        .iter()
+        .zip(&columns[1])
+        .zip(&columns[2])
+        .zip(&columns[3])
+        .take(num_rows);
+    for (row, (((&t, s0), s1), v)) in rows.enumerate() {
+        let num_lookups = match (s0.as_int(), s1.as_int()) {
+            (0, 0) => 0,
+            (1, 0) => 1,
+            (0, 1) => 2,
+            _ => 4,
+        };
+        // the padding rows are followed by the first row of the 8-bit segment, which contains
+        // lookups for ZERO
+        if first_row.is_none() && num_lookups != 0 {
+            first_row = Some(row);
+        }
+        if t == ONE && num_lookups != 0 {
+            *lookups.entry(v.as_int()).or_insert(0) += num_lookups;
+        }
+    }
+    let range_checker_len = num_rows - first_row.unwrap();
+    assert!(range_checker_len < range_checker_len_with_full_bridges(&lookups));
+
+    test.prove_and_verify(vec![], false);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the number of rows which the range checker needs to support the specified lookups if
+/// the bridge rows of the 16-bit segment are placed at increments of 255.
+fn range_checker_len_with_full_bridges(lookups: &BTreeMap<u64, usize>) -> usize {
+    let lookups_to_rows = |num_lookups: usize| match num_lookups {
+        0 => 1,
+        _ => num_lookups / 4 + num_lookups % 4 / 2 + num_lookups % 2,
+    };
+
+    // the 16-bit segment ends with an extra row for u16::MAX, which requires a lookup for ZERO
+    let mut lookups_8bit = [0; 256];
+    lookups_8bit[0] = 1;
+    let mut num_16bit_rows = 1;
+
+    // the 16-bit segment always contains rows for ZERO and u16::MAX, even if they are not looked up
+    let mut lookups = lookups.clone();
+    lookups.entry(0).or_insert(0);
+    lookups.entry(u16::MAX as u64).or_insert(0);
+
+    let mut prev_value = 0;
+    for (&value, &num_lookups) in lookups.iter() {
+        let num_rows = lookups_to_rows(num_lookups);
+        lookups_8bit[0] += num_rows - 1;
+        num_16bit_rows += num_rows;
+
+        let delta = (value - prev_value) as usize;
+        let (delta_q, delta_r) = (delta / 255, delta % 255);
+        if delta_q != 0 {
+            lookups_8bit[255] += delta_q;
+            num_16bit_rows += if delta_r == 0 { delta_q - 1 } else { delta_q };
+        }
+        if delta_r != 0 {
+            lookups_8bit[delta_r] += 1;
+        }
+        prev_value = value;
+    }
+
+    lookups_8bit.into_iter().map(lookups_to_rows).sum::<usize>() + num_16bit_rows
+}
@@ -25,6 +25,7 @@ fn test_exec_iter() {
             stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
             fmp,
             memory: Vec::new(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 1,
@@ -34,6 +35,7 @@ fn test_exec_iter() {
             stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 1].to_elements(),
             fmp,
             memory: Vec::new(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 2,
@@ -43,6 +45,7 @@ fn test_exec_iter() {
             stack: [0, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
             fmp,
             memory: Vec::new(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 3,
@@ -52,6 +55,7 @@ fn test_exec_iter() {
             stack: [1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2].to_elements(),
             fmp,
             memory: Vec::new(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 4,
@@ -61,6 +65,7 @@ fn test_exec_iter() {
             stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
             fmp,
             memory: mem.clone(),
+            memory_writes: vec![(1_u64, slice_to_word(&[13, 14, 15, 16]))],
         },
         VmState {
             clk: 5,
@@ -70,6 +75,7 @@ fn test_exec_iter() {
             stack: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0].to_elements(),
             fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 6,
@@ -79,6 +85,7 @@ fn test_exec_iter() {
             stack: [14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 7,
@@ -88,6 +95,7 @@ fn test_exec_iter() {
             stack: [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 8,
@@ -97,6 +105,7 @@ fn test_exec_iter() {
             stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 9,
@@ -106,6 +115,7 @@ fn test_exec_iter() {
             stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 10,
@@ -115,6 +125,7 @@ fn test_exec_iter() {
             stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 11,
@@ -124,6 +135,7 @@ fn test_exec_iter() {
             stack: [1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
             fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 12,
@@ -133,6 +145,7 @@ fn test_exec_iter() {
             stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
             fmp: next_fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 13,
@@ -142,6 +155,7 @@ fn test_exec_iter() {
             stack: [0, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
             fmp: next_fmp,
             memory: mem.clone(),
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 14,
@@ -170,6 +184,7 @@ fn test_exec_iter() {
             .to_elements(),
             fmp: next_fmp,
             memory: mem,
+            memory_writes: Vec::new(),
         },
         VmState {
             clk: 15,
@@ -182,6 +197,7 @@ fn test_exec_iter() {
                 (1_u64, slice_to_word(&[13, 14, 15, 16])),
                 (2u64.pow(30) + 1, slice_to_word(&[17, 0, 0, 0])),
             ],
+            memory_writes: vec![(2u64.pow(30) + 1, slice_to_word(&[17, 0, 0, 0]))],
         },
         VmState {
             clk: 16,
@@ -194,6 +210,7 @@ fn test_exec_iter() {
                 (1_u64, slice_to_word(&[13, 14, 15, 16])),
                 (2u64.pow(30) + 1, slice_to_word(&[17, 0, 0, 0])),
             ],
+            memory_writes: Vec::new(),
         },
     ];
     for (expected, t) in expected_states.iter().zip(traces) {
@@ -202,6 +219,60 @@ fn test_exec_iter() {
     }
 }
 
+#[test]
+fn test_exec_iter_stack_overflow() {
+    let source = "begin push.17 push.18 swap swap mem_store.3 end";
+    let init_stack: Vec<u64> = (1..=16).collect();
+    let test = build_debug_test!(source, &init_stack);
+    let states = test.execute_iter().collect::<Result<Vec<_>, _>>().unwrap();
+
+    // after the two pushes, two items are located in the overflow table
+    let state = states
+        .iter()
+        .find(|state| state.op == Some(Operation::Swap))
+        .unwrap();
+    let stack_full = state.stack_full();
+    assert_eq!(18, stack_full.len());
+    assert_eq!(Felt::new(17), stack_full[0]);
+    assert_eq!(Felt::new(18), stack_full[1]);
+    assert_eq!([2, 1].to_elements(), state.stack_overflow());
+    assert!(state.memory_changes().is_empty());
+
+    // the store writes to memory exactly in the cycle in which it is executed
+    let state = states
+        .iter()
+        .find(|state| state.op == Some(Operation::MStore))
+        .unwrap();
+    let expected = vec![(3_u64, slice_to_word(&[18, 0, 0, 0]))];
+    assert_eq!(expected, state.memory_changes());
+    assert_eq!(expected, state.memory);
+
+    let next_state = &states[state.clk as usize + 1];
+    assert!(next_state.memory_changes().is_empty());
+    assert_eq!(expected, next_state.memory);
+}
+
+#[test]
+fn test_stack_state_at() {
+    let source = "proc.foo.1 loc_store.0 end begin mem_storew.1 dropw push.17 exec.foo swap.2 end";
+    let init_stack = (1..=16).collect::<Vec<u64>>();
+    let test = build_debug_test!(source, &init_stack);
+    let trace = processor::execute(&test.compile(), &test.inputs).unwrap();
+
+    // the states read from the trace must match the states produced by the iterator
+    for state in test.execute_iter() {
+        let state = state.unwrap();
+        let expected_stack: [Felt; 16] = state.stack[..16].try_into().unwrap();
+        assert_eq!(
+            Some(expected_stack),
+            trace.stack_state_at(state.clk as usize)
+        );
+    }
+
+    // cycles beyond the end of the trace have no stack state
+    assert_eq!(None, trace.stack_state_at(usize::MAX));
+}
+
 // HELPER FUNCTIONS
 // =================================================================
 fn slice_to_word(values: &[i32]) -> [Felt; 4] {
@@ -2,6 +2,7 @@ use crate::{
     build_test,
     helpers::{Test, TestError},
 };
+use processor::{ExecutionError, Process};
 use vm_core::ProgramInputs;
 
 // SIMPLE FLOW CONTROL TESTS
@@ -122,6 +123,68 @@ fn if_in_loop_in_if() {
     test.expect_stack(&[121]);
 }
 
+#[test]
+fn if_branch_stack_mismatch() {
+    // the false branch is executed in the first iteration and pushes one element onto the stack,
+    // while the true branch is executed in the second iteration and leaves the depth unchanged
+    let source = "
+        begin
+            repeat.2
+                if.true
+                    push.0 drop
+                else
+                    push.7 swap
+                end
+            end
+        end";
+
+    // the check is performed only in debug mode
+    let test = build_test!(source, &[1, 0]);
+    test.expect_stack(&[7]);
+
+    let err = test
+        .execute_iter()
+        .collect::<Result<Vec<_>, _>>()
+        .err()
+        .unwrap();
+    assert!(matches!(
+        err.root_cause(),
+        ExecutionError::BranchStackMismatch(_, 0, 1)
+    ));
+
+    // when both branches change the depth of the stack by the same amount, the check succeeds
+    let source = "
+        begin
+            repeat.2
+                if.true
+                    push.5 swap
+                else
+                    push.7 swap
+                end
+            end
+        end";
+
+    let test = build_test!(source, &[1, 0]);
+    assert!(test.execute_iter().all(|state| state.is_ok()));
+
+    // the depth of the stack is not clamped to 16 when comparing branches; here, the false branch
+    // drops an element from a stack of depth 16, which still counts as consuming one element
+    let source = "
+        begin
+            push.0 push.7 push.1
+            repeat.2
+                if.true
+                    drop
+                else
+                    drop
+                end
+            end
+        end";
+
+    let test = build_test!(source);
+    assert!(test.execute_iter().all(|state| state.is_ok()));
+}
+
 // FUNCTION CALLS
 // ================================================================================================
 
@@ -187,6 +250,271 @@ fn local_fn_call_with_mem_access() {
     test.prove_and_verify(vec![3, 7], false);
 }
 
+#[test]
+fn nested_fn_call_failure() {
+    // a failure in a nested procedure should report the full chain of active procedures
+    let source = "
+        proc.bar
+            push.0
+            assert
+        end
+
+        proc.foo
+            call.bar
+        end
+
+        begin
+            call.foo
+        end";
+
+    let test = build_test!(source);
+    let program = test.compile();
+    let call_graph = program.call_graph();
+    let foo_hash = call_graph[0].1[0];
+    let bar_hash = call_graph
+        .iter()
+        .find(|(caller, _)| *caller == foo_hash)
+        .map(|(_, callees)| callees[0])
+        .unwrap();
+
+    let err = processor::execute(&program, &ProgramInputs::none())
+        .err()
+        .unwrap();
+    assert_eq!(&[foo_hash, bar_hash], err.call_stack());
+    assert!(matches!(
+        err.root_cause(),
+        ExecutionError::FailedAssertion(_)
+    ));
+
+    // a failure outside of procedure calls should report an empty call stack
+    let err = build_test!("begin push.0 assert end")
+        .execute()
+        .err()
+        .unwrap();
+    assert!(err.call_stack().is_empty());
+}
+
+#[test]
+fn fn_call_depth_limit() {
+    let source = "
+        proc.baz
+            push.3 swap drop
+        end
+
+        proc.bar
+            call.baz
+        end
+
+        proc.foo
+            call.bar
+        end
+
+        begin
+            call.foo
+        end";
+
+    let test = build_test!(source);
+    let program = test.compile();
+
+    // three nested calls can be executed when the depth is limited to three
+    let mut process = Process::new(program.kernel(), ProgramInputs::none()).with_max_call_depth(3);
+    let outputs = process.execute(&program).unwrap();
+    assert_eq!(3, outputs.stack()[0]);
+
+    // but not when the depth is limited to two; the limit is checked before the third call
+    // starts executing, and thus, only the first two CALL operations are executed
+    let mut process = Process::new(program.kernel(), ProgramInputs::none()).with_max_call_depth(2);
+    let err = process.execute(&program).err().unwrap();
+    assert_eq!(2, err.call_stack().len());
+    assert!(matches!(
+        err.root_cause(),
+        ExecutionError::CallDepthExceeded(2)
+    ));
+    assert!(err
+        .to_string()
+        .starts_with("call depth exceeded the maximum of 2"));
+    assert_eq!(2, process.to_components().0.clk());
+
+    // the same holds when the program is executed tick by tick
+    let mut process = Process::new(program.kernel(), ProgramInputs::none()).with_max_call_depth(2);
+    let err = process.run_ticks(&program, 1000).err().unwrap();
+    assert!(matches!(
+        err.root_cause(),
+        ExecutionError::CallDepthExceeded(2)
+    ));
+    assert_eq!(2, process.to_components().0.clk());
+}
+
+#[test]
+fn fn_call_failure_diagnostic() {
+    let source = "
+        proc.foo
+            push.0
+            div
+        end
+
+        begin
+            push.5
+            call.foo
+        end";
+
+    let program = assembly::Assembler::new()
+        .with_debug_mode(true)
+        .with_debug_symbols(true)
+        .compile(source)
+        .unwrap();
+    let foo_hash = program.call_graph()[0].1[0];
+
+    let mut process = processor::Process::new_debug(program.kernel(), ProgramInputs::none());
+    let err = process.execute(&program).err().unwrap();
+    assert!(matches!(err.root_cause(), ExecutionError::DivideByZero(_)));
+    assert_eq!(&[foo_hash], err.call_stack());
+
+    // the diagnostic points at the failing instruction and includes the call stack and the state
+    // of the stack before the failing operation
+    let context = process.failure_context();
+    let clk = context.unwrap().clk();
+    let diagnostic = err.as_diagnostic(&program, context);
+    let mut lines = diagnostic.lines();
+    assert_eq!(
+        Some(format!("error: division by zero at clk {clk}").as_str()),
+        lines.next()
+    );
+    assert_eq!(
+        Some(format!("  --> 4:13, cycle {clk}, instruction `div` (operation 1 of 2)").as_str()),
+        lines.next()
+    );
+    assert_eq!(Some("call stack (innermost last):"), lines.next());
+    assert_eq!(Some("  0: program root"), lines.next());
+    assert!(lines.next().unwrap().starts_with("  1: procedure foo ("));
+    assert_eq!(
+        Some("stack: [0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]"),
+        lines.next()
+    );
+    assert_eq!(None, lines.next());
+
+    // with the source code, the diagnostic also includes an excerpt pointing at the instruction
+    let diagnostic = err.as_diagnostic_with_source(&program, context, source);
+    let mut lines = diagnostic.lines().skip(2);
+    assert_eq!(Some("   |"), lines.next());
+    assert_eq!(Some(" 4 |             div"), lines.next());
+    assert_eq!(Some("   |             ^^^"), lines.next());
+    assert_eq!(Some("call stack (innermost last):"), lines.next());
+
+    // without debug mode, the failure context is not captured, but the error is the same
+    let mut process = processor::Process::new(program.kernel(), ProgramInputs::none());
+    let err_non_debug = process.execute(&program).err().unwrap();
+    assert_eq!(err.to_string(), err_non_debug.to_string());
+    assert!(matches!(
+        err_non_debug,
+        ExecutionError::ProcedureCallFailed(ref cause, _)
+            if matches!(**cause, ExecutionError::DivideByZero(_))
+    ));
+    assert!(process.failure_context().is_none());
+    assert!(!err_non_debug
+        .as_diagnostic(&program, None)
+        .contains("stack:"));
+}
+
+#[test]
+fn mocked_fn_call() {
+    // `scale` would fail if executed, but it is mocked to multiply the top of the stack by 10
+    let source = "
+        proc.scale
+            push.0
+            assert
+        end
+
+        proc.scale_twice_and_inc
+            exec.scale
+            call.scale
+            add.1
+        end
+
+        begin
+            exec.scale_twice_and_inc
+            swap
+            exec.scale_twice_and_inc
+        end";
+
+    let test = build_test!(source, &[2, 3]).with_mock_proc("scale", |stack| {
+        let mut result = stack.to_vec();
+        result[0] *= 10;
+        result
+    });
+    test.expect_stack(&[201, 301]);
+    test.prove_and_verify(vec![2, 3], false);
+
+    // without the mock, the procedure is executed as is
+    build_test!(source, &[2, 3]).expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn mocked_one_line_fn_call() {
+    // the mocked procedure is defined on the same line as other procedures, and contains a block
+    let source = "
+        proc.inc add.1 end proc.scale if.true push.0 assert end end proc.dec sub.1 end # scale
+        begin push.1 exec.scale exec.inc push.1 exec.scale exec.dec end";
+
+    let test = build_test!(source, &[2]).with_mock_proc("scale", |stack| {
+        let mut result = stack[1..].to_vec();
+        result[0] *= 10;
+        result
+    });
+    test.expect_stack(&[209]);
+}
+
+#[test]
+fn library_fn_call() {
+    // the library is compiled once and linked into two different programs
+    let library = Test::compile_library(&[
+        (
+            "mylib::math",
+            "
+            export.double
+                dup add
+            end
+
+            export.double_and_inc
+                exec.double
+                add.1
+            end",
+        ),
+        (
+            "mylib::io",
+            "
+            export.store_twice
+                dup mem_store.0 mem_store.1
+            end",
+        ),
+    ]);
+
+    let source = "
+        use.mylib::math
+        begin
+            exec.math::double_and_inc
+        end";
+    let test = build_test!(source, &[5]).with_library(&library);
+    test.expect_stack(&[11]);
+    test.prove_and_verify(vec![5], false);
+
+    let source = "
+        use.mylib::math
+        use.mylib::io
+        use.std::math::u64
+        begin
+            call.math::double
+            exec.io::store_twice
+            push.1.0.2.0 exec.u64::checked_add
+            mem_load.0 mem_load.1 add
+        end";
+    let test = build_test!(source, &[7]).with_library(&library);
+    test.expect_stack(&[28, 0, 3]);
+
+    // without the library, the imported modules cannot be found
+    build_test!(source, &[7]).expect_error(TestError::AssemblyError("ImportedProcModuleNotFound"));
+}
+
 #[test]
 fn simple_syscall() {
     let kernel_source = "
@@ -206,6 +534,8 @@ fn simple_syscall() {
         kernel: Some(kernel_source.to_string()),
         inputs: ProgramInputs::from_stack_inputs(&[1, 2]).unwrap(),
         in_debug_mode: false,
+        mocks: Vec::new(),
+        libraries: Vec::new(),
     };
     test.expect_stack(&[3]);
 
@@ -0,0 +1,83 @@
+use super::{Operation, Test};
+use processor::ExecutionError;
+use std::collections::BTreeMap;
+use vm_core::ALL_OPERATIONS;
+
+// COVERAGE COLLECTOR
+// ================================================================================================
+
+/// Records which VM operations were executed across a number of test runs, and reports the
+/// operations which were never executed.
+///
+/// Operations are tracked by opcode; thus, for example, `push` operations with different values
+/// are treated as the same operation.
+#[derive(Debug, Default)]
+pub struct CoverageCollector {
+    counts: BTreeMap<u8, usize>,
+}
+
+impl CoverageCollector {
+    /// Returns a new collector with no operations recorded.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Executes the specified test and records all operations executed by it.
+    ///
+    /// If the execution fails, the operations executed before the failure are still recorded, and
+    /// the error is returned.
+    pub fn record(&mut self, test: &Test) -> Result<(), ExecutionError> {
+        for state in test.execute_iter() {
+            if let Some(op) = state?.op {
+                *self.counts.entry(op.op_code()).or_default() += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the number of times the specified operation was executed.
+    pub fn count(&self, op: Operation) -> usize {
+        self.counts.get(&op.op_code()).copied().unwrap_or(0)
+    }
+
+    /// Returns true if the specified operation was executed at least once.
+    pub fn is_covered(&self, op: Operation) -> bool {
+        self.count(op) > 0
+    }
+
+    /// Returns the operations which were never executed, in opcode order.
+    pub fn unused_ops(&self) -> Vec<Operation> {
+        let mut ops = ALL_OPERATIONS
+            .into_iter()
+            .filter(|&op| !self.is_covered(op))
+            .collect::<Vec<_>>();
+        ops.sort_by_key(|op| op.op_code());
+        ops
+    }
+
+    /// Returns a report listing the number of executed operations and the names of the
+    /// operations which were never executed.
+    pub fn report(&self) -> String {
+        let unused = self.unused_ops();
+        let names = unused.iter().map(|&op| op_name(op)).collect::<Vec<_>>();
+        format!(
+            "operations executed: {} of {}\nunused operations: {}",
+            ALL_OPERATIONS.len() - unused.len(),
+            ALL_OPERATIONS.len(),
+            names.join(", ")
+        )
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the name of the operation without the value it carries (if any).
+fn op_name(op: Operation) -> String {
+    let name = op.to_string();
+    name.split('(')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
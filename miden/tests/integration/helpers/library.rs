@@ -0,0 +1,88 @@
+use assembly::{
+    parse_module, AssemblyError, ModuleAst, ModuleProvider, NamedModuleAst, ProcedureId,
+};
+use std::{collections::BTreeMap, sync::Arc};
+use stdlib::StdLibrary;
+
+// TEST LIBRARY
+// ================================================================================================
+
+/// A library of modules compiled once and shared between the tests which link against it (see
+/// [super::Test::compile_library()]).
+///
+/// Cloning a library is cheap, since the compiled modules are shared between the clones.
+#[derive(Clone)]
+pub struct TestLibrary(Arc<LibraryModules>);
+
+struct LibraryModules {
+    modules: Vec<(String, ModuleAst)>,
+    proc_to_module: BTreeMap<ProcedureId, usize>,
+}
+
+impl TestLibrary {
+    /// Compiles the provided (path, source) pairs into a library; the path of each module is the
+    /// path by which it is imported by programs (e.g., `mylib::math`).
+    ///
+    /// # Errors
+    /// Returns an error if any of the module sources could not be parsed.
+    pub fn compile(modules: &[(&str, &str)]) -> Result<Self, AssemblyError> {
+        let mut result = LibraryModules {
+            modules: Vec::with_capacity(modules.len()),
+            proc_to_module: BTreeMap::new(),
+        };
+
+        for (module_idx, &(path, source)) in modules.iter().enumerate() {
+            let module_ast = parse_module(source)?;
+            for proc_ast in module_ast.local_procs.iter() {
+                let proc_id = ProcedureId::from_name(&proc_ast.name, path);
+                result.proc_to_module.insert(proc_id, module_idx);
+            }
+            result.modules.push((path.to_string(), module_ast));
+        }
+
+        Ok(Self(Arc::new(result)))
+    }
+}
+
+impl ModuleProvider for TestLibrary {
+    fn get_module(&self, proc_id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        self.0
+            .proc_to_module
+            .get(proc_id)
+            .map(|&module_idx| &self.0.modules[module_idx])
+            .map(|(path, ast)| ast.named_ref(path))
+    }
+}
+
+// TEST MODULE PROVIDER
+// ================================================================================================
+
+/// A module provider which looks up modules in the libraries linked into a test, and then in the
+/// standard library.
+pub struct TestModuleProvider {
+    libraries: Vec<TestLibrary>,
+    stdlib: StdLibrary,
+}
+
+impl TestModuleProvider {
+    /// Returns a new provider of the modules of the specified libraries and the standard library.
+    pub fn new(libraries: &[TestLibrary]) -> Self {
+        Self {
+            libraries: libraries.to_vec(),
+            stdlib: StdLibrary::default(),
+        }
+    }
+}
+
+impl ModuleProvider for TestModuleProvider {
+    fn get_module(&self, proc_id: &ProcedureId) -> Option<NamedModuleAst<'_>> {
+        self.libraries
+            .iter()
+            .find_map(|library| library.get_module(proc_id))
+            .or_else(|| self.stdlib.get_module(proc_id))
+    }
+
+    fn library_hashes(&self) -> Vec<(String, String)> {
+        self.stdlib.library_hashes()
+    }
+}
@@ -0,0 +1,248 @@
+use super::{Felt, Program, ProgramInputs, STACK_TOP_SIZE};
+use processor::ExecutionError;
+use std::{collections::BTreeMap, panic::RefUnwindSafe};
+use vm_core::{utils::IntoBytes, StarkField, Word};
+
+// MOCK PROCEDURES
+// ================================================================================================
+
+/// A callback which computes the top 16 elements of the stack after a mocked procedure returns
+/// from the top 16 elements of the stack before the procedure is invoked. Both are listed in
+/// stack order (i.e., the first value is at the top of the stack); if the callback returns fewer
+/// than 16 values, the remaining ones are set to zeros.
+pub type MockFn = Box<dyn Fn(&[u64]) -> Vec<u64> + RefUnwindSafe>;
+
+/// A local procedure of a test program whose body is replaced by a host callback.
+///
+/// The VM has no way to call back into the host; instead, the body of the procedure is replaced
+/// with code which hashes the top 16 elements of the stack into a key, loads the new top of the
+/// stack from the entry of the advice map under this key, and replaces the top 16 elements of the
+/// stack with it. The entries are not known before the program is executed, and thus, the program
+/// is executed repeatedly: every time the execution fails on a missing entry, the callback is
+/// invoked with the stack at the failure point, its result is added to the advice map, and the
+/// program is executed again (see [resolve_mocks()]).
+///
+/// Consequently, a mocked procedure can only affect the top 16 elements of the stack, and its
+/// callback is invoked once per distinct state of these elements.
+pub struct MockProc {
+    name: String,
+    callback: MockFn,
+}
+
+impl MockProc {
+    /// Returns a new mock of the procedure with the specified name.
+    pub fn new(name: &str, callback: MockFn) -> Self {
+        Self {
+            name: name.to_string(),
+            callback,
+        }
+    }
+}
+
+/// Returns the provided source with the bodies of the mocked procedures replaced by the code
+/// which loads their results from the advice map.
+///
+/// # Panics
+/// Panics if any of the mocked procedures is not defined in the source.
+pub fn apply_mocks(source: &str, mocks: &[MockProc]) -> String {
+    mocks
+        .iter()
+        .enumerate()
+        .fold(source.to_string(), |source, (mock_id, mock)| {
+            replace_proc_body(&source, &mock.name, &build_mock_body(mock_id))
+        })
+}
+
+/// Returns the provided inputs extended with the advice map entries required by the mocked
+/// procedures invoked during the execution of the program.
+///
+/// The program is executed until it either succeeds, or fails with an error other than a missing
+/// mock result. In the latter case, the error is reported by the subsequent execution of the
+/// program against the returned inputs.
+pub fn resolve_mocks(
+    program: &Program,
+    inputs: &ProgramInputs,
+    mocks: &[MockProc],
+) -> ProgramInputs {
+    if mocks.is_empty() {
+        return inputs.clone();
+    }
+
+    let mut mock_results = BTreeMap::new();
+    loop {
+        let mocked_inputs = extend_advice_map(inputs, &mock_results);
+
+        let mut last_stack = Vec::new();
+        let mut missing_key = None;
+        for state in processor::execute_iter(program, &mocked_inputs) {
+            match state {
+                Ok(state) => last_stack = state.stack_full(),
+                Err(err) => {
+                    if let ExecutionError::AdviceMapKeyNotFound(key) = err.root_cause() {
+                        missing_key = Some(*key);
+                    }
+                }
+            }
+        }
+
+        // the key is the top word of the stack, with the mock ID at the top
+        let key = match missing_key {
+            Some(key) if is_mock_key(&key, &last_stack, mocks) => key,
+            _ => return mocked_inputs,
+        };
+        let mock = &mocks[key[3].as_int() as usize];
+
+        // the stack at the entry to the mocked procedure is located right below the key
+        let entry_stack = last_stack[4..4 + STACK_TOP_SIZE]
+            .iter()
+            .map(|v| v.as_int())
+            .collect::<Vec<_>>();
+        let mut result = (mock.callback)(&entry_stack);
+        assert!(
+            result.len() <= STACK_TOP_SIZE,
+            "mock of procedure '{}' returned more than {STACK_TOP_SIZE} values",
+            mock.name
+        );
+        result.resize(STACK_TOP_SIZE, 0);
+
+        // the words are pushed onto the stack one by one, starting with the top word; the values
+        // of each word are pushed one by one as well, and thus, the top value of a word goes last
+        let values = result
+            .chunks(4)
+            .flat_map(|word| word.iter().rev())
+            .map(|&value| Felt::new(value))
+            .collect();
+        mock_results.insert(key.into_bytes(), values);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the body of the mocked procedure with the specified ID.
+///
+/// The body copies the top 16 elements of the stack and hashes them into a word, replacing the
+/// top element of the word with the mock ID. The word is used as the key for loading the new top
+/// of the stack from the advice map. Then, the words of the old top of the stack are replaced
+/// with the new ones one by one, so that the depth of the stack is not affected.
+fn build_mock_body(mock_id: usize) -> String {
+    format!(
+        "dupw.3 dupw.3 dupw.3 dupw.3 rphash rphash rphash drop push.{mock_id} \
+        adv.keyval dropw repeat.4 adv_push.4 swapw dropw movdnw.3 end"
+    )
+}
+
+/// Returns true if the missing key was requested by the body of one of the mocked procedures.
+fn is_mock_key(key: &Word, stack: &[Felt], mocks: &[MockProc]) -> bool {
+    let mock_id = key[3].as_int();
+    stack.len() >= 4 + STACK_TOP_SIZE
+        && stack[..4].iter().rev().eq(key.iter())
+        && mock_id < mocks.len() as u64
+}
+
+/// Returns the provided inputs with the specified entries added to their advice map.
+fn extend_advice_map(
+    inputs: &ProgramInputs,
+    entries: &BTreeMap<[u8; 32], Vec<Felt>>,
+) -> ProgramInputs {
+    let (stack_init, advice_tape, mut advice_map, advice_sets) = inputs.clone().into_parts();
+    advice_map.extend(entries.clone());
+
+    let stack_init = stack_init
+        .iter()
+        .rev()
+        .map(|v| v.as_int())
+        .collect::<Vec<_>>();
+    let advice_tape = advice_tape.iter().map(|v| v.as_int()).collect::<Vec<_>>();
+    let advice_sets = advice_sets.into_values().collect();
+    ProgramInputs::with_advice_map(&stack_init, &advice_tape, advice_map, advice_sets).unwrap()
+}
+
+/// Replaces the body of the procedure with the specified name defined in the provided source.
+///
+/// The header of the procedure is kept intact (including the number of locals), while all tokens
+/// up to the `end` token which closes the procedure are replaced with the specified body. The
+/// source is processed token by token, and thus, the header, the body, and the closing `end` of
+/// the procedure may be located on the same line (e.g., `proc.foo add end`).
+///
+/// # Panics
+/// Panics if the procedure is not defined in the source.
+fn replace_proc_body(source: &str, name: &str, body: &str) -> String {
+    let mut result = Vec::new();
+    // None until the header of the procedure is found, then the nesting depth of the blocks in
+    // the skipped body until the closing `end` is found
+    let mut depth = None;
+    let mut found = false;
+
+    for line in source.lines() {
+        if found && depth.is_none() {
+            result.push(line.to_string());
+            continue;
+        }
+
+        // the part of the line which is kept; tokens of the skipped body are removed from it
+        let mut kept = String::new();
+        let mut rest_start = 0;
+        for token in line
+            .split_whitespace()
+            .take_while(|token| !token.starts_with('#'))
+        {
+            let start = token.as_ptr() as usize - line.as_ptr() as usize;
+            let end = start + token.len();
+            match depth {
+                None if is_proc_header(token, name) => {
+                    kept.push_str(&line[..end]);
+                    kept.push('\n');
+                    kept.push_str(body);
+                    depth = Some(0);
+                    found = true;
+                }
+                None => (),
+                Some(0) if token == "end" => {
+                    if !kept.is_empty() {
+                        kept.push('\n');
+                    }
+                    kept.push_str("end");
+                    rest_start = end;
+                    depth = None;
+                    break;
+                }
+                Some(d) if token == "end" => depth = Some(d - 1),
+                Some(d) if is_block_start(token) => depth = Some(d + 1),
+                Some(_) => (),
+            }
+        }
+
+        match (found, depth) {
+            // the line does not contain any tokens of the procedure
+            (false, _) => result.push(line.to_string()),
+            // the body continues on the next line
+            (true, Some(_)) => result.push(kept),
+            // the procedure is closed on this line
+            (true, None) => {
+                kept.push_str(&line[rest_start..]);
+                result.push(kept);
+            }
+        }
+    }
+
+    assert!(
+        found,
+        "procedure '{name}' is not defined in the test source"
+    );
+    result.join("\n")
+}
+
+/// Returns true if the provided token starts a block which is closed by an `end` token.
+fn is_block_start(token: &str) -> bool {
+    ["if.", "while.", "repeat."]
+        .iter()
+        .any(|prefix| token.starts_with(prefix))
+}
+
+/// Returns true if the provided token is the header of a procedure with the specified name (e.g.,
+/// `proc.foo` or `export.foo.2`).
+fn is_proc_header(token: &str, name: &str) -> bool {
+    let mut parts = token.split('.');
+    matches!(parts.next(), Some("proc") | Some("export")) && parts.next() == Some(name)
+}
@@ -1,17 +1,38 @@
+use assembly::AssemblyError;
 pub use miden::{ProofOptions, StarkProof};
 use processor::{ExecutionError, ExecutionTrace, Process, VmStateIterator};
-use proptest::prelude::*;
-use stdlib::StdLibrary;
+use proptest::{
+    prelude::*,
+    test_runner::{TestCaseError, TestRunner},
+};
+use regex::Regex;
+use std::panic::RefUnwindSafe;
 pub use vm_core::{
     stack::STACK_TOP_SIZE, Felt, FieldElement, Program, ProgramInputs, ProgramOutputs,
 };
+use vm_core::{Operation, Word};
 
+mod coverage;
+pub use coverage::CoverageCollector;
 pub mod crypto;
+mod library;
+pub use library::TestLibrary;
+use library::TestModuleProvider;
+mod mock;
+use mock::MockProc;
+mod reference;
+use reference::ReferenceState;
 
 // CONSTANTS
 // ================================================================================================
 pub const U32_BOUND: u64 = u32::MAX as u64 + 1;
 
+// TYPE ALIASES
+// ================================================================================================
+
+/// The full stack and the memory of the active context at the end of an execution.
+type FinalState = (Vec<Felt>, Vec<(u64, Word)>);
+
 // TEST HANDLER
 // ================================================================================================
 
@@ -24,6 +45,13 @@ pub enum TestError<'a> {
     ExecutionError(&'a str),
 }
 
+/// This is used to specify the stage at which a test is expected to fail when using
+/// `Test::expect_error_regex`.
+pub enum TestErrorKind {
+    AssemblyError,
+    ExecutionError,
+}
+
 /// This is a container for the data required to run tests, which allows for running several
 /// different types of tests.
 ///
@@ -42,6 +70,8 @@ pub struct Test {
     pub kernel: Option<String>,
     pub inputs: ProgramInputs,
     pub in_debug_mode: bool,
+    pub mocks: Vec<MockProc>,
+    pub libraries: Vec<TestLibrary>,
 }
 
 impl Test {
@@ -55,9 +85,72 @@ impl Test {
             kernel: None,
             inputs: ProgramInputs::none(),
             in_debug_mode,
+            mocks: Vec::new(),
+            libraries: Vec::new(),
+        }
+    }
+
+    /// Creates a new test with the provided source string, and initial stack set to the provided
+    /// stack-ordered array (i.e., the first value ends up at the top of the stack).
+    pub fn with_stack_inputs(source: &str, stack_inputs: &[u64], in_debug_mode: bool) -> Self {
+        let stack_init = stack_inputs.iter().rev().copied().collect::<Vec<_>>();
+        Test {
+            source: String::from(source),
+            kernel: None,
+            inputs: ProgramInputs::new(&stack_init, &[], vec![]).unwrap(),
+            in_debug_mode,
+            mocks: Vec::new(),
+            libraries: Vec::new(),
+        }
+    }
+
+    /// Creates a randomized test which executes the program compiled from the provided source
+    /// against stack and advice tape inputs generated by the provided proptest strategy.
+    pub fn with_random_inputs<S>(source: &str, strategy: S) -> RandomizedTest<S>
+    where
+        S: Strategy<Value = RandomInputs>,
+    {
+        RandomizedTest {
+            source: String::from(source),
+            strategy,
+            in_debug_mode: false,
         }
     }
 
+    /// Replaces the body of the local procedure with the specified name with the provided callback,
+    /// which computes the top 16 elements of the stack after the procedure returns from the top 16
+    /// elements of the stack before the procedure is invoked (both in stack order).
+    ///
+    /// This allows testing the callers of a procedure independently of the procedure itself. The
+    /// results of the callback are delivered to the VM via the advice map, and thus, the test
+    /// program is executed several times (see [mock::MockProc] for details).
+    ///
+    /// The mocks apply to all execution methods (except for the differential testing) of the test.
+    pub fn with_mock_proc<F>(mut self, name: &str, callback: F) -> Self
+    where
+        F: Fn(&[u64]) -> Vec<u64> + RefUnwindSafe + 'static,
+    {
+        self.mocks.push(MockProc::new(name, Box::new(callback)));
+        self
+    }
+
+    /// Compiles the provided (path, source) pairs of module sources into a library which can be
+    /// linked into any number of tests via [Test::with_library()].
+    ///
+    /// # Panics
+    /// Panics if any of the module sources could not be compiled.
+    pub fn compile_library(modules: &[(&str, &str)]) -> TestLibrary {
+        TestLibrary::compile(modules).expect("Failed to compile test library.")
+    }
+
+    /// Links the provided library into the test, so that the modules of the library can be
+    /// imported by the test source. The modules of the linked libraries take precedence over the
+    /// modules of the standard library.
+    pub fn with_library(mut self, library: &TestLibrary) -> Self {
+        self.libraries.push(library.clone());
+        self
+    }
+
     // TEST METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -84,13 +177,52 @@ impl Test {
         }
     }
 
+    /// Asserts that compiling or executing the test, according to the provided TestErrorKind,
+    /// will result in an error with a message matching the specified regular expression.
+    ///
+    /// Unlike `Test::expect_error`, the pattern is matched against the display form of the
+    /// error (e.g., "assertion failed at clk 3").
+    pub fn expect_error_regex(&self, kind: TestErrorKind, pattern: &str) {
+        let regex = Regex::new(pattern).expect("invalid error pattern");
+        let message = match kind {
+            TestErrorKind::AssemblyError => self
+                .try_compile()
+                .expect_err("compilation succeeded, but an error was expected")
+                .to_string(),
+            TestErrorKind::ExecutionError => self
+                .execute()
+                .err()
+                .expect("execution succeeded, but an error was expected")
+                .to_string(),
+        };
+        assert!(
+            regex.is_match(&message),
+            "error message \"{message}\" does not match pattern \"{pattern}\""
+        );
+    }
+
     /// Builds a final stack from the provided stack-ordered array and asserts that executing the
     /// test will result in the expected final stack state.
     pub fn expect_stack(&self, final_stack: &[u64]) {
-        let expected = convert_to_stack(final_stack);
-        let result = self.get_last_stack_state();
+        let expected = convert_to_stack(final_stack).to_vec();
+        let expected = ProgramOutputs::from_elements(expected, vec![]);
+        let result = ProgramOutputs::from_elements(self.get_last_stack_state().to_vec(), vec![]);
 
-        assert_eq!(expected, result);
+        let diff = expected.diff(&result);
+        if !diff.is_empty() {
+            let positions = diff
+                .iter()
+                .map(|(i, expected, actual)| {
+                    format!("  position {i}: expected {expected}, actual {actual}")
+                })
+                .collect::<Vec<_>>();
+            panic!(
+                "final stack does not match the expected stack:\n{}\nexpected: {:?}\n  actual: {:?}",
+                positions.join("\n"),
+                expected.stack(),
+                result.stack(),
+            );
+        }
     }
 
     /// Executes the test and validates that the process memory has the elements of `expected_mem`
@@ -106,7 +238,8 @@ impl Test {
         let program = self.compile();
 
         // execute the test
-        let mut process = Process::new(program.kernel(), self.inputs.clone());
+        let inputs = mock::resolve_mocks(&program, &self.inputs, &self.mocks);
+        let mut process = Process::new(program.kernel(), inputs);
         process.execute(&program).unwrap();
 
         // validate the memory state
@@ -118,6 +251,47 @@ impl Test {
         self.expect_stack(final_stack);
     }
 
+    /// Asserts that executing the test succeeds without producing any warnings (e.g., about reads
+    /// of uninitialized memory); this is useful for keeping library code warning-clean.
+    ///
+    /// The assembler does not produce any warnings, and thus, only the warnings collected during
+    /// the execution of the test are checked.
+    pub fn assert_no_warnings(&self) {
+        let program = self.compile();
+        let inputs = mock::resolve_mocks(&program, &self.inputs, &self.mocks);
+        let (_, warnings) = processor::execute_with_warnings(&program, &inputs).unwrap();
+        if !warnings.is_empty() {
+            let warnings = warnings
+                .iter()
+                .map(|warning| format!("  {warning}"))
+                .collect::<Vec<_>>();
+            panic!("execution produced warnings:\n{}", warnings.join("\n"));
+        }
+    }
+
+    /// Asserts that the program compiled from the test's source in debug mode and the program
+    /// compiled in non-debug mode behave identically: either both executions fail with the same
+    /// error, or both leave the same stack (including the overflow table) and the same memory of
+    /// the root context.
+    ///
+    /// Only the final states are compared; thus, debug-only side effects (e.g., the source
+    /// mappings of the executed instructions) are ignored.
+    pub fn assert_debug_release_equivalent(&self) {
+        let debug = self.execute_in_mode(true);
+        let release = self.execute_in_mode(false);
+        match (debug, release) {
+            (Ok((debug_stack, debug_mem)), Ok((release_stack, release_mem))) => {
+                assert_eq!(debug_stack, release_stack, "final stacks differ");
+                assert_eq!(debug_mem, release_mem, "final memory states differ");
+            }
+            (Err(debug_err), Err(release_err)) => {
+                assert_eq!(debug_err, release_err, "execution errors differ");
+            }
+            (Ok(_), Err(err)) => panic!("debug build succeeded, but release build failed: {err}"),
+            (Err(err), Ok(_)) => panic!("release build succeeded, but debug build failed: {err}"),
+        }
+    }
+
     /// Asserts that executing the test inside a proptest results in the expected final stack state.
     /// The proptest will return a test failure instead of panicking if the assertion condition
     /// fails.
@@ -133,30 +307,104 @@ impl Test {
         Ok(())
     }
 
+    /// Asserts that executing the test inside a proptest results in an execution trace of the
+    /// expected length, where the length is the number of trace rows before padding (i.e., the
+    /// number of executed cycles plus one for the initial state). The proptest will return a test
+    /// failure instead of panicking if execution fails or the assertion condition fails.
+    ///
+    /// This is useful for checking that the cost of a program does not depend on its inputs.
+    pub fn prop_expect_trace_length(&self, expected: usize) -> Result<(), TestCaseError> {
+        let trace_len = self
+            .execute_iter()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| TestCaseError::fail(format!("execution failed: {err:?}")))?
+            .len();
+
+        prop_assert_eq!(expected, trace_len);
+
+        Ok(())
+    }
+
+    // DIFFERENTIAL TESTING
+    // --------------------------------------------------------------------------------------------
+
+    /// Asserts that executing the test on the processor results in the same sequence of VM states
+    /// as executing it on a simple reference interpreter.
+    ///
+    /// The states are compared after every executed operation except for NOOPs (which the
+    /// processor may insert for alignment purposes). For each state, the operation, the top 16
+    /// elements of the stack, and the non-zero words of memory in the active context must match.
+    /// If one of the executions fails, the other one is expected to fail as well.
+    pub fn assert_matches_reference(&self) {
+        let program = self.compile();
+        let expected = reference::execute(&program, &self.inputs);
+
+        let mut actual = Vec::new();
+        let mut processor_error = None;
+        for state in processor::execute_iter(&program, &self.inputs) {
+            match state {
+                Ok(state) => match state.op {
+                    Some(op) if op != Operation::Noop => actual.push(ReferenceState {
+                        op,
+                        stack: state.stack[..STACK_TOP_SIZE].to_vec(),
+                        memory: reference::non_zero_words(state.memory),
+                    }),
+                    _ => (),
+                },
+                Err(err) => processor_error = Some(err),
+            }
+        }
+
+        match (expected, processor_error) {
+            (Ok(expected), None) => {
+                for (i, (expected, actual)) in expected.iter().zip(actual.iter()).enumerate() {
+                    assert_eq!(expected, actual, "state mismatch after operation {i}");
+                }
+                assert_eq!(
+                    expected.len(),
+                    actual.len(),
+                    "number of operations mismatch"
+                );
+            }
+            (Err(_), Some(_)) => (),
+            (Ok(_), Some(err)) => panic!("processor failed, but reference succeeded: {err:?}"),
+            (Err(err), None) => panic!("reference failed, but processor succeeded: {err}"),
+        }
+    }
+
     // UTILITY METHODS
     // --------------------------------------------------------------------------------------------
 
     /// Compiles a test's source and returns the resulting Program.
     pub fn compile(&self) -> Program {
+        self.try_compile().expect("Failed to compile test source.")
+    }
+
+    /// Compiles a test's source and returns the resulting Program, or an error if the source or
+    /// the kernel of the test could not be compiled.
+    pub fn try_compile(&self) -> Result<Program, AssemblyError> {
+        self.try_compile_in_mode(self.in_debug_mode)
+    }
+
+    /// Compiles a test's source in the specified mode, regardless of the mode of the test.
+    fn try_compile_in_mode(&self, in_debug_mode: bool) -> Result<Program, AssemblyError> {
         let assembler = assembly::Assembler::new()
-            .with_debug_mode(self.in_debug_mode)
-            .with_module_provider(StdLibrary::default());
+            .with_debug_mode(in_debug_mode)
+            .with_module_provider(TestModuleProvider::new(&self.libraries));
 
         match self.kernel.as_ref() {
-            Some(kernel) => assembler
-                .with_kernel(kernel)
-                .expect("kernel compilation failed"),
+            Some(kernel) => assembler.with_kernel(kernel)?,
             None => assembler,
         }
-        .compile(&self.source)
-        .expect("Failed to compile test source.")
+        .compile(mock::apply_mocks(&self.source, &self.mocks))
     }
 
     /// Compiles the test's source to a Program and executes it with the tests inputs. Returns a
     /// resulting execution trace or error.
     pub fn execute(&self) -> Result<ExecutionTrace, ExecutionError> {
         let program = self.compile();
-        processor::execute(&program, &self.inputs)
+        let inputs = mock::resolve_mocks(&program, &self.inputs, &self.mocks);
+        processor::execute(&program, &inputs)
     }
 
     /// Compiles the test's code into a program, then generates and verifies a proof of execution
@@ -164,8 +412,9 @@ impl Test {
     /// is true, this function will force a failure by modifying the first output.
     pub fn prove_and_verify(&self, pub_inputs: Vec<u64>, test_fail: bool) {
         let program = self.compile();
+        let inputs = mock::resolve_mocks(&program, &self.inputs, &self.mocks);
         let (mut outputs, proof) =
-            prover::prove(&program, &self.inputs, &ProofOptions::default()).unwrap();
+            prover::prove(&program, &inputs, &ProofOptions::default()).unwrap();
 
         if test_fail {
             outputs.stack_mut()[0] += 1;
@@ -181,7 +430,24 @@ impl Test {
     /// state.
     pub fn execute_iter(&self) -> VmStateIterator {
         let program = self.compile();
-        processor::execute_iter(&program, &self.inputs)
+        let inputs = mock::resolve_mocks(&program, &self.inputs, &self.mocks);
+        processor::execute_iter(&program, &inputs)
+    }
+
+    /// Compiles the test's source in the specified mode and executes it with the test's inputs.
+    /// Returns the full stack and the memory of the context active at the end of the execution,
+    /// or the message of the error which caused the execution to fail.
+    fn execute_in_mode(&self, in_debug_mode: bool) -> Result<FinalState, String> {
+        let program = self
+            .try_compile_in_mode(in_debug_mode)
+            .expect("Failed to compile test source.");
+        let inputs = mock::resolve_mocks(&program, &self.inputs, &self.mocks);
+        let mut last_state = None;
+        for state in processor::execute_iter(&program, &inputs) {
+            let state = state.map_err(|err| err.root_cause().to_string())?;
+            last_state = Some((state.stack_full(), state.memory));
+        }
+        Ok(last_state.expect("no states were produced"))
     }
 
     /// Returns the last state of the stack after executing a test.
@@ -192,6 +458,66 @@ impl Test {
     }
 }
 
+// RANDOMIZED TEST HANDLER
+// ================================================================================================
+
+/// Inputs generated for a single run of a [RandomizedTest]. The first vector contains the initial
+/// stack values, and the second vector contains the advice tape values.
+pub type RandomInputs = (Vec<u64>, Vec<u64>);
+
+/// This is a container for running the same test source against many sets of random inputs
+/// generated by a proptest strategy, which allows for asserting invariants across these inputs.
+///
+/// Generated inputs which are not valid field elements are rejected by the test runner.
+pub struct RandomizedTest<S> {
+    pub source: String,
+    pub strategy: S,
+    pub in_debug_mode: bool,
+}
+
+impl<S: Strategy<Value = RandomInputs>> RandomizedTest<S> {
+    /// Runs the provided test function for each set of inputs generated by the strategy. The test
+    /// function receives a [Test] instantiated with the generated inputs as well as the raw
+    /// inputs themselves.
+    ///
+    /// # Panics
+    /// Panics if the test function fails for any of the generated inputs.
+    pub fn run<F>(&self, test_fn: F)
+    where
+        F: Fn(&Test, &[u64], &[u64]) -> Result<(), TestCaseError>,
+    {
+        let mut runner = TestRunner::default();
+        let result = runner.run(&self.strategy, |(stack_inputs, advice_tape)| {
+            let inputs = ProgramInputs::new(&stack_inputs, &advice_tape, vec![])
+                .map_err(|err| TestCaseError::reject(format!("invalid inputs: {err:?}")))?;
+            let test = Test {
+                source: self.source.clone(),
+                kernel: None,
+                inputs,
+                in_debug_mode: self.in_debug_mode,
+                mocks: Vec::new(),
+                libraries: Vec::new(),
+            };
+            test_fn(&test, &stack_inputs, &advice_tape)
+        });
+
+        if let Err(err) = result {
+            panic!("{}", err);
+        }
+    }
+
+    /// Asserts that executing the test against each set of generated inputs results in the final
+    /// stack state computed by `expected_fn` from these inputs.
+    pub fn prop_expect_stack<F>(&self, expected_fn: F)
+    where
+        F: Fn(&[u64], &[u64]) -> Vec<u64>,
+    {
+        self.run(|test, stack_inputs, advice_tape| {
+            test.prop_expect_stack(&expected_fn(stack_inputs, advice_tape))
+        });
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
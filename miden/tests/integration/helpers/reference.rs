@@ -0,0 +1,707 @@
+use super::{Felt, FieldElement, Program, ProgramInputs, STACK_TOP_SIZE};
+use processor::{FMP_MAX, FMP_MIN, SYSCALL_FMP_MIN};
+use std::collections::{BTreeMap, VecDeque};
+use vm_core::{
+    chiplets::hasher::{self, Digest},
+    code_blocks::{CodeBlock, Span},
+    utils::IntoBytes,
+    AdviceInjector, AdviceSet, Decorator, Operation, StarkField, Word,
+};
+
+// REFERENCE STATE
+// ================================================================================================
+
+/// The state of the VM after executing a single operation in the reference interpreter.
+///
+/// The stack contains only the top 16 elements, and the memory contains only non-zero words of
+/// the active execution context, sorted by address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReferenceState {
+    pub op: Operation,
+    pub stack: Vec<Felt>,
+    pub memory: Vec<(u64, Word)>,
+}
+
+/// Executes the provided program against the provided inputs using the reference interpreter, and
+/// returns the state of the VM after every executed operation except for NOOPs.
+///
+/// Returns an error message if the execution failed.
+pub fn execute(program: &Program, inputs: &ProgramInputs) -> Result<Vec<ReferenceState>, String> {
+    let mut interpreter = Interpreter::new(inputs.clone());
+    interpreter.execute_block(program.root(), program)?;
+    Ok(interpreter.states)
+}
+
+// REFERENCE INTERPRETER
+// ================================================================================================
+
+/// A straightforward interpreter of Miden VM programs.
+///
+/// The interpreter does not build any execution traces and does not care about the number of
+/// cycles needed to execute control flow; it only tracks the values on the stack, in memory and
+/// in the advice provider. It is intended to be used to check the processor for correctness.
+///
+/// The stack is kept in a vector with the top of the stack at index 0. The stack never contains
+/// fewer than 16 elements; removing elements from a stack of 16 elements inserts a ZERO at the
+/// bottom.
+struct Interpreter {
+    stack: Vec<Felt>,
+    hidden_stacks: Vec<Vec<Felt>>,
+    ctx: u32,
+    next_ctx: u32,
+    fmp: Felt,
+    fn_hash: Word,
+    in_syscall: bool,
+    memory: BTreeMap<u32, BTreeMap<u64, Word>>,
+    tape: VecDeque<Felt>,
+    advice_map: BTreeMap<[u8; 32], Vec<Felt>>,
+    advice_sets: BTreeMap<[u8; 32], AdviceSet>,
+    states: Vec<ReferenceState>,
+}
+
+impl Interpreter {
+    fn new(inputs: ProgramInputs) -> Self {
+        let (mut stack, tape, advice_map, advice_sets) = inputs.into_parts();
+        stack.resize(STACK_TOP_SIZE.max(stack.len()), Felt::ZERO);
+
+        Self {
+            stack,
+            hidden_stacks: Vec::new(),
+            ctx: 0,
+            next_ctx: 1,
+            fmp: Felt::new(FMP_MIN),
+            fn_hash: [Felt::ZERO; 4],
+            in_syscall: false,
+            memory: BTreeMap::new(),
+            tape: tape.into(),
+            advice_map,
+            advice_sets,
+            states: Vec::new(),
+        }
+    }
+
+    // CONTROL FLOW
+    // --------------------------------------------------------------------------------------------
+
+    fn execute_block(&mut self, block: &CodeBlock, program: &Program) -> Result<(), String> {
+        match block {
+            CodeBlock::Join(block) => {
+                self.record(Operation::Join);
+                self.execute_block(block.first(), program)?;
+                self.execute_block(block.second(), program)?;
+                self.record(Operation::End);
+            }
+            CodeBlock::Split(block) => {
+                let condition = self.pop();
+                self.record(Operation::Split);
+                if condition == Felt::ONE {
+                    self.execute_block(block.on_true(), program)?;
+                } else if condition == Felt::ZERO {
+                    self.execute_block(block.on_false(), program)?;
+                } else {
+                    return Err(format!("split condition {condition} is not binary"));
+                }
+                self.record(Operation::End);
+            }
+            CodeBlock::Loop(block) => {
+                let mut condition = self.pop();
+                self.record(Operation::Loop);
+                let entered = condition == Felt::ONE;
+                while condition == Felt::ONE {
+                    self.execute_block(block.body(), program)?;
+                    condition = self.stack[0];
+                    if condition == Felt::ONE {
+                        self.pop();
+                        self.record(Operation::Repeat);
+                    }
+                }
+                if condition != Felt::ZERO {
+                    return Err(format!("loop condition {condition} is not binary"));
+                }
+                if entered {
+                    self.pop();
+                }
+                self.record(Operation::End);
+            }
+            CodeBlock::Call(block) => {
+                let fn_hash: Word = block.fn_hash().into();
+                if block.is_syscall() && !program.kernel().contains_proc(block.fn_hash()) {
+                    return Err("syscall target is not in the kernel".to_string());
+                }
+                let body = program
+                    .cb_table()
+                    .get(block.fn_hash())
+                    .ok_or("procedure body not found")?;
+
+                // save the current context and start a new one
+                let parent = (self.ctx, self.fmp, self.fn_hash);
+                let hidden = self.stack.split_off(STACK_TOP_SIZE);
+                self.hidden_stacks.push(hidden);
+                if block.is_syscall() {
+                    self.ctx = 0;
+                    self.fmp = Felt::new(SYSCALL_FMP_MIN);
+                    self.in_syscall = true;
+                    self.record(Operation::SysCall);
+                } else {
+                    self.ctx = self.next_ctx;
+                    self.next_ctx += 1;
+                    self.fmp = Felt::new(FMP_MIN);
+                    self.fn_hash = fn_hash;
+                    self.record(Operation::Call);
+                }
+
+                self.execute_block(body, program)?;
+
+                // make sure the stack is clean and restore the parent context
+                if self.stack.len() > STACK_TOP_SIZE {
+                    return Err("invalid stack depth on return".to_string());
+                }
+                let hidden = self.hidden_stacks.pop().expect("no hidden stack");
+                self.stack.extend(hidden);
+                (self.ctx, self.fmp, self.fn_hash) = parent;
+                self.in_syscall = false;
+                self.record(Operation::End);
+            }
+            CodeBlock::Span(block) => self.execute_span(block)?,
+            CodeBlock::Proxy(_) => return Err("proxy blocks cannot be executed".to_string()),
+        }
+        Ok(())
+    }
+
+    fn execute_span(&mut self, block: &Span) -> Result<(), String> {
+        self.record(Operation::Span);
+
+        let mut op_idx = 0;
+        for (batch_idx, batch) in block.op_batches().iter().enumerate() {
+            if batch_idx > 0 {
+                self.record(Operation::Respan);
+            }
+            for &op in batch.ops() {
+                for (_, decorator) in block.decorators().iter().filter(|(i, _)| *i == op_idx) {
+                    if let Decorator::Advice(injector) = decorator {
+                        self.execute_injector(injector)?;
+                    }
+                }
+                self.execute_op(op)?;
+                self.record(op);
+                op_idx += 1;
+            }
+        }
+
+        self.record(Operation::End);
+        Ok(())
+    }
+
+    // OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    fn execute_op(&mut self, op: Operation) -> Result<(), String> {
+        match op {
+            // ----- system operations ------------------------------------------------------------
+            Operation::Noop => (),
+            Operation::Assert => {
+                if self.pop() != Felt::ONE {
+                    return Err("assertion failed".to_string());
+                }
+            }
+            Operation::FmpAdd => self.stack[0] += self.fmp,
+            Operation::FmpUpdate => {
+                let fmp = self.fmp + self.pop();
+                if fmp.as_int() < FMP_MIN || fmp.as_int() > FMP_MAX {
+                    return Err(format!("invalid fmp value {fmp}"));
+                }
+                self.fmp = fmp;
+            }
+            Operation::SDepth => self.push(Felt::new(self.stack.len() as u64)),
+            Operation::Caller => {
+                if !self.in_syscall {
+                    return Err("caller is only available in syscalls".to_string());
+                }
+                self.set_word(0, self.fn_hash);
+            }
+
+            // ----- field operations -------------------------------------------------------------
+            Operation::Add => self.binary_op(|a, b| a + b),
+            Operation::Neg => self.stack[0] = -self.stack[0],
+            Operation::Mul => self.binary_op(|a, b| a * b),
+            Operation::Inv => {
+                if self.stack[0] == Felt::ZERO {
+                    return Err("cannot invert zero".to_string());
+                }
+                self.stack[0] = self.stack[0].inv();
+            }
+            Operation::Incr => self.stack[0] += Felt::ONE,
+            Operation::And => {
+                let (b, a) = (self.binary(0)?, self.binary(1)?);
+                self.binary_op(|_, _| to_felt(a && b));
+            }
+            Operation::Or => {
+                let (b, a) = (self.binary(0)?, self.binary(1)?);
+                self.binary_op(|_, _| to_felt(a || b));
+            }
+            Operation::Not => self.stack[0] = to_felt(!self.binary(0)?),
+            Operation::Eq => self.binary_op(|a, b| to_felt(a == b)),
+            Operation::Eqz => self.stack[0] = to_felt(self.stack[0] == Felt::ZERO),
+            Operation::Expacc => {
+                let (exp, acc, b) = (self.stack[1], self.stack[2], self.stack[3].as_int());
+                let bit = b & 1;
+                let value = if bit == 1 { exp } else { Felt::ONE };
+                self.stack[0] = Felt::new(bit);
+                self.stack[1] = exp * exp;
+                self.stack[2] = acc * value;
+                self.stack[3] = Felt::new(b >> 1);
+            }
+
+            // ----- u32 operations ---------------------------------------------------------------
+            Operation::U32split => {
+                let a = self.pop().as_int();
+                self.push(Felt::new(a & u32::MAX as u64));
+                self.push(Felt::new(a >> 32));
+            }
+            Operation::U32assert2 => {
+                self.u32(0)?;
+                self.u32(1)?;
+            }
+            Operation::U32add => {
+                let (b, a) = (self.pop().as_int(), self.pop().as_int());
+                self.push_split(a as u128 + b as u128);
+            }
+            Operation::U32add3 => {
+                let (c, b, a) = (
+                    self.pop().as_int(),
+                    self.pop().as_int(),
+                    self.pop().as_int(),
+                );
+                self.push_split(a as u128 + b as u128 + c as u128);
+            }
+            Operation::U32sub => {
+                let (b, a) = (self.pop().as_int(), self.pop().as_int());
+                self.push(Felt::new(a.wrapping_sub(b) & u32::MAX as u64));
+                self.push(to_felt(a < b));
+            }
+            Operation::U32mul => {
+                let (b, a) = (self.pop().as_int(), self.pop().as_int());
+                self.push_split(a as u128 * b as u128);
+            }
+            Operation::U32madd => {
+                let (b, a, c) = (
+                    self.pop().as_int(),
+                    self.pop().as_int(),
+                    self.pop().as_int(),
+                );
+                self.push_split(a as u128 * b as u128 + c as u128);
+            }
+            Operation::U32div => {
+                let (b, a) = (self.pop().as_int(), self.pop().as_int());
+                if b == 0 {
+                    return Err("division by zero".to_string());
+                }
+                self.push(Felt::new(a / b));
+                self.push(Felt::new(a % b));
+            }
+            Operation::U32and => {
+                let (b, a) = (self.u32(0)?, self.u32(1)?);
+                self.binary_op(|_, _| Felt::new(a & b));
+            }
+            Operation::U32xor => {
+                let (b, a) = (self.u32(0)?, self.u32(1)?);
+                self.binary_op(|_, _| Felt::new(a ^ b));
+            }
+
+            // ----- stack manipulation -----------------------------------------------------------
+            Operation::Pad => self.push(Felt::ZERO),
+            Operation::Drop => {
+                self.pop();
+            }
+            Operation::Dup0 => self.push(self.stack[0]),
+            Operation::Dup1 => self.push(self.stack[1]),
+            Operation::Dup2 => self.push(self.stack[2]),
+            Operation::Dup3 => self.push(self.stack[3]),
+            Operation::Dup4 => self.push(self.stack[4]),
+            Operation::Dup5 => self.push(self.stack[5]),
+            Operation::Dup6 => self.push(self.stack[6]),
+            Operation::Dup7 => self.push(self.stack[7]),
+            Operation::Dup9 => self.push(self.stack[9]),
+            Operation::Dup11 => self.push(self.stack[11]),
+            Operation::Dup13 => self.push(self.stack[13]),
+            Operation::Dup15 => self.push(self.stack[15]),
+            Operation::Swap => self.stack.swap(0, 1),
+            Operation::SwapW => self.swap_ranges(0, 4, 4),
+            Operation::SwapW2 => self.swap_ranges(0, 8, 4),
+            Operation::SwapW3 => self.swap_ranges(0, 12, 4),
+            Operation::SwapDW => self.swap_ranges(0, 8, 8),
+            Operation::MovUp2 => self.move_up(2),
+            Operation::MovUp3 => self.move_up(3),
+            Operation::MovUp4 => self.move_up(4),
+            Operation::MovUp5 => self.move_up(5),
+            Operation::MovUp6 => self.move_up(6),
+            Operation::MovUp7 => self.move_up(7),
+            Operation::MovUp8 => self.move_up(8),
+            Operation::MovDn2 => self.move_down(2),
+            Operation::MovDn3 => self.move_down(3),
+            Operation::MovDn4 => self.move_down(4),
+            Operation::MovDn5 => self.move_down(5),
+            Operation::MovDn6 => self.move_down(6),
+            Operation::MovDn7 => self.move_down(7),
+            Operation::MovDn8 => self.move_down(8),
+            Operation::CSwap => {
+                if self.binary(0)? {
+                    self.stack.swap(1, 2);
+                }
+                self.pop();
+            }
+            Operation::CSwapW => {
+                if self.binary(0)? {
+                    self.swap_ranges(1, 5, 4);
+                }
+                self.pop();
+            }
+
+            // ----- input / output ---------------------------------------------------------------
+            Operation::Push(value) => self.push(value),
+            Operation::Read => {
+                let value = self.read_tape()?;
+                self.push(value);
+            }
+            Operation::ReadW => {
+                let word = self.read_tapew()?;
+                self.set_word(0, word);
+            }
+            Operation::MLoadW => {
+                let addr = self.pop().as_int();
+                let word = self.read_mem(addr);
+                self.set_word(0, word);
+            }
+            Operation::MStoreW => {
+                let addr = self.pop().as_int();
+                let word = self.get_word(0);
+                self.write_mem(addr, word);
+            }
+            Operation::MLoad => {
+                let addr = self.stack[0].as_int();
+                self.stack[0] = self.read_mem(addr)[0];
+            }
+            Operation::MStore => {
+                let addr = self.pop().as_int();
+                let mut word = self.read_mem(addr);
+                word[0] = self.stack[0];
+                self.write_mem(addr, word);
+            }
+            Operation::MStream => {
+                let addr = self.stack[12].as_int();
+                let words = [self.read_mem(addr), self.read_mem(addr + 1)];
+                self.absorb_double_word(words);
+            }
+            Operation::Pipe => {
+                let addr = self.stack[12].as_int();
+                let words = [self.read_tapew()?, self.read_tapew()?];
+                self.write_mem(addr, words[0]);
+                self.write_mem(addr + 1, words[1]);
+                self.absorb_double_word(words);
+            }
+
+            // ----- cryptographic operations -----------------------------------------------------
+            Operation::RpPerm => {
+                let mut state = [Felt::ZERO; 12];
+                for (i, value) in state.iter_mut().enumerate() {
+                    *value = self.stack[11 - i];
+                }
+                hasher::apply_permutation(&mut state);
+                for (i, &value) in state.iter().enumerate() {
+                    self.stack[11 - i] = value;
+                }
+            }
+            Operation::MpVerify => {
+                let (node, depth, index, root) = self.merkle_args();
+                let path = self
+                    .advice_set(root)?
+                    .get_path(depth, index)
+                    .map_err(|err| format!("{err:?}"))?;
+                if compute_merkle_root(node, &path, index) != root {
+                    return Err("inconsistent Merkle tree root".to_string());
+                }
+            }
+            Operation::MrUpdate(copy) => {
+                let (old_node, depth, index, old_root) = self.merkle_args();
+                let new_node = self.get_word(10);
+
+                let mut set = self.advice_set(old_root)?.clone();
+                let path = set
+                    .get_path(set.depth(), index)
+                    .map_err(|err| format!("{err:?}"))?;
+                if path.len() != depth as usize
+                    || compute_merkle_root(old_node, &path, index) != old_root
+                {
+                    return Err("inconsistent Merkle tree root".to_string());
+                }
+                set.update_leaf(index, new_node)
+                    .map_err(|err| format!("{err:?}"))?;
+
+                let new_root = compute_merkle_root(new_node, &path, index);
+                if !copy {
+                    self.advice_sets.remove(&old_root.into_bytes());
+                }
+                self.advice_sets.insert(new_root.into_bytes(), set);
+                self.set_word(0, new_root);
+            }
+
+            // ----- flow control operations ------------------------------------------------------
+            Operation::Join
+            | Operation::Split
+            | Operation::Loop
+            | Operation::Call
+            | Operation::SysCall
+            | Operation::Span
+            | Operation::End
+            | Operation::Repeat
+            | Operation::Respan
+            | Operation::Halt => unreachable!("control flow operation in a span"),
+        }
+        Ok(())
+    }
+
+    fn execute_injector(&mut self, injector: &AdviceInjector) -> Result<(), String> {
+        match injector {
+            AdviceInjector::MerkleNode => {
+                let depth = self.stack[0].as_int() as u32;
+                let index = self.stack[1].as_int();
+                let root = self.get_word(2);
+                let node = self
+                    .advice_set(root)?
+                    .get_node(depth, index)
+                    .map_err(|err| format!("{err:?}"))?;
+                for &value in node.iter().rev() {
+                    self.tape.push_front(value);
+                }
+            }
+            AdviceInjector::DivResultU64 => {
+                let divisor = (self.stack[0].as_int() << 32) + self.stack[1].as_int();
+                let dividend = (self.stack[2].as_int() << 32) + self.stack[3].as_int();
+                if divisor == 0 {
+                    return Err("division by zero".to_string());
+                }
+                let (q, r) = (dividend / divisor, dividend % divisor);
+                for value in [r >> 32, r & u32::MAX as u64, q >> 32, q & u32::MAX as u64] {
+                    self.tape.push_front(Felt::new(value));
+                }
+            }
+            AdviceInjector::MapValue => {
+                let key = self.get_word(0).into_bytes();
+                let values = self.advice_map.get(&key).ok_or("advice key not found")?;
+                for &value in values.iter().rev() {
+                    self.tape.push_front(value);
+                }
+            }
+            AdviceInjector::TakeMapValue => {
+                let key = self.get_word(0).into_bytes();
+                let values = self.advice_map.remove(&key).ok_or("advice key not found")?;
+                if values.len() != 4 {
+                    return Err("advice map value is not a word".to_string());
+                }
+                for &value in values.iter().rev() {
+                    self.tape.push_front(value);
+                }
+            }
+            AdviceInjector::Memory(start_addr, num_words) => {
+                let key = self.get_word(0).into_bytes();
+                let values = (0..*num_words)
+                    .flat_map(|i| self.read_mem((start_addr + i) as u64))
+                    .collect();
+                if self.advice_map.insert(key, values).is_some() {
+                    return Err("duplicate advice key".to_string());
+                }
+            }
+            // the processor is executed without a host context as well, and thus, environment
+            // values are never available
+            AdviceInjector::Env(value) => {
+                return Err(format!("environment value {value} not available"));
+            }
+        }
+        Ok(())
+    }
+
+    // STACK HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn push(&mut self, value: Felt) {
+        self.stack.insert(0, value);
+    }
+
+    fn pop(&mut self) -> Felt {
+        if self.stack.len() == STACK_TOP_SIZE {
+            self.stack.push(Felt::ZERO);
+        }
+        self.stack.remove(0)
+    }
+
+    /// Removes the top two elements `b` (top) and `a` and pushes `f(a, b)` onto the stack.
+    fn binary_op<F: Fn(Felt, Felt) -> Felt>(&mut self, f: F) {
+        let b = self.pop();
+        let a = self.pop();
+        self.push(f(a, b));
+    }
+
+    /// Pushes the lower and then the upper 32 bits of the provided value onto the stack.
+    fn push_split(&mut self, value: u128) {
+        self.push(Felt::new((value as u64) & u32::MAX as u64));
+        self.push(Felt::new((value >> 32) as u64));
+    }
+
+    fn binary(&self, pos: usize) -> Result<bool, String> {
+        match self.stack[pos].as_int() {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(format!("value {value} is not binary")),
+        }
+    }
+
+    fn u32(&self, pos: usize) -> Result<u64, String> {
+        let value = self.stack[pos].as_int();
+        if value > u32::MAX as u64 {
+            return Err(format!("value {value} is not a u32 value"));
+        }
+        Ok(value)
+    }
+
+    fn swap_ranges(&mut self, a: usize, b: usize, len: usize) {
+        for i in 0..len {
+            self.stack.swap(a + i, b + i);
+        }
+    }
+
+    fn move_up(&mut self, n: usize) {
+        let value = self.stack.remove(n);
+        self.stack.insert(0, value);
+    }
+
+    fn move_down(&mut self, n: usize) {
+        let value = self.stack.remove(0);
+        self.stack.insert(n, value);
+    }
+
+    /// Returns the word located at the specified stack position in memory order, i.e., the element
+    /// at the specified position is the last element of the word.
+    fn get_word(&self, pos: usize) -> Word {
+        [
+            self.stack[pos + 3],
+            self.stack[pos + 2],
+            self.stack[pos + 1],
+            self.stack[pos],
+        ]
+    }
+
+    fn set_word(&mut self, pos: usize, word: Word) {
+        for (i, &value) in word.iter().rev().enumerate() {
+            self.stack[pos + i] = value;
+        }
+    }
+
+    /// Adds the provided words to the top 8 stack elements and increments the address at stack
+    /// position 12 by 2.
+    fn absorb_double_word(&mut self, words: [Word; 2]) {
+        for (i, &value) in words.iter().flatten().rev().enumerate() {
+            self.stack[i] += value;
+        }
+        self.stack[12] += Felt::new(2);
+    }
+
+    fn merkle_args(&self) -> (Word, u32, u64, Word) {
+        let node = self.get_word(0);
+        let depth = self.stack[4].as_int() as u32;
+        let index = self.stack[5].as_int();
+        let root = self.get_word(6);
+        (node, depth, index, root)
+    }
+
+    // MEMORY AND ADVICE HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    fn read_mem(&self, addr: u64) -> Word {
+        self.memory
+            .get(&self.ctx)
+            .and_then(|memory| memory.get(&addr))
+            .copied()
+            .unwrap_or([Felt::ZERO; 4])
+    }
+
+    fn write_mem(&mut self, addr: u64, word: Word) {
+        self.memory.entry(self.ctx).or_default().insert(addr, word);
+    }
+
+    fn read_tape(&mut self) -> Result<Felt, String> {
+        self.tape
+            .pop_front()
+            .ok_or_else(|| "advice tape is empty".to_string())
+    }
+
+    fn read_tapew(&mut self) -> Result<Word, String> {
+        if self.tape.len() < 4 {
+            return Err("advice tape is empty".to_string());
+        }
+        Ok([
+            self.read_tape()?,
+            self.read_tape()?,
+            self.read_tape()?,
+            self.read_tape()?,
+        ])
+    }
+
+    fn advice_set(&self, root: Word) -> Result<&AdviceSet, String> {
+        self.advice_sets
+            .get(&root.into_bytes())
+            .ok_or_else(|| "advice set not found".to_string())
+    }
+
+    // STATE RECORDING
+    // --------------------------------------------------------------------------------------------
+
+    fn record(&mut self, op: Operation) {
+        if op == Operation::Noop {
+            return;
+        }
+
+        self.states.push(ReferenceState {
+            op,
+            stack: self.stack[..STACK_TOP_SIZE].to_vec(),
+            memory: non_zero_words(
+                self.memory
+                    .get(&self.ctx)
+                    .into_iter()
+                    .flatten()
+                    .map(|(&addr, &word)| (addr, word)),
+            ),
+        });
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the list of (address, word) pairs from the provided memory state with all zero words
+/// removed.
+pub fn non_zero_words<I: IntoIterator<Item = (u64, Word)>>(memory: I) -> Vec<(u64, Word)> {
+    memory
+        .into_iter()
+        .filter(|(_, word)| word.iter().any(|&value| value != Felt::ZERO))
+        .collect()
+}
+
+fn to_felt(value: bool) -> Felt {
+    Felt::new(value as u64)
+}
+
+/// Computes the root of a Merkle tree from a node at the specified index and its Merkle path.
+fn compute_merkle_root(node: Word, path: &[Word], index: u64) -> Word {
+    let mut node = Digest::from(node);
+    let mut index = index;
+    for &sibling in path {
+        let sibling = Digest::from(sibling);
+        node = if index & 1 == 0 {
+            hasher::merge(&[node, sibling])
+        } else {
+            hasher::merge(&[sibling, node])
+        };
+        index >>= 1;
+    }
+    node.into()
+}
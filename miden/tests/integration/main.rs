@@ -1,4 +1,7 @@
 mod helpers;
+use helpers::{Felt, ProofOptions};
+use miden::{FieldExtension, HashFunction, ProvingPhase, VerificationError};
+use vm_core::Operation;
 
 mod air;
 mod exec_iters;
@@ -14,12 +17,536 @@ fn simple_program() {
     build_test!("begin push.1 push.2 add end").expect_stack(&[3]);
 }
 
+#[test]
+fn program_with_stack_inputs() {
+    let test = helpers::Test::with_stack_inputs("begin swap swap end", &[1, 2, 3], false);
+    let expected = build_test!("begin swap swap end", &[3, 2, 1]).inputs;
+    assert_eq!(expected.stack_init(), test.inputs.stack_init());
+    test.expect_stack(&[1, 2, 3]);
+
+    // the first input is at the top of the stack
+    helpers::Test::with_stack_inputs("begin sub end", &[5, 8], false).expect_stack(&[3]);
+}
+
 #[test]
 fn multi_output_program() {
     let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
     test.prove_and_verify(vec![1, 2, 3], false);
 }
 
+#[test]
+fn execute_chain() {
+    let assembler = miden::Assembler::new();
+    let writer = assembler
+        .compile("begin dup push.7 mul mem_store.5 end")
+        .unwrap();
+    let reader = assembler
+        .compile("begin mem_load.5 add push.2 mem_load.6 add end")
+        .unwrap();
+    let inputs = miden::ProgramInputs::new(&[3], &[], vec![]).unwrap();
+
+    // the reader sees the memory written by the writer and the stack left by the writer
+    let trace = miden::execute_chain(&[&writer, &reader], &inputs).unwrap();
+    assert_eq!(&[2, 24], &trace.program_outputs().stack()[..2]);
+
+    // the trace is for the program combining both programs
+    let program = miden::Program::chain(&[&writer, &reader]);
+    assert_eq!(program.hash(), trace.program_hash());
+    let (outputs, proof) = miden::prove(&program, &inputs, &ProofOptions::default()).unwrap();
+    let result = miden::verify(program.hash(), &[3], &outputs, proof);
+    assert!(result.is_ok(), "error: {:?}", result);
+}
+
+#[test]
+fn execute_single_proc() {
+    let source = "
+        use.std::math::u64
+        proc.inc add.1 end
+        begin
+            call.u64::checked_add
+            exec.u64::wrapping_sub
+        end";
+    let program = miden::Assembler::default()
+        .with_module_provider(::stdlib::StdLibrary::default())
+        .compile(source)
+        .unwrap();
+
+    // a = 2^32 + 5, b = 7; the limbs of each value are pushed low limb first
+    let inputs = miden::ProgramInputs::from_stack_inputs(&[5, 1, 7, 0]).unwrap();
+    let outputs = miden::execute_proc(&program, "std::math::u64::checked_add", &inputs).unwrap();
+    assert_eq!(&[1, 12, 0, 0], &outputs.stack()[..4]);
+
+    // procedures invoked via `exec` and local procedures which are not invoked can be executed as
+    // well; a - b = 2^32 - 2
+    let outputs = miden::execute_proc(&program, "std::math::u64::wrapping_sub", &inputs).unwrap();
+    assert_eq!(&[0, 4294967294, 0, 0], &outputs.stack()[..4]);
+    let outputs = miden::execute_proc(&program, "inc", &inputs).unwrap();
+    assert_eq!(&[1, 7, 1, 5], &outputs.stack()[..4]);
+
+    // library procedures which are not executed by the program are unknown
+    let err = miden::execute_proc(&program, "std::math::u64::wrapping_add", &inputs)
+        .err()
+        .unwrap();
+    assert!(matches!(err, miden::ExecutionError::ProcedureNotFound(_)));
+}
+
+#[test]
+fn chain_executions_through_outputs() {
+    let assembler = miden::Assembler::new();
+    // the first program leaves 18 values on the stack, and thus, 2 values in the overflow table
+    let first = assembler
+        .compile("begin push.1 push.2 push.3 add mul end")
+        .unwrap();
+    let second = assembler.compile("begin drop movup.15 add end").unwrap();
+    let stack_inputs = (1..=17).collect::<Vec<u64>>();
+    let inputs = miden::ProgramInputs::from_stack_inputs(&stack_inputs).unwrap();
+
+    let (outputs, proof) = miden::prove(&first, &inputs, &ProofOptions::default()).unwrap();
+    assert_eq!(18, outputs.stack().len());
+    assert!(outputs.has_overflow());
+    let result = miden::verify(first.hash(), &stack_inputs, &outputs, proof);
+    assert!(result.is_ok(), "error: {:?}", result);
+
+    // the second execution starts with the stack left by the first one, including the overflow
+    let mut process =
+        processor::Process::with_initial_stack_from_outputs(second.kernel(), &outputs);
+    let chained_outputs = process.execute(&second).unwrap();
+    let mut expected = vec![19];
+    expected.extend((3..=16).rev());
+    expected.push(1);
+    assert_eq!(expected, chained_outputs.stack());
+
+    // the second execution can be proven separately; the verifier expects the inputs in the
+    // order in which they are pushed onto the stack, which is the reverse of the output order
+    let second_inputs = miden::ProgramInputs::from_outputs(&outputs);
+    let (second_outputs, proof) =
+        miden::prove(&second, &second_inputs, &ProofOptions::default()).unwrap();
+    assert_eq!(chained_outputs.stack(), second_outputs.stack());
+    let stack_inputs = outputs.stack().iter().rev().copied().collect::<Vec<_>>();
+    let result = miden::verify(second.hash(), &stack_inputs, &second_outputs, proof);
+    assert!(result.is_ok(), "error: {:?}", result);
+}
+
+#[test]
+fn coverage_collector() {
+    let mut collector = helpers::CoverageCollector::new();
+    collector
+        .record(&build_test!("begin push.3 push.4 add end"))
+        .unwrap();
+    collector
+        .record(&build_test!(
+            "begin push.1 if.true push.5 else swap end end"
+        ))
+        .unwrap();
+    collector
+        .record(&build_test!("begin push.5 push.6 u32checked_add end"))
+        .unwrap();
+
+    // operations of failing programs executed before the failure are recorded as well
+    assert!(collector
+        .record(&build_test!("begin push.0 assert end"))
+        .is_err());
+
+    for op in [
+        Operation::Span,
+        Operation::End,
+        Operation::Split,
+        Operation::Push(Felt::new(3)),
+        Operation::Pad,
+        Operation::Incr,
+        Operation::Add,
+        Operation::U32add,
+        Operation::U32assert2,
+        Operation::Assert,
+    ] {
+        assert!(collector.is_covered(op), "{op} was not recorded");
+    }
+    // push.0 and push.1 are compiled into PAD and PAD INCR; thus, only 5 PUSH operations are run
+    assert_eq!(5, collector.count(Operation::Push(Felt::new(0))));
+
+    // the false branch of the conditional is not executed
+    let unused = collector.unused_ops();
+    assert!(unused.contains(&Operation::Swap));
+    assert!(unused.contains(&Operation::Loop));
+    assert!(!unused.contains(&Operation::Add));
+
+    let report = collector.report();
+    assert!(report.contains("unused operations: "));
+    assert!(report.contains("swap"));
+    assert!(report.contains("mpverify"));
+}
+
+#[test]
+fn disassemble_program() {
+    let source = "
+        begin
+            push.3
+            repeat.2
+                if.true
+                    push.5
+                else
+                    push.6 mul
+                end
+            end
+            push.1
+            while.true
+                push.0
+            end
+        end";
+    let program = build_test!(source).compile();
+    let disassembly = program.disassemble();
+
+    let expected = "\
+begin
+    push(3)
+    repeat.2
+        if.true
+            push(5)
+        else
+            push(6)
+            mul
+        end
+    end
+    pad
+    incr
+    while.true
+        pad
+    end
+end
+";
+    assert_eq!(expected, disassembly);
+}
+
+#[test]
+fn verify_with_different_hash_functions() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+
+    let mut proof_bytes = Vec::new();
+    for hash_fn in [HashFunction::Blake3_256, HashFunction::Sha3_256] {
+        let options = ProofOptions::new(27, 8, 16, hash_fn, FieldExtension::Quadratic, 8, 256);
+        let (outputs, proof) = prover::prove(&program, &test.inputs, &options).unwrap();
+        assert_eq!(hash_fn, proof.options().hash_fn());
+        proof_bytes.push(miden::proof_to_bytes(&proof));
+
+        let result = miden::verify(program.hash(), &[1, 2, 3], &outputs, proof);
+        assert!(result.is_ok(), "error: {:?}", result);
+    }
+
+    // the proofs are identical up to the hash function identifier in their options; a proof
+    // with an unknown identifier is rejected
+    let hash_fn_idx = proof_bytes[0]
+        .iter()
+        .zip(proof_bytes[1].iter())
+        .position(|(a, b)| a != b)
+        .unwrap();
+    assert_eq!(HashFunction::Sha3_256 as u8, proof_bytes[1][hash_fn_idx]);
+    proof_bytes[1][hash_fn_idx] = 0xff;
+    assert!(matches!(
+        miden::proof_from_bytes(&proof_bytes[1]),
+        Err(VerificationError::UnsupportedHashFunction(0xff))
+    ));
+}
+
+#[test]
+fn prove_with_progress() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+
+    let mut events = Vec::new();
+    let (outputs, proof) = miden::prove_with_progress(
+        &program,
+        &test.inputs,
+        &ProofOptions::default(),
+        |phase, progress| events.push((phase, progress)),
+    )
+    .unwrap();
+
+    let expected = [
+        (ProvingPhase::TraceGeneration, 0),
+        (ProvingPhase::TraceGeneration, 100),
+        (ProvingPhase::TraceCommitment, 0),
+        (ProvingPhase::TraceCommitment, 100),
+        (ProvingPhase::ProofConstruction, 0),
+        (ProvingPhase::ProofConstruction, 100),
+    ];
+    assert_eq!(expected.to_vec(), events);
+
+    let result = miden::verify(program.hash(), &[1, 2, 3], &outputs, proof);
+    assert!(result.is_ok(), "error: {:?}", result);
+}
+
+#[test]
+fn verify_with_cache() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+    let (outputs, proof) = miden::prove(&program, &test.inputs, &ProofOptions::default()).unwrap();
+
+    let mut verifier = miden::Verifier::new().with_cache(4);
+    for _ in 0..2 {
+        let result = verifier.verify(program.hash(), &[1, 2, 3], &outputs, proof.clone());
+        assert!(result.is_ok(), "error: {:?}", result);
+    }
+    assert_eq!(1, verifier.num_cache_hits());
+
+    // verifying the same proof against different inputs does not hit the cache
+    let result = verifier.verify(program.hash(), &[1, 2, 4], &outputs, proof.clone());
+    assert!(result.is_err());
+    assert_eq!(1, verifier.num_cache_hits());
+
+    // without a cache, every proof is verified
+    let mut verifier = miden::Verifier::new();
+    for _ in 0..2 {
+        let result = verifier.verify(program.hash(), &[1, 2, 3], &outputs, proof.clone());
+        assert!(result.is_ok(), "error: {:?}", result);
+    }
+    assert_eq!(0, verifier.num_cache_hits());
+}
+
+#[test]
+fn verify_with_allowed_programs() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+    let (outputs, proof) = miden::prove(&program, &test.inputs, &ProofOptions::default()).unwrap();
+    let other_program = build_test!("begin add movup.2 drop end", &[1, 2, 3]).compile();
+
+    let mut verifier = miden::Verifier::new().with_allowed_programs(&[program.hash()]);
+    let result = verifier.verify(program.hash(), &[1, 2, 3], &outputs, proof.clone());
+    assert!(result.is_ok(), "error: {:?}", result);
+
+    // a program which is not allowed is rejected before the proof is verified; otherwise, the
+    // verification would fail because the proof is not a proof of this program
+    let result = verifier.verify(other_program.hash(), &[1, 2, 3], &outputs, proof.clone());
+    assert_eq!(
+        Err(VerificationError::ProgramNotAllowed(other_program.hash())),
+        result
+    );
+
+    // the proof is still verified for allowed programs
+    let mut verifier =
+        miden::Verifier::new().with_allowed_programs(&[program.hash(), other_program.hash()]);
+    let result = verifier.verify(other_program.hash(), &[1, 2, 3], &outputs, proof);
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+}
+
+#[test]
+fn verify_with_cache_and_allowed_programs() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+    let (outputs, proof) = miden::prove(&program, &test.inputs, &ProofOptions::default()).unwrap();
+    let other_program = build_test!("begin add movup.2 drop end", &[1, 2, 3]).compile();
+
+    let mut verifier = miden::Verifier::new()
+        .with_cache(4)
+        .with_allowed_programs(&[program.hash()]);
+    for _ in 0..2 {
+        let result = verifier.verify(program.hash(), &[1, 2, 3], &outputs, proof.clone());
+        assert!(result.is_ok(), "error: {:?}", result);
+    }
+    assert_eq!(1, verifier.num_cache_hits());
+
+    // programs which are not allowed are rejected, and the rejections are not cached
+    for _ in 0..2 {
+        let result = verifier.verify(other_program.hash(), &[1, 2, 3], &outputs, proof.clone());
+        assert_eq!(
+            Err(VerificationError::ProgramNotAllowed(other_program.hash())),
+            result
+        );
+    }
+    assert_eq!(1, verifier.num_cache_hits());
+
+    // the builder methods can be applied in any order
+    let mut verifier = miden::Verifier::new()
+        .with_allowed_programs(&[program.hash()])
+        .with_cache(4);
+    for _ in 0..2 {
+        let result = verifier.verify(program.hash(), &[1, 2, 3], &outputs, proof.clone());
+        assert!(result.is_ok(), "error: {:?}", result);
+    }
+    assert_eq!(1, verifier.num_cache_hits());
+    let result = verifier.verify(other_program.hash(), &[1, 2, 3], &outputs, proof);
+    assert_eq!(
+        Err(VerificationError::ProgramNotAllowed(other_program.hash())),
+        result
+    );
+}
+
+#[test]
+fn proof_serialization() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+    let (outputs, proof) = miden::prove(&program, &test.inputs, &ProofOptions::default()).unwrap();
+
+    // a proof serialized in the current format can be deserialized and verified
+    let mut bytes = miden::proof_to_bytes(&proof);
+    assert_eq!(miden::PROOF_VERSION, bytes[0]);
+    let proof = miden::proof_from_bytes(&bytes).unwrap();
+    let result = miden::verify(program.hash(), &[1, 2, 3], &outputs, proof);
+    assert!(result.is_ok(), "error: {:?}", result);
+
+    // a proof with an unknown format version is rejected
+    bytes[0] = u8::MAX;
+    let result = miden::proof_from_bytes(&bytes);
+    assert_eq!(
+        Some(VerificationError::UnsupportedProofVersion(u8::MAX)),
+        result.err()
+    );
+
+    // an empty or a truncated proof is rejected
+    assert!(matches!(
+        miden::proof_from_bytes(&[]),
+        Err(VerificationError::InvalidProofBytes(_))
+    ));
+    bytes[0] = miden::PROOF_VERSION;
+    assert!(matches!(
+        miden::proof_from_bytes(&bytes[..bytes.len() / 2]),
+        Err(VerificationError::InvalidProofBytes(_))
+    ));
+}
+
+#[test]
+fn proof_options_serialization() {
+    let options = ProofOptions::new(
+        20,
+        16,
+        4,
+        HashFunction::Blake3_256,
+        FieldExtension::Quadratic,
+        4,
+        128,
+    );
+    let bytes = options.to_bytes();
+    let deserialized = ProofOptions::from_bytes(&bytes).unwrap();
+    assert_eq!(options, deserialized);
+
+    // a proof generated with the deserialized options is generated with the original options
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+    let (outputs, proof) = miden::prove(&program, &test.inputs, &deserialized).unwrap();
+    assert_eq!(*options, proof.context.options().clone());
+    let result = miden::verify(program.hash(), &[1, 2, 3], &outputs, proof);
+    assert!(result.is_ok(), "error: {:?}", result);
+
+    // invalid options and trailing bytes are rejected
+    let mut invalid_bytes = bytes.clone();
+    invalid_bytes[0] = 0;
+    assert!(ProofOptions::from_bytes(&invalid_bytes).is_err());
+    let mut extended_bytes = bytes.clone();
+    extended_bytes.push(0);
+    assert!(ProofOptions::from_bytes(&extended_bytes).is_err());
+    assert!(ProofOptions::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+    // the seed is neither serialized nor compared
+    let seeded_options = options.clone().with_zk_seed([7; 32]);
+    assert_eq!(bytes, seeded_options.to_bytes());
+    let deserialized = ProofOptions::from_bytes(&seeded_options.to_bytes()).unwrap();
+    assert_eq!(None, deserialized.zk_seed());
+    assert_eq!(seeded_options, deserialized);
+}
+
+#[test]
+fn prove_with_zk_seed() {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile();
+    let options = ProofOptions::default().with_zk_seed([7; 32]);
+    assert_eq!(Some([7; 32]), options.zk_seed());
+
+    // proofs generated with the same seed are identical
+    let (outputs, proof) = miden::prove(&program, &test.inputs, &options).unwrap();
+    let (_, same_seed_proof) = miden::prove(&program, &test.inputs, &options).unwrap();
+    assert_eq!(
+        miden::proof_to_bytes(&proof),
+        miden::proof_to_bytes(&same_seed_proof)
+    );
+
+    // a different seed results in a different proof
+    let other_options = ProofOptions::default().with_zk_seed([8; 32]);
+    let (_, other_proof) = miden::prove(&program, &test.inputs, &other_options).unwrap();
+    assert_ne!(
+        miden::proof_to_bytes(&proof),
+        miden::proof_to_bytes(&other_proof)
+    );
+
+    // all of the proofs verify
+    for proof in [proof, same_seed_proof, other_proof] {
+        let result = miden::verify(program.hash(), &[1, 2, 3], &outputs, proof);
+        assert!(result.is_ok(), "error: {:?}", result);
+    }
+}
+
+#[test]
+fn prove_from_serialized_trace() {
+    let source = "
+        begin
+            u32checked_and
+            push.0 mem_storew dropw
+            push.0 mem_loadw
+            rpperm
+        end";
+    let test = build_test!(
+        source,
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17]
+    );
+    let program = test.compile();
+    let trace = processor::execute(&program, &test.inputs).unwrap();
+    let bytes = trace.to_prover_bytes();
+
+    // the trace can be proven without re-executing the program
+    let trace = miden::ExecutionTrace::from_prover_bytes(&bytes).unwrap();
+    let (outputs, proof) = miden::prove_trace(trace, &ProofOptions::default()).unwrap();
+    let (expected_outputs, _) =
+        miden::prove(&program, &test.inputs, &ProofOptions::default()).unwrap();
+    assert_eq!(expected_outputs.stack(), outputs.stack());
+    assert_eq!(expected_outputs.overflow_addrs(), outputs.overflow_addrs());
+
+    let stack_inputs = (1..=17).collect::<Vec<u64>>();
+    let result = miden::verify(program.hash(), &stack_inputs, &outputs, proof);
+    assert!(result.is_ok(), "error: {:?}", result);
+
+    // a truncated trace is rejected
+    assert!(miden::ExecutionTrace::from_prover_bytes(&bytes[..bytes.len() / 2]).is_err());
+}
+
+#[test]
+fn debug_release_equivalence() {
+    // a program exercising procedure locals, memory, loops, conditionals, calls, and the
+    // standard library; debug mode must not affect its results
+    let source = "
+        use.std::math::u64
+
+        proc.foo.2
+            loc_store.0 loc_store.1
+            loc_load.0 loc_load.1 mul
+        end
+
+        proc.bar
+            exec.u64::checked_add
+        end
+
+        begin
+            push.3 push.4 exec.foo mem_store.5
+            push.0 push.10
+            dup neq.0
+            while.true
+                dup movup.2 add swap sub.1 dup neq.0
+            end
+            drop
+            dup push.40 u32checked_gt
+            if.true push.1 else push.2 end
+            mem_store.6
+            push.0 mem_loadw.5
+            call.bar
+        end";
+
+    // the program executes successfully, so its final states are compared
+    let test = build_test!(source, &[1, 2, 3, 4]);
+    test.execute().unwrap();
+    test.assert_debug_release_equivalent();
+
+    // failures are equivalent as well
+    build_test!("begin push.0 push.1 add assertz end").assert_debug_release_equivalent();
+}
+
 // MACROS TO BUILD TESTS
 // ================================================================================================
 
@@ -110,6 +637,8 @@ macro_rules! build_test_by_mode {
             kernel: None,
             inputs,
             in_debug_mode: $in_debug_mode,
+            mocks: Vec::new(),
+            libraries: Vec::new(),
         }
     }};
     ($in_debug_mode:expr, $source:expr, $stack_inputs:expr, $advice_tape:expr, $advice_sets:expr) => {{
@@ -121,6 +650,8 @@ macro_rules! build_test_by_mode {
             kernel: None,
             inputs,
             in_debug_mode: $in_debug_mode,
+            mocks: Vec::new(),
+            libraries: Vec::new(),
         }
     }};
 }
@@ -1,11 +1,12 @@
+use processor::HashOp;
 use rand_utils::rand_vector;
 use vm_core::{
-    chiplets::hasher::{apply_permutation, hash_elements, STATE_WIDTH},
-    AdviceSet, Felt, FieldElement, StarkField,
+    chiplets::hasher::{apply_permutation, STATE_WIDTH},
+    hash_elements, AdviceSet, Felt, FieldElement, StarkField,
 };
 
-use crate::build_op_test;
 use crate::helpers::crypto::{init_merkle_leaf, init_merkle_leaves};
+use crate::{build_op_test, build_test};
 
 // TESTS
 // ================================================================================================
@@ -189,6 +190,44 @@ fn mtree_update() {
     test.expect_stack(&final_stack);
 }
 
+#[test]
+fn hash_operations() {
+    // the SPAN block of the program is hashed before its operations are executed
+    let test = build_test!("begin rphash rphash end");
+    let operations = test.execute().unwrap().hash_operations();
+    let expected = [
+        HashOp::SpanBlock(1),
+        HashOp::Permutation,
+        HashOp::Permutation,
+    ];
+    assert_eq!(expected.to_vec(), operations);
+
+    // Merkle path verifications are recorded together with their depths
+    let leaves = init_merkle_leaves(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = AdviceSet::new_merkle_tree(leaves).unwrap();
+    let stack_inputs = [
+        tree.root()[0].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[3].as_int(),
+        3,
+        tree.depth() as u64,
+    ];
+    let source = "begin mtree_get push.1 if.true rphash end end";
+    let test = build_test!(source, &stack_inputs, &[], vec![tree]);
+    let operations = test.execute().unwrap().hash_operations();
+    let program_hashes = operations
+        .iter()
+        .filter(|op| matches!(op, HashOp::Permutation | HashOp::MerklePath(_)))
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![HashOp::MerklePath(3), HashOp::Permutation],
+        program_hashes
+    );
+    assert_eq!(Some(&HashOp::ControlBlock), operations.first());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -2,8 +2,8 @@ use proptest::prelude::*;
 use rand_utils::rand_value;
 use vm_core::{Felt, FieldElement, StarkField, WORD_LEN};
 
-use crate::build_op_test;
-use crate::helpers::{prop_randw, TestError};
+use crate::helpers::{prop_randw, Test, TestError, TestErrorKind};
+use crate::{build_op_test, build_test};
 
 // FIELD OPS ASSERTIONS - MANUAL TESTS
 // ================================================================================================
@@ -24,6 +24,27 @@ fn assert_fail() {
     test.expect_error(TestError::ExecutionError("FailedAssertion"));
 }
 
+#[test]
+fn assert_fail_regex() {
+    // the cycle at which the assertion fails depends on the number of preceding operations
+    for num_ops in [0, 3, 10] {
+        let source = format!("begin {} push.2 assert end", "push.0 drop ".repeat(num_ops));
+        let test = build_test!(&source, &[]);
+        test.expect_error_regex(
+            TestErrorKind::ExecutionError,
+            r"^assertion failed at clk \d+$",
+        );
+    }
+
+    // the cycle number is reported
+    let test = build_op_test!("assert", &[2]);
+    test.expect_error_regex(TestErrorKind::ExecutionError, r"at clk [1-9]\d*$");
+
+    // compilation errors can be matched as well
+    let test = build_op_test!("assert.1", &[1]);
+    test.expect_error_regex(TestErrorKind::AssemblyError, r"assert\.1");
+}
+
 #[test]
 fn assert_eq() {
     let asm_op = "assert_eq";
@@ -44,6 +65,60 @@ fn assert_eq_fail() {
 
     let test = build_op_test!(asm_op, &[1, 4]);
     test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // both operands are included in the error
+    let test = build_op_test!(asm_op, &[5, 4]);
+    let err = test.execute().err().unwrap();
+    assert_eq!("assertion failed: 5 != 4 at clk 2", err.to_string());
+}
+
+#[test]
+fn assert_eqw() {
+    let asm_op = "assert_eqw";
+
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 1, 2, 3, 4]);
+    test.expect_stack(&[]);
+
+    // the rest of the stack isn't affected
+    let test = build_op_test!(asm_op, &[5, 1, 2, 3, 4, 1, 2, 3, 4]);
+    test.expect_stack(&[5]);
+}
+
+#[test]
+fn assert_eqw_fail() {
+    let asm_op = "assert_eqw";
+
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 5, 6, 7, 8]);
+    test.expect_error(TestError::ExecutionError("FailedAssertionEqw"));
+
+    // lanes are numbered from the top of the stack; the lane which differed and both of its
+    // values are included in the error
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 1, 7, 3, 4]);
+    let err = test.execute().err().unwrap();
+    assert_eq!(
+        "word assertion failed: lane 2 differs (2 != 7) at clk 9",
+        err.to_string()
+    );
+
+    // the first lane which differed is reported
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 9, 2, 3, 8]);
+    let err = test.execute().err().unwrap();
+    assert_eq!(
+        "word assertion failed: lane 0 differs (4 != 8) at clk 3",
+        err.to_string()
+    );
+
+    let test = build_op_test!(asm_op, &[1, 2, 3, 4, 9, 2, 3, 4]);
+    let err = test.execute().err().unwrap();
+    assert_eq!(
+        "word assertion failed: lane 3 differs (1 != 9) at clk 11",
+        err.to_string()
+    );
+
+    // an assertion which is not a part of assert_eqw is reported as a single assertion
+    let test = build_op_test!("movup.4 assert_eq", &[1, 2, 3, 4, 5, 6, 7, 8]);
+    let err = test.execute().err().unwrap();
+    assert_eq!("assertion failed: 8 != 4 at clk 3", err.to_string());
 }
 
 // FIELD OPS ARITHMETIC - MANUAL TESTS
@@ -554,6 +629,23 @@ fn gte() {
 // FIELD OPS ARITHMETIC - RANDOMIZED TESTS
 // ================================================================================================
 
+#[test]
+fn add_random_inputs() {
+    let strategy =
+        (any::<u32>(), any::<u32>()).prop_map(|(a, b)| (vec![a as u64, b as u64], vec![]));
+
+    // the result of addition matches the sum of the inputs
+    Test::with_random_inputs("begin add end", strategy.clone())
+        .prop_expect_stack(|stack_inputs, _| vec![stack_inputs[0] + stack_inputs[1]]);
+
+    // addition is commutative
+    Test::with_random_inputs("begin add end", strategy).run(|test, stack_inputs, _| {
+        let swapped = build_test!(&test.source, &[stack_inputs[1], stack_inputs[0]]);
+        prop_assert_eq!(test.get_last_stack_state(), swapped.get_last_stack_state());
+        Ok(())
+    });
+}
+
 proptest! {
     #[test]
     fn add_proptest(a in any::<u64>(), b in any::<u64>()) {
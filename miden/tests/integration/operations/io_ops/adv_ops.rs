@@ -1,5 +1,11 @@
 use super::{build_op_test, build_test, TestError};
-use vm_core::{chiplets::hasher::apply_permutation, utils::ToElements, Felt, StarkField};
+use processor::{AdviceProvider, ExecutionError, Process, SharedAdviceProvider, SharedAdviceTape};
+use vm_core::{
+    chiplets::hasher::apply_permutation,
+    errors::InputError,
+    utils::{IntoBytes, ToElements},
+    AdviceSet, Felt, FieldElement, ProgramInputs, StarkField, Word,
+};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
 // ================================================================================================
@@ -53,6 +59,42 @@ fn adv_loadw_invalid() {
     test.expect_error(TestError::ExecutionError("AdviceTapeReadFailed"));
 }
 
+#[test]
+fn adv_loadw_key() {
+    // the keys are overwritten with the values stored under them in the advice map
+    let source = "begin push.1.2.3.4 adv_loadw.key push.9.10.11.12 adv_loadw.key end";
+    let program = build_test!(source).compile();
+
+    let key1: Word = [1, 2, 3, 4].to_elements().try_into().unwrap();
+    let key2: Word = [9, 10, 11, 12].to_elements().try_into().unwrap();
+    let inputs = ProgramInputs::builder()
+        .with_advice_map([
+            (key1.into_bytes(), [5, 6, 7, 8].to_elements()),
+            (key2.into_bytes(), [13, 14, 15, 16].to_elements()),
+        ])
+        .build()
+        .unwrap();
+
+    let trace = processor::execute(&program, &inputs).unwrap();
+    let mut expected = [16, 15, 14, 13, 8, 7, 6, 5].to_elements();
+    expected.resize(16, Felt::ZERO);
+    assert_eq!(expected, trace.last_stack_state());
+
+    // the values are removed from the advice map once they are loaded
+    let source = "begin push.1.2.3.4 adv_loadw.key push.1.2.3.4 adv_loadw.key end";
+    let program = build_test!(source).compile();
+    let result = processor::execute(&program, &inputs);
+    assert!(matches!(result, Err(ExecutionError::AdviceMapKeyNotFound(key)) if key == key1));
+
+    // values which are not words cannot be loaded
+    let inputs = ProgramInputs::builder()
+        .with_advice_map([(key1.into_bytes(), [5, 6, 7].to_elements())])
+        .build()
+        .unwrap();
+    let result = processor::execute(&program, &inputs);
+    assert!(matches!(result, Err(ExecutionError::AdviceMapValueNotWord(key, 3)) if key == key1));
+}
+
 // MOVING ELEMENTS TO MEMORY VIA THE STACK (PIPE)
 // ================================================================================================
 
@@ -87,3 +129,225 @@ fn adv_pipe() {
     let test = build_test!(source, &[], &advice_tape, vec![]);
     test.expect_stack(&final_stack);
 }
+
+// INSPECTING THE ADVICE TAPE
+// ================================================================================================
+
+#[test]
+fn advice_remaining() {
+    let test = build_test!("begin adv_push.3 end", &[], &[1, 2, 3, 4, 5], vec![]);
+    let program = test.compile();
+
+    let mut process = Process::new(program.kernel(), test.inputs.clone());
+    assert_eq!(5, process.advice_remaining());
+    process.execute(&program).unwrap();
+
+    // the remaining elements are returned with the next element to be read last
+    assert_eq!(2, process.advice_remaining());
+    assert_eq!([5, 4].to_elements(), process.advice_peek_all());
+}
+
+// BUILDING PROGRAM INPUTS
+// ================================================================================================
+
+#[test]
+fn execute_with_built_inputs() {
+    // read the values under the key at the top of the stack into the advice tape, get a leaf of
+    // the Merkle tree, and then read the advice tape
+    let source = "begin adv.keyval dropw mtree_get adv_push.3 end";
+    let program = build_test!(source).compile();
+
+    let index = 1;
+    let leaves = [[1, 2, 3, 4], [5, 6, 7, 8]].map(|leaf| leaf.to_elements().try_into().unwrap());
+    let tree = AdviceSet::new_merkle_tree(leaves.to_vec()).unwrap();
+    let root = tree.root().map(|v| v.as_int());
+    let depth = tree.depth() as u64;
+    let key: Word = [9, 10, 11, 12].to_elements().try_into().unwrap();
+
+    let inputs = ProgramInputs::builder()
+        .with_stack(&[
+            root[0], root[1], root[2], root[3], index, depth, 9, 10, 11, 12,
+        ])
+        .with_advice_tape(&[30])
+        .with_advice_map([(key.into_bytes(), [10, 20].to_elements())])
+        .with_merkle_store(vec![tree])
+        .build()
+        .unwrap();
+
+    // values from the advice map are read before the values initially on the advice tape
+    let trace = processor::execute(&program, &inputs).unwrap();
+    let mut expected = [30, 20, 10, 8, 7, 6, 5, root[3], root[2], root[1], root[0]].to_elements();
+    expected.resize(16, Felt::ZERO);
+    assert_eq!(expected, trace.last_stack_state());
+
+    // duplicate advice sets are reported when the inputs are built
+    let tree = AdviceSet::new_merkle_tree(leaves.to_vec()).unwrap();
+    let result = ProgramInputs::builder()
+        .with_merkle_store(vec![tree.clone(), tree])
+        .build();
+    assert!(matches!(result, Err(InputError::DuplicateAdviceRoot(_))));
+}
+
+// CUSTOM ADVICE PROVIDERS
+// ================================================================================================
+
+/// An advice provider with an unbounded advice tape which yields consecutive integers starting
+/// from 1. Only the values written onto the tape are reported as remaining on the tape, since the
+/// generated values are not known in advance. Advice map and advice sets are not supported by
+/// this provider.
+#[derive(Default)]
+struct CountingAdviceProvider {
+    step: u32,
+    counter: u64,
+    written: Vec<Felt>,
+}
+
+impl AdviceProvider for CountingAdviceProvider {
+    fn read_tape(&mut self) -> Result<Felt, ExecutionError> {
+        Ok(self.written.pop().unwrap_or_else(|| {
+            self.counter += 1;
+            Felt::new(self.counter)
+        }))
+    }
+
+    fn read_tapew(&mut self) -> Result<Word, ExecutionError> {
+        let mut word = [Felt::ZERO; 4];
+        for element in word.iter_mut() {
+            *element = self.read_tape()?;
+        }
+        Ok(word)
+    }
+
+    fn write_tape(&mut self, value: Felt) {
+        self.written.push(value);
+    }
+
+    fn peek_tape(&self) -> &[Felt] {
+        &self.written
+    }
+
+    fn write_tape_from_map(&mut self, key: Word) -> Result<(), ExecutionError> {
+        Err(ExecutionError::AdviceMapKeyNotFound(key))
+    }
+
+    fn take_from_map(&mut self, key: Word) -> Result<Vec<Felt>, ExecutionError> {
+        Err(ExecutionError::AdviceMapKeyNotFound(key))
+    }
+
+    fn insert_into_map(&mut self, key: Word, _values: Vec<Felt>) -> Result<(), ExecutionError> {
+        Err(ExecutionError::DuplicateAdviceKey(key))
+    }
+
+    fn has_advice_set(&self, _root: Word) -> bool {
+        false
+    }
+
+    fn get_tree_node(&mut self, root: Word, _: Felt, _: Felt) -> Result<Word, ExecutionError> {
+        Err(ExecutionError::AdviceSetNotFound(root.into_bytes()))
+    }
+
+    fn get_merkle_path(
+        &mut self,
+        root: Word,
+        _: Felt,
+        _: Felt,
+    ) -> Result<Vec<Word>, ExecutionError> {
+        Err(ExecutionError::AdviceSetNotFound(root.into_bytes()))
+    }
+
+    fn update_merkle_leaf(
+        &mut self,
+        root: Word,
+        _: Felt,
+        _: Word,
+        _: bool,
+    ) -> Result<Vec<Word>, ExecutionError> {
+        Err(ExecutionError::AdviceSetNotFound(root.into_bytes()))
+    }
+
+    fn advance_clock(&mut self) {
+        self.step += 1;
+    }
+}
+
+#[test]
+fn execute_with_custom_advice_provider() {
+    let source = "begin adv_push.3 adv_loadw end";
+    let test = build_test!(source);
+    let program = test.compile();
+
+    let advice = Box::new(CountingAdviceProvider::default());
+    let stack_inputs = [5_u64, 6, 7, 8].to_elements();
+    let trace = processor::execute_with_advice(&program, &stack_inputs, advice).unwrap();
+
+    // adv_push.3 pushes 1, 2, 3 onto the stack; adv_loadw then overwrites the top word (which
+    // consists of 3, 2, 1, 8) with the next 4 values from the tape.
+    let mut expected = [7, 6, 5, 4, 7, 6, 5].to_elements();
+    expected.resize(16, Felt::ZERO);
+    assert_eq!(expected, trace.last_stack_state());
+
+    // using an advice provider with an empty tape fails
+    let advice = Box::new(processor::MemAdviceProvider::new(test.inputs.clone()));
+    let result = processor::execute_with_advice(&program, &stack_inputs, advice);
+    assert!(matches!(
+        result,
+        Err(ExecutionError::AdviceTapeReadFailed(_))
+    ));
+}
+
+// SHARED ADVICE TAPE
+// ================================================================================================
+
+#[test]
+fn execute_with_shared_advice_tape() {
+    let values = (1..=10).collect::<Vec<u64>>();
+    let tape = SharedAdviceTape::new(&values.to_elements());
+    let reader = build_test!("begin adv_push.3 end").compile();
+    let word_reader = build_test!("begin adv_loadw end").compile();
+
+    // each execution consumes the shared tape independently, starting from its first element
+    let advice = Box::new(SharedAdviceProvider::new(
+        tape.clone(),
+        ProgramInputs::none(),
+    ));
+    let mut process = Process::with_advice(reader.kernel(), &[], advice);
+    let outputs = process.execute(&reader).unwrap();
+    assert_eq!(&[3, 2, 1], &outputs.stack()[..3]);
+    assert_eq!(7, process.advice_remaining());
+    assert_eq!(
+        [10, 9, 8, 7, 6, 5, 4].to_elements(),
+        process.advice_peek_all()
+    );
+
+    let advice = Box::new(SharedAdviceProvider::new(
+        tape.clone(),
+        ProgramInputs::none(),
+    ));
+    let mut process = Process::with_advice(word_reader.kernel(), &[], advice);
+    let outputs = process.execute(&word_reader).unwrap();
+    let inputs = ProgramInputs::new(&[], &values, vec![]).unwrap();
+    let trace = processor::execute(&word_reader, &inputs).unwrap();
+    assert_eq!(
+        trace.last_stack_state().to_vec(),
+        outputs.stack().to_vec().to_elements()
+    );
+    assert_eq!(6, process.advice_remaining());
+    assert_eq!(10, tape.len());
+
+    // values written onto the tape (here, from the advice map) and the values on the tape of the
+    // inputs are read before the values of the shared tape
+    let source = "begin adv.keyval dropw adv_push.4 end";
+    let program = build_test!(source).compile();
+    let key: Word = [9, 10, 11, 12].to_elements().try_into().unwrap();
+    let inputs = ProgramInputs::builder()
+        .with_advice_tape(&[30])
+        .with_advice_map([(key.into_bytes(), [10, 20].to_elements())])
+        .build()
+        .unwrap();
+    let advice = Box::new(SharedAdviceProvider::new(tape, inputs));
+    let stack_init = [12, 11, 10, 9].to_elements();
+    let mut process = Process::with_advice(program.kernel(), &stack_init, advice);
+    let outputs = process.execute(&program).unwrap();
+    assert_eq!(&[1, 30, 20, 10], &outputs.stack()[..4]);
+    assert_eq!(9, process.advice_remaining());
+}
@@ -1,4 +1,6 @@
-use super::build_op_test;
+use super::{build_op_test, build_test, TestError};
+use crate::helpers::crypto::init_merkle_leaves;
+use vm_core::{AdviceSet, StarkField};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
 // ================================================================================================
@@ -86,3 +88,55 @@ fn push_without_separator() {
     let test = build_op_test!(asm_op);
     test.expect_stack(&expected);
 }
+
+#[test]
+fn push_mtree_root() {
+    let tree = AdviceSet::new_merkle_tree(init_merkle_leaves(&[1, 2, 3, 4])).unwrap();
+    let root = tree.root().map(|v| v.as_int());
+
+    // --- the root is computed at assembly time ----------------------------------------------
+    let test = build_op_test!("push.mtree_root([1,2,3,4])");
+    test.expect_stack(&[root[3], root[2], root[1], root[0]]);
+
+    // --- the embedded root matches the root computed at runtime -----------------------------
+    let source = "
+        begin
+            push.mtree_root([1,2,3,4])
+            push.1.0.0.0 push.2.0.0.0 rphash
+            push.3.0.0.0 push.4.0.0.0 rphash
+            rphash
+            eqw movdn.8 dropw dropw
+        end";
+    let test = build_test!(source);
+    test.expect_stack(&[1]);
+
+    // --- the number of leaves must be a power of two ----------------------------------------
+    let test = build_op_test!("push.mtree_root([1,2,3])");
+    test.expect_error(TestError::AssemblyError("mtree_root"));
+}
+
+#[test]
+fn push_fixed() {
+    // --- values are converted into fixed-point representation at assembly time -------------
+    let test = build_op_test!("push.fixed(1.5, Q16.16)");
+    test.expect_stack(&[0x18000]);
+
+    // 3.14 * 2^16 = 205783.04 is rounded to the nearest integer
+    let test = build_op_test!("push.fixed(3.14, Q16.16) push.fixed(0.75,Q8.8) push.fixed(7, Q3.0)");
+    test.expect_stack(&[7, 0xc0, 205783]);
+
+    // --- values which do not fit into the format are rejected -------------------------------
+    let test = build_op_test!("push.fixed(65536, Q16.16)");
+    test.expect_error(TestError::AssemblyError("value overflows the format"));
+
+    // 0.9999999 * 2^16 is rounded to 2^16, which does not fit into 16 bits
+    let test = build_op_test!("push.fixed(0.9999999, Q0.16)");
+    test.expect_error(TestError::AssemblyError("value overflows the format"));
+
+    // --- malformed values and formats are rejected ------------------------------------------
+    let test = build_op_test!("push.fixed(-1.5, Q16.16)");
+    test.expect_error(TestError::AssemblyError("invalid decimal value"));
+
+    let test = build_op_test!("push.fixed(1.5, Q32.32)");
+    test.expect_error(TestError::AssemblyError("invalid fixed-point format"));
+}
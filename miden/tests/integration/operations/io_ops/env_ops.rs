@@ -1,7 +1,7 @@
 use crate::{build_op_test, build_test, helpers::Test};
-use processor::FMP_MIN;
+use processor::{ExecutionError, HostContext, Process, FMP_MIN};
 use vm_core::{
-    code_blocks::CodeBlock, stack::STACK_TOP_SIZE, Operation, ProgramInputs, StarkField, Word,
+    code_blocks::CodeBlock, stack::STACK_TOP_SIZE, Felt, Operation, ProgramInputs, StarkField, Word,
 };
 
 // SDEPTH INSTRUCTION
@@ -148,6 +148,8 @@ fn caller() {
         kernel: Some(kernel_source.to_string()),
         inputs: ProgramInputs::from_stack_inputs(&[1, 2, 3, 4, 5]).unwrap(),
         in_debug_mode: false,
+        mocks: Vec::new(),
+        libraries: Vec::new(),
     };
     // top 4 elements should be overwritten with the hash of `bar` procedure, but the 5th
     // element should remain untouched
@@ -157,6 +159,50 @@ fn caller() {
     test.prove_and_verify(vec![1, 2, 3, 4, 5], false);
 }
 
+// PUSH.ENV INSTRUCTION
+// ================================================================================================
+
+#[test]
+fn push_env() {
+    let source = "begin push.env.timestamp push.env.block_number push.env.block_number add end";
+    let test = build_test!(source, &[7]);
+    let program = test.compile();
+
+    let host = HostContext::new()
+        .with_block_number(123)
+        .and_then(|host| host.with_timestamp(1_665_000_000))
+        .unwrap();
+    let mut process = Process::new(program.kernel(), test.inputs.clone()).with_host_context(host);
+    let outputs = process.execute(&program).unwrap();
+    assert_eq!(&[246, 1_665_000_000, 7], &outputs.stack()[..3]);
+
+    // the host context can also be supplied when proving the program; the environment values are
+    // bound to the proof only via the program outputs
+    let host = HostContext::new()
+        .with_block_number(123)
+        .and_then(|host| host.with_timestamp(1_665_000_000))
+        .unwrap();
+    let options = miden::ProofOptions::default();
+    let (outputs, proof) =
+        miden::prove_with_host_context(&program, &test.inputs, host, &options).unwrap();
+    assert_eq!(&[246, 1_665_000_000, 7], &outputs.stack()[..3]);
+    assert!(miden::verify(program.hash(), &[7], &outputs, proof).is_ok());
+
+    // values which were not supplied by the host cannot be read
+    let host = HostContext::new().with_block_number(123).unwrap();
+    let mut process = Process::new(program.kernel(), test.inputs.clone()).with_host_context(host);
+    let err = process.execute(&program).err().unwrap();
+    assert!(matches!(err, ExecutionError::EnvValueNotAvailable(_)));
+    assert_eq!(
+        "environment value timestamp not supplied by the host",
+        err.to_string()
+    );
+
+    // values which are not valid field elements are rejected
+    assert!(HostContext::new().with_block_number(u64::MAX).is_err());
+    assert!(HostContext::new().with_timestamp(Felt::MODULUS).is_err());
+}
+
 fn build_bar_hash() -> [u64; 4] {
     let foo_root = CodeBlock::new_span(vec![Operation::Caller]);
     let bar_root = CodeBlock::new_syscall(foo_root.hash());
@@ -1,4 +1,5 @@
 use super::{build_op_test, build_test};
+use processor::{ExecutionWarning, MemoryAccess};
 use vm_core::{chiplets::hasher::apply_permutation, utils::ToElements, Felt, StarkField};
 
 // LOADING SINGLE ELEMENT ONTO THE STACK (MLOAD)
@@ -184,3 +185,96 @@ fn read_after_write() {
     let test = build_op_test!("mem_storew.0 dropw mem_loadw.0", &[1, 2, 3, 4, 5, 6, 7, 8]);
     test.expect_stack(&[8, 7, 6, 5]);
 }
+
+// WARNINGS
+// ================================================================================================
+
+#[test]
+fn uninitialized_memory_read_warnings() {
+    // address 2 is written before it is read, while addresses 3 and 4 are not; address 3 is read
+    // twice, but only the first read is reported
+    let source = "
+        begin
+            push.1 mem_store.2
+            mem_load.2
+            mem_load.3
+            mem_load.3
+            padw mem_loadw.4
+        end";
+
+    let test = build_test!(source, &[]);
+    let (trace, warnings) =
+        processor::execute_with_warnings(&test.compile(), &test.inputs).unwrap();
+    assert_eq!(2, warnings.len(), "unexpected warnings: {warnings:?}");
+    assert!(matches!(
+        warnings[0],
+        ExecutionWarning::UninitializedMemoryRead(_, 0, 3)
+    ));
+    assert!(matches!(
+        warnings[1],
+        ExecutionWarning::UninitializedMemoryRead(_, 0, 4)
+    ));
+    assert!(warnings[0]
+        .to_string()
+        .starts_with("read of uninitialized memory address 3"));
+
+    // the warnings do not affect the result of the execution
+    assert_eq!(
+        test.execute().unwrap().last_stack_state(),
+        trace.last_stack_state()
+    );
+}
+
+#[test]
+fn assert_no_warnings() {
+    // a program which reads only the memory it has written is warning-clean
+    let test = build_test!("begin push.1 mem_store.2 mem_load.2 end", &[]);
+    test.assert_no_warnings();
+
+    // a read of uninitialized memory fails the assertion
+    let test = build_test!("begin push.1 mem_store.2 mem_load.3 end", &[]);
+    let message = std::panic::catch_unwind(|| test.assert_no_warnings())
+        .err()
+        .and_then(|err| err.downcast_ref::<String>().cloned())
+        .expect("assertion succeeded, but a warning was expected");
+    assert!(message.contains("read of uninitialized memory address 3"));
+}
+
+// MEMORY ACCESS LOG
+// ================================================================================================
+
+#[test]
+fn memory_access_log() {
+    let source = "
+        begin
+            push.7 mem_store.2
+            mem_load.2 drop
+            push.4 padw padw padw mem_stream
+        end";
+
+    let test = build_test!(source, &[]);
+    let program = test.compile();
+    let log = processor::execute_with_memory_access_log(&program, &test.inputs)
+        .unwrap()
+        .memory_access_log();
+
+    let accesses = log
+        .iter()
+        .map(|&(_, access, addr)| (access, addr))
+        .collect::<Vec<_>>();
+    let expected = [
+        (MemoryAccess::Write, 2),
+        (MemoryAccess::Read, 2),
+        (MemoryAccess::Read, 4),
+        (MemoryAccess::Read, 5),
+    ];
+    assert_eq!(expected.to_vec(), accesses);
+
+    // both words read by mem_stream are read in the same cycle
+    assert!(log[0].0 < log[1].0);
+    assert!(log[1].0 < log[2].0);
+    assert_eq!(log[2].0, log[3].0);
+
+    // the accesses are not recorded by default
+    assert!(test.execute().unwrap().memory_access_log().is_empty());
+}
@@ -0,0 +1,63 @@
+use super::build_test;
+use crate::helpers::{crypto::init_merkle_leaves, TestError};
+use vm_core::{AdviceSchema, AdviceSet, Felt, StarkField, Word};
+
+#[test]
+fn read_structured_advice() {
+    let tree = AdviceSet::new_merkle_tree(init_merkle_leaves(&[1, 2, 3, 4])).unwrap();
+    let path = tree.get_path(2, 1).unwrap();
+    let key = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+
+    // values are provided in an order different from the declared one
+    let schema = AdviceSchema::new()
+        .with_int("nonce")
+        .with_word("key")
+        .with_merkle_path("path", 2);
+    let advice_tape = schema
+        .values()
+        .with_merkle_path("path", &path)
+        .with_word("key", key)
+        .with_int("nonce", 42)
+        .to_advice_tape()
+        .unwrap();
+
+    // the fields are read in the declared order
+    let source = "
+        use.std::advice
+        begin
+            exec.advice::read_int
+            exec.advice::read_word
+            push.100 push.2 exec.advice::read_merkle_path
+            padw mem_loadw.100 padw mem_loadw.101
+        end";
+
+    let mut expected_stack = Vec::new();
+    expected_stack.extend_from_slice(&word_to_stack(path[1]));
+    expected_stack.extend_from_slice(&word_to_stack(path[0]));
+    expected_stack.extend_from_slice(&word_to_stack(key));
+    expected_stack.push(42);
+
+    let test = build_test!(source, &[], &advice_tape, vec![]);
+    test.expect_stack(&expected_stack);
+
+    // reading a Merkle path of a different depth fails
+    let source = "
+        use.std::advice
+        begin
+            exec.advice::read_int
+            exec.advice::read_word
+            push.100 push.3 exec.advice::read_merkle_path
+        end";
+
+    let test = build_test!(source, &[], &advice_tape, vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the elements of the word in the order they are located on the stack, starting from
+/// the top.
+fn word_to_stack(word: Word) -> [u64; 4] {
+    [word[3], word[2], word[1], word[0]].map(|v| v.as_int())
+}
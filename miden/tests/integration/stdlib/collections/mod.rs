@@ -0,0 +1,3 @@
+use super::build_test;
+
+mod ring_buffer;
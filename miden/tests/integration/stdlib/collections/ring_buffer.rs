@@ -0,0 +1,132 @@
+use super::build_test;
+use crate::helpers::TestError;
+use std::collections::VecDeque;
+
+// CONSTANTS
+// ================================================================================================
+
+const BUFFER_ADDR: u64 = 100;
+
+// RING BUFFER
+// ================================================================================================
+
+#[test]
+fn ring_buffer_fifo_order() {
+    // fill the buffer, pop one element, and push one more element which wraps around into the
+    // first slot; then drain the buffer
+    let source = format!(
+        "
+        use.std::collections::ring_buffer
+        begin
+            push.{BUFFER_ADDR} push.3 exec.ring_buffer::init
+
+            push.1 push.{BUFFER_ADDR} exec.ring_buffer::push
+            push.2 push.{BUFFER_ADDR} exec.ring_buffer::push
+            push.3 push.{BUFFER_ADDR} exec.ring_buffer::push
+            push.{BUFFER_ADDR} exec.ring_buffer::is_full
+
+            push.{BUFFER_ADDR} exec.ring_buffer::pop
+            push.4 push.{BUFFER_ADDR} exec.ring_buffer::push
+            push.{BUFFER_ADDR} exec.ring_buffer::len
+
+            push.{BUFFER_ADDR} exec.ring_buffer::pop
+            push.{BUFFER_ADDR} exec.ring_buffer::pop
+            push.{BUFFER_ADDR} exec.ring_buffer::pop
+            push.{BUFFER_ADDR} exec.ring_buffer::is_empty
+        end"
+    );
+
+    // the element pushed after the wraparound is stored in the first slot
+    let test = build_test!(&source, &[]);
+    test.expect_stack_and_memory(&[1, 4, 3, 2, 3, 1, 1], BUFFER_ADDR + 1, &[4, 0, 0, 0]);
+}
+
+#[test]
+fn ring_buffer_wraparound() {
+    // push and pop elements in batches which do not align with the capacity, so that both the
+    // head and the tail of the buffer wrap around several times
+    let capacity = 4;
+    let mut source = format!(
+        "
+        use.std::collections::ring_buffer
+        begin
+            push.{BUFFER_ADDR} push.{capacity} exec.ring_buffer::init"
+    );
+
+    let mut buffer = VecDeque::new();
+    let mut popped = Vec::new();
+    let mut next_value = 1;
+    for (num_pushes, num_pops) in [(3, 2), (3, 3), (3, 2), (2, 4), (1, 0)] {
+        for _ in 0..num_pushes {
+            source.push_str(&format!(
+                " push.{next_value} push.{BUFFER_ADDR} exec.ring_buffer::push"
+            ));
+            buffer.push_back(next_value);
+            next_value += 1;
+        }
+        assert!(buffer.len() <= capacity);
+        for _ in 0..num_pops {
+            source.push_str(&format!(" push.{BUFFER_ADDR} exec.ring_buffer::pop"));
+            popped.push(buffer.pop_front().unwrap());
+        }
+    }
+    source.push_str(&format!(" push.{BUFFER_ADDR} exec.ring_buffer::len end"));
+
+    // the top of the stack is the length of the buffer followed by the popped elements, with the
+    // most recently popped element first
+    let mut expected = vec![buffer.len() as u64];
+    expected.extend(popped.iter().rev());
+    let test = build_test!(&source, &[]);
+    test.expect_stack(&expected);
+}
+
+#[test]
+fn ring_buffer_capacity() {
+    // pushing into a full buffer fails
+    let source = format!(
+        "
+        use.std::collections::ring_buffer
+        begin
+            push.{BUFFER_ADDR} push.2 exec.ring_buffer::init
+            push.1 push.{BUFFER_ADDR} exec.ring_buffer::push
+            push.2 push.{BUFFER_ADDR} exec.ring_buffer::push
+            push.3 push.{BUFFER_ADDR} exec.ring_buffer::push
+        end"
+    );
+    let test = build_test!(&source, &[]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // popping from an empty buffer fails, including after the buffer has been drained
+    let source = format!(
+        "
+        use.std::collections::ring_buffer
+        begin
+            push.{BUFFER_ADDR} push.2 exec.ring_buffer::init
+            push.1 push.{BUFFER_ADDR} exec.ring_buffer::push
+            push.{BUFFER_ADDR} exec.ring_buffer::pop
+            push.{BUFFER_ADDR} exec.ring_buffer::pop
+        end"
+    );
+    let test = build_test!(&source, &[]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // pushing into an uninitialized buffer fails
+    let source = format!(
+        "
+        use.std::collections::ring_buffer
+        begin
+            push.1 push.{BUFFER_ADDR} exec.ring_buffer::push
+        end"
+    );
+    let test = build_test!(&source, &[]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // a buffer cannot be initialized with zero capacity
+    let source = "
+        use.std::collections::ring_buffer
+        begin
+            exec.ring_buffer::init
+        end";
+    let test = build_test!(source, &[BUFFER_ADDR, 0]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
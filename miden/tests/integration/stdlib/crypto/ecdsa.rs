@@ -0,0 +1,191 @@
+use super::build_test;
+use crate::helpers::{Test, TestError};
+use sha2::{Digest, Sha256};
+
+// TEST VECTORS
+// ================================================================================================
+
+/// A published secp256k1 test vector for deterministic (RFC 6979) signatures over SHA256, with s
+/// normalized to the lower half of the group order: (private key, message, r, s).
+///
+/// In addition to the public key Q = d * G, the verifier needs the y-coordinate of the point
+/// R = (z / s) * G + (r / s) * Q as advice; this is provided as ry.
+struct TestVector {
+    message: &'static [u8],
+    r: &'static str,
+    s: &'static str,
+    qx: &'static str,
+    qy: &'static str,
+    ry: &'static str,
+}
+
+// d = 1, message "Satoshi Nakamoto"
+const SATOSHI: TestVector = TestVector {
+    message: b"Satoshi Nakamoto",
+    r: "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8",
+    s: "2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+    qx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    qy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    ry: "f2d7ce7bf98625b5c232bdf04fb255600a20a2fa11c95c9a229561c05780f06f",
+};
+
+// d = 1, message "All those moments will be lost in time, like tears in rain. Time to die..."
+const TEARS_IN_RAIN: TestVector = TestVector {
+    message: b"All those moments will be lost in time, like tears in rain. Time to die...",
+    r: "8600dbd41e348fe5c9465ab92d23e3db8b98b873beecd930736488696438cb6b",
+    s: "547fe64427496db33bf66019dacbf0039c04199abb0122918601db38a72cfc21",
+    qx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    qy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    ry: "501591a6a022d7190a5e3ed59e2dd108b8e93c9e30633be072aec318e69a5492",
+};
+
+// d = f8b8af8ce3c7cca5e300d33939540c10d45ce001b8f252bfbc57ba0342904181, message "Alan Turing"
+const ALAN_TURING: TestVector = TestVector {
+    message: b"Alan Turing",
+    r: "7063ae83e7f62bbb171798131b4a0564b956930092b33b07b395615d9ec7e15c",
+    s: "58dfcc1e00a35e1572f366ffe34ba0fc47db1e7189759b9fb233c5b05ab388ea",
+    qx: "92df7b245b81aa637ab4e867c8d511008f79161a97d64f2ac709600352f7acbc",
+    qy: "e9bfdf1b13fa0cb1de4521e5386cde3a1cd26c5ab584989d07bbed58a5419f62",
+    ry: "121ce0dc52cf946b3e01e6d06d4112f7cee932c77e35e03cacedcbb5b5c2b546",
+};
+
+// order of secp256k1 group
+const N: &str = "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+// TESTS
+// ================================================================================================
+
+// executing full verification takes ~3 scalar multiplications, and building the execution trace
+// for it requires more than 5 GB of memory; thus, these tests are ignored by default.
+
+#[test]
+#[ignore]
+fn test_ecdsa_verify() {
+    for vector in [SATOSHI, TEARS_IN_RAIN, ALAN_TURING] {
+        let z = hex_limbs(&hex_digest(vector.message));
+        let test = build_verify_test(&vector, z, hex_limbs(vector.r), hex_limbs(vector.s));
+        test.execute().unwrap();
+    }
+}
+
+#[test]
+#[ignore]
+fn test_ecdsa_verify_fail() {
+    let vector = SATOSHI;
+
+    // signature of a different message
+    let z = hex_limbs(&hex_digest(b"Satoshi Nakamoto!"));
+    let test = build_verify_test(&vector, z, hex_limbs(vector.r), hex_limbs(vector.s));
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // tampered signature
+    let mut s = hex_limbs(vector.s);
+    s[7] ^= 1;
+    let z = hex_limbs(&hex_digest(vector.message));
+    let test = build_verify_test(&vector, z, hex_limbs(vector.r), s);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// the tests below are rejected before any scalar multiplication is performed, and thus, are cheap
+// enough to run by default.
+
+#[test]
+fn test_ecdsa_verify_invalid_points() {
+    let z = hex_limbs(&hex_digest(SATOSHI.message));
+    let (r, s) = (hex_limbs(SATOSHI.r), hex_limbs(SATOSHI.s));
+
+    // R = (r, ry) must lie on the curve
+    let vector = TestVector {
+        ry: "f2d7ce7bf98625b5c232bdf04fb255600a20a2fa11c95c9a229561c05780f06e",
+        ..SATOSHI
+    };
+    let test = build_verify_test(&vector, z, r, s);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the public key Q = (qx, qy) must lie on the curve
+    let vector = TestVector {
+        qy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b9",
+        ..SATOSHI
+    };
+    let test = build_verify_test(&vector, z, r, s);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn test_ecdsa_verify_invalid_scalars() {
+    let vector = SATOSHI;
+    let z = hex_limbs(&hex_digest(vector.message));
+
+    // both r and s are checked by assert_scalar to be in the range [1, n)
+    let test = build_verify_test(&vector, z, hex_limbs(N), hex_limbs(vector.s));
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    let test = build_verify_test(&vector, z, hex_limbs(vector.r), hex_limbs(N));
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    let test = build_verify_test(&vector, z, [0; 8], hex_limbs(vector.s));
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    let test = build_verify_test(&vector, z, hex_limbs(vector.r), [0; 8]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds a test which verifies the signature (r, s) of the message hash z against the public key
+/// of the provided test vector, using the y-coordinate of R from the test vector as advice. All
+/// values are provided as 32-bit limbs, most significant limb first.
+fn build_verify_test(vector: &TestVector, z: [u32; 8], r: [u32; 8], s: [u32; 8]) -> Test {
+    let source = format!(
+        "
+    use.std::crypto::dsa::ecdsa
+
+    begin
+        {}
+        {}
+        {}
+        {}
+        {}
+
+        exec.ecdsa::verify
+    end",
+        push_u256(hex_limbs(vector.qy)),
+        push_u256(hex_limbs(vector.qx)),
+        push_u256(s),
+        push_u256(r),
+        push_u256(z),
+    );
+
+    let advice_tape = hex_limbs(vector.ry)
+        .iter()
+        .map(|&limb| limb as u64)
+        .collect::<Vec<_>>();
+    build_test!(&source, &[], &advice_tape, vec![])
+}
+
+/// Returns instructions which push the specified 256-bit number onto the stack, such that its
+/// least significant limb ends up at the top of the stack.
+fn push_u256(limbs: [u32; 8]) -> String {
+    format!(
+        "push.{}.{}.{}.{} push.{}.{}.{}.{}",
+        limbs[0], limbs[1], limbs[2], limbs[3], limbs[4], limbs[5], limbs[6], limbs[7]
+    )
+}
+
+/// Splits a 256-bit number in hex form into 32-bit limbs, most significant limb first.
+fn hex_limbs(hex: &str) -> [u32; 8] {
+    let mut limbs = [0; 8];
+    for (limb, chunk) in limbs.iter_mut().zip(hex.as_bytes().chunks(8)) {
+        *limb = u32::from_str_radix(core::str::from_utf8(chunk).unwrap(), 16).unwrap();
+    }
+    limbs
+}
+
+/// Returns SHA256 digest of the message in hex form.
+fn hex_digest(message: &[u8]) -> String {
+    Sha256::digest(message)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
@@ -65,6 +65,89 @@ fn keccak256_2_to_1_hash() {
 
     let test = build_test!(source, &in_stack);
     test.expect_stack(&expected_stack);
+    test.assert_matches_reference();
+}
+
+#[test]
+fn keccak256_hash_memory_kat() {
+    // known answer tests from https://keccak.team/archives.html
+    let vectors: [(&[u8], &str); 2] = [
+        (
+            b"",
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470",
+        ),
+        (
+            b"abc",
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+        ),
+    ];
+
+    for (msg, digest) in vectors {
+        let digest = (0..32)
+            .map(|i| u8::from_str_radix(&digest[2 * i..2 * i + 2], 16).unwrap())
+            .collect::<Vec<u8>>();
+        expect_hash_memory(msg, &digest);
+    }
+}
+
+#[test]
+fn keccak256_hash_memory() {
+    // messages of various lengths, including ones spanning multiple 136 -bytes blocks, and  ones
+    // which end exactly at or right after a block boundary
+    for len in [1, 7, 8, 20, 135, 136, 137, 300] {
+        let msg = rand_utils::rand_vector::<u8>(len);
+
+        let mut hasher = Keccak256::new();
+        hasher.update(&msg);
+        let digest = hasher.finalize();
+
+        expect_hash_memory(&msg, &digest);
+    }
+}
+
+/// Stores the message in memory, computes its keccak256 digest using `hash_memory` procedure and
+/// checks that the result matches the expected digest.
+fn expect_hash_memory(msg: &[u8], digest: &[u8]) {
+    const MSG_ADDR: usize = 100;
+
+    // pad the message to a whole number of memory words ( = 16 bytes ) with non-zero bytes, which
+    // must be ignored by the hash function
+    let mut padded_msg = msg.to_vec();
+    padded_msg.resize((msg.len() / 16 + 1) * 16, 0xff);
+    let mut msg_u32s = vec![0u64; padded_msg.len() / 4];
+    to_stack(&padded_msg, &mut msg_u32s);
+
+    let store_msg = msg_u32s
+        .chunks(4)
+        .enumerate()
+        .map(|(i, w)| {
+            let addr = MSG_ADDR + i;
+            format!(
+                "push.{}.{}.{}.{} mem_storew.{addr} dropw",
+                w[3], w[2], w[1], w[0]
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let source = format!(
+        "
+    use.std::crypto::hashes::keccak256
+
+    begin
+        {store_msg}
+        push.{} push.{MSG_ADDR}
+        exec.keccak256::hash_memory
+    end
+    ",
+        msg.len()
+    );
+
+    let mut expected_stack = [0u64; STACK_TOP_SIZE >> 1];
+    to_stack(digest, &mut expected_stack);
+
+    let test = build_test!(&source);
+    test.expect_stack(&expected_stack);
 }
 
 /// Given N -many bytes ( such that N % 8 == 0 ), this function considers
@@ -2,6 +2,8 @@ use super::build_test;
 use crate::helpers::{Felt, STACK_TOP_SIZE};
 
 mod blake3;
+mod ecdsa;
 mod falcon;
 mod keccak256;
 mod sha256;
+mod smt;
@@ -51,4 +51,5 @@ fn sha256_2_to_1_hash() {
 
     let test = build_test!(source, &i_words);
     test.expect_stack(&digest_words);
+    test.assert_matches_reference();
 }
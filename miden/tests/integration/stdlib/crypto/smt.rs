@@ -0,0 +1,144 @@
+use super::{build_test, Felt};
+use std::collections::BTreeMap;
+use vm_core::{
+    chiplets::hasher::{self, Digest},
+    AdviceSet, FieldElement, StarkField, Word,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+const DEPTH: u32 = 63;
+const EMPTY: Word = [Felt::ZERO; 4];
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn smt_get() {
+    let leaves = [
+        (1, int_to_word(11)),
+        (1 << 40, int_to_word(12)),
+        ((1 << 63) - 1, int_to_word(13)),
+    ];
+    let smt = build_smt(&leaves);
+    let root = smt.root();
+
+    // existing keys return their values; a key which was never set returns an empty word
+    for (key, value) in leaves.iter().chain([(5, EMPTY)].iter()) {
+        let source = format!(
+            "
+            use.std::crypto::merkle::smt
+            begin
+                push.{key}
+                exec.smt::get
+            end"
+        );
+
+        let test = build_test!(&source, &word_to_ints(&root), &[], vec![smt.clone()]);
+        test.expect_stack(&build_expected_stack(&[*value, root]));
+    }
+}
+
+#[test]
+fn smt_insert() {
+    let smt = build_smt(&[]);
+    assert_eq!(compute_root(&BTreeMap::new()), smt.root());
+
+    // insert several keys into an empty tree, and then overwrite one of them
+    let source = "
+        use.std::crypto::merkle::smt
+        begin
+            push.1.0.0.0 push.7 exec.smt::insert dropw
+            push.2.0.0.0 push.1099511627776 exec.smt::insert dropw
+            push.3.4.5.6 push.9223372036854775807 exec.smt::insert dropw
+            push.8.0.0.0 push.7 exec.smt::insert
+        end";
+
+    let mut expected = BTreeMap::new();
+    expected.insert(7, int_to_word(8));
+    expected.insert(1 << 40, int_to_word(2));
+    expected.insert(
+        (1 << 63) - 1,
+        [Felt::new(3), Felt::new(4), Felt::new(5), Felt::new(6)],
+    );
+    let new_root = compute_root(&expected);
+
+    let test = build_test!(source, &word_to_ints(&smt.root()), &[], vec![smt.clone()]);
+    test.expect_stack(&build_expected_stack(&[int_to_word(1), new_root]));
+}
+
+#[test]
+fn smt_remove() {
+    let leaves = [(3, int_to_word(21)), (1 << 50, int_to_word(22))];
+    let smt = build_smt(&leaves);
+
+    let source = "
+        use.std::crypto::merkle::smt
+        begin
+            push.3 exec.smt::remove
+        end";
+
+    // the removed key is no longer part of the tree
+    let expected = leaves[1..].iter().cloned().collect::<BTreeMap<_, _>>();
+    let new_root = compute_root(&expected);
+
+    let test = build_test!(source, &word_to_ints(&smt.root()), &[], vec![smt.clone()]);
+    test.expect_stack(&build_expected_stack(&[leaves[0].1, new_root]));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn build_smt(leaves: &[(u64, Word)]) -> AdviceSet {
+    let (keys, values) = leaves.iter().cloned().unzip();
+    AdviceSet::new_sparse_merkle_tree(keys, values, DEPTH).unwrap()
+}
+
+/// Computes the root of a sparse Merkle tree of depth 63 with the specified leaves, as described
+/// in the `std::crypto::merkle::smt` module, independently of the [AdviceSet] implementation.
+fn compute_root(leaves: &BTreeMap<u64, Word>) -> Word {
+    // empty_hashes[d] is the root of an empty subtree with its root at depth d
+    let mut empty_hashes = vec![Digest::from(EMPTY); DEPTH as usize + 1];
+    for d in (0..DEPTH as usize).rev() {
+        empty_hashes[d] = hasher::merge(&[empty_hashes[d + 1], empty_hashes[d + 1]]);
+    }
+
+    // hash the non-empty nodes of the tree level by level
+    let mut nodes: BTreeMap<u64, Digest> = leaves.iter().map(|(&k, &v)| (k, v.into())).collect();
+    for d in (0..DEPTH as usize).rev() {
+        let empty = empty_hashes[d + 1];
+        nodes = nodes
+            .keys()
+            .map(|k| k >> 1)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|parent| {
+                let left = *nodes.get(&(parent << 1)).unwrap_or(&empty);
+                let right = *nodes.get(&((parent << 1) + 1)).unwrap_or(&empty);
+                (parent, hasher::merge(&[left, right]))
+            })
+            .collect();
+    }
+
+    nodes.get(&0).copied().unwrap_or(empty_hashes[0]).into()
+}
+
+fn int_to_word(value: u64) -> Word {
+    [Felt::new(value), Felt::ZERO, Felt::ZERO, Felt::ZERO]
+}
+
+/// Returns the elements of the word in the order in which they are provided as stack inputs, i.e.,
+/// with the first element of the word at the bottom of the stack.
+fn word_to_ints(word: &Word) -> Vec<u64> {
+    word.iter().map(|v| v.as_int()).collect()
+}
+
+/// Returns the expected state of the stack (from the top) containing the specified words, with
+/// the first word at the top of the stack.
+fn build_expected_stack(words: &[Word]) -> Vec<u64> {
+    words
+        .iter()
+        .flat_map(|word| word.iter().rev().map(|v| v.as_int()))
+        .collect()
+}
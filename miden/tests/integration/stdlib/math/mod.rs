@@ -4,8 +4,11 @@ use crate::helpers::{Felt, TestError};
 mod ec_ext5;
 mod ext2;
 mod ext5;
+mod modexp;
 mod ntt512;
+mod poly;
 mod poly512;
 mod secp256k1;
 mod u256_mod;
+mod u32_mod;
 mod u64_mod;
@@ -0,0 +1,336 @@
+use super::{build_test, TestError};
+use crate::helpers::Test;
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+// CONSTANTS
+// ------------------------------------------------------------------------------------------------
+
+/// Memory addresses at which the operands and the result of `modexp` are stored by the tests.
+const BASE_ADDR: u32 = 0;
+const EXP_ADDR: u32 = 100;
+const M_ADDR: u32 = 200;
+const R_ADDR: u32 = 300;
+
+// MULTI-LIMB MODULAR EXPONENTIATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn modexp_known_vectors() {
+    // textbook RSA with p = 61, q = 53, e = 17, and d = 413: encrypting 65 yields 2790, and
+    // decrypting 2790 yields 65
+    build_modexp_test(&[65], &[17], &[3233]).expect_stack(&[2790]);
+    build_modexp_test(&[2790], &[413], &[3233]).expect_stack(&[65]);
+
+    // Fermat's little theorem for the Mersenne prime 2^127 - 1: 3^(p - 1) = 1 mod p
+    let p = (BigUint::from(1_u32) << 127_u32) - 1_u32;
+    let exp = &p - 1_u32;
+    build_modexp_test(
+        &to_limbs(&3_u32.into(), 4),
+        &to_limbs(&exp, 4),
+        &to_limbs(&p, 4),
+    )
+    .expect_stack(&[0, 0, 0, 1]);
+
+    // (base, exp, m, base^exp % m) for 64 -bit values
+    let vectors: [(u64, u64, u64, u64); 5] = [
+        (4, 13, 497, 445),
+        (2, 10, 1001, 23),
+        (3, 200, 1_000_000_007, 136_318_165),
+        (5, 0xffff_ffff, 0xffff_fffb, 0xc35),
+        (
+            0x1234_5678_9abc_def0,
+            0xfedc_ba98_7654_3210,
+            0xffff_ffff_ffff_ffc5,
+            0xf693_ca11_b688_a5f0,
+        ),
+    ];
+    for (base, exp, m, expected) in vectors {
+        let [base, exp, m, expected] = [base, exp, m, expected].map(|v| to_limbs(&v.into(), 2));
+        build_modexp_test(&base, &exp, &m).expect_stack(&limbs_to_stack(&expected));
+    }
+}
+
+#[test]
+fn modexp_edge_cases() {
+    let m = to_limbs(&((BigUint::from(1_u32) << 127_u32) - 1_u32), 4);
+    let zero = [0; 4];
+    let one = [0, 0, 0, 1];
+
+    // exp = 0
+    build_modexp_test(&[5, 6, 7, 8], &zero, &m).expect_stack(&one);
+
+    // base = 0
+    build_modexp_test(&zero, &[5, 0, 0, 0], &m).expect_stack(&[0; 4]);
+
+    // 0^0 = 1
+    build_modexp_test(&zero, &zero, &m).expect_stack(&one);
+
+    // modulus of 1 reduces everything to 0
+    build_modexp_test(&[7, 0], &[0, 0], &[1, 0]).expect_stack(&[0, 0]);
+    build_modexp_test(&[7, 0], &[3, 0], &[1, 0]).expect_stack(&[0, 0]);
+
+    // base is reduced modulo m first, including when it has more significant limbs than m
+    build_modexp_test(&[1005, 0], &[3, 0], &[1001, 0]).expect_stack(&[0, 64]);
+    build_modexp_test(&[4, 1], &[1, 0], &[1001, 0]).expect_stack(&[0, (4 + (1 << 32)) % 1001]);
+
+    // base = m - 1 (i.e. -1 mod m) raised to an odd exponent
+    let mut m_minus_one = m.clone();
+    m_minus_one[0] -= 1;
+    build_modexp_test(&m_minus_one, &[3, 0, 0, 0], &m).expect_stack(&limbs_to_stack(&m_minus_one));
+
+    // the result may be written over one of the inputs
+    let source = format!(
+        "
+        use.std::math::modexp
+        begin
+            {}
+            push.{BASE_ADDR}.{M_ADDR}.{EXP_ADDR}.{BASE_ADDR}.1
+            exec.modexp::modexp
+            mem_load.{BASE_ADDR}
+        end",
+        store_limbs(&[(BASE_ADDR, &[65]), (EXP_ADDR, &[17]), (M_ADDR, &[3233])])
+    );
+    build_test!(source, &[]).expect_stack(&[2790]);
+}
+
+#[test]
+fn modexp_fail() {
+    // the modulus must be odd
+    build_modexp_test(&[3, 0], &[5, 0], &[1000, 0])
+        .expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // all limbs must be 32-bit values
+    build_modexp_test(&[3, 1 << 32], &[5, 0], &[1001, 0])
+        .expect_error(TestError::ExecutionError("NotU32Value"));
+    build_modexp_test(&[3, 0], &[5, 1 << 32], &[1001, 0])
+        .expect_error(TestError::ExecutionError("NotU32Value"));
+    build_modexp_test(&[3, 0], &[5, 0], &[1001, 1 << 32])
+        .expect_error(TestError::ExecutionError("NotU32Value"));
+
+    // the number of limbs must be between 1 and 64
+    for n in [0, 65] {
+        let source = format!(
+            "
+            use.std::math::modexp
+            begin
+                push.{R_ADDR}.{M_ADDR}.{EXP_ADDR}.{BASE_ADDR}.{n}
+                exec.modexp::modexp
+            end"
+        );
+        build_test!(source, &[]).expect_error(TestError::ExecutionError("FailedAssertion"));
+    }
+}
+
+#[test]
+fn modexp_256_bit() {
+    // RSA-style exponentiation with a public exponent of 65537 and a 256 -bit odd modulus
+    let m = BigUint::parse_bytes(
+        b"e9b5d0a3c1f7e2468ace13579bdf02468ace13579bdf02468ace13579bdf0247",
+        16,
+    )
+    .unwrap();
+    let base = BigUint::parse_bytes(b"123456789abcdef0fedcba9876543210", 16).unwrap();
+    let exp = BigUint::from(65537_u32);
+    let expected = base.modpow(&exp, &m);
+
+    build_modexp_test(&to_limbs(&base, 8), &to_limbs(&exp, 8), &to_limbs(&m, 8))
+        .expect_stack(&limbs_to_stack(&to_limbs(&expected, 8)));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn modexp_proptest(
+        limbs in prop::collection::vec(any::<u32>(), 3..=12),
+        exp_bits in 1_usize..64,
+    ) {
+        // split the random limbs into a base, an exponent, and an odd modulus with the same number
+        // of limbs; the exponent is truncated to keep the test fast
+        let n = limbs.len() / 3;
+        let base = BigUint::from_slice(&limbs[..n]);
+        let exp = BigUint::from_slice(&limbs[n..2 * n]) % (BigUint::from(1_u32) << exp_bits);
+        let m = BigUint::from_slice(&limbs[2 * n..3 * n]) | BigUint::from(1_u32);
+        let expected = base.modpow(&exp, &m);
+
+        build_modexp_test(&to_limbs(&base, n), &to_limbs(&exp, n), &to_limbs(&m, n))
+            .prop_expect_stack(&limbs_to_stack(&to_limbs(&expected, n)))?;
+    }
+}
+
+// 64-BIT MODULAR EXPONENTIATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn modexp_u64_known_vectors() {
+    // (base, exp, m, base^exp % m)
+    let vectors: [(u64, u64, u64, u64); 5] = [
+        (4, 13, 497, 445),
+        (2, 10, 1001, 23),
+        (3, 200, 1_000_000_007, 136_318_165),
+        (5, 0xffff_ffff, 0xffff_fffb, 0xc35),
+        (
+            0x1234_5678_9abc_def0,
+            0xfedc_ba98_7654_3210,
+            0xffff_ffff_ffff_ffc5,
+            0xf693_ca11_b688_a5f0,
+        ),
+    ];
+
+    for (base, exp, m, expected) in vectors {
+        assert_eq!(expected, modexp_reference(base, exp, m));
+        build_modexp_u64_test(base, exp, m).expect_stack(&split_u64(expected));
+    }
+}
+
+#[test]
+fn modexp_u64_edge_cases() {
+    let m = 0xffff_ffff_ffff_ffc5;
+
+    // exp = 0
+    build_modexp_u64_test(12345, 0, 1001).expect_stack(&[0, 1]);
+    build_modexp_u64_test(u64::MAX, 0, m).expect_stack(&[0, 1]);
+
+    // base = 0
+    build_modexp_u64_test(0, 5, 1001).expect_stack(&[0, 0]);
+    build_modexp_u64_test(0, u64::MAX, m).expect_stack(&[0, 0]);
+
+    // 0^0 = 1
+    build_modexp_u64_test(0, 0, 1001).expect_stack(&[0, 1]);
+
+    // modulus of 1 reduces everything to 0
+    build_modexp_u64_test(7, 0, 1).expect_stack(&[0, 0]);
+    build_modexp_u64_test(7, 3, 1).expect_stack(&[0, 0]);
+
+    // base is reduced modulo m first
+    build_modexp_u64_test(1001 + 4, 3, 1001).expect_stack(&[0, 64]);
+
+    // base = m - 1 (i.e. -1 mod m) raised to an odd exponent
+    build_modexp_u64_test(m - 1, 3, m).expect_stack(&split_u64(m - 1));
+}
+
+#[test]
+fn modexp_u64_fail() {
+    // the modulus must be odd
+    build_modexp_u64_test(3, 5, 1000).expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // all limbs must be 32-bit values
+    let source = "
+        use.std::math::modexp
+        begin
+            exec.modexp::modexp_u64
+        end";
+    let test = build_test!(source, &[3, 0, 5, 0, 1001, 1 << 32]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn modexp_u64_proptest(base in any::<u64>(), exp in any::<u64>(), m in any::<u64>()) {
+        let m = m | 1;
+        let expected = modexp_reference(base, exp, m);
+        build_modexp_u64_test(base, exp, m).prop_expect_stack(&split_u64(expected))?;
+    }
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Builds a test which computes base^exp % m using `std::math::modexp::modexp`, and puts the limbs
+/// of the result onto the stack with the most significant limb at the top.
+///
+/// Limbs of the operands are provided with the least significant limb first.
+fn build_modexp_test(base: &[u64], exp: &[u64], m: &[u64]) -> Test {
+    let n = base.len();
+    assert!(
+        exp.len() == n && m.len() == n,
+        "operands must have the same number of limbs"
+    );
+    let load_result = (0..n as u32)
+        .map(|i| format!("mem_load.{}", R_ADDR + i))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let source = format!(
+        "
+        use.std::math::modexp
+        begin
+            {}
+            push.{R_ADDR}.{M_ADDR}.{EXP_ADDR}.{BASE_ADDR}.{n}
+            exec.modexp::modexp
+            {load_result}
+        end",
+        store_limbs(&[(BASE_ADDR, base), (EXP_ADDR, exp), (M_ADDR, m)])
+    );
+
+    build_test!(source, &[])
+}
+
+/// Returns the instructions which store the limbs of the provided integers in memory, starting at
+/// the associated addresses.
+fn store_limbs(values: &[(u32, &[u64])]) -> String {
+    values
+        .iter()
+        .flat_map(|(addr, limbs)| {
+            limbs
+                .iter()
+                .enumerate()
+                .map(move |(i, limb)| format!("push.{limb} mem_store.{}", addr + i as u32))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the n 32-bit limbs of the value with the least significant limb first.
+fn to_limbs(value: &BigUint, n: usize) -> Vec<u64> {
+    let mut limbs = value
+        .to_u32_digits()
+        .into_iter()
+        .map(u64::from)
+        .collect::<Vec<_>>();
+    assert!(limbs.len() <= n, "value does not fit into {n} limbs");
+    limbs.resize(n, 0);
+    limbs
+}
+
+/// Returns the limbs in the order in which `build_modexp_test` puts them onto the stack.
+fn limbs_to_stack(limbs: &[u64]) -> Vec<u64> {
+    limbs.iter().rev().copied().collect()
+}
+
+/// Builds a test which computes base^exp % m using `std::math::modexp::modexp_u64`.
+fn build_modexp_u64_test(base: u64, exp: u64, m: u64) -> Test {
+    let source = "
+        use.std::math::modexp
+        begin
+            exec.modexp::modexp_u64
+        end";
+
+    let [b1, b0] = split_u64(base);
+    let [e1, e0] = split_u64(exp);
+    let [m1, m0] = split_u64(m);
+    build_test!(source, &[b0, b1, e0, e1, m0, m1])
+}
+
+/// Computes base^exp % m using square-and-multiply over 128-bit integers.
+fn modexp_reference(base: u64, exp: u64, m: u64) -> u64 {
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Splits the value into its high and low 32-bit limbs.
+fn split_u64(value: u64) -> [u64; 2] {
+    [value >> 32, value as u32 as u64]
+}
@@ -0,0 +1,86 @@
+use super::build_test;
+
+// p(x) = 3 + 2x + 5x^2 + x^3 with coefficients stored at addresses 100..=103
+const STORE_POLY: &str = "
+    push.3 mem_store.100
+    push.2 mem_store.101
+    push.5 mem_store.102
+    push.1 mem_store.103";
+
+#[test]
+fn eval() {
+    for (x, expected) in [(0, 3), (1, 11), (2, 35), (10, 1523)] {
+        let source = format!(
+            "
+            use.std::math::poly
+
+            begin
+                {STORE_POLY}
+                push.4.100.{x}
+                exec.poly::eval
+            end"
+        );
+
+        let test = build_test!(source, &[]);
+        test.expect_stack(&[expected]);
+    }
+}
+
+#[test]
+fn eval_empty_and_constant() {
+    // a polynomial with no coefficients evaluates to 0
+    let source = "
+        use.std::math::poly
+
+        begin
+            push.0.100.7
+            exec.poly::eval
+        end";
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[0]);
+
+    // a constant polynomial evaluates to its only coefficient
+    let source = format!(
+        "
+        use.std::math::poly
+
+        begin
+            {STORE_POLY}
+            push.1.100.7
+            exec.poly::eval
+        end"
+    );
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[3]);
+}
+
+#[test]
+fn eval_batch() {
+    // evaluate at points 0, 1, 2 and 10 stored at addresses 200..=203 and write the results into
+    // addresses 300..=303
+    let source = format!(
+        "
+        use.std::math::poly
+
+        begin
+            {STORE_POLY}
+            push.0 mem_store.200
+            push.1 mem_store.201
+            push.2 mem_store.202
+            push.10 mem_store.203
+
+            push.4.100.300.4.200
+            exec.poly::eval_batch
+
+            push.300 mem_load
+            push.301 mem_load
+            push.302 mem_load
+            push.303 mem_load
+        end"
+    );
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[1523, 35, 11, 3]);
+}
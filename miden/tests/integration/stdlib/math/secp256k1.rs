@@ -258,6 +258,48 @@ fn test_u256_mod_add_sub_cycle() {
     }
 }
 
+#[test]
+fn test_u256_mod_inv() {
+    // inverse of 2 is (p + 1) / 2
+    let source = "
+    use.std::math::secp256k1
+
+    begin
+        exec.secp256k1::to_mont
+        exec.secp256k1::u256_mod_inv
+        exec.secp256k1::from_mont
+    end";
+
+    let test = build_test!(source, &[0, 0, 0, 0, 0, 0, 0, 2]);
+    test.expect_stack(&[
+        0x7ffffe18, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff,
+        0x7fffffff,
+    ]);
+
+    // a * a^-1 = 1 for a random field element a
+    let source = "
+    use.std::math::secp256k1
+
+    begin
+        exec.secp256k1::to_mont
+        dupw.1
+        dupw.1
+        exec.secp256k1::u256_mod_inv
+        exec.secp256k1::u256_mod_mul
+        exec.secp256k1::from_mont
+    end";
+
+    let mut stack = [0u64; 8];
+    for limb in stack.iter_mut() {
+        *limb = rand_utils::rand_value::<u32>() as u64;
+    }
+    // make sure that the element is smaller than the prime
+    stack[0] >>= 1;
+
+    let test = build_test!(source, &stack);
+    test.expect_stack(&[1, 0, 0, 0, 0, 0, 0, 0]);
+}
+
 // Wrapper types introduced for parameterized testing
 struct FieldElement([u32; 8]);
 struct Point([FieldElement; 3]);
@@ -0,0 +1,448 @@
+use super::{build_test, TestError};
+use crate::helpers::U32_BOUND;
+use proptest::prelude::*;
+
+// ADDITION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_add() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::checked_add
+    end";
+
+    // --- no overflow ----------------------------------------------------------------------------
+    let test = build_test!(source, &[1, 2]);
+    test.expect_stack(&[0, 3]);
+
+    // --- overflow -------------------------------------------------------------------------------
+    let test = build_test!(source, &[u32::MAX as u64, 5]);
+    test.expect_stack(&[1, 4]);
+}
+
+#[test]
+fn checked_add_fail() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::checked_add
+    end";
+
+    let test = build_test!(source, &[U32_BOUND, 1]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// SUBTRACTION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_sub() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::checked_sub
+    end";
+
+    // --- no underflow ---------------------------------------------------------------------------
+    let test = build_test!(source, &[5, 3]);
+    test.expect_stack(&[0, 2]);
+
+    // --- underflow ------------------------------------------------------------------------------
+    let test = build_test!(source, &[3, 5]);
+    test.expect_stack(&[1, U32_BOUND - 2]);
+}
+
+#[test]
+fn checked_sub_fail() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::checked_sub
+    end";
+
+    let test = build_test!(source, &[1, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// MULTIPLICATION
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn checked_mul() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::checked_mul
+    end";
+
+    // --- no overflow ----------------------------------------------------------------------------
+    let test = build_test!(source, &[3, 5]);
+    test.expect_stack(&[0, 15]);
+
+    // --- overflow -------------------------------------------------------------------------------
+    let test = build_test!(source, &[u32::MAX as u64, 2]);
+    test.expect_stack(&[1, u32::MAX as u64 - 1]);
+}
+
+#[test]
+fn checked_mul_fail() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::checked_mul
+    end";
+
+    let test = build_test!(source, &[U32_BOUND, 2]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// SHIFTS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn overflowing_shl() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::overflowing_shl
+    end";
+
+    let test = build_test!(source, &[0xf000_000f, 4]);
+    test.expect_stack(&[0xf, 0xf0]);
+
+    let test = build_test!(source, &[3, 31]);
+    test.expect_stack(&[1, 0x8000_0000]);
+
+    // --- shifting by 0 leaves the value unchanged --------------------------------------------
+    let test = build_test!(source, &[u32::MAX as u64, 0]);
+    test.expect_stack(&[0, u32::MAX as u64]);
+}
+
+#[test]
+fn overflowing_shr() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::overflowing_shr
+    end";
+
+    let test = build_test!(source, &[0xf000_000f, 4]);
+    test.expect_stack(&[0xf000_0000, 0x0f00_0000]);
+
+    let test = build_test!(source, &[u32::MAX as u64, 31]);
+    test.expect_stack(&[0xffff_fffe, 1]);
+
+    // --- shifting by 0 leaves the value unchanged --------------------------------------------
+    let test = build_test!(source, &[u32::MAX as u64, 0]);
+    test.expect_stack(&[0, u32::MAX as u64]);
+}
+
+#[test]
+fn overflowing_shift_fail() {
+    for proc in ["overflowing_shl", "overflowing_shr"] {
+        let source = format!(
+            "
+            use.std::math::u32
+            begin
+                exec.u32::{proc}
+            end"
+        );
+
+        let test = build_test!(&source, &[1, 32]);
+        test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+        let test = build_test!(&source, &[U32_BOUND, 1]);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+    }
+}
+
+#[test]
+fn multi_word_shl() {
+    // shifts a 64-bit value represented by two 32-bit limbs, carrying the bits shifted out of the
+    // low limb into the high limb
+    let source = "
+    use.std::math::u32
+    begin
+        dup movup.3 swap exec.u32::overflowing_shl
+        movup.3 movup.3 exec.u32::overflowing_shl
+        drop u32checked_or
+    end";
+
+    let a: u64 = 0x1234_5678_9abc_def0;
+    for b in [0, 1, 4, 17, 31] {
+        let expected = a << b;
+        let test = build_test!(source, &[a as u32 as u64, a >> 32, b]);
+        test.expect_stack(&[expected >> 32, expected as u32 as u64]);
+    }
+}
+
+// MIN / MAX
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn min_max() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::min
+    end";
+
+    // --- ordered and reversed inputs ------------------------------------------------------------
+    build_test!(source, &[3, 7]).expect_stack(&[3]);
+    build_test!(source, &[7, 3]).expect_stack(&[3]);
+    build_test!(source, &[5, 5]).expect_stack(&[5]);
+    build_test!(source, &[0, u32::MAX as u64]).expect_stack(&[0]);
+
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::max
+    end";
+
+    build_test!(source, &[3, 7]).expect_stack(&[7]);
+    build_test!(source, &[7, 3]).expect_stack(&[7]);
+    build_test!(source, &[5, 5]).expect_stack(&[5]);
+    build_test!(source, &[0, u32::MAX as u64]).expect_stack(&[u32::MAX as u64]);
+}
+
+#[test]
+fn min_max_fail() {
+    for proc in ["min", "max"] {
+        let source = format!(
+            "
+            use.std::math::u32
+            begin
+                exec.u32::{proc}
+            end"
+        );
+
+        let test = build_test!(&source, &[U32_BOUND, 1]);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+        let test = build_test!(&source, &[1, U32_BOUND]);
+        test.expect_error(TestError::ExecutionError("NotU32Value"));
+    }
+}
+
+#[test]
+fn clamp() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::clamp
+    end";
+
+    // --- value inside the range -----------------------------------------------------------------
+    build_test!(source, &[15, 10, 20]).expect_stack(&[15]);
+
+    // --- value outside of the range -------------------------------------------------------------
+    build_test!(source, &[5, 10, 20]).expect_stack(&[10]);
+    build_test!(source, &[25, 10, 20]).expect_stack(&[20]);
+
+    // --- range boundaries -----------------------------------------------------------------------
+    build_test!(source, &[10, 10, 20]).expect_stack(&[10]);
+    build_test!(source, &[20, 10, 20]).expect_stack(&[20]);
+    build_test!(source, &[7, 12, 12]).expect_stack(&[12]);
+    build_test!(source, &[u32::MAX as u64, 0, u32::MAX as u64]).expect_stack(&[u32::MAX as u64]);
+}
+
+#[test]
+fn clamp_fail() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::clamp
+    end";
+
+    // --- lo > hi --------------------------------------------------------------------------------
+    let test = build_test!(source, &[15, 20, 10]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // --- values are not u32 ---------------------------------------------------------------------
+    let test = build_test!(source, &[U32_BOUND, 10, 20]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+
+    let test = build_test!(source, &[15, 10, U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// BIT COUNTING
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn popcount() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::popcount
+    end";
+
+    build_test!(source, &[0]).expect_stack(&[0]);
+    build_test!(source, &[u32::MAX as u64]).expect_stack(&[32]);
+
+    let patterns: [u32; 7] = [
+        1, 0x80000000, 0x55555555, 0xaaaaaaaa, 0x0f0f0f0f, 0x12345678, 0xfffffffe,
+    ];
+    for a in patterns {
+        build_test!(source, &[a as u64]).expect_stack(&[a.count_ones() as u64]);
+    }
+}
+
+#[test]
+fn popcount_fail() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::popcount
+    end";
+
+    let test = build_test!(source, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// SQUARE ROOT
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn isqrt() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::isqrt
+    end";
+
+    // --- perfect squares ------------------------------------------------------------------------
+    for r in [0_u64, 1, 2, 3, 255, 256, 4097, 65535] {
+        build_test!(source, &[r * r]).expect_stack(&[r]);
+    }
+
+    // --- non-squares ----------------------------------------------------------------------------
+    build_test!(source, &[2]).expect_stack(&[1]);
+    build_test!(source, &[3]).expect_stack(&[1]);
+    build_test!(source, &[15]).expect_stack(&[3]);
+    build_test!(source, &[17]).expect_stack(&[4]);
+    build_test!(source, &[65535 * 65535 - 1]).expect_stack(&[65534]);
+    build_test!(source, &[u32::MAX as u64]).expect_stack(&[65535]);
+}
+
+#[test]
+fn isqrt_fail() {
+    let source = "
+    use.std::math::u32
+    begin
+        exec.u32::isqrt
+    end";
+
+    let test = build_test!(source, &[U32_BOUND]);
+    test.expect_error(TestError::ExecutionError("NotU32Value"));
+}
+
+// RANDOMIZED TESTS
+// ================================================================================================
+
+proptest! {
+    #[test]
+    fn checked_add_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let (c, overflow) = a.overflowing_add(b);
+
+        let source = "
+            use.std::math::u32
+            begin
+                exec.u32::checked_add
+            end";
+
+        build_test!(source, &[a as u64, b as u64]).prop_expect_stack(&[overflow as u64, c as u64])?;
+    }
+
+    #[test]
+    fn checked_sub_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let (c, underflow) = a.overflowing_sub(b);
+
+        let source = "
+            use.std::math::u32
+            begin
+                exec.u32::checked_sub
+            end";
+
+        build_test!(source, &[a as u64, b as u64]).prop_expect_stack(&[underflow as u64, c as u64])?;
+    }
+
+    #[test]
+    fn checked_mul_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let (c, overflow) = a.overflowing_mul(b);
+
+        let source = "
+            use.std::math::u32
+            begin
+                exec.u32::checked_mul
+            end";
+
+        build_test!(source, &[a as u64, b as u64]).prop_expect_stack(&[overflow as u64, c as u64])?;
+    }
+
+    #[test]
+    fn overflowing_shl_proptest(a in any::<u32>(), b in 0_u32..32) {
+        let c = a << b;
+        let d = ((a as u64) << b) >> 32;
+
+        let source = "
+            use.std::math::u32
+            begin
+                exec.u32::overflowing_shl
+            end";
+
+        build_test!(source, &[a as u64, b as u64]).prop_expect_stack(&[d, c as u64])?;
+    }
+
+    #[test]
+    fn overflowing_shr_proptest(a in any::<u32>(), b in 0_u32..32) {
+        let c = a >> b;
+        let d = ((a as u64) << (32 - b)) as u32;
+
+        let source = "
+            use.std::math::u32
+            begin
+                exec.u32::overflowing_shr
+            end";
+
+        build_test!(source, &[a as u64, b as u64]).prop_expect_stack(&[d as u64, c as u64])?;
+    }
+
+    #[test]
+    fn min_max_proptest(a in any::<u32>(), b in any::<u32>()) {
+        let source = "
+            use.std::math::u32
+            begin
+                dup.1 dup.1 exec.u32::min movdn.2 exec.u32::max
+            end";
+
+        build_test!(source, &[a as u64, b as u64])
+            .prop_expect_stack(&[a.max(b) as u64, a.min(b) as u64])?;
+    }
+
+    #[test]
+    fn popcount_proptest(a in any::<u32>()) {
+        let source = "
+            use.std::math::u32
+            begin
+                exec.u32::popcount
+            end";
+
+        build_test!(source, &[a as u64]).prop_expect_stack(&[a.count_ones() as u64])?;
+    }
+
+    #[test]
+    fn isqrt_proptest(a in any::<u32>()) {
+        let c = (a as f64).sqrt() as u64;
+
+        let source = "
+            use.std::math::u32
+            begin
+                exec.u32::isqrt
+            end";
+
+        build_test!(source, &[a as u64]).prop_expect_stack(&[c])?;
+    }
+}
@@ -23,6 +23,27 @@ fn wrapping_add() {
     let (b1, b0) = split_u64(b);
     let (c1, c0) = split_u64(c);
 
+    let test = build_test!(source, &[a0, a1, b0, b1]);
+    test.expect_stack(&[c1, c0]);
+    test.assert_matches_reference();
+}
+
+#[test]
+fn wrapping_add_full_path() {
+    let a: u64 = rand_value();
+    let b: u64 = rand_value();
+    let c = a.wrapping_add(b);
+
+    // the module is resolved from the full path without a `use` statement
+    let source = "
+        begin
+            exec.std::math::u64::wrapping_add
+        end";
+
+    let (a1, a0) = split_u64(a);
+    let (b1, b0) = split_u64(b);
+    let (c1, c0) = split_u64(c);
+
     let test = build_test!(source, &[a0, a1, b0, b1]);
     test.expect_stack(&[c1, c0]);
 }
@@ -306,6 +327,7 @@ fn checked_mul_fail() {
         stack_init[i] = U32_BOUND;
         let test = build_test!(source, &stack_init);
         test.expect_error(TestError::ExecutionError("NotU32Value"));
+        test.assert_matches_reference();
     }
 
     // Higher bits assertion failure (a_hi * b_hi != 0)
@@ -601,6 +623,7 @@ fn unchecked_div() {
 
     let test = build_test!(source, &[a0, a1, b0, 0]);
     test.expect_stack(&[d1, d0]);
+    test.assert_matches_reference();
 }
 
 #[test]
@@ -1160,6 +1183,58 @@ fn unchecked_rotr() {
     build_test!(source, &[5, a0, a1, b as u64]).expect_stack(&[c1, c0, 5]);
 }
 
+#[test]
+fn clz() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::clz
+        end";
+
+    // values at the boundaries between the limbs, as well as the extremes
+    for a in [
+        0_u64,
+        1,
+        0xffff_ffff,
+        0x1_0000_0000,
+        0x8000_0000_0000_0000,
+        u64::MAX,
+    ] {
+        let (a1, a0) = split_u64(a);
+        let c = a.leading_zeros() as u64;
+        build_test!(source, &[5, a0, a1]).expect_stack(&[c, 5]);
+    }
+
+    // 2^32 has 31 leading zeros over 64 bits
+    build_test!(source, &[5, 0, 1]).expect_stack(&[31, 5]);
+}
+
+#[test]
+fn ctz() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::ctz
+        end";
+
+    // values at the boundaries between the limbs, as well as the extremes
+    for a in [
+        0_u64,
+        1,
+        0x8000_0000,
+        0x1_0000_0000,
+        0x8000_0000_0000_0000,
+        u64::MAX,
+    ] {
+        let (a1, a0) = split_u64(a);
+        let c = a.trailing_zeros() as u64;
+        build_test!(source, &[5, a0, a1]).expect_stack(&[c, 5]);
+    }
+
+    // 2^32 has 32 trailing zeros
+    build_test!(source, &[5, 0, 1]).expect_stack(&[32, 5]);
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -1180,6 +1255,24 @@ proptest! {
         build_test!(source, &[a0, a1, b0, b1]).prop_expect_stack(&[c])?;
     }
 
+    #[test]
+    fn wrapping_mul_constant_trace_length_proptest(a in any::<u64>(), b in any::<u64>()) {
+
+        let (a1, a0) = split_u64(a);
+        let (b1, b0) = split_u64(b);
+
+        let source = "
+            use.std::math::u64
+            begin
+                exec.u64::wrapping_mul
+            end";
+
+        // wrapping multiplication is branchless, and thus, must take the same number of cycles
+        // as for zero inputs regardless of the values being multiplied
+        let expected = build_test!(source, &[0, 0, 0, 0]).execute_iter().count();
+        build_test!(source, &[a0, a1, b0, b1]).prop_expect_trace_length(expected)?;
+    }
+
     #[test]
     fn unchecked_gt_proptest(a in any::<u64>(), b in any::<u64>()) {
 
@@ -1330,6 +1423,41 @@ proptest! {
 
         build_test!(source, &[5, a0, a1, b as u64]).prop_expect_stack(&[c1, c0, 5])?;
     }
+
+    #[test]
+    fn clz_proptest(a in any::<u64>(), b in 0_u32..64) {
+        // shift the value right to cover the whole range of the number of leading zeros
+        let a = a >> b;
+        let c = a.leading_zeros() as u64;
+
+        let (a1, a0) = split_u64(a);
+
+        let source = "
+        use.std::math::u64
+        begin
+            exec.u64::clz
+        end";
+
+        build_test!(source, &[5, a0, a1]).prop_expect_stack(&[c, 5])?;
+    }
+
+    #[test]
+    fn ctz_proptest(a in any::<u64>(), b in 0_u32..64) {
+
+        // shift the value left to cover the whole range of the number of trailing zeros
+        let a = a << b;
+        let c = a.trailing_zeros() as u64;
+
+        let (a1, a0) = split_u64(a);
+
+        let source = "
+        use.std::math::u64
+        begin
+            exec.u64::ctz
+        end";
+
+        build_test!(source, &[5, a0, a1]).prop_expect_stack(&[c, 5])?;
+    }
 }
 
 // HELPER FUNCTIONS
@@ -0,0 +1,218 @@
+use super::build_test;
+use crate::helpers::TestError;
+use processor::Process;
+use rand_utils::rand_vector;
+use vm_core::{hash_elements, Felt, StarkField};
+
+#[test]
+fn pipe_words_to_memory() {
+    let write_ptr = 1000;
+    let num_words = 8;
+    let source = format!(
+        "
+        use.std::mem
+        begin
+            push.{write_ptr} push.{num_words}
+            exec.mem::pipe_words_to_memory
+        end"
+    );
+
+    let advice_tape = rand_vector::<u64>(num_words * 4);
+    let test = build_test!(&source, &[], &advice_tape, vec![]);
+
+    // compute the expected digest independently over all moved elements
+    let elements = advice_tape
+        .iter()
+        .map(|&v| Felt::new(v))
+        .collect::<Vec<_>>();
+    let digest = hash_elements(&elements);
+    let mut final_stack = digest
+        .as_elements()
+        .iter()
+        .map(|v| v.as_int())
+        .collect::<Vec<_>>();
+    final_stack.reverse();
+    final_stack.push(write_ptr + num_words as u64);
+    test.expect_stack(&final_stack);
+    test.assert_matches_reference();
+
+    // make sure all words were written into memory in order
+    let program = test.compile();
+    let mut process = Process::new(program.kernel(), test.inputs.clone());
+    process.execute(&program).unwrap();
+    for (i, word) in elements.chunks(4).enumerate() {
+        let mem_state = process.get_memory_value(0, write_ptr + i as u64).unwrap();
+        assert_eq!(word, mem_state);
+    }
+}
+
+#[test]
+fn hash_elements_matches_vm_hash() {
+    let source = "
+        use.std::mem
+        begin
+            exec.mem::pipe_words_to_memory
+            movup.4 drop
+        end";
+
+    for num_words in [2, 4, 10] {
+        let advice_tape = rand_vector::<u64>(num_words * 4);
+        let test = build_test!(source, &[1000, num_words as u64], &advice_tape, vec![]);
+
+        // the digest computed on the host must match the digest computed by the program
+        let elements = advice_tape
+            .iter()
+            .map(|&v| Felt::new(v))
+            .collect::<Vec<_>>();
+        let digest = hash_elements(&elements);
+        let expected = digest
+            .as_elements()
+            .iter()
+            .rev()
+            .map(|v| v.as_int())
+            .collect::<Vec<_>>();
+        test.expect_stack(&expected);
+    }
+}
+
+#[test]
+fn pipe_words_to_memory_empty() {
+    let source = "
+        use.std::mem
+        begin
+            push.1000 push.0
+            exec.mem::pipe_words_to_memory
+        end";
+
+    // moving zero words results in the hash of an empty list of elements
+    let digest = hash_elements(&[]);
+    let mut final_stack = digest
+        .as_elements()
+        .iter()
+        .rev()
+        .map(|v| v.as_int())
+        .collect::<Vec<_>>();
+    final_stack.push(1000);
+
+    let test = build_test!(source, &[]);
+    test.expect_stack(&final_stack);
+}
+
+#[test]
+fn pipe_words_to_memory_odd() {
+    let source = "
+        use.std::mem
+        begin
+            push.1000 push.3
+            exec.mem::pipe_words_to_memory
+        end";
+
+    let test = build_test!(source, &[], &[0; 12], vec![]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
+
+#[test]
+fn felts_to_bytes_and_back() {
+    let src_ptr = 1000;
+    let bytes_ptr = 2000;
+    let dst_ptr = 3000;
+
+    let values = [
+        0,
+        1,
+        255,
+        256,
+        0x0102030405060708,
+        u32::MAX as u64,
+        1 << 32,
+        Felt::MODULUS - 1,
+    ];
+    let elements = values.iter().map(|&v| Felt::new(v)).collect::<Vec<_>>();
+
+    // store the elements in memory, four elements per word
+    let mut store_elements = String::new();
+    for (i, word) in values.chunks(4).enumerate() {
+        let word = word
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+        let addr = src_ptr + i;
+        store_elements.push_str(&format!("push.{word} push.{addr} mem_storew dropw\n"));
+    }
+
+    let source = format!(
+        "
+        use.std::mem
+        begin
+            {store_elements}
+
+            push.{bytes_ptr} push.8 push.{src_ptr}
+            exec.mem::felts_to_bytes
+
+            push.{dst_ptr} push.8 push.{bytes_ptr}
+            exec.mem::bytes_to_felts
+        end"
+    );
+
+    let test = build_test!(&source, &[]);
+    test.expect_stack(&[]);
+
+    let program = test.compile();
+    let mut process = Process::new(program.kernel(), test.inputs.clone());
+    process.execute(&program).unwrap();
+
+    // the bytes of each element are stored in little-endian order, four bytes per word
+    for (i, value) in values.iter().enumerate() {
+        let bytes = value.to_le_bytes().map(|b| Felt::new(b as u64));
+        let lo = process
+            .get_memory_value(0, bytes_ptr + 2 * i as u64)
+            .unwrap();
+        let hi = process
+            .get_memory_value(0, bytes_ptr + 2 * i as u64 + 1)
+            .unwrap();
+        assert_eq!(bytes[..4], lo);
+        assert_eq!(bytes[4..], hi);
+    }
+
+    // the elements converted back from the bytes match the original elements
+    for (i, word) in elements.chunks(4).enumerate() {
+        let mem_state = process.get_memory_value(0, dst_ptr + i as u64).unwrap();
+        assert_eq!(word, mem_state);
+    }
+}
+
+#[test]
+fn bytes_to_felts_invalid() {
+    // a value which is not a byte
+    let source = "
+        use.std::mem
+        begin
+            push.0.0.0.256 push.1000 mem_storew dropw
+            push.2000 push.4 push.1000
+            exec.mem::bytes_to_felts
+        end";
+    let test = build_test!(source, &[]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // bytes which encode a value greater than the field modulus
+    let source = "
+        use.std::mem
+        begin
+            push.255.255.255.255 push.1000 mem_storew push.1001 mem_storew dropw
+            push.2000 push.4 push.1000
+            exec.mem::bytes_to_felts
+        end";
+    let test = build_test!(source, &[]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+
+    // the number of elements must be a multiple of 4
+    let source = "
+        use.std::mem
+        begin
+            push.2000 push.3 push.1000
+            exec.mem::felts_to_bytes
+        end";
+    let test = build_test!(source, &[]);
+    test.expect_error(TestError::ExecutionError("FailedAssertion"));
+}
@@ -1,5 +1,8 @@
 use crate::build_test;
 
+mod advice;
+mod collections;
 mod crypto;
 mod math;
+mod mem;
 mod sys;
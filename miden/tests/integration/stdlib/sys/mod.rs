@@ -3,6 +3,8 @@ use proptest::prelude::*;
 use rand_utils::rand_vector;
 use vm_core::stack::STACK_TOP_SIZE;
 
+mod random;
+
 #[test]
 fn truncate_stack() {
     let source = "use.std::sys begin repeat.12 push.0 end exec.sys::truncate_stack end";
@@ -11,6 +13,7 @@ fn truncate_stack() {
         &[16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1]
     );
     test.expect_stack(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4]);
+    test.assert_matches_reference();
 }
 
 proptest! {
@@ -0,0 +1,94 @@
+use super::build_test;
+use vm_core::{
+    chiplets::hasher::{apply_permutation, STATE_WIDTH},
+    Felt, FieldElement, StarkField,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+const STATE_ADDR: u64 = 100;
+
+// RANDOM
+// ================================================================================================
+
+#[test]
+fn random_next() {
+    let seed = [1, 2, 3, 4];
+    let source = format!(
+        "
+        use.std::sys::random
+        begin
+            push.{STATE_ADDR} push.1.2.3.4 exec.random::seed
+            repeat.5
+                push.{STATE_ADDR} exec.random::next
+            end
+        end"
+    );
+
+    // the last returned element is at the top of the stack
+    let (sequence, state) = build_expected_sequence(seed, 5);
+    let expected = sequence.iter().rev().copied().collect::<Vec<_>>();
+    let test = build_test!(&source, &[]);
+    test.expect_stack_and_memory(&expected, STATE_ADDR, &state);
+}
+
+#[test]
+fn random_same_seed_same_sequence() {
+    // two generators seeded with the same seed produce the same sequence, even if their calls
+    // are interleaved, while a generator seeded with a different seed produces another sequence
+    let source = format!(
+        "
+        use.std::sys::random
+        begin
+            push.{STATE_ADDR} push.5.6.7.8 exec.random::seed
+            push.{} push.5.6.7.8 exec.random::seed
+            push.{} push.5.6.7.9 exec.random::seed
+            repeat.3
+                push.{STATE_ADDR} exec.random::next
+                push.{} exec.random::next
+                push.{} exec.random::next
+            end
+        end",
+        STATE_ADDR + 1,
+        STATE_ADDR + 2,
+        STATE_ADDR + 1,
+        STATE_ADDR + 2,
+    );
+
+    let (sequence, _) = build_expected_sequence([5, 6, 7, 8], 3);
+    let (other_sequence, _) = build_expected_sequence([5, 6, 7, 9], 3);
+    assert!(sequence
+        .iter()
+        .zip(other_sequence.iter())
+        .all(|(a, b)| a != b));
+
+    let expected = sequence
+        .iter()
+        .zip(other_sequence.iter())
+        .flat_map(|(&value, &other_value)| [value, value, other_value])
+        .rev()
+        .collect::<Vec<_>>();
+    build_test!(&source, &[]).expect_stack(&expected);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the first n elements of the sequence produced by a generator seeded with the specified
+/// seed (listed in the order in which the seed is pushed onto the stack), as well as the state of
+/// the generator afterwards.
+fn build_expected_sequence(seed: [u64; 4], n: usize) -> (Vec<u64>, [u64; 4]) {
+    let mut state = seed.map(Felt::new);
+    let sequence = (0..n)
+        .map(|_| {
+            let mut perm_state = [Felt::ZERO; STATE_WIDTH];
+            perm_state[..4].copy_from_slice(&state);
+            apply_permutation(&mut perm_state);
+            state.copy_from_slice(&perm_state[4..8]);
+            perm_state[STATE_WIDTH - 1].as_int()
+        })
+        .collect();
+
+    (sequence, state.map(|value| value.as_int()))
+}
@@ -0,0 +1,196 @@
+use super::{AdviceProvider, ExecutionError, Felt, MemAdviceProvider, ProgramInputs, Word};
+use vm_core::utils::{collections::Vec, Arc};
+
+// SHARED ADVICE TAPE
+// ================================================================================================
+
+/// An advice tape which can be shared between multiple executions without being copied.
+///
+/// The tape is copied once when it is created; cloning the tape after that is cheap, since the
+/// clones refer to the same underlying buffer.
+#[derive(Debug, Clone)]
+pub struct SharedAdviceTape(Arc<[Felt]>);
+
+impl SharedAdviceTape {
+    /// Returns a new shared advice tape containing the specified elements. The first element of
+    /// the `tape` slice is read first.
+    pub fn new(tape: &[Felt]) -> Self {
+        // reverse the tape so that the elements can be read off the end of the buffer
+        Self(tape.iter().rev().copied().collect())
+    }
+
+    /// Returns the number of elements in this tape.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if this tape does not contain any elements.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+// SHARED ADVICE PROVIDER
+// ================================================================================================
+
+/// An [AdviceProvider] which reads the advice tape from a [SharedAdviceTape].
+///
+/// Elements are read from the shared tape without being copied and without affecting the other
+/// executions which share the tape: each provider tracks only the number of elements it has
+/// consumed. The advice map and the advice sets are kept in memory, same as in
+/// [MemAdviceProvider].
+///
+/// The shared tape cannot be modified; thus, if an element is written onto the advice tape (e.g.,
+/// from the advice map), the remaining elements of the shared tape are copied into this provider
+/// first, and are read from the copy after that.
+pub struct SharedAdviceProvider {
+    step: u32,
+    tape: SharedAdviceTape,
+    tape_len: usize,
+    inner: MemAdviceProvider,
+}
+
+impl SharedAdviceProvider {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new advice provider which reads the advice tape from the specified shared tape,
+    /// with the advice map and the advice sets taken from the specified program inputs.
+    ///
+    /// The initial stack values of the inputs are ignored. The elements of the advice tape of the
+    /// inputs are read before the elements of the shared tape; in such a case, the shared tape is
+    /// copied into this provider.
+    pub fn new(tape: SharedAdviceTape, inputs: ProgramInputs) -> Self {
+        let (_, advice_tape, advice_map, advice_sets) = inputs.into_parts();
+        let inner = MemAdviceProvider::from_parts(Vec::new(), advice_map, advice_sets);
+
+        let mut provider = Self {
+            step: 0,
+            tape_len: tape.len(),
+            tape,
+            inner,
+        };
+        for &value in advice_tape.iter().rev() {
+            provider.write_tape(value);
+        }
+        provider
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Copies the remaining elements of the shared tape into the advice tape of the inner
+    /// provider; the elements are read from the inner provider after that.
+    ///
+    /// Elements are written onto the tape of the inner provider only after this has been done,
+    /// and thus, the tape of the inner provider is empty until then.
+    fn copy_shared_tape(&mut self) {
+        for &value in self.tape.0[..self.tape_len].iter() {
+            self.inner.write_tape(value);
+        }
+        self.tape_len = 0;
+    }
+}
+
+impl AdviceProvider for SharedAdviceProvider {
+    // ADVICE TAPE
+    // --------------------------------------------------------------------------------------------
+
+    fn read_tape(&mut self) -> Result<Felt, ExecutionError> {
+        if self.tape_len == 0 {
+            return self.inner.read_tape();
+        }
+
+        self.tape_len -= 1;
+        Ok(self.tape.0[self.tape_len])
+    }
+
+    fn read_tapew(&mut self) -> Result<Word, ExecutionError> {
+        if self.tape_len == 0 {
+            return self.inner.read_tapew();
+        } else if self.tape_len < 4 {
+            return Err(ExecutionError::AdviceTapeReadFailed(self.step));
+        }
+
+        let idx = self.tape_len - 4;
+        let tape = &self.tape.0;
+        let result = [tape[idx + 3], tape[idx + 2], tape[idx + 1], tape[idx]];
+
+        self.tape_len = idx;
+
+        Ok(result)
+    }
+
+    fn write_tape(&mut self, value: Felt) {
+        self.copy_shared_tape();
+        self.inner.write_tape(value);
+    }
+
+    fn tape_len(&self) -> usize {
+        self.tape_len + self.inner.tape_len()
+    }
+
+    fn peek_tape(&self) -> &[Felt] {
+        if self.tape_len == 0 {
+            self.inner.peek_tape()
+        } else {
+            &self.tape.0[..self.tape_len]
+        }
+    }
+
+    fn write_tape_from_map(&mut self, key: Word) -> Result<(), ExecutionError> {
+        self.copy_shared_tape();
+        self.inner.write_tape_from_map(key)
+    }
+
+    fn take_from_map(&mut self, key: Word) -> Result<Vec<Felt>, ExecutionError> {
+        self.inner.take_from_map(key)
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.inner.insert_into_map(key, values)
+    }
+
+    // ADVISE SETS
+    // --------------------------------------------------------------------------------------------
+
+    fn has_advice_set(&self, root: Word) -> bool {
+        self.inner.has_advice_set(root)
+    }
+
+    fn get_tree_node(
+        &mut self,
+        root: Word,
+        depth: Felt,
+        index: Felt,
+    ) -> Result<Word, ExecutionError> {
+        self.inner.get_tree_node(root, depth, index)
+    }
+
+    fn get_merkle_path(
+        &mut self,
+        root: Word,
+        depth: Felt,
+        index: Felt,
+    ) -> Result<Vec<Word>, ExecutionError> {
+        self.inner.get_merkle_path(root, depth, index)
+    }
+
+    fn update_merkle_leaf(
+        &mut self,
+        root: Word,
+        index: Felt,
+        leaf_value: Word,
+        update_in_copy: bool,
+    ) -> Result<Vec<Word>, ExecutionError> {
+        self.inner
+            .update_merkle_leaf(root, index, leaf_value, update_in_copy)
+    }
+
+    // CONTEXT MANAGEMENT
+    // --------------------------------------------------------------------------------------------
+
+    fn advance_clock(&mut self) {
+        self.step += 1;
+        self.inner.advance_clock();
+    }
+}
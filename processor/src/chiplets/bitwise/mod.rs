@@ -3,9 +3,12 @@ use super::{
     Vec, BITWISE_AND_LABEL, BITWISE_XOR_LABEL,
 };
 use crate::{utils::get_trace_len, Matrix};
-use vm_core::chiplets::bitwise::{
-    A_COL_IDX, A_COL_RANGE, BITWISE_AND, BITWISE_XOR, B_COL_IDX, B_COL_RANGE, OP_CYCLE_LEN,
-    OUTPUT_COL_IDX, PREV_OUTPUT_COL_IDX, TRACE_WIDTH,
+use vm_core::{
+    chiplets::bitwise::{
+        A_COL_IDX, A_COL_RANGE, BITWISE_AND, BITWISE_XOR, B_COL_IDX, B_COL_RANGE, OP_CYCLE_LEN,
+        OUTPUT_COL_IDX, PREV_OUTPUT_COL_IDX, TRACE_WIDTH,
+    },
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 #[cfg(test)]
@@ -273,3 +276,16 @@ impl LookupTableRow for BitwiseLookup {
             + alphas[4].mul_base(self.z)
     }
 }
+
+impl Serializable for BitwiseLookup {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        Felt::write_batch_into(&[self.op_id, self.a, self.b, self.z], target);
+    }
+}
+
+impl Deserializable for BitwiseLookup {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let values = Felt::read_batch_from(source, 4)?;
+        Ok(Self::new(values[0], values[1], values[2], values[3]))
+    }
+}
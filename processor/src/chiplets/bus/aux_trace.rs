@@ -1,8 +1,10 @@
 use super::{ChipletsLookup, ChipletsLookupRow, Felt, FieldElement};
 use crate::{
     trace::{build_lookup_table_row_values, AuxColumnBuilder, LookupTableRow},
+    utils::{check_hint_cycle, check_table_row_idx, read_vec, write_vec},
     Matrix, Vec,
 };
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 // AUXILIARY TRACE BUILDER
 // ================================================================================================
@@ -92,3 +94,68 @@ impl AuxColumnBuilder<ChipletsLookup, ChipletsLookupRow, u32> for AuxTraceBuilde
         (row_values, inv_row_values)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl AuxTraceBuilder {
+    /// Returns an error if any of the lookup hints or lookup rows of this builder cannot be applied
+    /// to an execution trace of the specified length.
+    pub(crate) fn validate(&self, trace_len: usize) -> Result<(), DeserializationError> {
+        for (cycle, _) in self.lookup_hints.iter() {
+            check_hint_cycle(*cycle as usize, trace_len)?;
+        }
+        for row in self.request_rows.iter().chain(self.response_rows.iter()) {
+            row.validate(trace_len)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serializable for AuxTraceBuilder {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.lookup_hints.len() as u64);
+        for (cycle, lookup) in self.lookup_hints.iter() {
+            target.write_u32(*cycle);
+            lookup.write_into(target);
+        }
+        write_vec(target, &self.request_rows);
+        write_vec(target, &self.response_rows);
+    }
+}
+
+impl Deserializable for AuxTraceBuilder {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_hints = source.read_u64()? as usize;
+        let mut lookup_hints = Vec::new();
+        for _ in 0..num_hints {
+            let cycle = source.read_u32()?;
+            lookup_hints.push((cycle, ChipletsLookup::read_from(source)?));
+        }
+        let request_rows: Vec<ChipletsLookupRow> = read_vec(source)?;
+        let response_rows: Vec<ChipletsLookupRow> = read_vec(source)?;
+
+        // all hints must refer to the request and response rows
+        for (_, lookup) in lookup_hints.iter() {
+            let (request_idx, response_idx) = match *lookup {
+                ChipletsLookup::Request(request_idx) => (Some(request_idx), None),
+                ChipletsLookup::Response(response_idx) => (None, Some(response_idx)),
+                ChipletsLookup::RequestAndResponse((request_idx, response_idx)) => {
+                    (Some(request_idx), Some(response_idx))
+                }
+            };
+            if let Some(request_idx) = request_idx {
+                check_table_row_idx(request_idx, request_rows.len(), "chiplets request")?;
+            }
+            if let Some(response_idx) = response_idx {
+                check_table_row_idx(response_idx, response_rows.len(), "chiplets response")?;
+            }
+        }
+
+        Ok(Self {
+            lookup_hints,
+            request_rows,
+            response_rows,
+        })
+    }
+}
@@ -2,7 +2,11 @@ use super::{
     hasher::HasherLookup, BTreeMap, BitwiseLookup, Felt, FieldElement, LookupTableRow,
     MemoryLookup, Vec,
 };
-use crate::Matrix;
+use crate::{
+    utils::{invalid_value, read_vec, write_vec},
+    Matrix,
+};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 mod aux_trace;
 pub use aux_trace::AuxTraceBuilder;
@@ -249,3 +253,99 @@ impl LookupTableRow for ChipletsLookupRow {
         }
     }
 }
+
+impl ChipletsLookupRow {
+    /// Returns an error if this lookup row refers to a row outside of an execution trace of the
+    /// specified length.
+    pub(super) fn validate(&self, trace_len: usize) -> Result<(), DeserializationError> {
+        match self {
+            Self::Hasher(lookup) => lookup.validate(trace_len),
+            Self::HasherMulti(lookups) => lookups
+                .iter()
+                .try_for_each(|lookup| lookup.validate(trace_len)),
+            Self::Bitwise(_) | Self::Memory(_) | Self::MemoryMulti(_) => Ok(()),
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ChipletsLookup {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Request(request_idx) => {
+                target.write_u8(0);
+                target.write_u64(*request_idx as u64);
+            }
+            Self::Response(response_idx) => {
+                target.write_u8(1);
+                target.write_u64(*response_idx as u64);
+            }
+            Self::RequestAndResponse((request_idx, response_idx)) => {
+                target.write_u8(2);
+                target.write_u64(*request_idx as u64);
+                target.write_u64(*response_idx as u64);
+            }
+        }
+    }
+}
+
+impl Deserializable for ChipletsLookup {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Request(source.read_u64()? as usize)),
+            1 => Ok(Self::Response(source.read_u64()? as usize)),
+            2 => {
+                let request_idx = source.read_u64()? as usize;
+                let response_idx = source.read_u64()? as usize;
+                Ok(Self::RequestAndResponse((request_idx, response_idx)))
+            }
+            tag => Err(invalid_value(format!("invalid chiplets lookup {tag}"))),
+        }
+    }
+}
+
+impl Serializable for ChipletsLookupRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Hasher(lookup) => {
+                target.write_u8(0);
+                lookup.write_into(target);
+            }
+            Self::HasherMulti(lookups) => {
+                target.write_u8(1);
+                write_vec(target, lookups);
+            }
+            Self::Bitwise(lookup) => {
+                target.write_u8(2);
+                lookup.write_into(target);
+            }
+            Self::Memory(lookup) => {
+                target.write_u8(3);
+                lookup.write_into(target);
+            }
+            Self::MemoryMulti(lookups) => {
+                target.write_u8(4);
+                MemoryLookup::write_batch_into(lookups, target);
+            }
+        }
+    }
+}
+
+impl Deserializable for ChipletsLookupRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Hasher(HasherLookup::read_from(source)?)),
+            1 => Ok(Self::HasherMulti(read_vec(source)?)),
+            2 => Ok(Self::Bitwise(BitwiseLookup::read_from(source)?)),
+            3 => Ok(Self::Memory(MemoryLookup::read_from(source)?)),
+            4 => {
+                let first = MemoryLookup::read_from(source)?;
+                let second = MemoryLookup::read_from(source)?;
+                Ok(Self::MemoryMulti([first, second]))
+            }
+            tag => Err(invalid_value(format!("invalid chiplets lookup row {tag}"))),
+        }
+    }
+}
@@ -1,8 +1,13 @@
 use super::{Felt, FieldElement, StarkField, Vec, Word};
 use crate::{
     trace::{AuxColumnBuilder, LookupTableRow},
+    utils::{
+        check_hint_cycle, check_table_row_idx, invalid_value, read_vec, read_word, write_vec,
+        write_word,
+    },
     Matrix,
 };
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 // AUXILIARY TRACE BUILDER
 // ================================================================================================
@@ -150,3 +155,92 @@ impl LookupTableRow for SiblingTableRow {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl AuxTraceBuilder {
+    /// Returns an error if any of the sibling table hints of this builder cannot be applied to an
+    /// execution trace of the specified length.
+    pub(crate) fn validate(&self, trace_len: usize) -> Result<(), DeserializationError> {
+        for (step, _) in self.sibling_hints.iter() {
+            check_hint_cycle(*step as usize, trace_len)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serializable for AuxTraceBuilder {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.sibling_hints.len() as u64);
+        for (step, update) in self.sibling_hints.iter() {
+            target.write_u32(*step);
+            update.write_into(target);
+        }
+        write_vec(target, &self.sibling_rows);
+    }
+}
+
+impl Deserializable for AuxTraceBuilder {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_hints = source.read_u64()? as usize;
+        let mut sibling_hints = Vec::new();
+        for _ in 0..num_hints {
+            let step = source.read_u32()?;
+            sibling_hints.push((step, SiblingTableUpdate::read_from(source)?));
+        }
+        let sibling_rows: Vec<SiblingTableRow> = read_vec(source)?;
+
+        // all hints must refer to the rows of the table
+        for (_, update) in sibling_hints.iter() {
+            let (SiblingTableUpdate::SiblingAdded(row_idx)
+            | SiblingTableUpdate::SiblingRemoved(row_idx)) = update;
+            check_table_row_idx(*row_idx as usize, sibling_rows.len(), "sibling table")?;
+        }
+
+        Ok(Self {
+            sibling_hints,
+            sibling_rows,
+        })
+    }
+}
+
+impl Serializable for SiblingTableUpdate {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::SiblingAdded(row_idx) => {
+                target.write_u8(0);
+                target.write_u32(*row_idx);
+            }
+            Self::SiblingRemoved(row_idx) => {
+                target.write_u8(1);
+                target.write_u32(*row_idx);
+            }
+        }
+    }
+}
+
+impl Deserializable for SiblingTableUpdate {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::SiblingAdded(source.read_u32()?)),
+            1 => Ok(Self::SiblingRemoved(source.read_u32()?)),
+            tag => Err(invalid_value(format!("invalid sibling table update {tag}"))),
+        }
+    }
+}
+
+impl Serializable for SiblingTableRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.index.write_into(target);
+        write_word(target, &self.sibling);
+    }
+}
+
+impl Deserializable for SiblingTableRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let index = Felt::read_from(source)?;
+        let sibling = read_word(source)?;
+        Ok(Self::new(index, sibling))
+    }
+}
@@ -1,7 +1,7 @@
 use core::ops::Range;
 
 use super::{Felt, FieldElement, LookupTableRow, StarkField};
-use crate::Matrix;
+use crate::{utils::invalid_value, Matrix};
 use vm_core::{
     chiplets::{
         hasher::{
@@ -11,7 +11,10 @@ use vm_core::{
         },
         HASHER_RATE_COL_RANGE, HASHER_STATE_COL_RANGE,
     },
-    utils::collections::Vec,
+    utils::{
+        collections::Vec, ByteReader, ByteWriter, Deserializable, DeserializationError,
+        Serializable,
+    },
 };
 
 // CONSTANTS
@@ -62,6 +65,19 @@ impl HasherLookup {
         self.addr - 1
     }
 
+    /// Returns an error if the rows of the hasher state read when computing the value of this
+    /// lookup are not a part of an execution trace of the specified length.
+    pub(crate) fn validate(&self, trace_len: usize) -> Result<(), DeserializationError> {
+        // an absorption also reads the row following the row of the lookup's address
+        if self.addr as usize >= trace_len {
+            return Err(invalid_value(format!(
+                "hasher lookup address {} is out of bounds for a trace of length {trace_len}",
+                self.addr
+            )));
+        }
+        Ok(())
+    }
+
     /// Returns the common header value which describes this hash operation. It is a combination of
     /// the transition label, the row address, and the node index.
     fn get_header_value<E: FieldElement<BaseField = Felt>>(&self, alphas: &[E]) -> E {
@@ -202,3 +218,64 @@ fn get_adjacent_hasher_rates(
 fn get_row_from_addr(addr: u32) -> usize {
     addr as usize - 1
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for HasherLookup {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.label);
+        target.write_u32(self.addr);
+        self.index.write_into(target);
+        let context = match self.context {
+            HasherLookupContext::Start => 0,
+            HasherLookupContext::Absorb => 1,
+            HasherLookupContext::Return => 2,
+        };
+        target.write_u8(context);
+    }
+}
+
+impl Deserializable for HasherLookup {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let label = source.read_u8()?;
+        let addr = source.read_u32()?;
+        if addr == 0 {
+            return Err(invalid_value(
+                "hasher lookup address must be greater than zero",
+            ));
+        }
+        let index = Felt::read_from(source)?;
+        let context = match source.read_u8()? {
+            0 => HasherLookupContext::Start,
+            1 => HasherLookupContext::Absorb,
+            2 => HasherLookupContext::Return,
+            tag => {
+                return Err(invalid_value(format!(
+                    "invalid hasher lookup context {tag}"
+                )))
+            }
+        };
+
+        // the values of lookups can be computed only for the hash operations recognized for the
+        // lookup's context
+        let is_valid_label = match context {
+            HasherLookupContext::Start => [
+                LINEAR_HASH_LABEL,
+                MP_VERIFY_LABEL,
+                MR_UPDATE_OLD_LABEL,
+                MR_UPDATE_NEW_LABEL,
+            ]
+            .contains(&label),
+            HasherLookupContext::Absorb => label == LINEAR_HASH_LABEL,
+            HasherLookupContext::Return => [RETURN_HASH_LABEL, RETURN_STATE_LABEL].contains(&label),
+        };
+        if !is_valid_label {
+            return Err(invalid_value(format!(
+                "invalid hasher lookup label {label}"
+            )));
+        }
+
+        Ok(Self::new(label, addr, index, context))
+    }
+}
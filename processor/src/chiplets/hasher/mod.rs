@@ -72,11 +72,13 @@ mod tests;
 ///   encountered with the same digest instead of building it from scratch everytime. The only
 ///   thing that changes in the copied trace are the `addr` column rows. The hash of the block
 ///   is used as the key here after converting it to a bytes array.
+/// - a list of the [HashOp]s performed by the hasher, in the order in which they were performed.
 #[derive(Default)]
 pub struct Hasher {
     trace: HasherTrace,
     aux_trace: AuxTraceBuilder,
     memoized_trace_map: BTreeMap<[u8; 32], (usize, usize)>,
+    operations: Vec<HashOp>,
 }
 
 impl Hasher {
@@ -88,6 +90,11 @@ impl Hasher {
         self.trace.trace_len()
     }
 
+    /// Returns the operations performed by this hasher so far, and clears the list of operations.
+    pub(super) fn take_operations(&mut self) -> Vec<HashOp> {
+        core::mem::take(&mut self.operations)
+    }
+
     /// Returns the [HasherLookup] from the provided label, index and context inputs.
     #[inline(always)]
     fn get_lookup(&self, label: u8, index: Felt, context: HasherLookupContext) -> HasherLookup {
@@ -115,6 +122,7 @@ impl Hasher {
         lookups: &mut Vec<HasherLookup>,
     ) -> (Felt, HasherState) {
         let addr = self.trace.next_row_addr();
+        self.operations.push(HashOp::Permutation);
 
         // add the lookup for the hash initialization.
         let lookup = self.get_lookup(LINEAR_HASH_LABEL, ZERO, HasherLookupContext::Start);
@@ -145,6 +153,7 @@ impl Hasher {
         lookups: &mut Vec<HasherLookup>,
     ) -> (Felt, Word) {
         let addr = self.trace.next_row_addr();
+        self.operations.push(HashOp::ControlBlock);
         let mut state = init_state_from_words(&h1, &h2);
 
         // add the lookup for the hash initialization.
@@ -198,6 +207,7 @@ impl Hasher {
         const CONTINUE: Selectors = [ZERO, LINEAR_HASH[1], LINEAR_HASH[2]];
 
         let addr = self.trace.next_row_addr();
+        self.operations.push(HashOp::SpanBlock(op_batches.len()));
 
         // initialize the state and absorb the first operation batch into it
         let mut state = init_state(op_batches[0].groups(), num_op_groups);
@@ -304,6 +314,7 @@ impl Hasher {
         lookups: &mut Vec<HasherLookup>,
     ) -> (Felt, Word) {
         let addr = self.trace.next_row_addr();
+        self.operations.push(HashOp::MerklePath(path.len()));
 
         let root = self.verify_merkle_path(
             value,
@@ -341,6 +352,7 @@ impl Hasher {
         lookups: &mut Vec<HasherLookup>,
     ) -> (Felt, Word, Word) {
         let addr = self.trace.next_row_addr();
+        self.operations.push(HashOp::MerkleRootUpdate(path.len()));
         let index = index.as_int();
 
         let old_root = self.verify_merkle_path(
@@ -565,6 +577,31 @@ impl Hasher {
     }
 }
 
+// HASH OPERATION
+// ================================================================================================
+
+/// A hash computation performed by the hash chiplet.
+///
+/// Each operation is recorded once, even if its execution trace is copied from the trace of an
+/// identical computation performed earlier (e.g., for code blocks executed multiple times).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashOp {
+    /// A single permutation of the hash function, requested by the RPPERM operation (e.g., as a
+    /// part of the `rphash` instruction merging two words, or of the `rpperm` instruction).
+    Permutation,
+    /// Hash of a control block (i.e., a JOIN, SPLIT, LOOP, CALL, or SYSCALL block), computed when
+    /// the block is decoded.
+    ControlBlock,
+    /// Hash of a SPAN block with the specified number of operation batches, computed when the
+    /// block is decoded.
+    SpanBlock(usize),
+    /// Verification of a Merkle path of the specified depth (e.g., by the `mtree_get` instruction).
+    MerklePath(usize),
+    /// Update of a Merkle root via a Merkle path of the specified depth (e.g., by the `mtree_set`
+    /// instruction).
+    MerkleRootUpdate(usize),
+}
+
 // MERKLE PATH CONTEXT
 // ================================================================================================
 
@@ -4,11 +4,14 @@ use super::{
 use crate::{
     range::RangeChecker,
     trace::LookupTableRow,
-    utils::{split_element_u32_into_u16, split_u32_into_u16},
+    utils::{read_word, split_element_u32_into_u16, split_u32_into_u16, write_word},
     Matrix,
 };
-use vm_core::chiplets::memory::{
-    ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, V_COL_RANGE,
+use vm_core::{
+    chiplets::memory::{
+        ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, V_COL_RANGE,
+    },
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 mod segment;
@@ -82,6 +85,10 @@ pub struct Memory {
     /// Total number of entries in the trace (across all contexts); tracked separately so that we
     /// don't have to sum up lengths of all address trace vectors for all contexts all the time.
     num_trace_rows: usize,
+
+    /// Sequence of (clock cycle, access type, address) tuples describing all memory accesses in
+    /// the order in which they happened; None if recording of memory accesses is not enabled.
+    access_log: Option<Vec<(u32, MemoryAccess, u64)>>,
 }
 
 impl Memory {
@@ -127,6 +134,20 @@ impl Memory {
         }
     }
 
+    /// Returns the memory writes for the specified execution context which are visible for the
+    /// first time at the specified cycle, i.e., the writes which happened during the previous
+    /// cycle. The writes are returned as a vector of (address, value) tuples.
+    pub fn get_writes_at(&self, ctx: u32, clk: u32) -> Vec<(u64, Word)> {
+        if clk == 0 {
+            return vec![];
+        }
+
+        match self.trace.get(&ctx) {
+            Some(segment) => segment.get_writes_at(clk),
+            None => vec![],
+        }
+    }
+
     // STATE ACCESSORS AND MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -136,6 +157,7 @@ impl Memory {
     /// returned. This effectively implies that memory is initialized to ZERO.
     pub fn read(&mut self, ctx: u32, addr: Felt, clk: u32) -> Word {
         self.num_trace_rows += 1;
+        self.log_access(clk, MemoryAccess::Read, addr);
         self.trace
             .entry(ctx)
             .or_insert_with(MemorySegmentTrace::default)
@@ -145,12 +167,38 @@ impl Memory {
     /// Writes the provided word at the specified context/address.
     pub fn write(&mut self, ctx: u32, addr: Felt, clk: u32, value: Word) {
         self.num_trace_rows += 1;
+        self.log_access(clk, MemoryAccess::Write, addr);
         self.trace
             .entry(ctx)
             .or_insert_with(MemorySegmentTrace::default)
             .write(addr, Felt::from(clk), value);
     }
 
+    // ACCESS LOG
+    // --------------------------------------------------------------------------------------------
+
+    /// Enables recording of all subsequent memory accesses; see [Memory::take_access_log()].
+    pub fn enable_access_log(&mut self) {
+        self.access_log.get_or_insert_with(Vec::new);
+    }
+
+    /// Returns the memory accesses recorded since recording was enabled as a sequence of (clock
+    /// cycle, access type, address) tuples, and clears the log. If recording was not enabled, an
+    /// empty vector is returned.
+    pub fn take_access_log(&mut self) -> Vec<(u32, MemoryAccess, u64)> {
+        self.access_log
+            .as_mut()
+            .map(core::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Records the specified access in the access log if recording of memory accesses is enabled.
+    fn log_access(&mut self, clk: u32, access: MemoryAccess, addr: Felt) {
+        if let Some(access_log) = self.access_log.as_mut() {
+            access_log.push((clk, access, addr.as_int()));
+        }
+    }
+
     // EXECUTION TRACE GENERATION
     // --------------------------------------------------------------------------------------------
 
@@ -295,6 +343,16 @@ impl Memory {
     }
 }
 
+// MEMORY ACCESS
+// ================================================================================================
+
+/// Type of a memory access recorded in the memory access log of an execution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum MemoryAccess {
+    Read,
+    Write,
+}
+
 // MEMORY LOOKUPS
 // ================================================================================================
 
@@ -355,3 +413,20 @@ impl LookupTableRow for MemoryLookup {
             + word_value
     }
 }
+
+impl Serializable for MemoryLookup {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.label);
+        Felt::write_batch_into(&[self.ctx, self.addr, self.clk], target);
+        write_word(target, &self.word);
+    }
+}
+
+impl Deserializable for MemoryLookup {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let label = source.read_u8()?;
+        let values = Felt::read_batch_from(source, 3)?;
+        let word = read_word(source)?;
+        Ok(Self::new(label, values[0], values[1], values[2], word))
+    }
+}
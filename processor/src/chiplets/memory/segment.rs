@@ -62,6 +62,30 @@ impl MemorySegmentTrace {
         result
     }
 
+    /// Returns the memory writes which happened during the cycle preceding the specified cycle.
+    pub fn get_writes_at(&self, clk: u32) -> Vec<(u64, Word)> {
+        if clk == 0 {
+            return Vec::new();
+        }
+
+        // memory state at the beginning of a cycle reflects the writes made during the previous
+        // cycle; see get_state_at() for details.
+        let search_clk = (clk - 1) as u64;
+
+        self.0
+            .iter()
+            .filter_map(|(&addr, addr_trace)| {
+                addr_trace
+                    .iter()
+                    .rev()
+                    .find(|access| {
+                        access.clk().as_int() == search_clk && access.op == MemoryOperation::Write
+                    })
+                    .map(|access| (addr, access.value()))
+            })
+            .collect()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
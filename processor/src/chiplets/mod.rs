@@ -18,9 +18,10 @@ use bitwise::{Bitwise, BitwiseLookup};
 
 mod hasher;
 use hasher::Hasher;
-pub use hasher::{AuxTraceBuilder as HasherAuxTraceBuilder, SiblingTableRow};
+pub use hasher::{AuxTraceBuilder as HasherAuxTraceBuilder, HashOp, SiblingTableRow};
 
 mod memory;
+pub use memory::MemoryAccess;
 use memory::{Memory, MemoryLookup};
 
 mod kernel_rom;
@@ -422,6 +423,30 @@ impl Chiplets {
         self.memory.get_state_at(ctx, clk)
     }
 
+    /// Returns the memory writes for the specified execution context which are visible for the
+    /// first time at the specified cycle. The writes are returned as a vector of (address, value)
+    /// tuples.
+    pub fn get_mem_writes_at(&self, ctx: u32, clk: u32) -> Vec<(u64, Word)> {
+        self.memory.get_writes_at(ctx, clk)
+    }
+
+    /// Enables recording of all subsequent memory accesses; see [Chiplets::take_mem_access_log()].
+    pub fn enable_mem_access_log(&mut self) {
+        self.memory.enable_access_log();
+    }
+
+    /// Returns the memory accesses recorded since recording was enabled as a sequence of (clock
+    /// cycle, access type, address) tuples, and clears the log.
+    pub fn take_mem_access_log(&mut self) -> Vec<(u32, MemoryAccess, u64)> {
+        self.memory.take_access_log()
+    }
+
+    /// Returns the operations performed by the Hash chiplet so far, in the order in which they were
+    /// performed, and clears the list of operations.
+    pub fn take_hash_operations(&mut self) -> Vec<HashOp> {
+        self.hasher.take_operations()
+    }
+
     /// Returns current size of the memory (in words) across all execution contexts.
     #[cfg(test)]
     pub fn get_mem_size(&self) -> usize {
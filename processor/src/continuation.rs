@@ -0,0 +1,536 @@
+use super::{
+    CodeBlock, CodeBlockTable, Digest, ExecutionError, OpBatch, Operation, Process, Program,
+    ProgramOutputs, Span, Vec, ONE, OP_GROUP_SIZE, ZERO,
+};
+
+// RUN STATUS
+// ================================================================================================
+
+/// Status of a program executed in a [Process] via [Process::run_ticks].
+#[derive(Clone, Debug)]
+pub enum RunStatus {
+    /// The program has not finished executing yet; the execution can be resumed by invoking
+    /// [Process::run_ticks] again.
+    Running,
+    /// The program has finished executing and produced the specified outputs.
+    Halted(ProgramOutputs),
+}
+
+// TICK EXECUTOR
+// ================================================================================================
+
+impl Process {
+    /// Executes up to `num_ticks` cycles of the provided [Program] in this process.
+    ///
+    /// The execution can be resumed by invoking this method again with the same program: the
+    /// state of the execution is kept in the process between invocations. This allows
+    /// interleaving executions of several programs in several processes on a single thread. The
+    /// resulting state of the process is the same as if the program was executed by a single
+    /// invocation of [Process::execute].
+    ///
+    /// Returns [RunStatus::Halted] with the outputs of the program once the program has finished
+    /// executing, and [RunStatus::Running] otherwise.
+    ///
+    /// # Errors
+    /// Returns an [ExecutionError] if executing the program fails for any reason; the execution
+    /// cannot be resumed after that.
+    ///
+    /// # Panics
+    /// Panics if the program differs from the program provided when the execution was started, or
+    /// if a program has already been executed in this process via [Process::execute].
+    pub fn run_ticks(
+        &mut self,
+        program: &Program,
+        num_ticks: usize,
+    ) -> Result<RunStatus, ExecutionError> {
+        let mut frames = match self.continuation.take() {
+            Some(continuation) => {
+                assert_eq!(
+                    program.hash(),
+                    continuation.program_hash,
+                    "execution was started for a different program"
+                );
+                continuation.frames
+            }
+            None => {
+                assert_eq!(
+                    self.system.clk(),
+                    0,
+                    "a program has already been executed in this process"
+                );
+                vec![Frame::Pending]
+            }
+        };
+
+        let mut blocks = resolve_blocks(program, &frames);
+        let last_clk = (self.system.clk() as usize).saturating_add(num_ticks);
+        while !frames.is_empty() && (self.system.clk() as usize) < last_clk {
+            self.execute_step(&mut frames, &mut blocks, program.cb_table())
+                .map_err(|err| err.with_call_stack(&self.call_stack))?;
+        }
+
+        let is_halted = frames.is_empty();
+        self.continuation = Some(Continuation {
+            program_hash: program.hash(),
+            frames,
+        });
+
+        if is_halted {
+            Ok(RunStatus::Halted(self.stack.get_outputs()))
+        } else {
+            Ok(RunStatus::Running)
+        }
+    }
+
+    // STEP EXECUTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Advances execution of the innermost block by a single step. A step executes at most one
+    /// VM cycle: it either executes a single operation, or it moves execution to a child block
+    /// without executing any operations.
+    fn execute_step<'a>(
+        &mut self,
+        frames: &mut Vec<Frame>,
+        blocks: &mut Vec<&'a CodeBlock>,
+        cb_table: &'a CodeBlockTable,
+    ) -> Result<(), ExecutionError> {
+        let frame = frames.pop().expect("no frames to execute");
+        let block = blocks.pop().expect("no blocks to execute");
+
+        match self.execute_frame(frame, block, cb_table)? {
+            Transition::Stay(frame) => {
+                frames.push(frame);
+                blocks.push(block);
+            }
+            Transition::Enter(frame, child) => {
+                frames.extend([frame, Frame::Pending]);
+                blocks.extend([block, child]);
+            }
+            Transition::Exit => (),
+        }
+
+        Ok(())
+    }
+
+    /// Executes a single step of the specified block in the state described by the frame, and
+    /// returns the transition to the next state of the block.
+    fn execute_frame<'a>(
+        &mut self,
+        frame: Frame,
+        block: &'a CodeBlock,
+        cb_table: &'a CodeBlockTable,
+    ) -> Result<Transition<'a>, ExecutionError> {
+        let transition = match (frame, block) {
+            // start the block and, unless this is a SPAN block, enter its first child
+            (Frame::Pending, CodeBlock::Join(block)) => {
+                self.start_join_block(block)?;
+                Transition::Enter(Frame::JoinFirst, block.first())
+            }
+            (Frame::Pending, CodeBlock::Split(block)) => {
+                let condition = self.start_split_block(block)?;
+                let depth = self.stack.unclamped_depth();
+                if condition == ONE {
+                    Transition::Enter(Frame::SplitBranch(true, depth), block.on_true())
+                } else if condition == ZERO {
+                    Transition::Enter(Frame::SplitBranch(false, depth), block.on_false())
+                } else {
+                    return Err(ExecutionError::NotBinaryValue(condition));
+                }
+            }
+            (Frame::Pending, CodeBlock::Loop(block)) => {
+                let condition = self.start_loop_block(block)?;
+                if condition == ONE {
+                    Transition::Enter(Frame::LoopBody, block.body())
+                } else if condition == ZERO {
+                    Transition::Stay(Frame::LoopSkipped)
+                } else {
+                    return Err(ExecutionError::NotBinaryValue(condition));
+                }
+            }
+            (Frame::Pending, CodeBlock::Call(block)) => {
+                self.push_call_stack(block.fn_hash())?;
+                if block.is_syscall() {
+                    self.chiplets.access_kernel_proc(block.fn_hash())?;
+                }
+                self.start_call_block(block)?;
+                let fn_body = cb_table
+                    .get(block.fn_hash())
+                    .ok_or_else(|| ExecutionError::CodeBlockNotFound(block.fn_hash()))?;
+                Transition::Enter(Frame::CallBody, fn_body)
+            }
+            (Frame::Pending, CodeBlock::Span(block)) => {
+                self.start_span_block(block)?;
+                Transition::Stay(Frame::Span(SpanState::new(0, 0, 0)))
+            }
+            (Frame::Pending, CodeBlock::Proxy(_)) => {
+                return Err(ExecutionError::UnexecutableCodeBlock(block.clone()))
+            }
+
+            // a child of the block has been executed; either enter the next child, or end the
+            // block
+            (Frame::JoinFirst, CodeBlock::Join(block)) => {
+                Transition::Enter(Frame::JoinSecond, block.second())
+            }
+            (Frame::JoinSecond, CodeBlock::Join(block)) => {
+                self.end_join_block(block)?;
+                Transition::Exit
+            }
+            (Frame::SplitBranch(on_true, depth), CodeBlock::Split(block)) => {
+                if self.decoder.in_debug_mode() {
+                    self.check_branch_stack_depth(block, on_true, depth)?;
+                }
+                self.end_split_block(block)?;
+                Transition::Exit
+            }
+            (Frame::LoopBody, CodeBlock::Loop(block)) => {
+                if self.stack.peek() == ONE {
+                    self.decoder.repeat();
+                    self.execute_op(Operation::Drop)?;
+                    Transition::Enter(Frame::LoopBody, block.body())
+                } else {
+                    self.end_loop_block(block, true)?;
+                    Transition::Exit
+                }
+            }
+            (Frame::LoopSkipped, CodeBlock::Loop(block)) => {
+                self.end_loop_block(block, false)?;
+                Transition::Exit
+            }
+            (Frame::CallBody, CodeBlock::Call(block)) => {
+                self.end_call_block(block)?;
+                self.call_stack.pop();
+                Transition::Exit
+            }
+            (Frame::Span(mut state), CodeBlock::Span(block)) => {
+                if self.execute_span_step(block, &mut state)? {
+                    Transition::Stay(Frame::Span(state))
+                } else {
+                    Transition::Exit
+                }
+            }
+            _ => unreachable!("frame does not match its code block"),
+        };
+
+        Ok(transition)
+    }
+
+    /// Executes a single cycle of the specified [Span] block in the provided state. This follows
+    /// the same rules as executing the block in one go, and thus, results in the same sequence of
+    /// operations (including RESPANs and alignment NOOPs).
+    ///
+    /// Returns false if the block has been ended by this step, and true otherwise.
+    fn execute_span_step(
+        &mut self,
+        block: &Span,
+        state: &mut SpanState,
+    ) -> Result<bool, ExecutionError> {
+        let batch = &block.op_batches()[state.batch_idx];
+        match state.phase {
+            SpanPhase::Operations => {
+                let i = state.batch_op_idx;
+                let op = batch.ops()[i];
+
+                let decorators = block.decorators();
+                while state.decorator_idx < decorators.len()
+                    && decorators[state.decorator_idx].0 == i + state.op_offset
+                {
+                    self.execute_decorator(&decorators[state.decorator_idx].1)?;
+                    state.decorator_idx += 1;
+                }
+
+                self.decoder.execute_user_op(op, state.op_idx);
+                self.execute_op(op).map_err(|err| {
+                    self.map_batch_op_error(block, batch, i, state.op_offset, err)
+                })?;
+                state.batch_op_idx += 1;
+
+                // the immediate value of an operation is stored in the next group
+                let has_imm = op.imm_value().is_some();
+                if has_imm {
+                    state.next_group_idx += 1;
+                }
+
+                // an operation with an immediate value cannot be the last operation in a group;
+                // so, a NOOP is executed after it in the next step
+                if state.op_idx == batch.op_counts()[state.group_idx] - 1 {
+                    if has_imm {
+                        debug_assert!(state.op_idx < OP_GROUP_SIZE - 1, "invalid op index");
+                        state.phase = SpanPhase::ImmediateNoop;
+                    } else {
+                        self.start_next_op_group(batch, state);
+                    }
+                } else {
+                    state.op_idx += 1;
+                }
+            }
+            SpanPhase::ImmediateNoop => {
+                self.decoder
+                    .execute_user_op(Operation::Noop, state.op_idx + 1);
+                self.execute_op(Operation::Noop)?;
+                self.start_next_op_group(batch, state);
+                state.phase = SpanPhase::Operations;
+            }
+            SpanPhase::Padding => {
+                self.decoder.execute_user_op(Operation::Noop, 0);
+                self.execute_op(Operation::Noop)?;
+                if state.group_idx < num_batch_groups(batch) - 1 {
+                    self.decoder.start_op_group(ZERO);
+                }
+                state.group_idx += 1;
+            }
+            SpanPhase::Respan => {
+                state.op_offset += batch.ops().len();
+                state.batch_idx += 1;
+                let batch = &block.op_batches()[state.batch_idx];
+                self.respan(batch);
+                self.execute_op(Operation::Noop)?;
+                *state = SpanState::new(state.batch_idx, state.op_offset, state.decorator_idx);
+            }
+            SpanPhase::End => {
+                self.end_span_block(block)?;
+                return Ok(false);
+            }
+        }
+
+        // move to the next phase if all operations or all padding groups of the batch have been
+        // executed; this does not require executing any cycles
+        let batch = &block.op_batches()[state.batch_idx];
+        if state.phase == SpanPhase::Operations && state.batch_op_idx == batch.ops().len() {
+            state.phase = SpanPhase::Padding;
+        }
+        if state.phase == SpanPhase::Padding && state.group_idx >= num_batch_groups(batch) {
+            state.phase = if state.batch_idx + 1 < block.op_batches().len() {
+                SpanPhase::Respan
+            } else {
+                SpanPhase::End
+            };
+        }
+
+        Ok(true)
+    }
+
+    /// Moves to the next operation group of the batch and, if the end of the batch has not been
+    /// reached yet, sets up the decoder for decoding this group.
+    fn start_next_op_group(&mut self, batch: &OpBatch, state: &mut SpanState) {
+        state.group_idx = state.next_group_idx;
+        state.next_group_idx += 1;
+        state.op_idx = 0;
+
+        if state.group_idx < num_batch_groups(batch) {
+            self.decoder.start_op_group(batch.groups()[state.group_idx]);
+        }
+    }
+}
+
+// CONTINUATION
+// ================================================================================================
+
+/// State of a suspended program execution.
+///
+/// The state is described by a list of frames, one for each block in the path from the root of
+/// the program to the innermost block being executed. Frames do not reference the blocks
+/// directly so that the continuation can be kept in a process between invocations of
+/// [Process::run_ticks]; instead, the block of each frame is derived from the block and the state
+/// of its parent frame.
+pub(super) struct Continuation {
+    program_hash: Digest,
+    frames: Vec<Frame>,
+}
+
+/// Execution state of a single code block.
+#[derive(Clone, Copy)]
+enum Frame {
+    /// The block has not been started yet.
+    Pending,
+    /// The first child of a JOIN block is being executed.
+    JoinFirst,
+    /// The second child of a JOIN block is being executed.
+    JoinSecond,
+    /// A branch of a SPLIT block is being executed; the frame records whether this is the true
+    /// branch, and the unclamped depth of the stack at the start of the branch.
+    SplitBranch(bool, isize),
+    /// The body of a LOOP block is being executed.
+    LoopBody,
+    /// The body of a LOOP block has been skipped.
+    LoopSkipped,
+    /// The function invoked by a CALL or a SYSCALL block is being executed.
+    CallBody,
+    /// Operations of a SPAN block are being executed.
+    Span(SpanState),
+}
+
+/// A transition of a block to its next execution state.
+enum Transition<'a> {
+    /// The block remains the innermost block being executed.
+    Stay(Frame),
+    /// The specified child of the block becomes the innermost block being executed.
+    Enter(Frame, &'a CodeBlock),
+    /// The block has been executed.
+    Exit,
+}
+
+/// Execution state of a SPAN block.
+#[derive(Clone, Copy)]
+struct SpanState {
+    phase: SpanPhase,
+    batch_idx: usize,
+    /// Index of the next operation to be executed within the current batch.
+    batch_op_idx: usize,
+    /// Index of the next operation to be executed within the current operation group.
+    op_idx: usize,
+    group_idx: usize,
+    next_group_idx: usize,
+    /// Number of operations in all batches preceding the current batch.
+    op_offset: usize,
+    /// Index of the next decorator to be executed.
+    decorator_idx: usize,
+}
+
+impl SpanState {
+    /// Returns the state of a SPAN block at the start of the specified batch.
+    fn new(batch_idx: usize, op_offset: usize, decorator_idx: usize) -> Self {
+        Self {
+            phase: SpanPhase::Operations,
+            batch_idx,
+            batch_op_idx: 0,
+            op_idx: 0,
+            group_idx: 0,
+            next_group_idx: 1,
+            op_offset,
+            decorator_idx,
+        }
+    }
+}
+
+/// Part of a SPAN block being executed.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum SpanPhase {
+    /// Operations of the current batch are being executed.
+    Operations,
+    /// A NOOP following an operation with an immediate value is to be executed.
+    ImmediateNoop,
+    /// NOOPs padding the number of groups in the batch to a power of two are being executed.
+    Padding,
+    /// A RESPAN starting the next batch is to be executed.
+    Respan,
+    /// The END of the block is to be executed.
+    End,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the code blocks of the specified frames of a program execution.
+fn resolve_blocks<'a>(program: &'a Program, frames: &[Frame]) -> Vec<&'a CodeBlock> {
+    let mut blocks = Vec::with_capacity(frames.len());
+    let mut block = program.root();
+    for (i, frame) in frames.iter().enumerate() {
+        blocks.push(block);
+        if i + 1 < frames.len() {
+            block = get_active_child(frame, block, program.cb_table());
+        }
+    }
+    blocks
+}
+
+/// Returns the child of the specified block which is being executed in the state described by
+/// the frame.
+fn get_active_child<'a>(
+    frame: &Frame,
+    block: &'a CodeBlock,
+    cb_table: &'a CodeBlockTable,
+) -> &'a CodeBlock {
+    match (frame, block) {
+        (Frame::JoinFirst, CodeBlock::Join(block)) => block.first(),
+        (Frame::JoinSecond, CodeBlock::Join(block)) => block.second(),
+        (Frame::SplitBranch(true, _), CodeBlock::Split(block)) => block.on_true(),
+        (Frame::SplitBranch(false, _), CodeBlock::Split(block)) => block.on_false(),
+        (Frame::LoopBody, CodeBlock::Loop(block)) => block.body(),
+        (Frame::CallBody, CodeBlock::Call(block)) => cb_table
+            .get(block.fn_hash())
+            .expect("function body not found"),
+        _ => unreachable!("frame does not have an active child"),
+    }
+}
+
+/// Returns the number of operation groups executed for the specified batch; this is the number of
+/// groups in the batch rounded up to the next power of two.
+fn num_batch_groups(batch: &OpBatch) -> usize {
+    batch.num_groups().next_power_of_two()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::{ExecutionTrace, ProgramInputs},
+        Process, RunStatus,
+    };
+    use miden_assembly::Assembler;
+
+    #[test]
+    fn run_ticks() {
+        let kernel = "export.foo add end";
+        let source = "
+            proc.bar
+                push.2 mul
+            end
+            begin
+                syscall.foo call.bar
+                push.0.7.0.100 adv.u64div adv_push.4 dropw dropw
+                push.3 dup neq.0
+                while.true
+                    push.1 sub dup neq.0
+                end
+                drop dup push.4 gt
+                if.true
+                    push.100 add
+                else
+                    push.7 mul
+                end
+                repeat.40
+                    push.1 add
+                end
+            end";
+        let assembler = Assembler::new()
+            .with_debug_mode(true)
+            .with_kernel(kernel)
+            .unwrap();
+        let program = assembler.compile(source).unwrap();
+        let inputs = ProgramInputs::new(&[1, 2, 3], &[], vec![]).unwrap();
+
+        // execute the program uninterrupted
+        let mut process = Process::new_debug(program.kernel(), inputs.clone());
+        let expected_outputs = process.execute(&program).unwrap();
+        let num_cycles = process.system.clk() as usize;
+        let (expected_trace, _) = ExecutionTrace::test_finalize_trace(process);
+
+        for num_ticks in [1, 2, 5, 64, num_cycles, num_cycles + 1] {
+            let mut process = Process::new_debug(program.kernel(), inputs.clone());
+
+            // every invocation except the last one executes exactly the requested number of cycles
+            let mut num_runs = 1;
+            let outputs = loop {
+                match process.run_ticks(&program, num_ticks).unwrap() {
+                    RunStatus::Running => {
+                        assert_eq!(num_ticks * num_runs, process.system.clk() as usize);
+                        num_runs += 1;
+                    }
+                    RunStatus::Halted(outputs) => break outputs,
+                }
+            };
+            assert_eq!((num_cycles + num_ticks - 1) / num_ticks, num_runs);
+            assert_eq!(expected_outputs.stack(), outputs.stack());
+
+            // resuming a halted execution does not execute any cycles
+            let status = process.run_ticks(&program, num_ticks).unwrap();
+            assert!(matches!(status, RunStatus::Halted(_)));
+            assert_eq!(num_cycles, process.system.clk() as usize);
+
+            let (trace, _) = ExecutionTrace::test_finalize_trace(process);
+            assert_eq!(expected_trace, trace);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{ExecutionError, Felt, Process, StarkField, Vec};
 use core::fmt;
-use vm_core::{utils::string::String, Operation, ProgramOutputs, Word};
+use vm_core::{stack::STACK_TOP_SIZE, utils::string::String, Operation, ProgramOutputs, Word};
 
 /// VmState holds a current process state information at a specific clock cycle.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -12,6 +12,29 @@ pub struct VmState {
     pub fmp: Felt,
     pub stack: Vec<Felt>,
     pub memory: Vec<(u64, Word)>,
+    pub memory_writes: Vec<(u64, Word)>,
+}
+
+impl VmState {
+    /// Returns the complete state of the stack at this clock cycle, including the items in the
+    /// overflow table. The top of the stack is the first item of the returned vector, and the
+    /// deepest overflow item is the last one.
+    pub fn stack_full(&self) -> Vec<Felt> {
+        self.stack.clone()
+    }
+
+    /// Returns the items located in the stack overflow table at this clock cycle, i.e., the items
+    /// deeper than the top 16 positions of the stack, starting with the shallowest one.
+    pub fn stack_overflow(&self) -> &[Felt] {
+        &self.stack[STACK_TOP_SIZE.min(self.stack.len())..]
+    }
+
+    /// Returns memory writes performed by the operation executed in the cycle leading to this
+    /// state as (address, value) tuples sorted by address. The writes are limited to the
+    /// execution context of this state.
+    pub fn memory_changes(&self) -> &[(u64, Word)] {
+        &self.memory_writes
+    }
 }
 
 impl fmt::Display for VmState {
@@ -57,7 +80,7 @@ impl VmStateIterator {
     fn get_asmop(&self) -> (Option<AsmOpInfo>, bool) {
         let assembly_ops = self.process.decoder.debug_info().assembly_ops();
 
-        if self.clk == 0 || self.asmop_idx > assembly_ops.len() {
+        if self.clk == 0 || assembly_ops.is_empty() || self.asmop_idx > assembly_ops.len() {
             return (None, false);
         }
 
@@ -145,6 +168,7 @@ impl Iterator for VmStateIterator {
             fmp: self.process.system.get_fmp_at(self.clk),
             stack: self.process.stack.get_state_at(self.clk),
             memory: self.process.chiplets.get_mem_state_at(ctx, self.clk),
+            memory_writes: self.process.chiplets.get_mem_writes_at(ctx, self.clk),
         }));
 
         self.clk += 1;
@@ -2,7 +2,18 @@ use super::{
     super::trace::LookupTableRow, get_num_groups_in_next_batch, BlockInfo, Felt, FieldElement,
     StarkField, Vec, Word, ONE, ZERO,
 };
-use crate::Matrix;
+use crate::{
+    utils::{
+        check_hint_cycle, check_table_row_idx, invalid_value, read_bool, read_vec, read_word,
+        write_bool, write_vec, write_word,
+    },
+    Matrix,
+};
+use vm_core::{
+    decoder::ADDR_COL_IDX,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    DECODER_TRACE_OFFSET,
+};
 
 // AUXILIARY TRACE HINTS
 // ================================================================================================
@@ -116,21 +127,20 @@ impl AuxTraceHints {
                 // the specified parent_id
                 if self.block_hash_rows[idx].is_first_child == is_first_child {
                     Some(idx)
-                } else if is_first_child {
-                    // if we got here, it means that is_first_child for the row at the found index
-                    // is false. thus, the row with is_first_child = true should be right before it
-                    let row = &self.block_hash_rows[idx - 1];
-                    debug_assert_eq!(row.parent_id.as_int(), parent_id);
-                    debug_assert_eq!(row.is_first_child, is_first_child);
-                    Some(idx - 1)
                 } else {
-                    // similarly, if we got here, is_first_child for the row at the found index
-                    // must be true. thus, the row with is_first_child = false should be right
-                    // after it
-                    let row = &self.block_hash_rows[idx + 1];
-                    debug_assert_eq!(row.parent_id.as_int(), parent_id);
-                    debug_assert_eq!(row.is_first_child, is_first_child);
-                    Some(idx + 1)
+                    // if we got here, it means that is_first_child for the row at the found index
+                    // is the opposite of the requested one. thus, the row with is_first_child =
+                    // true should be right before it, and the row with is_first_child = false
+                    // should be right after it. the rows may be missing only if the hints were
+                    // deserialized from untrusted bytes.
+                    let idx = if is_first_child {
+                        idx.checked_sub(1)?
+                    } else {
+                        idx + 1
+                    };
+                    let row = self.block_hash_rows.get(idx)?;
+                    (row.parent_id.as_int() == parent_id && row.is_first_child == is_first_child)
+                        .then_some(idx)
                 }
             }
             Err(_) => None,
@@ -487,3 +497,294 @@ impl LookupTableRow for OpGroupTableRow {
             + alphas[3].mul_base(self.group_value)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl AuxTraceHints {
+    /// Returns an error if any of the hints cannot be applied to the provided main trace segment,
+    /// either because the hint refers to a row outside of the trace, or because the table row
+    /// updated by the hint does not exist.
+    ///
+    /// The rows of the block stack and block hash tables removed by the hints are looked up by the
+    /// block addresses in the main trace; the rows added by the hints are taken from the lists of
+    /// rows in the order in which the rows were added.
+    pub(crate) fn validate(&self, main_trace: &Matrix<Felt>) -> Result<(), DeserializationError> {
+        let trace_len = main_trace.num_rows();
+        let get_block_addr =
+            |row_idx: usize| main_trace.get(DECODER_TRACE_OFFSET + ADDR_COL_IDX, row_idx);
+
+        // the first row of the block hash table is the entry for the root block of the program
+        let mut started_stack_idx = 0_usize;
+        let mut started_hash_idx = 1_usize;
+        for &(clk, update) in self.block_exec_hints.iter() {
+            let clk = clk as usize;
+            check_hint_cycle(clk, trace_len)?;
+            match update {
+                BlockTableUpdate::BlockStarted(num_children) => {
+                    check_table_row_idx(
+                        started_stack_idx,
+                        self.block_stack_rows.len(),
+                        "block stack table",
+                    )?;
+                    started_stack_idx += 1;
+
+                    // only the first child of a JOIN block is marked as the first child
+                    let expected_children: &[bool] = match num_children {
+                        0 => &[],
+                        1 => &[false],
+                        2 => &[true, false],
+                        _ => {
+                            return Err(invalid_value(format!(
+                                "invalid number of children {num_children} for a block"
+                            )))
+                        }
+                    };
+                    let children = self
+                        .block_hash_rows
+                        .get(started_hash_idx..started_hash_idx + expected_children.len())
+                        .ok_or_else(|| invalid_value("block hash table row not found"))?;
+                    if !children
+                        .iter()
+                        .map(|row| row.is_first_child)
+                        .eq(expected_children.iter().copied())
+                    {
+                        return Err(invalid_value(
+                            "invalid children of a block in the block hash table",
+                        ));
+                    }
+                    started_hash_idx += num_children as usize;
+                }
+                BlockTableUpdate::SpanExtended => {
+                    if started_stack_idx == 0 {
+                        return Err(invalid_value("span extended before any block was started"));
+                    }
+                    check_table_row_idx(
+                        started_stack_idx,
+                        self.block_stack_rows.len(),
+                        "block stack table",
+                    )?;
+                    started_stack_idx += 1;
+                }
+                BlockTableUpdate::LoopRepeated => {
+                    if self
+                        .get_block_hash_row_idx(get_block_addr(clk + 1), false)
+                        .is_none()
+                    {
+                        return Err(invalid_value(
+                            "block hash table row of a loop body not found",
+                        ));
+                    }
+                }
+                BlockTableUpdate::BlockEnded(is_first_child) => {
+                    if self.get_block_stack_row_idx(get_block_addr(clk)).is_none() {
+                        return Err(invalid_value(
+                            "block stack table row of an ended block not found",
+                        ));
+                    }
+                    let parent_id = get_block_addr(clk + 1);
+                    if self
+                        .get_block_hash_row_idx(parent_id, is_first_child)
+                        .is_none()
+                    {
+                        return Err(invalid_value(
+                            "block hash table row of an ended block not found",
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut inserted_group_idx = 0_usize;
+        let mut removed_group_idx = 0_usize;
+        for &(clk, update) in self.op_group_hints.iter() {
+            check_hint_cycle(clk as usize, trace_len)?;
+            match update {
+                OpGroupTableUpdate::InsertRows(num_op_groups) => {
+                    // the value of the first inserted row is read even if no rows are inserted
+                    let last_idx = inserted_group_idx + (num_op_groups as usize).max(1) - 1;
+                    check_table_row_idx(last_idx, self.op_group_rows.len(), "op group table")?;
+                    inserted_group_idx += num_op_groups as usize;
+                }
+                OpGroupTableUpdate::RemoveRow => {
+                    check_table_row_idx(
+                        removed_group_idx,
+                        self.op_group_rows.len(),
+                        "op group table",
+                    )?;
+                    removed_group_idx += 1;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Serializable for AuxTraceHints {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.block_exec_hints.len() as u64);
+        for (clk, update) in self.block_exec_hints.iter() {
+            target.write_u32(*clk);
+            update.write_into(target);
+        }
+        write_vec(target, &self.block_stack_rows);
+        write_vec(target, &self.block_hash_rows);
+
+        target.write_u64(self.op_group_hints.len() as u64);
+        for (clk, update) in self.op_group_hints.iter() {
+            target.write_u32(*clk);
+            update.write_into(target);
+        }
+        write_vec(target, &self.op_group_rows);
+    }
+}
+
+impl Deserializable for AuxTraceHints {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_hints = source.read_u64()? as usize;
+        let block_exec_hints = (0..num_hints)
+            .map(|_| Ok((source.read_u32()?, BlockTableUpdate::read_from(source)?)))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+        let block_stack_rows = read_vec(source)?;
+        let block_hash_rows: Vec<BlockHashTableRow> = read_vec(source)?;
+        if block_hash_rows.is_empty() {
+            return Err(invalid_value(
+                "block hash table must contain the entry for the program",
+            ));
+        }
+
+        let num_hints = source.read_u64()? as usize;
+        let op_group_hints = (0..num_hints)
+            .map(|_| Ok((source.read_u32()?, OpGroupTableUpdate::read_from(source)?)))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+        let op_group_rows = read_vec(source)?;
+
+        Ok(Self {
+            block_exec_hints,
+            block_stack_rows,
+            block_hash_rows,
+            op_group_hints,
+            op_group_rows,
+        })
+    }
+}
+
+impl Serializable for BlockTableUpdate {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::BlockStarted(num_children) => {
+                target.write_u8(0);
+                target.write_u32(*num_children);
+            }
+            Self::SpanExtended => target.write_u8(1),
+            Self::LoopRepeated => target.write_u8(2),
+            Self::BlockEnded(is_first_child) => {
+                target.write_u8(3);
+                write_bool(target, *is_first_child);
+            }
+        }
+    }
+}
+
+impl Deserializable for BlockTableUpdate {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::BlockStarted(source.read_u32()?)),
+            1 => Ok(Self::SpanExtended),
+            2 => Ok(Self::LoopRepeated),
+            3 => Ok(Self::BlockEnded(read_bool(source)?)),
+            tag => Err(invalid_value(format!("invalid block table update {tag}"))),
+        }
+    }
+}
+
+impl Serializable for OpGroupTableUpdate {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::InsertRows(num_rows) => {
+                target.write_u8(0);
+                target.write_u32(*num_rows);
+            }
+            Self::RemoveRow => target.write_u8(1),
+        }
+    }
+}
+
+impl Deserializable for OpGroupTableUpdate {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::InsertRows(source.read_u32()?)),
+            1 => Ok(Self::RemoveRow),
+            tag => Err(invalid_value(format!(
+                "invalid op group table update {tag}"
+            ))),
+        }
+    }
+}
+
+impl Serializable for BlockStackTableRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_id.write_into(target);
+        self.parent_id.write_into(target);
+        write_bool(target, self.is_loop);
+        target.write_u32(self.parent_ctx);
+        write_word(target, &self.parent_fn_hash);
+        self.parent_fmp.write_into(target);
+        target.write_u32(self.parent_stack_depth);
+        self.parent_next_overflow_addr.write_into(target);
+    }
+}
+
+impl Deserializable for BlockStackTableRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            block_id: Felt::read_from(source)?,
+            parent_id: Felt::read_from(source)?,
+            is_loop: read_bool(source)?,
+            parent_ctx: source.read_u32()?,
+            parent_fn_hash: read_word(source)?,
+            parent_fmp: Felt::read_from(source)?,
+            parent_stack_depth: source.read_u32()?,
+            parent_next_overflow_addr: Felt::read_from(source)?,
+        })
+    }
+}
+
+impl Serializable for BlockHashTableRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.parent_id.write_into(target);
+        write_word(target, &self.block_hash);
+        write_bool(target, self.is_first_child);
+        write_bool(target, self.is_loop_body);
+    }
+}
+
+impl Deserializable for BlockHashTableRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            parent_id: Felt::read_from(source)?,
+            block_hash: read_word(source)?,
+            is_first_child: read_bool(source)?,
+            is_loop_body: read_bool(source)?,
+        })
+    }
+}
+
+impl Serializable for OpGroupTableRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.batch_id.write_into(target);
+        self.group_pos.write_into(target);
+        self.group_value.write_into(target);
+    }
+}
+
+impl Deserializable for OpGroupTableRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            batch_id: Felt::read_from(source)?,
+            group_pos: Felt::read_from(source)?,
+            group_value: Felt::read_from(source)?,
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use super::{AdviceInjector, Decorator, ExecutionError, Felt, Process, StarkField};
-use vm_core::{utils::collections::Vec, WORD_LEN, ZERO};
+use vm_core::{utils::collections::Vec, EnvValue, WORD_LEN, ZERO};
 
 // DECORATORS
 // ================================================================================================
@@ -30,9 +30,11 @@ impl Process {
             AdviceInjector::MerkleNode => self.inject_merkle_node(),
             AdviceInjector::DivResultU64 => self.inject_div_result_u64(),
             AdviceInjector::MapValue => self.inject_map_value(),
+            AdviceInjector::TakeMapValue => self.inject_taken_map_value(),
             AdviceInjector::Memory(start_addr, num_words) => {
                 self.inject_mem_values(*start_addr, *num_words)
             }
+            AdviceInjector::Env(value) => self.inject_env_value(*value),
         }
     }
 
@@ -126,6 +128,33 @@ impl Process {
         Ok(())
     }
 
+    /// Removes a word from the key-value map maintained by the advice provider and injects it at
+    /// the front of the advice tape. The word is looked up using the top 4 elements on the stack
+    /// as the key, and is injected such that its first element is at the head of the advice tape.
+    ///
+    /// # Errors
+    /// Returns an error if the required key was not found in the key-value map, or if the value
+    /// stored under the key does not consist of exactly 4 elements.
+    fn inject_taken_map_value(&mut self) -> Result<(), ExecutionError> {
+        let top_word = self.stack.get_top_word();
+
+        // inject the value first, so that it can be validated before it is removed from the map
+        let tape_len = self.advice.tape_len();
+        self.advice.write_tape_from_map(top_word)?;
+        let num_values = self.advice.tape_len() - tape_len;
+        if num_values != WORD_LEN {
+            // remove the injected values from the advice tape, so that a failed injection leaves
+            // the advice provider unchanged
+            for _ in 0..num_values {
+                self.advice.read_tape()?;
+            }
+            return Err(ExecutionError::AdviceMapValueNotWord(top_word, num_values));
+        }
+        self.advice.take_from_map(top_word)?;
+
+        Ok(())
+    }
+
     /// Reads the specfied number of words from the memory starting at the given start address and
     /// writes the vector of field elements to the advice map with the top 4 elements on the stack
     /// as the key. This operation does not affect the state of the Memory chiplet and the VM in
@@ -148,6 +177,21 @@ impl Process {
 
         Ok(())
     }
+
+    /// Injects the specified environment value supplied by the host at the head of the advice
+    /// tape.
+    ///
+    /// # Errors
+    /// Returns an error if the value was not supplied by the host.
+    fn inject_env_value(&mut self, value: EnvValue) -> Result<(), ExecutionError> {
+        let env_value = self
+            .host
+            .get(value)
+            .ok_or(ExecutionError::EnvValueNotAvailable(value))?;
+        self.advice.write_tape(env_value);
+
+        Ok(())
+    }
 }
 
 // HELPER FUNCTIONS
@@ -166,11 +210,11 @@ fn u64_to_u32_elements(value: u64) -> (Felt, Felt) {
 mod tests {
     use super::{
         super::{Felt, FieldElement, Kernel, Operation, StarkField},
-        Process,
+        ExecutionError, Process, Vec,
     };
     use crate::Word;
 
-    use vm_core::{AdviceInjector, AdviceSet, Decorator, ProgramInputs};
+    use vm_core::{utils::IntoBytes, AdviceInjector, AdviceSet, Decorator, ProgramInputs};
 
     #[test]
     fn inject_merkle_node() {
@@ -216,6 +260,52 @@ mod tests {
         assert_eq!(expected_stack, process.stack.trace_state());
     }
 
+    #[test]
+    fn inject_taken_map_value() {
+        let key: Word = [1, 2, 3, 4].map(Felt::new);
+        let build_process = |value: &[u64]| {
+            let inputs = ProgramInputs::builder()
+                .with_stack(&[1, 2, 3, 4])
+                .with_advice_tape(&[9])
+                .with_advice_map([(
+                    key.into_bytes(),
+                    value.iter().map(|&v| Felt::new(v)).collect(),
+                )])
+                .build()
+                .unwrap();
+            Process::new(&Kernel::default(), inputs)
+        };
+
+        // the word is moved from the map onto the advice tape with its first element at the head
+        let mut process = build_process(&[5, 6, 7, 8]);
+        process.inject_taken_map_value().unwrap();
+        assert_eq!(
+            [5, 6, 7, 8, 9].map(Felt::new),
+            process
+                .advice_peek_all()
+                .iter()
+                .rev()
+                .copied()
+                .collect::<Vec<_>>()[..]
+        );
+        assert!(matches!(
+            process.inject_taken_map_value(),
+            Err(ExecutionError::AdviceMapKeyNotFound(k)) if k == key
+        ));
+
+        // a value which is not a word is neither injected nor removed from the map
+        let mut process = build_process(&[5, 6, 7]);
+        assert!(matches!(
+            process.inject_taken_map_value(),
+            Err(ExecutionError::AdviceMapValueNotWord(k, 3)) if k == key
+        ));
+        assert_eq!(&[Felt::new(9)], process.advice_peek_all());
+        assert_eq!(
+            vec![Felt::new(5), Felt::new(6), Felt::new(7)],
+            process.advice.take_from_map(key).unwrap()
+        );
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
     fn init_leaf(value: u64) -> Word {
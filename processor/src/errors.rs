@@ -1,4 +1,11 @@
-use super::{AdviceSetError, CodeBlock, Digest, Felt, Word};
+use super::{
+    AdviceSetError, AsmOpInfo, Box, CodeBlock, Digest, Felt, Program, StarkField, Vec, Word,
+};
+use core::fmt;
+use vm_core::{
+    utils::string::{String, ToString},
+    EnvValue, SourceLocation,
+};
 use winterfell::ProverError;
 
 // EXECUTION ERROR
@@ -6,21 +13,362 @@ use winterfell::ProverError;
 
 #[derive(Debug)]
 pub enum ExecutionError {
-    AdviceKeyNotFound(Word),
+    AdviceMapKeyNotFound(Word),
+    AdviceMapValueNotWord(Word, usize),
     AdviceSetLookupFailed(AdviceSetError),
     AdviceSetNotFound([u8; 32]),
     AdviceSetUpdateFailed(AdviceSetError),
     AdviceTapeReadFailed(u32),
+    BranchStackMismatch(Digest, isize, isize),
+    CallDepthExceeded(usize),
     CodeBlockNotFound(Digest),
     CallerNotInSyscall,
     DivideByZero(u32),
     DuplicateAdviceKey(Word),
+    EnvValueNotAvailable(EnvValue),
     FailedAssertion(u32),
+    FailedAssertionEq(Felt, Felt, u32),
+    FailedAssertionEqw(usize, Felt, Felt, u32),
     InvalidFmpValue(Felt, Felt),
     InvalidStackDepthOnReturn(usize),
     NotBinaryValue(Felt),
     NotU32Value(Felt),
+    ProcedureCallFailed(Box<ExecutionError>, Vec<Digest>),
+    ProcedureNotFound(String),
     ProverError(ProverError),
     SyscallTargetNotInKernel(Digest),
     UnexecutableCodeBlock(CodeBlock),
 }
+
+impl ExecutionError {
+    /// Returns hashes of procedures which were active at the time of failure.
+    ///
+    /// The hashes are ordered from the outermost to the innermost procedure. Only procedures
+    /// invoked via `call` and `syscall` instructions are tracked, as procedures invoked via `exec`
+    /// are inlined into the body of the calling procedure. If the failure occurred outside of any
+    /// procedure call, an empty slice is returned.
+    pub fn call_stack(&self) -> &[Digest] {
+        match self {
+            Self::ProcedureCallFailed(_, call_stack) => call_stack,
+            _ => &[],
+        }
+    }
+
+    /// Returns the error which caused the execution to fail, stripping the call stack
+    /// information (if any).
+    pub fn root_cause(&self) -> &ExecutionError {
+        match self {
+            Self::ProcedureCallFailed(error, _) => error.root_cause(),
+            _ => self,
+        }
+    }
+
+    /// Returns a multi-line report describing this error in the context of the provided program.
+    ///
+    /// The report includes the error message and the call stack with procedures identified by
+    /// name where the program contains debug symbols. If the state of the VM at the failing cycle
+    /// is provided (see [crate::Process::failure_context()]), the report also includes the cycle
+    /// at which execution failed, the assembly instruction which was being executed at that cycle,
+    /// and the state of the stack right before executing the failing operation.
+    ///
+    /// The assembly instruction, as well as its line and column in the source code, are known
+    /// only for programs compiled in debug mode.
+    pub fn as_diagnostic(&self, program: &Program, context: Option<&FailureContext>) -> String {
+        self.build_diagnostic(program, context, None)
+    }
+
+    /// Returns a multi-line report describing this error in the same way as
+    /// [Self::as_diagnostic()], but also includes an excerpt of the provided source code pointing
+    /// at the failing instruction.
+    ///
+    /// The provided source code must be the source code the program was compiled from. The
+    /// excerpt is included only if the location of the failing instruction is known.
+    pub fn as_diagnostic_with_source(
+        &self,
+        program: &Program,
+        context: Option<&FailureContext>,
+        source: &str,
+    ) -> String {
+        self.build_diagnostic(program, context, Some(source))
+    }
+
+    /// Builds the report for [Self::as_diagnostic()] and [Self::as_diagnostic_with_source()].
+    fn build_diagnostic(
+        &self,
+        program: &Program,
+        context: Option<&FailureContext>,
+        source: Option<&str>,
+    ) -> String {
+        let mut report = format!("error: {}", self.root_cause());
+
+        if let Some(context) = context {
+            report.push_str("\n  --> ");
+            if let Some(location) = context.location() {
+                report.push_str(&format!("{location}, "));
+            }
+            report.push_str(&format!("cycle {}", context.clk()));
+            if let Some(asmop) = context.asmop() {
+                report.push_str(&format!(
+                    ", instruction `{}` (operation {} of {})",
+                    asmop.op(),
+                    asmop.cycle_idx(),
+                    asmop.num_cycles()
+                ));
+            }
+            if let (Some(location), Some(source)) = (context.location(), source) {
+                if let Some(excerpt) = source_excerpt(source, location) {
+                    report.push_str(&excerpt);
+                }
+            }
+        }
+
+        let call_stack = self.call_stack();
+        if !call_stack.is_empty() {
+            report.push_str("\ncall stack (innermost last):");
+            report.push_str("\n  0: program root");
+            for (i, &hash) in call_stack.iter().enumerate() {
+                let kind = if program.kernel().contains_proc(hash) {
+                    "kernel procedure"
+                } else {
+                    "procedure"
+                };
+                let hash_hex = hex(&<[u8; 32]>::from(hash));
+                match program.procedure_name(hash) {
+                    Some(name) => {
+                        report.push_str(&format!("\n  {}: {kind} {name} ({hash_hex})", i + 1))
+                    }
+                    None => report.push_str(&format!("\n  {}: {kind} {hash_hex}", i + 1)),
+                }
+            }
+        }
+
+        if let Some(context) = context {
+            let stack = context
+                .stack()
+                .iter()
+                .map(|value| value.as_int().to_string())
+                .collect::<Vec<_>>();
+            report.push_str(&format!("\nstack: [{}]", stack.join(", ")));
+        }
+
+        report
+    }
+
+    /// Attaches the provided call stack to this error. If the call stack is empty, the error is
+    /// returned unchanged.
+    pub(super) fn with_call_stack(self, call_stack: &[Digest]) -> Self {
+        if call_stack.is_empty() {
+            self
+        } else {
+            Self::ProcedureCallFailed(Box::new(self), call_stack.to_vec())
+        }
+    }
+}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ExecutionError::*;
+
+        match self {
+            AdviceMapKeyNotFound(key) => {
+                let key = format_word(key);
+                write!(f, "value for key {key} not present in the advice map")
+            }
+            AdviceMapValueNotWord(key, len) => {
+                let key = format_word(key);
+                write!(
+                    f,
+                    "value for key {key} in the advice map must be a word, but has {len} elements"
+                )
+            }
+            AdviceSetLookupFailed(err) => write!(f, "advice set lookup failed: {err:?}"),
+            AdviceSetNotFound(root) => write!(f, "advice set with root {} not found", hex(root)),
+            AdviceSetUpdateFailed(err) => write!(f, "advice set update failed: {err:?}"),
+            AdviceTapeReadFailed(clk) => write!(f, "advice tape read failed at clk {clk}"),
+            BranchStackMismatch(hash, on_true, on_false) => {
+                let hash = hex(&<[u8; 32]>::from(*hash));
+                write!(
+                    f,
+                    "branches of split block {hash} changed stack depth by different amounts: \
+                    {on_true} in the true branch and {on_false} in the false branch"
+                )
+            }
+            CallDepthExceeded(max_depth) => {
+                write!(f, "call depth exceeded the maximum of {max_depth}")
+            }
+            CodeBlockNotFound(hash) => {
+                let hash = hex(&<[u8; 32]>::from(*hash));
+                write!(f, "code block with hash {hash} not found")
+            }
+            CallerNotInSyscall => write!(f, "caller instruction used outside of kernel context"),
+            DivideByZero(clk) => write!(f, "division by zero at clk {clk}"),
+            DuplicateAdviceKey(key) => {
+                let key = format_word(key);
+                write!(f, "key {key} already present in the advice map")
+            }
+            EnvValueNotAvailable(value) => {
+                write!(f, "environment value {value} not supplied by the host")
+            }
+            FailedAssertion(clk) => write!(f, "assertion failed at clk {clk}"),
+            FailedAssertionEq(lhs, rhs, clk) => {
+                write!(f, "assertion failed: {lhs} != {rhs} at clk {clk}")
+            }
+            FailedAssertionEqw(lane, lhs, rhs, clk) => {
+                write!(
+                    f,
+                    "word assertion failed: lane {lane} differs ({lhs} != {rhs}) at clk {clk}"
+                )
+            }
+            InvalidFmpValue(old, new) => {
+                write!(
+                    f,
+                    "updating FMP register from {old} to {new} failed: value out of bounds"
+                )
+            }
+            InvalidStackDepthOnReturn(depth) => {
+                write!(
+                    f,
+                    "when returning from a call, stack depth must be 16, but was {depth}"
+                )
+            }
+            NotBinaryValue(v) => write!(f, "expected a binary value, but received {v}"),
+            NotU32Value(v) => write!(f, "expected a u32 value, but received {v}"),
+            ProcedureCallFailed(err, call_stack) => {
+                let callee = call_stack.last().expect("empty call stack");
+                write!(f, "{err} in procedure {}", hex(&<[u8; 32]>::from(*callee)))
+            }
+            ProcedureNotFound(name) => write!(f, "procedure {name} not found in the program"),
+            ProverError(err) => write!(f, "proof generation failed: {err}"),
+            SyscallTargetNotInKernel(hash) => {
+                let hash = hex(&<[u8; 32]>::from(*hash));
+                write!(f, "syscall target {hash} is not a part of the kernel")
+            }
+            UnexecutableCodeBlock(block) => {
+                write!(f, "execution reached unexecutable code block {block}")
+            }
+        }
+    }
+}
+
+// EXECUTION WARNING
+// ================================================================================================
+
+/// An anomaly encountered during program execution which does not cause the execution to fail,
+/// but may indicate a mistake in the program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionWarning {
+    /// A word was read from a memory address which had not been accessed before, and thus, the
+    /// read returned zeros. Contains the clock cycle of the read, the execution context, and the
+    /// address.
+    UninitializedMemoryRead(u32, u32, u64),
+}
+
+impl fmt::Display for ExecutionWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ExecutionWarning::*;
+
+        match self {
+            UninitializedMemoryRead(clk, ctx, addr) => write!(
+                f,
+                "read of uninitialized memory address {addr} in context {ctx} at clk {clk}"
+            ),
+        }
+    }
+}
+
+// FAILURE CONTEXT
+// ================================================================================================
+
+/// State of the VM at the cycle at which execution of a program failed.
+#[derive(Debug)]
+pub struct FailureContext {
+    clk: u32,
+    asmop: Option<AsmOpInfo>,
+    location: Option<SourceLocation>,
+    stack: Vec<Felt>,
+}
+
+impl FailureContext {
+    /// Returns a new [FailureContext] instantiated with the specified failing cycle, assembly
+    /// instruction executed at that cycle together with its location in the source code, and
+    /// state of the stack at that cycle.
+    pub(super) fn new(
+        clk: u32,
+        asmop: Option<AsmOpInfo>,
+        location: Option<SourceLocation>,
+        stack: Vec<Felt>,
+    ) -> Self {
+        Self {
+            clk,
+            asmop,
+            location,
+            stack,
+        }
+    }
+
+    /// Returns the clock cycle at which execution failed.
+    pub fn clk(&self) -> u32 {
+        self.clk
+    }
+
+    /// Returns the assembly instruction which was being executed when execution failed, or None
+    /// if the program was not compiled in debug mode.
+    pub fn asmop(&self) -> Option<&AsmOpInfo> {
+        self.asmop.as_ref()
+    }
+
+    /// Returns the location in the source code of the assembly instruction which was being
+    /// executed when execution failed, or None if the location is not known.
+    pub fn location(&self) -> Option<SourceLocation> {
+        self.location
+    }
+
+    /// Returns the state of the stack right before executing the failing operation, including the
+    /// items in the overflow table. The top of the stack is the first item of the returned slice.
+    pub fn stack(&self) -> &[Felt] {
+        &self.stack
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an excerpt of the source code consisting of the line at the specified location, with
+/// the instruction at that location underlined, or None if the source code has no such line.
+fn source_excerpt(source: &str, location: SourceLocation) -> Option<String> {
+    let line = source
+        .lines()
+        .nth((location.line() as usize).checked_sub(1)?)?;
+    let line = line.trim_end();
+
+    // keep tabs in the indentation of the underline, so that it is aligned with the instruction
+    // regardless of the width of the tabs
+    let mut chars = line.chars();
+    let indent = chars
+        .by_ref()
+        .take(location.column().saturating_sub(1) as usize)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect::<String>();
+    let width = chars.take_while(|c| !c.is_whitespace()).count().max(1);
+
+    let line_number = location.line().to_string();
+    let gutter = " ".repeat(line_number.len());
+    Some(format!(
+        "\n {gutter} |\n {line_number} | {line}\n {gutter} | {indent}{}",
+        "^".repeat(width)
+    ))
+}
+
+/// Formats the word as a list of its elements.
+fn format_word(word: &Word) -> String {
+    let elements = word
+        .iter()
+        .map(|element| element.as_int().to_string())
+        .collect::<Vec<_>>();
+    format!("[{}]", elements.join(", "))
+}
+
+/// Formats the bytes as a hex string.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
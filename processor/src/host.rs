@@ -0,0 +1,69 @@
+use super::{Felt, InputError};
+use vm_core::{EnvValue, StarkField};
+
+// HOST CONTEXT
+// ================================================================================================
+
+/// Environment values supplied by the host to a program at execution time.
+///
+/// A program reads these values via `push.env.<name>` instructions (e.g., `push.env.block_number`).
+/// Environment values are non-deterministic advice: they are delivered to the VM via the advice
+/// tape, and thus, they are neither constrained by the VM nor committed to by the proof of the
+/// execution. Committing environment values via the public inputs is not supported, as the AIR
+/// has no trace column through which a boundary assertion could bind them to the values read by
+/// the program. To bind a proof to specific environment values, a program should output them on
+/// the stack so that they are committed to as a part of the program outputs.
+///
+/// A host context can be supplied when executing or proving a program via
+/// [execute_with_host_context()](crate::execute_with_host_context) or
+/// [Process::with_host_context()](crate::Process::with_host_context).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostContext {
+    block_number: Option<Felt>,
+    timestamp: Option<Felt>,
+}
+
+impl HostContext {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new host context with no environment values set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of the block in the context of which the program is executed.
+    ///
+    /// # Errors
+    /// Returns an error if the block number is not a valid field element.
+    pub fn with_block_number(mut self, block_number: u64) -> Result<Self, InputError> {
+        if block_number >= Felt::MODULUS {
+            return Err(InputError::NotFieldElement(block_number, "block number"));
+        }
+        self.block_number = Some(Felt::new(block_number));
+        Ok(self)
+    }
+
+    /// Sets the timestamp of the block in the context of which the program is executed.
+    ///
+    /// # Errors
+    /// Returns an error if the timestamp is not a valid field element.
+    pub fn with_timestamp(mut self, timestamp: u64) -> Result<Self, InputError> {
+        if timestamp >= Felt::MODULUS {
+            return Err(InputError::NotFieldElement(timestamp, "timestamp"));
+        }
+        self.timestamp = Some(Felt::new(timestamp));
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the specified environment value, or None if the value was not supplied.
+    pub fn get(&self, value: EnvValue) -> Option<Felt> {
+        match value {
+            EnvValue::BlockNumber => self.block_number,
+            EnvValue::Timestamp => self.timestamp,
+        }
+    }
+}
@@ -7,18 +7,23 @@ extern crate alloc;
 pub use vm_core::{
     chiplets::hasher::Digest,
     errors::{AdviceSetError, InputError},
-    AdviceSet, Program, ProgramInputs, ProgramOutputs,
+    AdviceSet, Program, ProgramInputs, ProgramOutputs, SourceLocation,
 };
 use vm_core::{
     code_blocks::{
         Call, CodeBlock, Join, Loop, OpBatch, Span, Split, OP_BATCH_SIZE, OP_GROUP_SIZE,
     },
-    utils::collections::{BTreeMap, Vec},
+    utils::{
+        collections::{BTreeMap, Vec},
+        Box,
+    },
     AdviceInjector, CodeBlockTable, Decorator, DecoratorIterator, Felt, FieldElement, Kernel,
-    Operation, StackTopState, StarkField, Word, CHIPLETS_WIDTH, DECODER_TRACE_WIDTH, MIN_TRACE_LEN,
-    ONE, RANGE_CHECK_TRACE_WIDTH, STACK_TRACE_WIDTH, SYS_TRACE_WIDTH, ZERO,
+    Operation, StackTopState, StarkField, Word, ASSERT_EQW_OPS, CHIPLETS_WIDTH,
+    DECODER_TRACE_WIDTH, MIN_TRACE_LEN, ONE, RANGE_CHECK_TRACE_WIDTH, STACK_TRACE_WIDTH,
+    SYS_TRACE_WIDTH, ZERO,
 };
 
+use log::debug;
 use winterfell::Matrix;
 
 mod decorators;
@@ -26,7 +31,7 @@ mod operations;
 
 mod system;
 use system::System;
-pub use system::{FMP_MIN, SYSCALL_FMP_MIN};
+pub use system::{FMP_MAX, FMP_MIN, SYSCALL_FMP_MIN};
 
 mod decoder;
 use decoder::Decoder;
@@ -38,23 +43,31 @@ mod range;
 use range::RangeChecker;
 
 mod advice;
-use advice::AdviceProvider;
+pub use advice::{AdviceProvider, MemAdviceProvider, SharedAdviceProvider, SharedAdviceTape};
 
 mod chiplets;
 use chiplets::Chiplets;
+pub use chiplets::{HashOp, MemoryAccess};
+
+mod host;
+pub use host::HostContext;
 
 mod trace;
-pub use trace::ExecutionTrace;
 use trace::TraceFragment;
+pub use trace::{AirDescription, ColumnGroup, ExecutionTrace};
 
 mod errors;
-pub use errors::ExecutionError;
+pub use errors::{ExecutionError, ExecutionWarning, FailureContext};
 
 mod utils;
 
 mod debug;
 pub use debug::{AsmOpInfo, VmState, VmStateIterator};
 
+mod continuation;
+use continuation::Continuation;
+pub use continuation::RunStatus;
+
 // TYPE ALIASES
 // ================================================================================================
 
@@ -90,15 +103,115 @@ pub fn execute(
     program: &Program,
     inputs: &ProgramInputs,
 ) -> Result<ExecutionTrace, ExecutionError> {
+    let process = Process::new(program.kernel(), inputs.clone());
+    execute_in_process(process, program).map(|(trace, _)| trace)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, together with the warnings about anomalies encountered during the execution (e.g.,
+/// reads of memory addresses which had never been written to).
+///
+/// The warnings are returned in the order in which they were encountered.
+pub fn execute_with_warnings(
+    program: &Program,
+    inputs: &ProgramInputs,
+) -> Result<(ExecutionTrace, Vec<ExecutionWarning>), ExecutionError> {
+    let process = Process::new(program.kernel(), inputs.clone()).with_warnings();
+    execute_in_process(process, program)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, with environment values requested via `push.env` instructions read from the provided
+/// host context.
+///
+/// Environment values are non-deterministic advice; see [HostContext] for details.
+pub fn execute_with_host_context(
+    program: &Program,
+    inputs: &ProgramInputs,
+    host: HostContext,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let process = Process::new(program.kernel(), inputs.clone()).with_host_context(host);
+    execute_in_process(process, program).map(|(trace, _)| trace)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, with all memory accesses performed during the execution recorded in the trace; see
+/// [ExecutionTrace::memory_access_log()].
+pub fn execute_with_memory_access_log(
+    program: &Program,
+    inputs: &ProgramInputs,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let process = Process::new(program.kernel(), inputs.clone()).with_memory_access_log();
+    execute_in_process(process, program).map(|(trace, _)| trace)
+}
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// initial stack values, with non-deterministic inputs supplied by the provided advice provider.
+///
+/// The initial stack values are put onto the stack in the order as if they were pushed onto the
+/// stack one by one. The result of this is that the last value in the `stack_inputs` slice will
+/// end up at the top of the stack.
+pub fn execute_with_advice(
+    program: &Program,
+    stack_inputs: &[Felt],
+    advice: Box<dyn AdviceProvider>,
+) -> Result<ExecutionTrace, ExecutionError> {
+    let stack_init = stack_inputs.iter().rev().copied().collect::<Vec<_>>();
+    let process = Process::with_advice(program.kernel(), &stack_init, advice);
+    execute_in_process(process, program).map(|(trace, _)| trace)
+}
+
+/// Returns an execution trace resulting from executing the provided programs one after another
+/// against the provided inputs.
+///
+/// Each program starts executing in the state left by the previous program: the stack, memory,
+/// and the advice provider are passed on as is, with no operations executed in between. The
+/// programs are executed as a single program built via [Program::chain()], and thus, the hash of
+/// the program in the resulting trace is the hash of that program.
+///
+/// # Panics
+/// Panics if no programs are specified, or if the programs use different kernels.
+pub fn execute_chain(
+    programs: &[&Program],
+    inputs: &ProgramInputs,
+) -> Result<ExecutionTrace, ExecutionError> {
+    execute(&Program::chain(programs), inputs)
+}
+
+/// Returns the outputs resulting from executing only the procedure with the specified name from
+/// the provided program against the provided inputs; see [Process::execute_single_proc()].
+///
+/// This allows testing a single procedure with a prepared stack without having to write a program
+/// which sets up the stack and invokes the procedure. Since only a part of the program is
+/// executed, no execution trace is built.
+pub fn execute_proc(
+    program: &Program,
+    proc_name: &str,
+    inputs: &ProgramInputs,
+) -> Result<ProgramOutputs, ExecutionError> {
     let mut process = Process::new(program.kernel(), inputs.clone());
+    process.execute_single_proc(program, proc_name)
+}
+
+/// Executes the provided program in the provided process, and returns the resulting execution
+/// trace together with the warnings collected during the execution.
+///
+/// The process determines how the program is executed (e.g., which inputs are used, and whether
+/// warnings are collected); if collection of warnings is not enabled for the process, the returned
+/// list of warnings is empty.
+fn execute_in_process(
+    mut process: Process,
+    program: &Program,
+) -> Result<(ExecutionTrace, Vec<ExecutionWarning>), ExecutionError> {
     let program_outputs = process.execute(program)?;
+    let warnings = process.warnings.take().unwrap_or_default();
     let trace = ExecutionTrace::new(process, program_outputs);
     assert_eq!(
         program.hash(),
         trace.program_hash(),
         "inconsistent program hash"
     );
-    Ok(trace)
+    Ok((trace, warnings))
 }
 
 /// Returns an iterator that allows callers to step through each execution and inspect
@@ -119,13 +232,24 @@ pub fn execute_iter(program: &Program, inputs: &ProgramInputs) -> VmStateIterato
 // PROCESS
 // ================================================================================================
 
+/// Default maximum number of nested `call` and `syscall` instructions which can be active at the
+/// same time; see [Process::with_max_call_depth()].
+pub const DEFAULT_MAX_CALL_DEPTH: usize = 1024;
+
 pub struct Process {
     system: System,
     decoder: Decoder,
     stack: Stack,
     range: RangeChecker,
     chiplets: Chiplets,
-    advice: AdviceProvider,
+    advice: Box<dyn AdviceProvider>,
+    host: HostContext,
+    call_stack: Vec<Digest>,
+    max_call_depth: usize,
+    branch_depth_deltas: BTreeMap<[u8; 32], [Option<isize>; 2]>,
+    continuation: Option<Continuation>,
+    warnings: Option<Vec<ExecutionWarning>>,
+    failure_context: Option<FailureContext>,
 }
 
 impl Process {
@@ -141,17 +265,123 @@ impl Process {
         Self::initialize(kernel, inputs, true)
     }
 
+    /// Creates a new process with the provided initial stack values and advice provider.
+    ///
+    /// The initial stack values are expected to be in stack order, i.e., the first value in the
+    /// `stack_init` slice will end up at the top of the stack.
+    pub fn with_advice(
+        kernel: &Kernel,
+        stack_init: &[Felt],
+        advice: Box<dyn AdviceProvider>,
+    ) -> Self {
+        Self::build(kernel, stack_init, advice, false)
+    }
+
+    /// Creates a new process with the stack initialized to the state of the stack at the end of a
+    /// previous execution, as described by the provided outputs of that execution.
+    ///
+    /// This allows continuing a computation in a new execution (and proving it separately); see
+    /// [ProgramInputs::from_outputs()] for details.
+    pub fn with_initial_stack_from_outputs(kernel: &Kernel, outputs: &ProgramOutputs) -> Self {
+        Self::new(kernel, ProgramInputs::from_outputs(outputs))
+    }
+
     fn initialize(kernel: &Kernel, inputs: ProgramInputs, in_debug_mode: bool) -> Self {
+        let stack_init = inputs.stack_init().to_vec();
+        let advice = Box::new(MemAdviceProvider::new(inputs));
+        Self::build(kernel, &stack_init, advice, in_debug_mode)
+    }
+
+    fn build(
+        kernel: &Kernel,
+        stack_init: &[Felt],
+        advice: Box<dyn AdviceProvider>,
+        in_debug_mode: bool,
+    ) -> Self {
         Self {
             system: System::new(MIN_TRACE_LEN),
             decoder: Decoder::new(in_debug_mode),
-            stack: Stack::new(&inputs, MIN_TRACE_LEN, in_debug_mode),
+            stack: Stack::new(stack_init, MIN_TRACE_LEN, in_debug_mode),
             range: RangeChecker::new(),
             chiplets: Chiplets::new(kernel),
-            advice: AdviceProvider::new(inputs),
+            advice,
+            host: HostContext::default(),
+            call_stack: Vec::new(),
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            branch_depth_deltas: BTreeMap::new(),
+            continuation: None,
+            warnings: None,
+            failure_context: None,
         }
     }
 
+    /// Sets the context from which environment values requested by the program are read.
+    ///
+    /// Environment values are non-deterministic advice; see [HostContext] for details.
+    pub fn with_host_context(mut self, host: HostContext) -> Self {
+        self.host = host;
+        self
+    }
+
+    /// Sets the maximum number of nested `call` and `syscall` instructions which can be active at
+    /// the same time; executing a call beyond this depth fails the execution. By default, the
+    /// depth is limited to [DEFAULT_MAX_CALL_DEPTH].
+    ///
+    /// When a single procedure is executed via [Process::execute_single_proc()], the procedure
+    /// itself counts towards the depth.
+    pub fn with_max_call_depth(mut self, max_call_depth: usize) -> Self {
+        self.max_call_depth = max_call_depth;
+        self
+    }
+
+    /// Enables collection of warnings about anomalies encountered during the execution (e.g., reads
+    /// of memory addresses which had never been written to); see [Process::warnings()].
+    pub fn with_warnings(mut self) -> Self {
+        self.warnings = Some(Vec::new());
+        self
+    }
+
+    /// Enables recording of all memory accesses performed during the execution; the recorded
+    /// accesses are included in the execution trace built for this process (see
+    /// [ExecutionTrace::memory_access_log()]).
+    pub fn with_memory_access_log(mut self) -> Self {
+        self.chiplets.enable_mem_access_log();
+        self
+    }
+
+    /// Returns the warnings collected during the execution, in the order in which they were
+    /// encountered. If collection of warnings was not enabled via [Process::with_warnings()], an
+    /// empty slice is returned.
+    pub fn warnings(&self) -> &[ExecutionWarning] {
+        self.warnings.as_deref().unwrap_or_default()
+    }
+
+    /// Returns the state of the VM at the cycle at which the execution in this process failed, or
+    /// None if the execution did not fail.
+    ///
+    /// The state is captured only for processes executing in debug mode (see
+    /// [Process::new_debug()]); the error returned by a failed execution is the same regardless of
+    /// the mode.
+    pub fn failure_context(&self) -> Option<&FailureContext> {
+        self.failure_context.as_ref()
+    }
+
+    // ADVICE INSPECTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of elements remaining on the advice tape of this process.
+    pub fn advice_remaining(&self) -> usize {
+        self.advice.tape_len()
+    }
+
+    /// Returns the elements remaining on the advice tape of this process without removing them.
+    ///
+    /// The elements are returned in reverse order: the last element of the returned slice is the
+    /// element which will be read next.
+    pub fn advice_peek_all(&self) -> &[Felt] {
+        self.advice.peek_tape()
+    }
+
     // PROGRAM EXECUTOR
     // --------------------------------------------------------------------------------------------
 
@@ -162,11 +392,73 @@ impl Process {
             0,
             "a program has already been executed in this process"
         );
-        self.execute_code_block(program.root(), program.cb_table())?;
+        self.execute_code_block(program.root(), program.cb_table())
+            .map_err(|err| self.annotate_error(err))?;
+        let num_cycles = self.system.clk();
+        debug!(num_cycles; "Executed program in {num_cycles} cycles");
+
+        Ok(self.stack.get_outputs())
+    }
+
+    /// Executes only the body of the procedure with the specified name in this process, and
+    /// returns the state of the stack after the procedure returns.
+    ///
+    /// The procedure is executed directly against the initial stack of this process (i.e., as if
+    /// it were invoked via `exec` from the program root). The procedure is looked up by its name
+    /// as described in [Program::procedure_hash()]: it can be any local procedure of the program
+    /// (e.g., `foo`), or any library procedure which may be executed by the program (e.g.,
+    /// `std::math::u64::checked_add`), regardless of whether it is invoked via `exec` or `call`
+    /// instructions.
+    ///
+    /// # Errors
+    /// Returns an error if the procedure could not be found in the program, or if executing the
+    /// procedure fails for any reason.
+    pub fn execute_single_proc(
+        &mut self,
+        program: &Program,
+        proc_name: &str,
+    ) -> Result<ProgramOutputs, ExecutionError> {
+        assert_eq!(
+            self.system.clk(),
+            0,
+            "a program has already been executed in this process"
+        );
+        let proc_body = program
+            .named_procedure(proc_name)
+            .ok_or_else(|| ExecutionError::ProcedureNotFound(proc_name.into()))?;
+
+        self.push_call_stack(proc_body.hash())?;
+        self.execute_code_block(proc_body, program.cb_table())
+            .map_err(|err| self.annotate_error(err))?;
+        self.call_stack.pop();
+        let num_cycles = self.system.clk();
+        debug!(num_cycles; "Executed procedure {proc_name} in {num_cycles} cycles");
 
         Ok(self.stack.get_outputs())
     }
 
+    /// Pushes the specified procedure onto the call stack of this process.
+    ///
+    /// # Errors
+    /// Returns an error if the call stack already contains the maximum allowed number of
+    /// procedures.
+    fn push_call_stack(&mut self, fn_hash: Digest) -> Result<(), ExecutionError> {
+        if self.call_stack.len() >= self.max_call_depth {
+            return Err(ExecutionError::CallDepthExceeded(self.max_call_depth));
+        }
+        self.call_stack.push(fn_hash);
+        Ok(())
+    }
+
+    /// Attaches the call stack of this process to the specified error; in debug mode, the state
+    /// of the VM at the failing cycle is captured as well (see [Process::failure_context()]).
+    fn annotate_error(&mut self, err: ExecutionError) -> ExecutionError {
+        if self.decoder.in_debug_mode() {
+            self.failure_context = Some(self.get_failure_context());
+        }
+        err.with_call_stack(&self.call_stack)
+    }
+
     // CODE BLOCK EXECUTORS
     // --------------------------------------------------------------------------------------------
 
@@ -214,6 +506,7 @@ impl Process {
     ) -> Result<(), ExecutionError> {
         // start the SPLIT block; this also pops the stack and returns the popped element
         let condition = self.start_split_block(block)?;
+        let depth = self.stack.unclamped_depth();
 
         // execute either the true or the false branch of the split block based on the condition
         if condition == ONE {
@@ -224,6 +517,10 @@ impl Process {
             return Err(ExecutionError::NotBinaryValue(condition));
         }
 
+        if self.decoder.in_debug_mode() {
+            self.check_branch_stack_depth(block, condition == ONE, depth)?;
+        }
+
         self.end_split_block(block)
     }
 
@@ -269,6 +566,11 @@ impl Process {
         block: &Call,
         cb_table: &CodeBlockTable,
     ) -> Result<(), ExecutionError> {
+        // make sure the call depth limit is not exceeded before the state of the VM is modified;
+        // the function is tracked in the call stack until it returns so that errors can report
+        // the active procedures
+        self.push_call_stack(block.fn_hash())?;
+
         // if this is a syscall, make sure the call target exists in the kernel
         if block.is_syscall() {
             self.chiplets.access_kernel_proc(block.fn_hash())?;
@@ -282,7 +584,10 @@ impl Process {
             .ok_or_else(|| ExecutionError::CodeBlockNotFound(block.fn_hash()))?;
         self.execute_code_block(fn_body, cb_table)?;
 
-        self.end_call_block(block)
+        self.end_call_block(block)?;
+        self.call_stack.pop();
+
+        Ok(())
     }
 
     /// Executes the specified [Span] block.
@@ -294,7 +599,7 @@ impl Process {
         let mut decorators = block.decorator_iter();
 
         // execute the first operation batch
-        self.execute_op_batch(&block.op_batches()[0], &mut decorators, op_offset)?;
+        self.execute_op_batch(block, &block.op_batches()[0], &mut decorators, op_offset)?;
         op_offset += block.op_batches()[0].ops().len();
 
         // if the span contains more operation batches, execute them. each additional batch is
@@ -303,7 +608,7 @@ impl Process {
         for op_batch in block.op_batches().iter().skip(1) {
             self.respan(op_batch);
             self.execute_op(Operation::Noop)?;
-            self.execute_op_batch(op_batch, &mut decorators, op_offset)?;
+            self.execute_op_batch(block, op_batch, &mut decorators, op_offset)?;
             op_offset += op_batch.ops().len();
         }
 
@@ -319,6 +624,7 @@ impl Process {
     #[inline(always)]
     fn execute_op_batch(
         &mut self,
+        block: &Span,
         batch: &OpBatch,
         decorators: &mut DecoratorIterator,
         op_offset: usize,
@@ -341,7 +647,8 @@ impl Process {
 
             // decode and execute the operation
             self.decoder.execute_user_op(op, op_idx);
-            self.execute_op(op)?;
+            self.execute_op(op)
+                .map_err(|err| self.map_batch_op_error(block, batch, i, op_offset, err))?;
 
             // if the operation carries an immediate value, the value is stored at the next group
             // pointer; so, we advance the pointer to the following group
@@ -397,6 +704,111 @@ impl Process {
         Ok(())
     }
 
+    /// Adds context to an error returned by the operation at the specified index of a batch; the
+    /// batch starts at the specified operation offset within the SPAN block.
+    ///
+    /// Specifically, an ASSERT immediately following an EQ comes from an `assert_eq` instruction;
+    /// in this case the operands of EQ are still available in the previous stack state. If the
+    /// ASSERT is a part of the operations of an `assert_eqw` instruction, the lane of the words
+    /// which differed is reported as well.
+    fn map_batch_op_error(
+        &self,
+        block: &Span,
+        batch: &OpBatch,
+        op_idx: usize,
+        op_offset: usize,
+        err: ExecutionError,
+    ) -> ExecutionError {
+        match err {
+            ExecutionError::FailedAssertion(clk)
+                if op_idx > 0 && batch.ops()[op_idx - 1] == Operation::Eq =>
+            {
+                let lhs = self.stack.get_at(clk - 1, 1);
+                let rhs = self.stack.get_at(clk - 1, 0);
+                match get_assert_eqw_lane(block, op_offset + op_idx) {
+                    // the last lane is compared in place; for the other lanes, the element of
+                    // the deeper word is moved to the top of the stack before EQ
+                    Some(3) => ExecutionError::FailedAssertionEqw(3, lhs, rhs, clk),
+                    Some(lane) => ExecutionError::FailedAssertionEqw(lane, rhs, lhs, clk),
+                    None => ExecutionError::FailedAssertionEq(lhs, rhs, clk),
+                }
+            }
+            _ => err,
+        }
+    }
+
+    // DEBUG CHECKS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the branch of the specified SPLIT block which has just been executed changed
+    /// the depth of the stack by the same amount as the other branch of this block did when it was
+    /// executed previously.
+    ///
+    /// Since only one of the branches is executed every time a SPLIT block is executed, the check
+    /// can fail only when both branches of the same block get executed during the program's
+    /// execution (e.g., when the block is executed inside a loop).
+    fn check_branch_stack_depth(
+        &mut self,
+        block: &Split,
+        is_true_branch: bool,
+        depth_before: isize,
+    ) -> Result<(), ExecutionError> {
+        let delta = self.stack.unclamped_depth() - depth_before;
+        let deltas = self
+            .branch_depth_deltas
+            .entry(block.hash().into())
+            .or_default();
+
+        let (branch_idx, other_idx) = if is_true_branch { (0, 1) } else { (1, 0) };
+        match deltas[other_idx] {
+            Some(other_delta) if other_delta != delta => {
+                let (on_true, on_false) = if is_true_branch {
+                    (delta, other_delta)
+                } else {
+                    (other_delta, delta)
+                };
+                Err(ExecutionError::BranchStackMismatch(
+                    block.hash(),
+                    on_true,
+                    on_false,
+                ))
+            }
+            _ => {
+                deltas[branch_idx].get_or_insert(delta);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the state of the VM at the current clock cycle. This is expected to be invoked
+    /// after execution fails, and thus, the current clock cycle is the cycle at which the failing
+    /// operation was executed.
+    ///
+    /// Only applicable in debug mode.
+    fn get_failure_context(&self) -> FailureContext {
+        let clk = self.system.clk();
+
+        // find the assembly instruction which contains the failing operation; the instruction
+        // is known only if the program was compiled in debug mode
+        let asmop = self
+            .decoder
+            .debug_info()
+            .assembly_ops()
+            .iter()
+            .rev()
+            .find(|(asmop_clk, _)| *asmop_clk as u32 <= clk)
+            .and_then(|(asmop_clk, asmop)| {
+                let cycle_idx = clk - *asmop_clk as u32 + 1;
+                (cycle_idx <= asmop.num_cycles() as u32).then_some((asmop, cycle_idx))
+            });
+        let location = asmop.and_then(|(asmop, _)| asmop.location());
+        let asmop = asmop.map(|(asmop, cycle_idx)| {
+            AsmOpInfo::new(asmop.op().clone(), asmop.num_cycles(), cycle_idx as u8)
+        });
+
+        FailureContext::new(clk, asmop, location, self.stack.get_state_at(clk))
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -414,3 +826,29 @@ impl Process {
         )
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the lane checked by the ASSERT at the specified index of the operations of a SPAN
+/// block, if this ASSERT is a part of the operations of an `assert_eqw` instruction.
+///
+/// The instruction is recognized by its operations as defined in [ASSERT_EQW_OPS], which is also
+/// used by the assembler to compile it. Thus, a sequence of instructions compiled into exactly the
+/// same operations is indistinguishable from `assert_eqw`, and is reported in the same way.
+fn get_assert_eqw_lane(block: &Span, op_idx: usize) -> Option<usize> {
+    let ops = block
+        .op_batches()
+        .iter()
+        .flat_map(|batch| batch.ops())
+        .copied()
+        .collect::<Vec<_>>();
+
+    // the lane i is checked by the i-th ASSERT of the sequence
+    ASSERT_EQW_OPS
+        .iter()
+        .enumerate()
+        .filter(|(_, &op)| op == Operation::Assert)
+        .map(|(pos, _)| pos)
+        .position(|pos| op_idx >= pos && ops[op_idx - pos..].starts_with(&ASSERT_EQW_OPS))
+}
@@ -1,4 +1,4 @@
-use super::{ExecutionError, Felt, Operation, Process};
+use super::{ExecutionError, ExecutionWarning, Felt, FieldElement, Operation, Process, StarkField};
 
 // CONSTANTS
 // ================================================================================================
@@ -38,6 +38,7 @@ impl Process {
         // get the address from the stack and read the word from current memory context
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_initialized(ctx, addr);
         let word = self.chiplets.read_mem(ctx, addr);
 
         // reverse the order of the memory word & update the stack state
@@ -65,6 +66,7 @@ impl Process {
         // get the address from the stack and read the word from memory
         let ctx = self.system.ctx();
         let addr = self.stack.get(0);
+        self.check_mem_initialized(ctx, addr);
         let mut word = self.chiplets.read_mem(ctx, addr);
         // put the retrieved word into stack order
         word.reverse();
@@ -95,6 +97,8 @@ impl Process {
         let addr = self.stack.get(12);
 
         // load two words from memory
+        self.check_mem_initialized(ctx, addr);
+        self.check_mem_initialized(ctx, addr + Felt::ONE);
         let words = self.chiplets.read_mem_double(ctx, addr);
 
         // add word elements to the elements already on the stack (in stack order)
@@ -258,6 +262,21 @@ impl Process {
 
         Ok(())
     }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records a warning if collection of warnings is enabled and the specified address in the
+    /// specified context has not been accessed before, and thus, reading from it returns zeros.
+    fn check_mem_initialized(&mut self, ctx: u32, addr: Felt) {
+        if let Some(warnings) = self.warnings.as_mut() {
+            let addr = addr.as_int();
+            if self.chiplets.get_mem_value(ctx, addr).is_none() {
+                let clk = self.system.clk();
+                warnings.push(ExecutionWarning::UninitializedMemoryRead(clk, ctx, addr));
+            }
+        }
+    }
 }
 
 // TESTS
@@ -1,4 +1,4 @@
-use super::{ExecutionError, Felt, FieldElement, Operation, Process, StarkField};
+use super::{ExecutionError, ExecutionWarning, Felt, FieldElement, Operation, Process, StarkField};
 use vm_core::stack::STACK_TOP_SIZE;
 
 mod crypto_ops;
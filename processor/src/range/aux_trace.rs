@@ -1,8 +1,14 @@
-use vm_core::{range::V_COL_IDX, utils::uninit_vector};
+use vm_core::{
+    range::V_COL_IDX,
+    utils::{
+        uninit_vector, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+    },
+};
 
 use super::{BTreeMap, CycleRangeChecks, Felt, FieldElement, RangeCheckFlag, Vec};
 use crate::{
     trace::{build_lookup_table_row_values, NUM_RAND_ROWS},
+    utils::{invalid_value, read_vec, write_vec},
     Matrix,
 };
 
@@ -233,3 +239,81 @@ impl AuxTraceBuilder {
         (p1, q)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl AuxTraceBuilder {
+    /// Returns an error if the 16-bit segment of the range checker's table described by this
+    /// builder does not start before the random rows of an execution trace of the specified
+    /// length.
+    pub(crate) fn validate(&self, trace_len: usize) -> Result<(), DeserializationError> {
+        if self.start_16bit + NUM_RAND_ROWS >= trace_len {
+            return Err(invalid_value(format!(
+                "16-bit segment start {} is out of bounds for a trace of length {trace_len}",
+                self.start_16bit
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Serializable for AuxTraceBuilder {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.cycle_range_checks.len() as u64);
+        for (clk, range_checks) in self.cycle_range_checks.iter() {
+            target.write_u32(*clk);
+            range_checks.write_into(target);
+        }
+        write_vec(target, &self.row_flags);
+        target.write_u64(self.start_16bit as u64);
+    }
+}
+
+impl Deserializable for AuxTraceBuilder {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_cycles = source.read_u64()? as usize;
+        let mut cycle_range_checks = BTreeMap::new();
+        for _ in 0..num_cycles {
+            let clk = source.read_u32()?;
+            cycle_range_checks.insert(clk, CycleRangeChecks::read_from(source)?);
+        }
+        let row_flags: Vec<RangeCheckFlag> = read_vec(source)?;
+        let start_16bit = source.read_u64()? as usize;
+        if start_16bit > row_flags.len() {
+            return Err(invalid_value(
+                "invalid start of the 16-bit range checker segment",
+            ));
+        }
+
+        Ok(Self {
+            cycle_range_checks,
+            row_flags,
+            start_16bit,
+        })
+    }
+}
+
+impl Serializable for RangeCheckFlag {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let tag = match self {
+            Self::F0 => 0,
+            Self::F1 => 1,
+            Self::F2 => 2,
+            Self::F3 => 3,
+        };
+        target.write_u8(tag);
+    }
+}
+
+impl Deserializable for RangeCheckFlag {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::F0),
+            1 => Ok(Self::F1),
+            2 => Ok(Self::F2),
+            3 => Ok(Self::F3),
+            tag => Err(invalid_value(format!("invalid range check flag {tag}"))),
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::{BTreeMap, Felt, FieldElement, Vec, ONE, ZERO};
 use crate::RangeCheckTrace;
+use core::iter;
 use vm_core::utils::uninit_vector;
 
 mod aux_trace;
@@ -20,12 +21,14 @@ pub const RANGE_CHECK_TRACE_TABLE_WIDTH: usize = 256;
 /// Range checker trace table.
 ///
 /// This component will store 8-bit lookup tables so it won't have to be reconstructed multiple
-/// times. It will also contain the combined length of the 8-bit and 16-bit tables.
+/// times. It will also contain the values of the "bridge" rows of the 16-bit table, as well as
+/// the combined length of the 8-bit and 16-bit tables.
 ///
 /// This is intended as internal helper structure and is not intended to be exported as part of the
 /// public API.
 pub struct RangeCheckTraceTable {
     pub lookups_8bit: [usize; RANGE_CHECK_TRACE_TABLE_WIDTH],
+    pub bridges: Vec<u16>,
     pub len: usize,
 }
 
@@ -73,6 +76,12 @@ pub struct RangeCheckTraceTable {
 /// If, on the other hand, the value was range-checked 5 times, we'll need two rows in the table:
 /// (1, 1, 1, v) and (1, 1, 0, v). The first row specifies that there was 4 lookups and the second
 /// row add the fifth lookup.
+///
+/// When two consecutive values in the 16-bit segment are more than 255 apart, "bridge" rows with
+/// no lookups are inserted between them. The constraints do not restrict where the bridge rows
+/// are placed, as long as the deltas between consecutive rows are 8-bit values. Since every delta
+/// is looked up in the 8-bit segment, the bridge rows are placed so that these lookups fit into
+/// the rows which the 8-bit segment contains anyway, as far as possible.
 pub struct RangeChecker {
     /// Tracks lookup count for each checked value.
     lookups: BTreeMap<u16, usize>,
@@ -217,11 +226,11 @@ impl RangeChecker {
 
         // build the 16-bit segment of the trace table
         let start_16bit = i;
-        let mut prev_value = 0u16;
+        let mut bridges = table.bridges.into_iter().peekable();
         for (&value, &num_lookups) in self.lookups.iter() {
             // when the delta between two values is greater than 255, insert "bridge" rows
-            for value in (prev_value..value).step_by(255).skip(1) {
-                write_value(&mut trace, &mut i, 0, value as u64, &mut row_flags);
+            while let Some(bridge) = bridges.next_if(|&bridge| bridge < value) {
+                write_value(&mut trace, &mut i, 0, bridge as u64, &mut row_flags);
             }
             write_value(
                 &mut trace,
@@ -230,7 +239,6 @@ impl RangeChecker {
                 value as u64,
                 &mut row_flags,
             );
-            prev_value = value;
         }
 
         // pad the trace with an extra row of 0 lookups for u16::MAX so that when b_range is built
@@ -240,6 +248,10 @@ impl RangeChecker {
         // the "current" row of the main trace but placed into the "next" row of the bus column.)
         write_value(&mut trace, &mut i, 0, (u16::MAX).into(), &mut row_flags);
 
+        // the flags of the rows with random values are never read when the auxiliary columns are
+        // built, but they are initialized so that the hints can be serialized.
+        row_flags[i..].fill(RangeCheckFlag::F0);
+
         RangeCheckTrace {
             trace,
             aux_builder: AuxTraceBuilder::new(self.cycle_range_checks, row_flags, start_16bit),
@@ -250,8 +262,8 @@ impl RangeChecker {
     // --------------------------------------------------------------------------------------------
 
     /// Builds an 8-bit lookup table required to support all 16-bit lookups currently in
-    /// self.lookups, and returns this table together with the number of 16-bit table rows needed
-    /// to support all 16-bit lookups.
+    /// self.lookups, and returns this table together with the values of the bridge rows and the
+    /// number of 16-bit table rows needed to support all 16-bit lookups.
     pub fn build_8bit_lookup(&self) -> RangeCheckTraceTable {
         let mut lookups_8bit = [0; 256];
 
@@ -262,6 +274,7 @@ impl RangeChecker {
         // add a lookup for ZERO to account for the extra row of the u16::MAX value
         lookups_8bit[0] = 1;
 
+        let mut gaps = Vec::new();
         let mut prev_value = 0u16;
         for (&value, &num_lookups) in self.lookups.iter() {
             // determine how many 16-bit lookup rows we need for this value; if the number of rows
@@ -271,29 +284,46 @@ impl RangeChecker {
             lookups_8bit[0] += num_rows - 1;
             num_16bit_rows += num_rows;
 
-            // determine the delta between this and the previous value. we need to know this delta
-            // to determine if we need to insert any "bridge" rows to the 16-bit portion of the the
-            // table, this is needed since rows in the 16-bit portion of the table can be at most
-            // 255 rows apart.
-            let delta = value - prev_value;
-            let (delta_q, delta_r) = div_rem(delta as usize, 255);
-
-            if delta_q != 0 {
-                lookups_8bit[255] += delta_q;
-                let num_bridge_rows = if delta_r == 0 { delta_q - 1 } else { delta_q };
-                num_16bit_rows += num_bridge_rows;
-            }
-            if delta_r != 0 {
-                lookups_8bit[delta_r] += 1;
+            // determine the delta between this and the previous value. if the delta is greater
+            // than 255, we'll need to insert "bridge" rows to the 16-bit portion of the table,
+            // since rows in this portion of the table can be at most 255 apart. bridge rows are
+            // placed once lookups for all other deltas are known.
+            let delta = (value - prev_value) as usize;
+            if delta > 255 {
+                gaps.push((prev_value, delta));
+            } else if delta != 0 {
+                lookups_8bit[delta] += 1;
             }
 
             prev_value = value;
         }
 
+        // split each gap into deltas of 255, except for the last two deltas which are chosen to
+        // minimize the number of rows in the 8-bit portion of the table.
+        let mut bridges = Vec::new();
+        for (start_value, delta) in gaps {
+            let num_full_deltas = (delta - 1) / 255 - 1;
+            lookups_8bit[255] += num_full_deltas;
+            let (delta_a, delta_b) = split_gap(delta - num_full_deltas * 255, &lookups_8bit);
+            lookups_8bit[delta_a] += 1;
+            lookups_8bit[delta_b] += 1;
+
+            let mut bridge = start_value;
+            for delta in iter::repeat(255).take(num_full_deltas).chain([delta_a]) {
+                bridge += delta as u16;
+                bridges.push(bridge);
+            }
+            num_16bit_rows += num_full_deltas + 1;
+        }
+
         let num_8bit_rows = get_num_8bit_rows(&lookups_8bit);
         let len = num_8bit_rows + num_16bit_rows;
 
-        RangeCheckTraceTable { lookups_8bit, len }
+        RangeCheckTraceTable {
+            lookups_8bit,
+            bridges,
+            len,
+        }
     }
 
     // TEST HELPERS
@@ -376,6 +406,35 @@ fn lookups_to_rows(num_lookups: usize) -> usize {
     }
 }
 
+/// Splits the remainder of a gap between two 16-bit values (between 256 and 510) into two 8-bit
+/// deltas, such that looking up these deltas adds the fewest rows to the specified 8-bit lookup
+/// table. If no split adds fewer rows than splitting the gap into 255 and the rest, this split
+/// is returned.
+fn split_gap(delta: usize, lookups: &[usize; 256]) -> (usize, usize) {
+    debug_assert!((256..=510).contains(&delta), "invalid gap remainder");
+    let added_rows = |delta_a: usize, delta_b: usize| {
+        let (count_a, count_b) = (lookups[delta_a], lookups[delta_b]);
+        if delta_a == delta_b {
+            lookups_to_rows(count_a + 2) as isize - lookups_to_rows(count_a) as isize
+        } else {
+            lookups_to_rows(count_a + 1) as isize - lookups_to_rows(count_a) as isize
+                + lookups_to_rows(count_b + 1) as isize
+                - lookups_to_rows(count_b) as isize
+        }
+    };
+
+    let mut best_split = (255, delta - 255);
+    let mut best_rows = added_rows(best_split.0, best_split.1);
+    for delta_a in ((delta + 1) / 2..255).rev() {
+        let rows = added_rows(delta_a, delta - delta_a);
+        if rows < best_rows {
+            best_split = (delta_a, delta - delta_a);
+            best_rows = rows;
+        }
+    }
+    best_split
+}
+
 /// Returns the number of trace rows needed to describe the specified 8-bit lookup table.
 fn get_num_8bit_rows(lookups: &[usize; 256]) -> usize {
     let mut result = 0;
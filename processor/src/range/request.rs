@@ -1,5 +1,6 @@
 use super::{Felt, FieldElement};
-use crate::{trace::LookupTableRow, Matrix};
+use crate::{trace::LookupTableRow, utils::invalid_value, Matrix};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 // PROCESSOR RANGE CHECKS
 // ================================================================================================
@@ -139,3 +140,68 @@ impl LookupTableRow for RangeCheckRequest {
         value
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CycleRangeChecks {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        for request in [&self.memory, &self.stack] {
+            match request {
+                Some(request) => {
+                    target.write_u8(1);
+                    request.write_into(target);
+                }
+                None => target.write_u8(0),
+            }
+        }
+    }
+}
+
+impl Deserializable for CycleRangeChecks {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut read_request = || match source.read_u8()? {
+            0 => Ok(None),
+            1 => Ok(Some(RangeCheckRequest::read_from(source)?)),
+            tag => Err(invalid_value(format!(
+                "invalid range check request flag {tag}"
+            ))),
+        };
+
+        Ok(Self {
+            memory: read_request()?,
+            stack: read_request()?,
+        })
+    }
+}
+
+impl Serializable for RangeCheckRequest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Memory(values) => {
+                target.write_u8(0);
+                Felt::write_batch_into(values, target);
+            }
+            Self::Stack(values) => {
+                target.write_u8(1);
+                Felt::write_batch_into(values, target);
+            }
+        }
+    }
+}
+
+impl Deserializable for RangeCheckRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => {
+                let values = Felt::read_batch_from(source, 2)?;
+                Ok(Self::Memory([values[0], values[1]]))
+            }
+            1 => {
+                let values = Felt::read_batch_from(source, 4)?;
+                Ok(Self::Stack([values[0], values[1], values[2], values[3]]))
+            }
+            tag => Err(invalid_value(format!("invalid range check request {tag}"))),
+        }
+    }
+}
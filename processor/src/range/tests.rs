@@ -59,6 +59,32 @@ fn range_checks_rand() {
     validate_trace(&trace, &values);
 }
 
+#[test]
+fn range_checks_bridges() {
+    let mut checker = RangeChecker::new();
+
+    // values 1..=250 are followed by values which are 300 apart; placing bridge rows at increments
+    // of 255 would require 416 rows for the 8-bit segment and 651 rows for the 16-bit segment.
+    let values = (1..=250)
+        .chain((1..=200).map(|i| 250 + 300 * i))
+        .collect::<Vec<u64>>()
+        .to_elements();
+
+    for &value in values.iter() {
+        checker.add_value(value.as_int() as u16);
+    }
+
+    // with bridge rows placed to reuse deltas of the 8-bit segment, the table fits into 1024 rows
+    let trace_len = checker.trace_len();
+    assert!(trace_len <= 1024);
+
+    let RangeCheckTrace {
+        trace,
+        aux_builder: _,
+    } = checker.into_trace(1024, 0);
+    validate_trace(&trace, &values);
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1,7 +1,11 @@
 use super::{
     super::trace::AuxColumnBuilder, Felt, FieldElement, OverflowTableRow, OverflowTableUpdate, Vec,
 };
-use crate::Matrix;
+use crate::{
+    utils::{check_hint_cycle, check_table_row_idx, invalid_value, read_vec, write_vec},
+    Matrix,
+};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 
 // AUXILIARY TRACE BUILDER
 // ================================================================================================
@@ -102,3 +106,73 @@ impl AuxColumnBuilder<OverflowTableUpdate, OverflowTableRow, u64> for AuxTraceBu
         final_column_value
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl AuxTraceBuilder {
+    /// Returns an error if any of the overflow table hints of this builder cannot be applied to an
+    /// execution trace of the specified length.
+    pub(crate) fn validate(&self, trace_len: usize) -> Result<(), DeserializationError> {
+        for (clk, _) in self.get_table_hints() {
+            check_hint_cycle(*clk as usize, trace_len)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serializable for AuxTraceBuilder {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.overflow_hints.len() as u64);
+        for (clk, update) in self.overflow_hints.iter() {
+            target.write_u64(*clk);
+            update.write_into(target);
+        }
+        write_vec(target, &self.overflow_table_rows);
+        target.write_u64(self.num_init_rows as u64);
+
+        target.write_u64(self.final_rows.len() as u64);
+        for &row_idx in self.final_rows.iter() {
+            target.write_u64(row_idx as u64);
+        }
+    }
+}
+
+impl Deserializable for AuxTraceBuilder {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_hints = source.read_u64()? as usize;
+        let overflow_hints = (0..num_hints)
+            .map(|_| Ok((source.read_u64()?, OverflowTableUpdate::read_from(source)?)))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+        let overflow_table_rows = read_vec(source)?;
+        let num_init_rows = source.read_u64()? as usize;
+        if num_init_rows > overflow_hints.len() {
+            return Err(invalid_value(
+                "invalid number of initial overflow table rows",
+            ));
+        }
+
+        let num_final_rows = source.read_u64()? as usize;
+        let final_rows = (0..num_final_rows)
+            .map(|_| Ok(source.read_u64()? as usize))
+            .collect::<Result<Vec<_>, DeserializationError>>()?;
+
+        // all hints and final rows must refer to the rows of the table
+        let num_rows = overflow_table_rows.len();
+        for (_, update) in overflow_hints.iter() {
+            let (OverflowTableUpdate::RowInserted(row_idx)
+            | OverflowTableUpdate::RowRemoved(row_idx)) = update;
+            check_table_row_idx(*row_idx as usize, num_rows, "overflow table")?;
+        }
+        for &row_idx in final_rows.iter() {
+            check_table_row_idx(row_idx, num_rows, "overflow table")?;
+        }
+
+        Ok(Self {
+            overflow_hints,
+            overflow_table_rows,
+            num_init_rows,
+            final_rows,
+        })
+    }
+}
@@ -1,6 +1,4 @@
-use super::{
-    BTreeMap, Felt, FieldElement, ProgramInputs, ProgramOutputs, Vec, ONE, STACK_TRACE_WIDTH, ZERO,
-};
+use super::{BTreeMap, Felt, FieldElement, ProgramOutputs, Vec, ONE, STACK_TRACE_WIDTH, ZERO};
 use core::cmp;
 use vm_core::stack::STACK_TOP_SIZE;
 use vm_core::Word;
@@ -59,18 +57,21 @@ pub struct Stack {
     overflow: OverflowTable,
     active_depth: usize,
     full_depth: usize,
+    num_underflows: usize,
+    saved_underflows: Vec<usize>,
+    init_values: Vec<Felt>,
 }
 
 impl Stack {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
-    /// Returns a [Stack] initialized with the specified program inputs.
+    /// Returns a [Stack] initialized with the specified values; the first value in `init_values`
+    /// is placed at the top of the stack.
     pub fn new(
-        inputs: &ProgramInputs,
+        init_values: &[Felt],
         init_trace_capacity: usize,
         keep_overflow_trace: bool,
     ) -> Self {
-        let init_values = inputs.stack_init();
         let depth = cmp::max(STACK_TOP_SIZE, init_values.len());
 
         let (trace, overflow) = if init_values.len() > STACK_TOP_SIZE {
@@ -97,17 +98,37 @@ impl Stack {
             overflow,
             active_depth: depth,
             full_depth: depth,
+            num_underflows: 0,
+            saved_underflows: Vec::new(),
+            init_values: init_values.to_vec(),
         }
     }
 
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the values with which the stack was initialized; the first value is the value which
+    /// was placed at the top of the stack.
+    pub fn init_values(&self) -> &[Felt] {
+        &self.init_values
+    }
+
     /// Returns depth of the stack at the current clock cycle.
     pub fn depth(&self) -> usize {
         self.active_depth
     }
 
+    /// Returns the depth of the stack at the current clock cycle without clamping it to the minimum
+    /// stack depth.
+    ///
+    /// When an item is removed from a stack of depth 16, a ZERO is shifted in and the depth stays
+    /// at 16; the value returned here is reduced by one instead. Items removed in a different
+    /// execution context (e.g., inside a function call) do not affect this value once the prior
+    /// context is restored.
+    pub fn unclamped_depth(&self) -> isize {
+        self.active_depth as isize - self.num_underflows as isize
+    }
+
     /// Returns the current clock cycle of the execution trace.
     pub fn current_clk(&self) -> u32 {
         self.clk
@@ -156,6 +177,14 @@ impl Stack {
         self.trace.get_stack_value_at(self.clk, pos)
     }
 
+    /// Returns a value located at the specified position on the stack at the specified clock
+    /// cycle.
+    pub fn get_at(&self, clk: u32, pos: usize) -> Felt {
+        debug_assert!(pos < STACK_TOP_SIZE, "stack underflow");
+        debug_assert!(clk <= self.clk, "clock cycle in the future");
+        self.trace.get_stack_value_at(clk, pos)
+    }
+
     /// Returns four values located at the top of the stack. The word is created in reverse order,
     /// so that the top element of the stack will be at the last position in the word. Creating a
     /// word does not change the state of the stack.
@@ -199,6 +228,7 @@ impl Stack {
                 // Shift in a ZERO, to prevent depth shrinking below the minimum stack depth.
                 self.trace
                     .stack_shift_left_at(self.clk, start_pos, ZERO, None);
+                self.num_underflows += 1;
             }
             _ => {
                 // Update the stack & overflow table.
@@ -252,6 +282,8 @@ impl Stack {
         let current_overflow_addr = self.overflow.last_row_addr();
         self.active_depth = STACK_TOP_SIZE;
         self.overflow.set_last_row_addr(ZERO);
+        self.saved_underflows.push(self.num_underflows);
+        self.num_underflows = 0;
         (current_depth, current_overflow_addr)
     }
 
@@ -266,6 +298,7 @@ impl Stack {
         );
         self.active_depth = stack_depth;
         self.overflow.set_last_row_addr(next_overflow_addr);
+        self.num_underflows = self.saved_underflows.pop().unwrap_or_default();
     }
 
     // TRACE GENERATION
@@ -1,8 +1,13 @@
 use super::{
     super::trace::LookupTableRow, AuxTraceBuilder, BTreeMap, Felt, FieldElement, Vec, ZERO,
 };
-use crate::Matrix;
-use vm_core::{utils::uninit_vector, StarkField};
+use crate::{utils::invalid_value, Matrix};
+use vm_core::{
+    utils::{
+        uninit_vector, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+    },
+    StarkField,
+};
 
 // OVERFLOW TABLE
 // ================================================================================================
@@ -299,3 +304,47 @@ pub enum OverflowTableUpdate {
     RowInserted(u32),
     RowRemoved(u32),
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for OverflowTableRow {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.val.write_into(target);
+        self.clk.write_into(target);
+        self.prev.write_into(target);
+    }
+}
+
+impl Deserializable for OverflowTableRow {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            val: Felt::read_from(source)?,
+            clk: Felt::read_from(source)?,
+            prev: Felt::read_from(source)?,
+        })
+    }
+}
+
+impl Serializable for OverflowTableUpdate {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let (tag, row_idx) = match self {
+            Self::RowInserted(row_idx) => (0, row_idx),
+            Self::RowRemoved(row_idx) => (1, row_idx),
+        };
+        target.write_u8(tag);
+        target.write_u32(*row_idx);
+    }
+}
+
+impl Deserializable for OverflowTableUpdate {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::RowInserted(source.read_u32()?)),
+            1 => Ok(Self::RowRemoved(source.read_u32()?)),
+            tag => Err(invalid_value(format!(
+                "invalid overflow table update {tag}"
+            ))),
+        }
+    }
+}
@@ -1,4 +1,5 @@
-use super::{Felt, OverflowTableRow, ProgramInputs, Stack, ONE, STACK_TOP_SIZE, ZERO};
+use super::{Felt, OverflowTableRow, Stack, ONE, STACK_TOP_SIZE, ZERO};
+use crate::ProgramInputs;
 use crate::StackTopState;
 use vm_core::{
     stack::{B0_COL_IDX, B1_COL_IDX, H0_COL_IDX, NUM_STACK_HELPER_COLS},
@@ -18,7 +19,7 @@ fn initialize() {
     // initialize a new stack with some initial values
     let mut stack_inputs = [1, 2, 3, 4];
     let inputs = ProgramInputs::new(&stack_inputs, &[], vec![]).unwrap();
-    let stack = Stack::new(&inputs, 4, false);
+    let stack = Stack::new(inputs.stack_init(), 4, false);
 
     // Prepare the expected results.
     stack_inputs.reverse();
@@ -39,7 +40,7 @@ fn initialize_overflow() {
         1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
     ];
     let inputs = ProgramInputs::new(&stack_inputs, &[], vec![]).unwrap();
-    let stack = Stack::new(&inputs, 4, false);
+    let stack = Stack::new(inputs.stack_init(), 4, false);
 
     // Prepare the expected results.
     stack_inputs.reverse();
@@ -75,7 +76,7 @@ fn initialize_overflow() {
 #[test]
 fn shift_left() {
     let inputs = ProgramInputs::new(&[1, 2, 3, 4], &[], vec![]).unwrap();
-    let mut stack = Stack::new(&inputs, 4, false);
+    let mut stack = Stack::new(inputs.stack_init(), 4, false);
 
     // ---- left shift an entire stack of minimum depth -------------------------------------------
     // Perform the left shift.
@@ -87,7 +88,7 @@ fn shift_left() {
     assert_eq!(stack.helpers_state(), build_helpers_partial(0, 0));
 
     // ---- left shift an entire stack with multiple overflow items -------------------------------
-    let mut stack = Stack::new(&inputs, 4, false);
+    let mut stack = Stack::new(inputs.stack_init(), 4, false);
 
     // make sure the first right shift is not executed at clk = 0
     stack.copy_state(0);
@@ -129,7 +130,7 @@ fn shift_left() {
 #[test]
 fn shift_right() {
     let inputs = ProgramInputs::new(&[1, 2, 3, 4], &[], vec![]).unwrap();
-    let mut stack = Stack::new(&inputs, 4, false);
+    let mut stack = Stack::new(inputs.stack_init(), 4, false);
 
     // make sure the first right shift is not executed at clk = 0
     stack.copy_state(0);
@@ -169,7 +170,7 @@ fn shift_right() {
 fn start_restore_context() {
     let stack_init = (0..16).map(|v| v as u64 + 1).collect::<Vec<u64>>();
     let inputs = ProgramInputs::new(&stack_init, &[], vec![]).unwrap();
-    let mut stack = Stack::new(&inputs, 8, false);
+    let mut stack = Stack::new(inputs.stack_init(), 8, false);
 
     // ----- when overflow table is empty -------------------------------------
 
@@ -207,7 +208,7 @@ fn start_restore_context() {
     // ----- when overflow table is not empty ---------------------------------
     let stack_init = (0..16).map(|v| v as u64 + 1).collect::<Vec<u64>>();
     let inputs = ProgramInputs::new(&stack_init, &[], vec![]).unwrap();
-    let mut stack = Stack::new(&inputs, 8, false);
+    let mut stack = Stack::new(inputs.stack_init(), 8, false);
 
     let mut stack_state = stack_init;
     stack_state.reverse();
@@ -280,7 +281,7 @@ fn start_restore_context() {
 #[test]
 fn generate_trace() {
     let inputs = ProgramInputs::new(&[1, 2, 3, 4], &[], vec![]).unwrap();
-    let mut stack = Stack::new(&inputs, 16, false);
+    let mut stack = Stack::new(inputs.stack_init(), 16, false);
 
     // clk = 0
     stack.copy_state(0);
@@ -0,0 +1,77 @@
+use super::{Felt, Vec};
+use core::ops::Range;
+use vm_core::{
+    CHIPLETS_AUX_TRACE_RANGE, CHIPLETS_RANGE, DECODER_AUX_TRACE_RANGE, DECODER_TRACE_RANGE,
+    HASHER_AUX_TRACE_RANGE, RANGE_CHECK_AUX_TRACE_RANGE, RANGE_CHECK_TRACE_RANGE,
+    STACK_AUX_TRACE_RANGE, STACK_TRACE_RANGE, SYS_TRACE_RANGE,
+};
+
+// AIR DESCRIPTION
+// ================================================================================================
+
+/// Description of an execution trace in the form of a randomized AIR with preprocessing (RAP).
+///
+/// This contains all data needed to feed the trace generated by Miden VM into an external STARK
+/// backend:
+/// - Columns of the main trace segment.
+/// - Columns of the auxiliary trace segment, built using the random elements included in the
+///   description.
+/// - Layout of both segments, i.e., which columns belong to which component of the VM.
+///
+/// Transition and boundary constraints over these columns are defined by `ProcessorAir` in the
+/// `miden-air` crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AirDescription {
+    /// Number of rows in each of the trace columns.
+    pub trace_len: usize,
+    /// Number of rows at the end of the trace which are filled with random values.
+    pub num_rand_rows: usize,
+    /// Layout of the main trace segment.
+    pub main_layout: Vec<ColumnGroup>,
+    /// Layout of the auxiliary trace segment.
+    pub aux_layout: Vec<ColumnGroup>,
+    /// Columns of the main trace segment.
+    pub main_columns: Vec<Vec<Felt>>,
+    /// Columns of the auxiliary trace segment.
+    pub aux_columns: Vec<Vec<Felt>>,
+    /// Random elements used to build the auxiliary trace segment.
+    pub aux_rand_elements: Vec<Felt>,
+}
+
+/// A named group of consecutive columns within a trace segment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnGroup {
+    pub name: &'static str,
+    pub columns: Range<usize>,
+}
+
+impl ColumnGroup {
+    fn new(name: &'static str, columns: Range<usize>) -> Self {
+        Self { name, columns }
+    }
+}
+
+// LAYOUT
+// ================================================================================================
+
+/// Returns the layout of the main trace segment.
+pub(super) fn main_layout() -> Vec<ColumnGroup> {
+    vec![
+        ColumnGroup::new("system", SYS_TRACE_RANGE),
+        ColumnGroup::new("decoder", DECODER_TRACE_RANGE),
+        ColumnGroup::new("stack", STACK_TRACE_RANGE),
+        ColumnGroup::new("range_checker", RANGE_CHECK_TRACE_RANGE),
+        ColumnGroup::new("chiplets", CHIPLETS_RANGE),
+    ]
+}
+
+/// Returns the layout of the auxiliary trace segment.
+pub(super) fn aux_layout() -> Vec<ColumnGroup> {
+    vec![
+        ColumnGroup::new("decoder", DECODER_AUX_TRACE_RANGE),
+        ColumnGroup::new("stack", STACK_AUX_TRACE_RANGE),
+        ColumnGroup::new("range_checker", RANGE_CHECK_AUX_TRACE_RANGE),
+        ColumnGroup::new("hasher", HASHER_AUX_TRACE_RANGE),
+        ColumnGroup::new("chiplets", CHIPLETS_AUX_TRACE_RANGE),
+    ]
+}
@@ -3,24 +3,28 @@ use super::{
     decoder::AuxTraceHints as DecoderAuxTraceHints,
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
     stack::AuxTraceBuilder as StackAuxTraceBuilder,
-    Digest, Felt, FieldElement, Process, StackTopState, Vec,
+    Digest, Felt, FieldElement, HashOp, MemoryAccess, Process, StackTopState, Vec,
 };
 use vm_core::{
+    chiplets::hasher,
     decoder::{NUM_USER_OP_HELPERS, USER_OP_HELPERS_OFFSET},
+    range::{S0_COL_IDX, S1_COL_IDX, T_COL_IDX, V_COL_IDX},
     stack::STACK_TOP_SIZE,
-    ProgramOutputs, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
-    STACK_TRACE_OFFSET, TRACE_WIDTH, ZERO,
+    ProgramOutputs, StarkField, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET,
+    MIN_TRACE_LEN, ONE, STACK_TRACE_OFFSET, TRACE_WIDTH, ZERO,
 };
-use winterfell::{EvaluationFrame, Matrix, Serializable, Trace, TraceLayout};
-
-#[cfg(feature = "std")]
-use vm_core::StarkField;
+use winterfell::{crypto::Digest as _, EvaluationFrame, Matrix, Serializable, Trace, TraceLayout};
 
 mod utils;
 pub use utils::{build_lookup_table_row_values, AuxColumnBuilder, LookupTableRow, TraceFragment};
 
+mod air_description;
+pub use air_description::{AirDescription, ColumnGroup};
+
 mod decoder;
 
+mod serialization;
+
 #[cfg(test)]
 mod tests;
 
@@ -59,7 +63,12 @@ pub struct ExecutionTrace {
     main_trace: Matrix<Felt>,
     aux_trace_hints: AuxTraceHints,
     program_hash: Digest,
+    stack_inputs: Vec<Felt>,
     program_outputs: ProgramOutputs,
+    num_cycles: usize,
+    memory_access_log: Vec<(u32, MemoryAccess, u64)>,
+    hash_operations: Vec<HashOp>,
+    rand_seed: [u8; 32],
 }
 
 impl ExecutionTrace {
@@ -72,13 +81,18 @@ impl ExecutionTrace {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
-    pub(super) fn new(process: Process, program_outputs: ProgramOutputs) -> Self {
+    pub(super) fn new(mut process: Process, program_outputs: ProgramOutputs) -> Self {
         // use program hash to initialize random element generator; this generator will be used
         // to inject random values at the end of the trace; using program hash here is OK because
         // we are using random values only to stabilize constraint degrees, and not to achieve
         // perfect zero knowledge.
         let program_hash: Digest = process.decoder.program_hash().into();
-        let rng = RandomCoin::new(&program_hash.to_bytes());
+        let num_cycles = process.system.clk() as usize;
+        let stack_inputs = process.stack.init_values().to_vec();
+        let memory_access_log = process.chiplets.take_mem_access_log();
+        let hash_operations = process.chiplets.take_hash_operations();
+        let rand_seed = program_hash.as_bytes();
+        let rng = RandomCoin::new(&rand_seed);
         let (main_trace, aux_trace_hints) = finalize_trace(process, rng);
 
         Self {
@@ -87,7 +101,12 @@ impl ExecutionTrace {
             main_trace: Matrix::new(main_trace),
             aux_trace_hints,
             program_hash,
+            stack_inputs,
             program_outputs,
+            num_cycles,
+            memory_access_log,
+            hash_operations,
+            rand_seed,
         }
     }
 
@@ -99,28 +118,53 @@ impl ExecutionTrace {
         self.program_hash
     }
 
+    /// Returns the values with which the stack was initialized before the program execution which
+    /// resulted in this execution trace. The first value is the value at the top of the stack.
+    pub fn stack_inputs(&self) -> &[Felt] {
+        &self.stack_inputs
+    }
+
     /// Returns outputs of the program execution which resulted in this execution trace.
     pub fn program_outputs(&self) -> ProgramOutputs {
         self.program_outputs.clone()
     }
 
+    /// Returns the number of VM cycles it took to execute the program.
+    ///
+    /// The trace is padded to the next power of two, and thus, its length may be greater than
+    /// this number.
+    pub fn num_cycles(&self) -> usize {
+        self.num_cycles
+    }
+
     /// Returns the initial state of the top 16 stack registers.
     pub fn init_stack_state(&self) -> StackTopState {
-        let mut result = [ZERO; STACK_TOP_SIZE];
-        for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[0];
-        }
-        result
+        self.stack_state_at(0).expect("trace is empty")
     }
 
     /// Returns the final state of the top 16 stack registers.
     pub fn last_stack_state(&self) -> StackTopState {
-        let last_step = self.last_step();
+        self.stack_state_at(self.last_step())
+            .expect("last step is out of bounds")
+    }
+
+    /// Returns the state of the top 16 stack registers at the specified clock cycle, or None if
+    /// the cycle is beyond the last step of the trace.
+    ///
+    /// The state is read directly from the trace, and thus, unlike iterating over the states
+    /// of the VM via [crate::execute_iter()], does not require re-executing the program. The
+    /// trace is padded after the program completes, and so for cycles after the end of the
+    /// execution (but still within the trace), the final state of the stack is returned.
+    pub fn stack_state_at(&self, clk: usize) -> Option<StackTopState> {
+        if clk > self.last_step() {
+            return None;
+        }
+
         let mut result = [ZERO; STACK_TOP_SIZE];
         for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[last_step];
+            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[clk];
         }
-        result
+        Some(result)
     }
 
     /// Returns helper registers state at the specified `clk` of the VM
@@ -134,65 +178,122 @@ impl ExecutionTrace {
         result
     }
 
-    // HELPER METHODS
-    // --------------------------------------------------------------------------------------------
-
-    /// Returns the index of the last row in the trace.
-    fn last_step(&self) -> usize {
-        self.length() - NUM_RAND_ROWS - 1
-    }
-
-    // TEST HELPERS
-    // --------------------------------------------------------------------------------------------
-    #[cfg(feature = "std")]
-    #[allow(dead_code)]
-    pub fn print(&self) {
-        let mut row = [ZERO; TRACE_WIDTH];
-        for i in 0..self.length() {
-            self.main_trace.read_row_into(i, &mut row);
-            println!("{:?}", row.iter().map(|v| v.as_int()).collect::<Vec<_>>());
+    /// Returns the distinct values which were range-checked during the program execution which
+    /// resulted in this execution trace, in ascending order.
+    ///
+    /// The range checker operates on 16-bit values, and thus, the returned values are the 16-bit
+    /// limbs of the values checked by the stack (e.g., results of u32 operations) and by the
+    /// memory chiplet (e.g., deltas between memory addresses), rather than the values themselves.
+    /// The values are read from the range checker segment of the trace, and are intended for
+    /// debugging failures related to range checks.
+    pub fn range_check_values(&self) -> Vec<u64> {
+        let rows = self
+            .main_trace
+            .get_column(T_COL_IDX)
+            .iter()
+            .zip(self.main_trace.get_column(S0_COL_IDX))
+            .zip(self.main_trace.get_column(S1_COL_IDX))
+            .zip(self.main_trace.get_column(V_COL_IDX))
+            .take(self.length() - NUM_RAND_ROWS);
+
+        // the lookups performed by the VM are in the 16-bit segment of the range checker (i.e.,
+        // where t = 1), in rows with at least one lookup; the rows are sorted by value, and a
+        // value looked up more than 4 times spans several rows.
+        let mut result: Vec<u64> = Vec::new();
+        for (((&t, &s0), &s1), &v) in rows {
+            if t == ONE && (s0 != ZERO || s1 != ZERO) {
+                let value = v.as_int();
+                if result.last() != Some(&value) {
+                    result.push(value);
+                }
+            }
         }
+        result
     }
 
-    #[cfg(test)]
-    pub fn test_finalize_trace(process: Process) -> (Vec<Vec<Felt>>, AuxTraceHints) {
-        let rng = RandomCoin::new(&[0; 32]);
-        finalize_trace(process, rng)
+    /// Returns the memory accesses performed during the program execution which resulted in this
+    /// execution trace as a sequence of (clock cycle, access type, address) tuples, in the order
+    /// in which the accesses happened.
+    ///
+    /// Accesses to the same address in different execution contexts are not distinguished. The
+    /// accesses are recorded only if the program was executed via
+    /// [crate::execute_with_memory_access_log()]; otherwise, an empty vector is returned.
+    pub fn memory_access_log(&self) -> Vec<(u32, MemoryAccess, u64)> {
+        self.memory_access_log.clone()
     }
-}
 
-// TRACE TRAIT IMPLEMENTATION
-// ================================================================================================
+    /// Returns the hash computations performed by the hash chiplet during the execution, in the
+    /// order in which they were performed.
+    ///
+    /// Besides the hashes computed by the program itself, this includes the hashes of all code
+    /// blocks executed by the program, which the decoder computes to verify the program hash.
+    pub fn hash_operations(&self) -> Vec<HashOp> {
+        self.hash_operations.clone()
+    }
 
-impl Trace for ExecutionTrace {
-    type BaseField = Felt;
+    // PUBLIC MUTATORS
+    // --------------------------------------------------------------------------------------------
 
-    fn layout(&self) -> &TraceLayout {
-        &self.layout
+    /// Replaces the random values injected into the last rows of this trace with the values drawn
+    /// from a random generator initialized with the specified seed.
+    ///
+    /// By default, the generator is initialized with the program hash. The random values are
+    /// injected only to stabilize the degrees of the trace polynomials, and thus, the trace
+    /// remains a valid execution trace of the program regardless of the seed.
+    pub fn set_rand_seed(&mut self, seed: [u8; 32]) {
+        let trace_len = self.length();
+        let mut rng = RandomCoin::new(&seed);
+        for i in trace_len - NUM_RAND_ROWS..trace_len {
+            for column in self.main_trace.columns_mut() {
+                column[i] = rng.draw().expect("failed to draw a random value");
+            }
+        }
+        self.rand_seed = seed;
     }
 
-    fn length(&self) -> usize {
-        self.main_trace.num_rows()
-    }
+    // AIR EXPORT
+    // --------------------------------------------------------------------------------------------
 
-    fn meta(&self) -> &[u8] {
-        &self.meta
+    /// Returns a description of this trace in the form of a randomized AIR with preprocessing
+    /// (RAP), which can be used to feed this trace into an external STARK backend.
+    ///
+    /// The auxiliary trace segment is built over the base field using random elements drawn from
+    /// a random coin seeded with the program hash and the main trace. Thus, the description is
+    /// deterministic, but the random elements are not bound to a commitment to the main trace as
+    /// they would be in a sound proof.
+    pub fn to_air_description(&self) -> AirDescription {
+        let main_columns: Vec<Vec<Felt>> = self.main_trace.columns().map(|c| c.to_vec()).collect();
+        let main_trace_hash = hasher::hash_elements(&main_columns.concat());
+        let seed = hasher::merge(&[self.program_hash, main_trace_hash]);
+        let mut rng = RandomCoin::new(&seed.to_bytes());
+        let aux_rand_elements = (0..AUX_TRACE_RAND_ELEMENTS)
+            .map(|_| rng.draw().expect("failed to draw a random value"))
+            .collect::<Vec<Felt>>();
+
+        AirDescription {
+            trace_len: self.length(),
+            num_rand_rows: NUM_RAND_ROWS,
+            main_layout: air_description::main_layout(),
+            aux_layout: air_description::aux_layout(),
+            main_columns,
+            aux_columns: self.build_aux_columns(&aux_rand_elements),
+            aux_rand_elements,
+        }
     }
 
-    fn main_segment(&self) -> &Matrix<Felt> {
-        &self.main_trace
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the last row in the trace.
+    fn last_step(&self) -> usize {
+        self.length() - NUM_RAND_ROWS - 1
     }
 
-    fn build_aux_segment<E: FieldElement<BaseField = Felt>>(
-        &mut self,
-        aux_segments: &[Matrix<E>],
+    /// Builds the columns of the auxiliary trace segment using the provided random elements.
+    fn build_aux_columns<E: FieldElement<BaseField = Felt>>(
+        &self,
         rand_elements: &[E],
-    ) -> Option<Matrix<E>> {
-        // we only have one auxiliary segment
-        if !aux_segments.is_empty() {
-            return None;
-        }
-
+    ) -> Vec<Vec<E>> {
         // TODO: build auxiliary columns in multiple threads
 
         // add decoder's running product columns
@@ -236,13 +337,68 @@ impl Trace for ExecutionTrace {
             .collect::<Vec<_>>();
 
         // inject random values into the last rows of the trace
-        let mut rng = RandomCoin::new(&self.program_hash.to_bytes());
+        let mut rng = RandomCoin::new(&self.rand_seed);
         for i in self.length() - NUM_RAND_ROWS..self.length() {
             for column in aux_columns.iter_mut() {
                 column[i] = rng.draw().expect("failed to draw a random value");
             }
         }
 
+        aux_columns
+    }
+
+    // TEST HELPERS
+    // --------------------------------------------------------------------------------------------
+    #[cfg(feature = "std")]
+    #[allow(dead_code)]
+    pub fn print(&self) {
+        let mut row = [ZERO; TRACE_WIDTH];
+        for i in 0..self.length() {
+            self.main_trace.read_row_into(i, &mut row);
+            println!("{:?}", row.iter().map(|v| v.as_int()).collect::<Vec<_>>());
+        }
+    }
+
+    #[cfg(test)]
+    pub fn test_finalize_trace(process: Process) -> (Vec<Vec<Felt>>, AuxTraceHints) {
+        let rng = RandomCoin::new(&[0; 32]);
+        finalize_trace(process, rng)
+    }
+}
+
+// TRACE TRAIT IMPLEMENTATION
+// ================================================================================================
+
+impl Trace for ExecutionTrace {
+    type BaseField = Felt;
+
+    fn layout(&self) -> &TraceLayout {
+        &self.layout
+    }
+
+    fn length(&self) -> usize {
+        self.main_trace.num_rows()
+    }
+
+    fn meta(&self) -> &[u8] {
+        &self.meta
+    }
+
+    fn main_segment(&self) -> &Matrix<Felt> {
+        &self.main_trace
+    }
+
+    fn build_aux_segment<E: FieldElement<BaseField = Felt>>(
+        &mut self,
+        aux_segments: &[Matrix<E>],
+        rand_elements: &[E],
+    ) -> Option<Matrix<E>> {
+        // we only have one auxiliary segment
+        if !aux_segments.is_empty() {
+            return None;
+        }
+
+        let aux_columns = self.build_aux_columns(rand_elements);
         Some(Matrix::new(aux_columns))
     }
 
@@ -0,0 +1,179 @@
+use super::{
+    AuxTraceHints, ChipletsAuxTraceBuilder, DecoderAuxTraceHints, Digest, ExecutionTrace, Felt,
+    HasherAuxTraceBuilder, RangeCheckerAuxTraceBuilder, StackAuxTraceBuilder, Vec,
+};
+use crate::utils::{invalid_value, read_vec, write_vec};
+use vm_core::{
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+    },
+    ProgramOutputs, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, MIN_TRACE_LEN, TRACE_WIDTH,
+};
+use winterfell::{crypto::Digest as _, Matrix, Trace, TraceLayout};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Bytes at the start of every serialized execution trace.
+const MAGIC: &[u8; 4] = b"MVTR";
+
+/// Version of the serialization format.
+const VERSION: u8 = 0;
+
+// EXECUTION TRACE SERIALIZATION
+// ================================================================================================
+
+impl ExecutionTrace {
+    /// Serializes this execution trace into a vector of bytes from which a proof of the program
+    /// execution can be generated without re-executing the program.
+    ///
+    /// The trace is serialized as follows:
+    /// - The `MVTR` magic bytes followed by the format version.
+    /// - Hash of the executed program.
+    /// - Initial state of the stack, and the outputs of the program execution.
+    /// - Number of VM cycles it took to execute the program.
+    /// - Width and length of the main trace segment followed by the columns of the main segment.
+    /// - Hints required to build the auxiliary trace segment.
+    pub fn to_prover_bytes(&self) -> Vec<u8> {
+        let mut target = Vec::new();
+        target.write_u8_slice(MAGIC);
+        target.write_u8(VERSION);
+
+        self.program_hash.write_into(&mut target);
+        write_vec(&mut target, &self.stack_inputs);
+        write_outputs(&mut target, &self.program_outputs);
+        target.write_u64(self.num_cycles as u64);
+
+        target.write_u64(self.main_trace.num_cols() as u64);
+        target.write_u64(self.main_trace.num_rows() as u64);
+        for column in self.main_trace.columns() {
+            Felt::write_batch_into(column, &mut target);
+        }
+
+        let hints = &self.aux_trace_hints;
+        hints.decoder.write_into(&mut target);
+        hints.stack.write_into(&mut target);
+        hints.range.write_into(&mut target);
+        hints.hasher.write_into(&mut target);
+        hints.chiplets.write_into(&mut target);
+
+        target
+    }
+
+    /// Deserializes an execution trace from the bytes produced by [Self::to_prover_bytes()].
+    ///
+    /// # Errors
+    /// Returns an error if the bytes do not start with the expected magic bytes and format version,
+    /// if the dimensions of the main trace segment are invalid, if any of the values cannot be
+    /// deserialized, if any of the hints for the auxiliary segment refers to a row outside of the
+    /// trace or to a table row which does not exist, or if not all of the bytes were consumed.
+    ///
+    /// The trace is not checked to be a valid execution trace of the program; a proof generated
+    /// from an invalid trace will not pass verification. However, for hints which are inconsistent
+    /// with the main trace segment (e.g., hints for which the running products of the auxiliary
+    /// columns do not end with the expected values), building the auxiliary segment panics.
+    ///
+    /// The memory access log and the hash operations of the execution (see
+    /// [Self::memory_access_log()] and [Self::hash_operations()]) are not serialized, and thus,
+    /// both are empty for the deserialized trace. The seed set via [Self::set_rand_seed()] is not
+    /// serialized either; the deserialized trace uses the default seed for the random values of
+    /// its auxiliary segment.
+    pub fn from_prover_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let magic = source.read_u8_array::<4>()?;
+        if &magic != MAGIC {
+            return Err(invalid_value("invalid execution trace magic bytes"));
+        }
+        let version = source.read_u8()?;
+        if version != VERSION {
+            return Err(invalid_value(format!(
+                "unsupported execution trace version {version}"
+            )));
+        }
+
+        let program_hash = Digest::read_from(&mut source)?;
+        let stack_inputs = read_vec(&mut source)?;
+        let program_outputs = read_outputs(&mut source)?;
+        let num_cycles = source.read_u64()? as usize;
+
+        let num_cols = source.read_u64()? as usize;
+        if num_cols != TRACE_WIDTH {
+            return Err(invalid_value(format!(
+                "main trace must consist of {TRACE_WIDTH} columns, but was {num_cols}"
+            )));
+        }
+        let num_rows = source.read_u64()? as usize;
+        if num_rows < MIN_TRACE_LEN || !num_rows.is_power_of_two() {
+            return Err(invalid_value(format!(
+                "main trace length must be a power of two of at least {MIN_TRACE_LEN}, but was {num_rows}"
+            )));
+        }
+        if num_cycles >= num_rows {
+            return Err(invalid_value(
+                "number of cycles must be smaller than the trace length",
+            ));
+        }
+        let mut columns = Vec::with_capacity(num_cols);
+        for _ in 0..num_cols {
+            columns.push(Felt::read_batch_from(&mut source, num_rows)?);
+        }
+
+        let aux_trace_hints = AuxTraceHints {
+            decoder: DecoderAuxTraceHints::read_from(&mut source)?,
+            stack: StackAuxTraceBuilder::read_from(&mut source)?,
+            range: RangeCheckerAuxTraceBuilder::read_from(&mut source)?,
+            hasher: HasherAuxTraceBuilder::read_from(&mut source)?,
+            chiplets: ChipletsAuxTraceBuilder::read_from(&mut source)?,
+        };
+
+        if source.has_more_bytes() {
+            return Err(invalid_value(
+                "execution trace bytes were not fully consumed",
+            ));
+        }
+
+        // make sure that the auxiliary segment can be built from the hints without accessing any
+        // rows outside of the trace or of the tables described by the hints
+        let main_trace = Matrix::new(columns);
+        aux_trace_hints.decoder.validate(&main_trace)?;
+        aux_trace_hints.stack.validate(num_rows)?;
+        aux_trace_hints.range.validate(num_rows)?;
+        aux_trace_hints.hasher.validate(num_rows)?;
+        aux_trace_hints.chiplets.validate(num_rows)?;
+
+        let trace = Self {
+            meta: Vec::new(),
+            layout: TraceLayout::new(TRACE_WIDTH, [AUX_TRACE_WIDTH], [AUX_TRACE_RAND_ELEMENTS]),
+            main_trace,
+            aux_trace_hints,
+            program_hash,
+            stack_inputs,
+            program_outputs,
+            num_cycles,
+            memory_access_log: Vec::new(),
+            hash_operations: Vec::new(),
+            rand_seed: program_hash.as_bytes(),
+        };
+        debug_assert_eq!(num_rows, trace.length());
+
+        Ok(trace)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes the stack and overflow addresses of the provided program outputs into the target.
+fn write_outputs<W: ByteWriter>(target: &mut W, outputs: &ProgramOutputs) {
+    for values in [outputs.stack(), outputs.overflow_addrs()] {
+        let values = values.iter().map(|&v| Felt::new(v)).collect::<Vec<_>>();
+        write_vec(target, &values);
+    }
+}
+
+/// Reads program outputs serialized via [write_outputs()] from the source.
+fn read_outputs<R: ByteReader>(source: &mut R) -> Result<ProgramOutputs, DeserializationError> {
+    let stack = read_vec(source)?;
+    let overflow_addrs = read_vec(source)?;
+    Ok(ProgramOutputs::from_elements(stack, overflow_addrs))
+}
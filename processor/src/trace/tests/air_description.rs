@@ -0,0 +1,62 @@
+use super::{build_trace_from_ops, Trace, NUM_RAND_ROWS};
+use vm_core::{Operation, AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, TRACE_WIDTH};
+
+#[test]
+fn air_description_layout() {
+    let ops = vec![
+        Operation::Push(5_u64.into()),
+        Operation::Add,
+        Operation::U32split,
+    ];
+    let trace = build_trace_from_ops(ops, &[1, 2, 3]);
+    let description = trace.to_air_description();
+
+    // column counts match the internal trace layout
+    assert_eq!(TRACE_WIDTH, description.main_columns.len());
+    assert_eq!(AUX_TRACE_WIDTH, description.aux_columns.len());
+    assert_eq!(
+        trace.layout().main_trace_width(),
+        description.main_columns.len()
+    );
+    assert_eq!(
+        trace.layout().aux_trace_width(),
+        description.aux_columns.len()
+    );
+    assert_eq!(AUX_TRACE_RAND_ELEMENTS, description.aux_rand_elements.len());
+
+    // column groups cover all columns of each segment without gaps
+    for (layout, width) in [
+        (&description.main_layout, TRACE_WIDTH),
+        (&description.aux_layout, AUX_TRACE_WIDTH),
+    ] {
+        let mut next_column = 0;
+        for group in layout.iter() {
+            assert_eq!(
+                next_column, group.columns.start,
+                "gap before {}",
+                group.name
+            );
+            next_column = group.columns.end;
+        }
+        assert_eq!(width, next_column);
+    }
+
+    // all columns have the length of the trace
+    assert_eq!(trace.length(), description.trace_len);
+    assert_eq!(NUM_RAND_ROWS, description.num_rand_rows);
+    for column in description
+        .main_columns
+        .iter()
+        .chain(description.aux_columns.iter())
+    {
+        assert_eq!(trace.length(), column.len());
+    }
+
+    // main columns are the same as the columns of the main trace segment
+    for (i, column) in description.main_columns.iter().enumerate() {
+        assert_eq!(trace.main_segment().get_column(i), column.as_slice());
+    }
+
+    // the description is deterministic
+    assert_eq!(description, trace.to_air_description());
+}
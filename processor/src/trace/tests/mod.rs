@@ -5,9 +5,11 @@ use vm_core::{
     ONE, ZERO,
 };
 
+mod air_description;
 mod chiplets;
 mod hasher;
 mod range;
+mod serialization;
 mod stack;
 
 // TEST HELPERS
@@ -252,3 +252,27 @@ fn p1_trace_mem() {
         assert_eq!(ONE, p1[i]);
     }
 }
+
+#[test]
+fn range_check_values() {
+    // --- u32add checks the 16-bit limbs of the sum: 0x0006, 0x0004 and two zero limbs -----------
+    let stack = [0x0001_0002, 0x0003_0004];
+    let trace = build_trace_from_ops(vec![Operation::U32add], &stack);
+    assert_eq!(vec![0, 4, 6], trace.range_check_values());
+
+    // --- values looked up more than 4 times are reported once ---------------------------------
+    let stack = [0x0001_0001, 0x0002_0002];
+    let operations = vec![
+        Operation::U32add,
+        Operation::Pad,
+        Operation::U32add,
+        Operation::Pad,
+        Operation::U32add,
+    ];
+    let trace = build_trace_from_ops(operations, &stack);
+    assert_eq!(vec![0, 3], trace.range_check_values());
+
+    // --- programs without range checks do not report any values -------------------------------
+    let trace = build_trace_from_ops(vec![Operation::Add], &stack);
+    assert!(trace.range_check_values().is_empty());
+}
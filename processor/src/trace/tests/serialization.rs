@@ -0,0 +1,123 @@
+use super::{build_trace_from_ops, ExecutionTrace, Felt, Trace};
+use rand_utils::rand_array;
+use vm_core::{
+    utils::{DeserializationError, Serializable},
+    Operation, AUX_TRACE_RAND_ELEMENTS,
+};
+
+#[test]
+fn trace_serialization_round_trip() {
+    // the operations exercise the stack overflow table, memory, bitwise, and hasher chiplets, as
+    // well as range checks
+    let stack = (1..=18).collect::<Vec<u64>>();
+    let operations = vec![
+        Operation::U32and,
+        Operation::Pad,
+        Operation::Pad,
+        Operation::MStoreW,
+        Operation::Pad,
+        Operation::MLoadW,
+        Operation::RpPerm,
+        Operation::Drop,
+        Operation::Drop,
+    ];
+    let mut trace = build_trace_from_ops(operations, &stack);
+
+    let bytes = trace.to_prover_bytes();
+    let mut result = ExecutionTrace::from_prover_bytes(&bytes).unwrap();
+    assert_eq!(bytes, result.to_prover_bytes());
+
+    assert_eq!(trace.program_hash(), result.program_hash());
+    assert_eq!(trace.stack_inputs(), result.stack_inputs());
+    assert_eq!(
+        trace.program_outputs().stack(),
+        result.program_outputs().stack()
+    );
+    assert_eq!(trace.num_cycles(), result.num_cycles());
+    assert_eq!(trace.length(), result.length());
+    for (expected, actual) in trace
+        .main_segment()
+        .columns()
+        .zip(result.main_segment().columns())
+    {
+        assert_eq!(expected, actual);
+    }
+
+    // the auxiliary segment built from the deserialized hints must be the same as the original one
+    let rand_elements = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let expected = trace.build_aux_segment(&[], &rand_elements).unwrap();
+    let actual = result.build_aux_segment(&[], &rand_elements).unwrap();
+    for (expected, actual) in expected.columns().zip(actual.columns()) {
+        assert_eq!(expected, actual);
+    }
+}
+
+#[test]
+fn trace_deserialization_errors() {
+    let trace = build_trace_from_ops(vec![Operation::Add], &[1, 2]);
+    let bytes = trace.to_prover_bytes();
+
+    // truncated bytes
+    let result = ExecutionTrace::from_prover_bytes(&bytes[..bytes.len() - 1]);
+    assert!(matches!(result, Err(DeserializationError::UnexpectedEOF)));
+
+    // trailing bytes
+    let mut extended = bytes.clone();
+    extended.push(0);
+    let result = ExecutionTrace::from_prover_bytes(&extended);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+    // invalid magic bytes
+    let mut corrupted = bytes.clone();
+    corrupted[0] = b'X';
+    let result = ExecutionTrace::from_prover_bytes(&corrupted);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+    // unsupported version
+    let mut corrupted = bytes;
+    corrupted[4] += 1;
+    let result = ExecutionTrace::from_prover_bytes(&corrupted);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
+
+#[test]
+fn trace_deserialization_invalid_hints() {
+    let stack = (1..=18).collect::<Vec<u64>>();
+    let trace = build_trace_from_ops(vec![Operation::U32and, Operation::Drop], &stack);
+    let bytes = trace.to_prover_bytes();
+
+    // the hints of the chiplets bus are serialized last: the number of hints is followed by the
+    // cycle of the first hint, its tag, and the index of its request or response row
+    let chiplets_offset = bytes.len() - trace.aux_trace_hints.chiplets.to_bytes().len();
+    let cycle_offset = chiplets_offset + 8;
+    let row_idx_offset = cycle_offset + 5;
+
+    // hint cycle outside of the trace
+    let mut corrupted = bytes.clone();
+    corrupted[cycle_offset..cycle_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    let result = ExecutionTrace::from_prover_bytes(&corrupted);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+    // lookup row which does not exist
+    let mut corrupted = bytes.clone();
+    corrupted[row_idx_offset..row_idx_offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+    let result = ExecutionTrace::from_prover_bytes(&corrupted);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+
+    // the hints of the stack overflow table are serialized before the hints of the range checker,
+    // the hasher, and the chiplets bus: the number of hints is followed by the cycle of the first
+    // hint, its tag, and the index of the overflow table row
+    let hints = &trace.aux_trace_hints;
+    let stack_offset = bytes.len()
+        - hints.stack.to_bytes().len()
+        - hints.range.to_bytes().len()
+        - hints.hasher.to_bytes().len()
+        - hints.chiplets.to_bytes().len();
+    let row_idx_offset = stack_offset + 17;
+
+    // overflow table row which does not exist
+    let mut corrupted = bytes;
+    corrupted[row_idx_offset..row_idx_offset + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    let result = ExecutionTrace::from_prover_bytes(&corrupted);
+    assert!(matches!(result, Err(DeserializationError::InvalidValue(_))));
+}
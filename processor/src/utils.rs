@@ -1,4 +1,11 @@
-use super::{Felt, StarkField, Vec};
+use super::{Felt, StarkField, Vec, Word};
+use vm_core::{
+    utils::{
+        string::ToString, ByteReader, ByteWriter, Deserializable, DeserializationError,
+        Serializable,
+    },
+    WORD_LEN,
+};
 
 // HELPER FUNCTIONS
 // ================================================================================================
@@ -38,3 +45,79 @@ pub fn split_u32_into_u16(value: u64) -> (u16, u16) {
 
     (hi, lo)
 }
+
+// SERIALIZATION HELPERS
+// ================================================================================================
+
+/// Writes the number of the provided items followed by the items themselves into the target.
+pub fn write_vec<W: ByteWriter, T: Serializable>(target: &mut W, items: &[T]) {
+    target.write_u64(items.len() as u64);
+    T::write_batch_into(items, target);
+}
+
+/// Reads a list of items serialized via [write_vec()] from the source.
+pub fn read_vec<R: ByteReader, T: Deserializable>(
+    source: &mut R,
+) -> Result<Vec<T>, DeserializationError> {
+    let num_items = source.read_u64()? as usize;
+    T::read_batch_from(source, num_items)
+}
+
+/// Writes a boolean value into the target as a single byte.
+pub fn write_bool<W: ByteWriter>(target: &mut W, value: bool) {
+    target.write_u8(value as u8);
+}
+
+/// Reads a boolean value serialized via [write_bool()] from the source.
+pub fn read_bool<R: ByteReader>(source: &mut R) -> Result<bool, DeserializationError> {
+    match source.read_u8()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        value => Err(invalid_value(format!("invalid boolean value {value}"))),
+    }
+}
+
+/// Writes the elements of the provided word into the target.
+pub fn write_word<W: ByteWriter>(target: &mut W, word: &Word) {
+    Felt::write_batch_into(word, target);
+}
+
+/// Reads a word serialized via [write_word()] from the source.
+pub fn read_word<R: ByteReader>(source: &mut R) -> Result<Word, DeserializationError> {
+    Ok(Felt::read_batch_from(source, WORD_LEN)?
+        .try_into()
+        .expect("failed to convert vector to array"))
+}
+
+/// Returns a [DeserializationError] describing an invalid value encountered in the serialized
+/// data.
+pub fn invalid_value<S: ToString>(msg: S) -> DeserializationError {
+    DeserializationError::InvalidValue(msg.to_string())
+}
+
+/// Returns an error if a deserialized auxiliary trace hint for the specified clock cycle cannot be
+/// applied to an execution trace of the specified length; such a hint updates the row following
+/// the cycle, and thus, this row must be a part of the trace as well.
+pub fn check_hint_cycle(clk: usize, trace_len: usize) -> Result<(), DeserializationError> {
+    if clk + 1 >= trace_len {
+        return Err(invalid_value(format!(
+            "hint cycle {clk} is out of bounds for a trace of length {trace_len}"
+        )));
+    }
+    Ok(())
+}
+
+/// Returns an error if the specified index of a row in a deserialized table with the specified
+/// number of rows is out of bounds.
+pub fn check_table_row_idx(
+    row_idx: usize,
+    num_rows: usize,
+    table: &str,
+) -> Result<(), DeserializationError> {
+    if row_idx >= num_rows {
+        return Err(invalid_value(format!(
+            "{table} row index {row_idx} is out of bounds for a table of {num_rows} rows"
+        )));
+    }
+    Ok(())
+}
@@ -1,14 +1,13 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use air::{ProcessorAir, PublicInputs};
+use core::cell::RefCell;
 use processor::ExecutionTrace;
-use prover::Prover;
-use vm_core::{utils::collections::Vec, Felt, ProgramOutputs};
+use prover::{Air, EvaluationFrame, Matrix, Prover, Trace, TraceLayout};
+use vm_core::{utils::collections::Vec, Felt, FieldElement, ProgramOutputs};
 
 #[cfg(feature = "std")]
-use log::debug;
-#[cfg(feature = "std")]
-use prover::Trace;
+use log::{debug, log_enabled, Level};
 #[cfg(feature = "std")]
 use std::time::Instant;
 
@@ -16,12 +15,12 @@ use std::time::Instant;
 // ================================================================================================
 
 pub use air::{FieldExtension, HashFunction, ProofOptions};
-pub use processor::ExecutionError;
+pub use processor::{ExecutionError, HostContext};
 pub use prover::StarkProof;
 pub use vm_core::{
     chiplets::hasher::Digest,
     errors::{AdviceSetError, InputError},
-    AdviceSet, Program, ProgramInputs,
+    AdviceSet, MerklePathOrder, Program, ProgramInputs,
 };
 
 // PROVER
@@ -41,46 +40,300 @@ pub fn prove(
     inputs: &ProgramInputs,
     options: &ProofOptions,
 ) -> Result<(ProgramOutputs, StarkProof), ExecutionError> {
+    prove_with_progress(program, inputs, options, |_, _| {})
+}
+
+/// Executes and proves the specified `program` in the same way as [prove()], but reads environment
+/// values requested via `push.env` instructions from the provided `host` context.
+///
+/// Environment values are non-deterministic advice: the proof does not commit to them. To bind
+/// the proof to specific environment values, the program should output them on the stack.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn prove_with_host_context(
+    program: &Program,
+    inputs: &ProgramInputs,
+    host: HostContext,
+    options: &ProofOptions,
+) -> Result<(ProgramOutputs, StarkProof), ExecutionError> {
+    prove_program(program, inputs, host, options, |_, _| {})
+}
+
+/// Executes and proves the specified `program` in the same way as [prove()], but also reports
+/// progress of proof generation via the provided `callback`.
+///
+/// The callback receives a [ProvingPhase] together with the progress of that phase in percent.
+/// Each phase is reported exactly twice: with progress of 0 when the phase starts, and with
+/// progress of 100 when the phase is complete. The phases are always reported in the order in
+/// which they are defined in [ProvingPhase]. If program execution or proof generation fails,
+/// the phase during which the failure occurred is not reported as complete.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn prove_with_progress<F>(
+    program: &Program,
+    inputs: &ProgramInputs,
+    options: &ProofOptions,
+    callback: F,
+) -> Result<(ProgramOutputs, StarkProof), ExecutionError>
+where
+    F: FnMut(ProvingPhase, u8),
+{
+    prove_program(program, inputs, HostContext::default(), options, callback)
+}
+
+/// Executes and proves the specified `program` with environment values read from the provided
+/// host context, reporting progress of proof generation via the provided `callback`.
+fn prove_program<F>(
+    program: &Program,
+    inputs: &ProgramInputs,
+    host: HostContext,
+    options: &ProofOptions,
+    callback: F,
+) -> Result<(ProgramOutputs, StarkProof), ExecutionError>
+where
+    F: FnMut(ProvingPhase, u8),
+{
+    let progress = ProgressTracker::new(callback);
+
     // execute the program to create an execution trace
+    progress.report(ProvingPhase::TraceGeneration, 0);
     #[cfg(feature = "std")]
     let now = Instant::now();
-    let trace = processor::execute(program, inputs)?;
+    let trace = processor::execute_with_host_context(program, inputs, host)?;
     #[cfg(feature = "std")]
-    debug!(
-        "Generated execution trace of {} columns and {} steps in {} ms",
-        trace.layout().main_trace_width(),
-        trace.length(),
-        now.elapsed().as_millis()
-    );
+    {
+        let (trace_width, trace_length) = (trace.layout().main_trace_width(), trace.length());
+        let elapsed_ms = now.elapsed().as_millis();
+        debug!(
+            trace_width, trace_length, elapsed_ms;
+            "Generated execution trace of {} columns and {} steps in {} ms",
+            trace_width, trace_length, elapsed_ms
+        );
+    }
+    progress.report(ProvingPhase::TraceGeneration, 100);
+
+    prove_execution_trace(trace, options, &progress)
+}
 
+/// Proves the execution which resulted in the specified `trace` and returns the outputs of the
+/// execution together with a STARK-based proof of the execution.
+///
+/// Unlike [prove()], this does not execute the program, and thus, can be used to prove an
+/// execution trace which was generated earlier (e.g., on a different machine) and transferred via
+/// [ExecutionTrace::to_prover_bytes()].
+///
+/// # Errors
+/// Returns an error if STARK proof generation fails for any reason.
+pub fn prove_trace(
+    trace: ExecutionTrace,
+    options: &ProofOptions,
+) -> Result<(ProgramOutputs, StarkProof), ExecutionError> {
+    let progress = ProgressTracker::new(|_, _| {});
+    prove_execution_trace(trace, options, &progress)
+}
+
+/// Generates a STARK proof for the specified execution trace reporting progress of proof
+/// generation via the provided tracker.
+fn prove_execution_trace<F>(
+    mut trace: ExecutionTrace,
+    options: &ProofOptions,
+    progress: &ProgressTracker<F>,
+) -> Result<(ProgramOutputs, StarkProof), ExecutionError>
+where
+    F: FnMut(ProvingPhase, u8),
+{
     let outputs = trace.program_outputs();
+    if let Some(seed) = options.zk_seed() {
+        trace.set_rand_seed(seed);
+    }
 
     // generate STARK proof
     let prover = ExecutionProver::new(
         options.clone(),
-        inputs.stack_init().to_vec(),
+        trace.stack_inputs().to_vec(),
         outputs.clone(),
+        progress,
     );
+    let trace = ProgressTrace {
+        inner: trace,
+        progress,
+    };
+    #[cfg(feature = "std")]
+    let now = Instant::now();
     let proof = prover.prove(trace).map_err(ExecutionError::ProverError)?;
+    #[cfg(feature = "std")]
+    if log_enabled!(Level::Debug) {
+        // serializing the proof is not free; so, the size is computed only if it will be logged
+        let proof_size = proof.to_bytes().len();
+        let elapsed_ms = now.elapsed().as_millis();
+        debug!(
+            proof_size, elapsed_ms;
+            "Generated proof of {proof_size} bytes in {elapsed_ms} ms"
+        );
+    }
+    progress.report(ProvingPhase::ProofConstruction, 100);
 
     Ok((outputs, proof))
 }
 
+// PROOF SIZE ESTIMATION
+// ================================================================================================
+
+/// Executes the specified `program` and returns an estimate of the size (in bytes) of the proof
+/// which [prove()] would generate for this execution with the specified `options`.
+///
+/// The estimate is computed from the dimensions of the execution trace, the degree of the
+/// constraint composition polynomial, and the FRI parameters, without generating the proof. Since
+/// the positions queried by the verifier are chosen at random, the size of Merkle authentication
+/// paths in the proof can only be estimated; the estimate is usually within 5% of the actual size.
+///
+/// # Errors
+/// Returns an error if program execution fails for any reason.
+pub fn estimate_proof_size(
+    program: &Program,
+    inputs: &ProgramInputs,
+    options: &ProofOptions,
+) -> Result<usize, ExecutionError> {
+    let trace = processor::execute(program, inputs)?;
+    let pub_inputs = PublicInputs::new(
+        trace.program_hash(),
+        trace.stack_inputs().to_vec(),
+        trace.program_outputs(),
+    );
+    let air = ProcessorAir::new(trace.get_info(), pub_inputs, options.clone().into_inner());
+
+    let lde_domain_size = air.lde_domain_size();
+    let num_queries = options.num_queries().min(lde_domain_size);
+    let element_size = Felt::ELEMENT_BYTES * options.field_extension().degree() as usize;
+    let digest_size = digest_size(options.hash_fn());
+    let layout = trace.layout();
+    let num_composition_cols = air.ce_blowup_factor();
+
+    // trace and constraint queries: queried values together with their authentication paths
+    let lde_tree_depth = lde_domain_size.trailing_zeros() as usize;
+    let lde_paths_size = batch_paths_size(num_queries, lde_tree_depth, digest_size);
+    let mut size = num_queries * layout.main_trace_width() * Felt::ELEMENT_BYTES + lde_paths_size;
+    for i in 0..layout.num_aux_segments() {
+        size += num_queries * layout.get_aux_segment_width(i) * element_size + lde_paths_size;
+    }
+    size += num_queries * num_composition_cols * element_size + lde_paths_size;
+
+    // out-of-domain frame: current and next rows of the trace, and composition evaluations
+    size += (2 * layout.main_trace_width() + num_composition_cols) * element_size;
+    size += 2 * layout.aux_trace_width() * element_size;
+
+    // FRI layers: each query opens a group of folding_factor values in every layer, followed by
+    // the remainder of the last layer
+    let fri_options = options.to_fri_options();
+    let folding_factor = fri_options.folding_factor();
+    let num_fri_layers = fri_options.num_fri_layers(lde_domain_size);
+    let mut domain_size = lde_domain_size;
+    for _ in 0..num_fri_layers {
+        domain_size /= folding_factor;
+        let tree_depth = domain_size.trailing_zeros() as usize;
+        size += num_queries * folding_factor * element_size;
+        size += batch_paths_size(num_queries.min(domain_size), tree_depth, digest_size);
+    }
+    size += fri_options.fri_remainder_size(lde_domain_size) * element_size;
+
+    // commitments to the trace segments, the constraint evaluations, and the FRI layers
+    size += (layout.num_segments() + 1 + num_fri_layers) * digest_size;
+
+    Ok(size + PROOF_SIZE_OVERHEAD)
+}
+
+/// Approximate number of bytes in a proof taken by the proof context, proof-of-work nonce, and
+/// the length prefixes of the serialized proof components.
+const PROOF_SIZE_OVERHEAD: usize = 128;
+
+/// Returns the number of bytes in a digest of the specified hash function.
+fn digest_size(hash_fn: HashFunction) -> usize {
+    match hash_fn {
+        HashFunction::Blake3_192 => 24,
+        HashFunction::Blake3_256 | HashFunction::Sha3_256 => 32,
+    }
+}
+
+/// Returns an estimate of the number of bytes in a batch of authentication paths for the
+/// specified number of leaves chosen at random in a Merkle tree of the specified depth.
+///
+/// A batch of paths includes a node at a given level of the tree whenever exactly one of the two
+/// sibling nodes is an ancestor of a chosen leaf; for n leaves and a level with 2^l nodes,
+/// the expected number of such nodes is 2^l * ((1 - 2^-l)^n - (1 - 2^(1-l))^n).
+fn batch_paths_size(num_leaves: usize, tree_depth: usize, digest_size: usize) -> usize {
+    let pow = |base: f64| (0..num_leaves).fold(1.0, |acc, _| acc * base);
+    let mut num_nodes = 0.0;
+    let mut level_size = 1.0;
+    for _ in 0..tree_depth {
+        level_size *= 2.0;
+        num_nodes += level_size * (pow(1.0 - 1.0 / level_size) - pow(1.0 - 2.0 / level_size));
+    }
+    // each path is prefixed with the number of its nodes, and the batch with the number of paths
+    num_nodes as usize * digest_size + num_leaves + 1
+}
+
+// PROVING PHASES
+// ================================================================================================
+
+/// Phases of proof generation reported by [prove_with_progress()].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProvingPhase {
+    /// Execution of the program to build the execution trace.
+    TraceGeneration,
+    /// Low-degree extension of the main segment of the execution trace and computation of the
+    /// commitment to the extended trace.
+    TraceCommitment,
+    /// Building and committing to the auxiliary segment of the execution trace, evaluating
+    /// constraints, building FRI layers, and querying them. These steps are performed by the
+    /// underlying STARK prover without intermediate feedback, and thus, are reported as a single
+    /// phase.
+    ProofConstruction,
+}
+
+/// Forwards progress reports to a user-provided callback.
+///
+/// The callback is wrapped into a [RefCell] so that it can be invoked from both the prover and
+/// the execution trace, each of which holds only a shared reference to the tracker.
+struct ProgressTracker<F> {
+    callback: RefCell<F>,
+}
+
+impl<F: FnMut(ProvingPhase, u8)> ProgressTracker<F> {
+    pub fn new(callback: F) -> Self {
+        Self {
+            callback: RefCell::new(callback),
+        }
+    }
+
+    pub fn report(&self, phase: ProvingPhase, progress: u8) {
+        (self.callback.borrow_mut())(phase, progress)
+    }
+}
+
 // PROVER
 // ================================================================================================
 
-struct ExecutionProver {
+struct ExecutionProver<'a, F> {
     options: ProofOptions,
     stack_inputs: Vec<Felt>,
     outputs: ProgramOutputs,
+    progress: &'a ProgressTracker<F>,
 }
 
-impl ExecutionProver {
-    pub fn new(options: ProofOptions, stack_inputs: Vec<Felt>, outputs: ProgramOutputs) -> Self {
+impl<'a, F: FnMut(ProvingPhase, u8)> ExecutionProver<'a, F> {
+    pub fn new(
+        options: ProofOptions,
+        stack_inputs: Vec<Felt>,
+        outputs: ProgramOutputs,
+        progress: &'a ProgressTracker<F>,
+    ) -> Self {
         Self {
             options,
             stack_inputs,
             outputs,
+            progress,
         }
     }
 
@@ -119,16 +372,21 @@ impl ExecutionProver {
     }
 }
 
-impl Prover for ExecutionProver {
+impl<'a, F: FnMut(ProvingPhase, u8)> Prover for ExecutionProver<'a, F> {
     type BaseField = Felt;
     type Air = ProcessorAir;
-    type Trace = ExecutionTrace;
+    type Trace = ProgressTrace<'a, F>;
 
     fn options(&self) -> &prover::ProofOptions {
         &self.options
     }
 
-    fn get_pub_inputs(&self, trace: &ExecutionTrace) -> PublicInputs {
+    fn get_pub_inputs(&self, trace: &ProgressTrace<'a, F>) -> PublicInputs {
+        // public inputs are requested right before the prover starts extending the main trace
+        self.progress.report(ProvingPhase::TraceCommitment, 0);
+
+        let trace = &trace.inner;
+
         // ensure inputs and outputs are consistent with the execution trace.
         debug_assert!(
             self.are_inputs_valid(trace),
@@ -146,3 +404,126 @@ impl Prover for ExecutionProver {
         )
     }
 }
+
+// PROGRESS TRACE
+// ================================================================================================
+
+/// A wrapper around [ExecutionTrace] which reports the progress of proof generation based on the
+/// requests made by the prover against the trace.
+struct ProgressTrace<'a, F> {
+    inner: ExecutionTrace,
+    progress: &'a ProgressTracker<F>,
+}
+
+impl<'a, F: FnMut(ProvingPhase, u8)> Trace for ProgressTrace<'a, F> {
+    type BaseField = Felt;
+
+    fn layout(&self) -> &TraceLayout {
+        self.inner.layout()
+    }
+
+    fn length(&self) -> usize {
+        self.inner.length()
+    }
+
+    fn meta(&self) -> &[u8] {
+        self.inner.meta()
+    }
+
+    fn main_segment(&self) -> &Matrix<Felt> {
+        self.inner.main_segment()
+    }
+
+    fn build_aux_segment<E: FieldElement<BaseField = Felt>>(
+        &mut self,
+        aux_segments: &[Matrix<E>],
+        rand_elements: &[E],
+    ) -> Option<Matrix<E>> {
+        // the first auxiliary segment is requested only after the prover has committed to the
+        // main segment of the trace
+        if aux_segments.is_empty() {
+            self.progress.report(ProvingPhase::TraceCommitment, 100);
+            self.progress.report(ProvingPhase::ProofConstruction, 0);
+        }
+        self.inner.build_aux_segment(aux_segments, rand_elements)
+    }
+
+    fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Felt>) {
+        self.inner.read_main_frame(row_idx, frame)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::{estimate_proof_size, prove, ProgramInputs, ProofOptions};
+    use miden_assembly::Assembler;
+
+    #[test]
+    fn prove_emits_log_events() {
+        let mut logger = logtest::Logger::start();
+
+        let program = Assembler::new()
+            .compile("proc.foo push.1 add end begin exec.foo end")
+            .unwrap();
+        let inputs = ProgramInputs::new(&[1], &[], vec![]).unwrap();
+        prove(&program, &inputs, &ProofOptions::default()).unwrap();
+
+        let mut events = Vec::new();
+        while let Some(record) = logger.pop() {
+            // keys are sorted because the logger does not preserve their order
+            let mut keys: Vec<_> = record
+                .key_values()
+                .into_iter()
+                .map(|(key, _)| key)
+                .collect();
+            keys.sort();
+            events.push((record.target().to_string(), keys));
+        }
+
+        let expected = [
+            ("miden_assembly::assembler", vec!["num_local_procs"]),
+            ("miden_processor", vec!["num_cycles"]),
+            (
+                "miden_prover",
+                vec!["elapsed_ms", "trace_length", "trace_width"],
+            ),
+            ("miden_prover", vec!["elapsed_ms", "proof_size"]),
+        ];
+        for (target, keys) in expected {
+            assert!(
+                events.iter().any(|(t, k)| t == target && *k == keys),
+                "missing {target} event with keys {keys:?}; emitted events: {events:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn estimate_proof_size_is_close_to_actual_size() {
+        // the estimate is documented to be within 5% of the actual size
+        const TOLERANCE_PERCENT: usize = 5;
+
+        let program = Assembler::new()
+            .compile("begin repeat.200 push.1 add dup push.3 u32checked_and drop end end")
+            .unwrap();
+        let inputs = ProgramInputs::new(&[1], &[], vec![]).unwrap();
+
+        for options in [
+            ProofOptions::with_96_bit_security(),
+            ProofOptions::with_128_bit_security(),
+        ] {
+            let estimate = estimate_proof_size(&program, &inputs, &options).unwrap();
+            let (_, proof) = prove(&program, &inputs, &options).unwrap();
+            let actual = proof.to_bytes().len();
+
+            let diff = estimate.abs_diff(actual);
+            assert!(
+                diff * 100 <= actual * TOLERANCE_PERCENT,
+                "estimated proof size {estimate} differs from actual size {actual} by more than \
+                {TOLERANCE_PERCENT}%"
+            );
+        }
+    }
+}
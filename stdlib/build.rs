@@ -5,6 +5,7 @@ use std::{
     path::PathBuf,
 };
 use vm_assembly::{parse_module, ModuleAst, ProcedureId};
+use vm_core::{hash_elements, Felt};
 
 mod md_renderer;
 use md_renderer::MarkdownRenderer;
@@ -62,6 +63,7 @@ fn main() -> io::Result<()> {
     )?;
 
     let mut docs = BTreeMap::new();
+    let mut contents = Vec::new();
 
     modules
         .into_iter()
@@ -70,6 +72,10 @@ fn main() -> io::Result<()> {
                 .map_err(|e| io::Error::new(io::ErrorKind::Other, e.message().as_str()))?;
             let serialized = module.to_bytes();
 
+            for part in [path.as_bytes(), &serialized] {
+                contents.extend_from_slice(&(part.len() as u64).to_le_bytes());
+                contents.extend_from_slice(part);
+            }
             docs.insert(path.clone(), module);
 
             writeln!(output, "(\"{path}\",&{serialized:?}),")
@@ -77,12 +83,37 @@ fn main() -> io::Result<()> {
 
     writeln!(output, "];")?;
 
+    writeln!(
+        output,
+        "\n/// A hash of the paths and the serialized contents of all modules in [MODULES]."
+    )?;
+    writeln!(
+        output,
+        "pub const MODULES_HASH: &str = \"{}\";",
+        hash_bytes(&contents)
+    )?;
+
     // updates the documentation of these modules
     build_stdlib_docs(&docs, DOC_DIR_PATH);
 
     Ok(())
 }
 
+/// Hashes the provided bytes, packed into field elements 7 bytes at a time, and returns the
+/// hexadecimal representation of the resulting digest.
+fn hash_bytes(bytes: &[u8]) -> String {
+    let elements: Vec<Felt> = bytes
+        .chunks(7)
+        .map(|chunk| {
+            let mut element = [0u8; 8];
+            element[..chunk.len()].copy_from_slice(chunk);
+            Felt::new(u64::from_le_bytes(element))
+        })
+        .collect();
+    let digest: [u8; 32] = hash_elements(&elements).into();
+    digest.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
 // STDLIB DOCUMENTATION
 // ================================================================================================
 
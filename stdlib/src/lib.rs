@@ -7,7 +7,7 @@ use vm_core::utils::{
 };
 
 pub mod asm;
-use asm::MODULES;
+use asm::{MODULES, MODULES_HASH};
 
 // CONSTANTS
 // ================================================================================================
@@ -30,6 +30,21 @@ impl ModuleProvider for StdLibrary {
             .map(|&module_idx| &self.modules[module_idx])
             .map(|(path, ast)| ast.named_ref(path))
     }
+
+    fn library_hashes(&self) -> Vec<(String, String)> {
+        Vec::from([(self.root_ns().to_string(), self.content_hash().to_string())])
+    }
+}
+
+impl StdLibrary {
+    /// Returns a hexadecimal hash of the contents of all modules of the standard library.
+    ///
+    /// Unlike the version of the library, the content hash changes with every change in the
+    /// library's code, and thus, it identifies the exact implementation a program is compiled
+    /// against.
+    pub fn content_hash(&self) -> &str {
+        MODULES_HASH
+    }
 }
 
 impl Library for StdLibrary {
@@ -93,11 +108,108 @@ impl Default for StdLibrary {
 
 #[cfg(test)]
 mod tests {
-    use super::Library;
+    use super::{Library, ToString};
+    use vm_assembly::{Assembler, AssemblyError};
 
     #[test]
     fn lib_version() {
         let stdlib = super::StdLibrary::default();
         assert_eq!("0.2.0", stdlib.version())
     }
+
+    #[test]
+    fn required_lib_hash() {
+        let source = "use.std::math::u64 begin exec.u64::checked_add end";
+        let stdlib = super::StdLibrary::default();
+        let content_hash = stdlib.content_hash().to_string();
+        assert_eq!(64, content_hash.len());
+
+        // the content hash of the standard library is recorded in the programs using it
+        let assembler = Assembler::new()
+            .with_module_provider(stdlib)
+            .with_required_library_hash("std", &content_hash);
+        let program = assembler.compile(source).unwrap();
+        assert_eq!(Some(content_hash.as_str()), program.library_hash("std"));
+
+        // but not in the programs which do not use any of its procedures
+        let program = assembler.compile("begin push.1 end").unwrap();
+        assert_eq!(None, program.library_hash("std"));
+
+        // compiling against a standard library with a different content hash fails
+        let other_hash = "0".repeat(64);
+        let assembler = Assembler::new()
+            .with_module_provider(super::StdLibrary::default())
+            .with_required_library_hash("std", &other_hash);
+        assert_eq!(
+            Some(AssemblyError::library_hash_mismatch(
+                "std",
+                &other_hash,
+                Some(&content_hash)
+            )),
+            assembler.compile(source).err()
+        );
+
+        // compiling without the required library fails as well
+        let assembler = Assembler::new().with_required_library_hash("std", &content_hash);
+        assert_eq!(
+            Some(AssemblyError::library_hash_mismatch(
+                "std",
+                &content_hash,
+                None
+            )),
+            assembler.compile(source).err()
+        );
+    }
+
+    #[test]
+    fn stdlib_dependencies() {
+        let assembler = Assembler::new().with_module_provider(super::StdLibrary::default());
+
+        // procedures invoked directly by the program and via its local procedures are listed
+        let source = "\
+            use.std::math::u64
+            use.std::math::u32
+            proc.foo exec.u32::popcount end
+            begin exec.u64::checked_add exec.foo end";
+        let program = assembler.compile(source).unwrap();
+        assert_eq!(
+            ["std::math::u32::popcount", "std::math::u64::checked_add"],
+            program.stdlib_dependencies().as_slice()
+        );
+
+        // procedures invoked by other library procedures are listed as well, including when the
+        // module has already been compiled by the assembler
+        let source = "use.std::math::modexp begin call.modexp::modexp_u64 end";
+        for _ in 0..2 {
+            let dependencies = assembler.compile(source).unwrap().stdlib_dependencies();
+            assert!(dependencies
+                .iter()
+                .any(|path| path == "std::math::modexp::modexp_u64"));
+            assert!(dependencies
+                .iter()
+                .any(|path| path == "std::math::u64::wrapping_mul"));
+        }
+
+        // a program which does not use the standard library has no dependencies
+        let program = assembler.compile("begin push.1 end").unwrap();
+        assert!(program.stdlib_dependencies().is_empty());
+    }
+
+    #[test]
+    fn library_procedure_hash() {
+        let assembler = Assembler::new().with_module_provider(super::StdLibrary::default());
+        let source = "use.std::math::u64 begin exec.u64::checked_add end";
+        let program = assembler.compile(source).unwrap();
+
+        // the hash of a library procedure invoked via `exec` is the hash of its body, which is
+        // also the hash referenced by the callers invoking it via `call`
+        let hash = program
+            .procedure_hash("std::math::u64::checked_add")
+            .unwrap();
+        let program = assembler
+            .compile("use.std::math::u64 begin call.u64::checked_add end")
+            .unwrap();
+        assert_eq!(&[hash], program.procedures().as_slice());
+        assert_eq!(None, program.procedure_hash("checked_add"));
+    }
 }
@@ -0,0 +1,171 @@
+use vm_core::{utils::collections::Vec, Felt, ProgramOutputs};
+use winterfell::{
+    crypto::{hashers::Blake3_256, Digest as HasherDigest, Hasher},
+    StarkProof,
+};
+
+use super::Digest;
+
+// VERIFICATION CACHE
+// ================================================================================================
+
+/// A least-recently-used cache of keys identifying successfully verified proofs.
+///
+/// Only successful verifications are cached: since verification is deterministic, a proof which
+/// was verified once against a given set of inputs and outputs will verify again, while failed
+/// verifications are expected to be rare and are simply retried.
+pub(crate) struct VerificationCache {
+    capacity: usize,
+    /// Cache keys ordered from the least recently used to the most recently used.
+    keys: Vec<[u8; 32]>,
+}
+
+impl VerificationCache {
+    /// Returns a new empty cache which can hold up to `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            keys: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns true if the specified key is in the cache, and marks it as most recently used.
+    ///
+    /// The key is compared against all keys in the cache in constant time, so that the time
+    /// taken by the lookup does not reveal which bytes of the key match the cached keys.
+    pub fn contains(&mut self, key: &[u8; 32]) -> bool {
+        let mut position = None;
+        for (idx, cached_key) in self.keys.iter().enumerate() {
+            if constant_time_eq(cached_key, key) {
+                position = Some(idx);
+            }
+        }
+
+        match position {
+            Some(idx) => {
+                let key = self.keys.remove(idx);
+                self.keys.push(key);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Adds the specified key to the cache, evicting the least recently used key if the cache
+    /// is full.
+    pub fn insert(&mut self, key: [u8; 32]) {
+        if self.capacity == 0 || self.contains(&key) {
+            return;
+        }
+        if self.keys.len() == self.capacity {
+            self.keys.remove(0);
+        }
+        self.keys.push(key);
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a key which uniquely identifies verification of the specified proof against the
+/// specified program hash, stack inputs, and outputs.
+pub(crate) fn build_cache_key(
+    program_hash: Digest,
+    stack_inputs: &[u64],
+    outputs: &ProgramOutputs,
+    proof: &StarkProof,
+) -> [u8; 32] {
+    // all variable-length components are prefixed with their lengths to make sure that different
+    // sets of inputs cannot result in the same sequence of bytes.
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&program_hash.as_bytes());
+    for values in [stack_inputs, outputs.stack(), outputs.overflow_addrs()] {
+        bytes.extend_from_slice(&(values.len() as u64).to_le_bytes());
+        values
+            .iter()
+            .for_each(|v| bytes.extend_from_slice(&v.to_le_bytes()));
+    }
+    bytes.extend_from_slice(&proof.to_bytes());
+
+    Blake3_256::<Felt>::hash(&bytes).as_bytes()
+}
+
+/// Returns true if both byte slices are equal.
+///
+/// All bytes of the slices are compared regardless of where the first difference is, and thus,
+/// the time taken by the comparison does not depend on the position of the first difference. The
+/// time does depend on the lengths of the slices, which are assumed to be public.
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b.iter()).fold(0, |acc, (x, y)| acc | (x ^ y));
+    diff == 0
+}
+
+/// Returns true if the specified digest is equal to any of the digests in the provided list.
+///
+/// The digest is compared against every digest in the list in constant time, regardless of
+/// whether a match has already been found.
+pub(crate) fn constant_time_contains(digests: &[Digest], digest: &Digest) -> bool {
+    let digest = digest.as_bytes();
+    digests.iter().fold(false, |found, other| {
+        constant_time_eq(&other.as_bytes(), &digest) | found
+    })
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{constant_time_contains, constant_time_eq, Digest, VerificationCache};
+    use vm_core::Felt;
+
+    #[test]
+    fn compare_keys_in_constant_time() {
+        let key = [7; 32];
+        assert!(constant_time_eq(&key, &[7; 32]));
+
+        // a difference at any position is detected
+        for idx in [0, 15, 31] {
+            let mut other = key;
+            other[idx] ^= 1;
+            assert!(!constant_time_eq(&key, &other));
+        }
+
+        // slices of different lengths are never equal
+        assert!(!constant_time_eq(&key, &key[..31]));
+    }
+
+    #[test]
+    fn find_digests_in_constant_time() {
+        let digests = [1, 2, 3].map(|i| Digest::new([Felt::new(i); 4]));
+        for digest in digests.iter() {
+            assert!(constant_time_contains(&digests, digest));
+        }
+
+        let other = Digest::new([Felt::new(4); 4]);
+        assert!(!constant_time_contains(&digests, &other));
+        assert!(!constant_time_contains(&[], &other));
+    }
+
+    #[test]
+    fn evict_least_recently_used() {
+        let mut cache = VerificationCache::new(2);
+        cache.insert([1; 32]);
+        cache.insert([2; 32]);
+
+        // accessing the first key makes the second key the least recently used one
+        assert!(cache.contains(&[1; 32]));
+        cache.insert([3; 32]);
+        assert!(cache.contains(&[1; 32]));
+        assert!(!cache.contains(&[2; 32]));
+        assert!(cache.contains(&[3; 32]));
+
+        // a cache with zero capacity never holds any keys
+        let mut cache = VerificationCache::new(0);
+        cache.insert([1; 32]);
+        assert!(!cache.contains(&[1; 32]));
+    }
+}
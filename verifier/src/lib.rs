@@ -3,7 +3,13 @@
 use air::{ProcessorAir, PublicInputs};
 use core::fmt;
 use vm_core::{utils::collections::Vec, ProgramOutputs};
-use winterfell::VerifierError;
+use winterfell::{DeserializationError, VerifierError};
+
+mod cache;
+use cache::{build_cache_key, constant_time_contains, VerificationCache};
+
+mod serialization;
+pub use serialization::{proof_from_bytes, proof_to_bytes, PROOF_VERSION};
 
 // EXPORTS
 // ================================================================================================
@@ -30,6 +36,17 @@ pub use winterfell::StarkProof;
 /// `stack_outputs` slice, and the order of the rest of the output elements will also match the
 /// order on the stack. This is the reverse of the order of the `stack_inputs` slice.
 ///
+/// The hash function used to verify the proof is determined by the proof options embedded in the
+/// proof itself, and thus proofs generated with any of the hash functions supported by the prover
+/// can be verified without any additional configuration. Proofs with an unknown hash function
+/// identifier are rejected by [proof_from_bytes()] with
+/// [VerificationError::UnsupportedHashFunction].
+///
+/// The outputs are not compared directly: they are checked against the proof via boundary
+/// assertions evaluated by the underlying STARK verifier, which makes no constant-time
+/// guarantees. Comparisons performed by this crate itself (i.e., lookups in the cache and in the
+/// list of allowed programs of a [Verifier]) are performed in constant time.
+///
 /// # Errors
 /// Returns an error if the provided proof does not prove a correct execution of the program.
 pub fn verify(
@@ -37,6 +54,115 @@ pub fn verify(
     stack_inputs: &[u64],
     outputs: &ProgramOutputs,
     proof: StarkProof,
+) -> Result<(), VerificationError> {
+    verify_proof(program_hash, stack_inputs, outputs, proof)
+}
+
+/// A verifier of Miden VM program execution proofs.
+///
+/// A verifier can optionally cache the results of successful verifications. This is useful in
+/// settings where the same proof may be submitted for verification multiple times (e.g., when
+/// requests are retried): in such cases, subsequent verifications of an identical proof against
+/// identical inputs and outputs return immediately.
+///
+/// A verifier can also be restricted to a set of allowed programs, in which case proofs of
+/// executions of any other programs are rejected without performing any cryptographic work.
+///
+/// A verifier is instantiated using a "builder" pattern: caching and the set of allowed programs
+/// are configured via the `with_cache()` and `with_allowed_programs()` methods respectively, and
+/// can be combined.
+#[derive(Default)]
+pub struct Verifier {
+    cache: Option<VerificationCache>,
+    num_cache_hits: usize,
+    allowed_programs: Option<Vec<Digest>>,
+}
+
+impl Verifier {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [Verifier] which does not cache verification results and accepts proofs of
+    /// executions of any program.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Instructs the verifier to cache up to `capacity` most recently verified proofs.
+    pub fn with_cache(mut self, capacity: usize) -> Self {
+        self.cache = Some(VerificationCache::new(capacity));
+        self
+    }
+
+    /// Restricts the verifier to accept only proofs of executions of programs with the specified
+    /// hashes.
+    pub fn with_allowed_programs(mut self, program_hashes: &[Digest]) -> Self {
+        self.allowed_programs = Some(program_hashes.to_vec());
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of verifications which were resolved using the cache.
+    pub fn num_cache_hits(&self) -> usize {
+        self.num_cache_hits
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns Ok(()) if the specified program was executed correctly against the specified
+    /// inputs and outputs.
+    ///
+    /// This is equivalent to the [verify()] function, except that if caching is enabled and an
+    /// identical proof was already successfully verified against the same program hash, inputs
+    /// and outputs, the proof is not verified again.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The verifier is restricted to a set of allowed programs, and the program hash is not in
+    ///   this set; in such a case, the proof is not verified.
+    /// - The provided proof does not prove a correct execution of the program.
+    pub fn verify(
+        &mut self,
+        program_hash: Digest,
+        stack_inputs: &[u64],
+        outputs: &ProgramOutputs,
+        proof: StarkProof,
+    ) -> Result<(), VerificationError> {
+        if let Some(allowed_programs) = self.allowed_programs.as_ref() {
+            if !constant_time_contains(allowed_programs, &program_hash) {
+                return Err(VerificationError::ProgramNotAllowed(program_hash));
+            }
+        }
+
+        let cache = match self.cache.as_mut() {
+            Some(cache) => cache,
+            None => return verify_proof(program_hash, stack_inputs, outputs, proof),
+        };
+
+        let key = build_cache_key(program_hash, stack_inputs, outputs, &proof);
+        if cache.contains(&key) {
+            self.num_cache_hits += 1;
+            return Ok(());
+        }
+
+        verify_proof(program_hash, stack_inputs, outputs, proof)?;
+        cache.insert(key);
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Verifies the proof of the specified program execution; see [verify()] for details.
+fn verify_proof(
+    program_hash: Digest,
+    stack_inputs: &[u64],
+    outputs: &ProgramOutputs,
+    proof: StarkProof,
 ) -> Result<(), VerificationError> {
     // convert stack inputs to field elements
     let mut stack_input_felts = Vec::with_capacity(stack_inputs.len());
@@ -62,6 +188,10 @@ pub enum VerificationError {
     VerifierError(VerifierError),
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
+    UnsupportedHashFunction(u8),
+    UnsupportedProofVersion(u8),
+    InvalidProofBytes(DeserializationError),
+    ProgramNotAllowed(Digest),
 }
 
 impl fmt::Display for VerificationError {
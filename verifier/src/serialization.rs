@@ -0,0 +1,82 @@
+use vm_core::utils::collections::Vec;
+use winterfell::{
+    ByteReader, Deserializable, DeserializationError, HashFunction, SliceReader, StarkProof,
+};
+
+use super::VerificationError;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Version of the proof serialization format produced by [proof_to_bytes()].
+///
+/// Version 1 consists of the version byte followed by the proof serialized via
+/// [StarkProof::to_bytes()].
+pub const PROOF_VERSION: u8 = 1;
+
+// PROOF SERIALIZATION
+// ================================================================================================
+
+/// Serializes the specified proof into a vector of bytes prefixed with [PROOF_VERSION].
+///
+/// Proofs serialized this way can be deserialized via [proof_from_bytes()] by later versions of
+/// the verifier even if the underlying proof format changes.
+pub fn proof_to_bytes(proof: &StarkProof) -> Vec<u8> {
+    let proof_bytes = proof.to_bytes();
+    let mut bytes = Vec::with_capacity(proof_bytes.len() + 1);
+    bytes.push(PROOF_VERSION);
+    bytes.extend_from_slice(&proof_bytes);
+    bytes
+}
+
+/// Deserializes a proof from the bytes produced by [proof_to_bytes()], using the format
+/// identified by the leading version byte.
+///
+/// # Errors
+/// Returns an error if:
+/// - The version byte does not identify a supported format version.
+/// - The bytes are empty, or the bytes following the version byte are not a valid proof in the
+///   format identified by the version byte.
+/// - The proof options specify a hash function which is not supported by the verifier.
+pub fn proof_from_bytes(bytes: &[u8]) -> Result<StarkProof, VerificationError> {
+    let (&version, proof_bytes) = match bytes.split_first() {
+        Some(parts) => parts,
+        None => {
+            let err = DeserializationError::UnexpectedEOF;
+            return Err(VerificationError::InvalidProofBytes(err));
+        }
+    };
+
+    // deserializers for older format versions are to be kept here when the format changes
+    match version {
+        1 => {
+            StarkProof::from_bytes(proof_bytes).map_err(|err| match read_hash_fn_id(proof_bytes) {
+                Some(id) if HashFunction::read_from(&mut SliceReader::new(&[id])).is_err() => {
+                    VerificationError::UnsupportedHashFunction(id)
+                }
+                _ => VerificationError::InvalidProofBytes(err),
+            })
+        }
+        _ => Err(VerificationError::UnsupportedProofVersion(version)),
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the hash function identifier from the options of a proof serialized via
+/// [StarkProof::to_bytes()], or None if the bytes end before the identifier.
+///
+/// The identifier follows the proof context: the trace layout (main trace width, and the width
+/// and the number of random elements of the auxiliary segment), the trace length, the trace
+/// metadata and the field modulus, and then the number of queries, the blowup factor and the
+/// grinding factor of the proof options.
+fn read_hash_fn_id(proof_bytes: &[u8]) -> Option<u8> {
+    let mut source = SliceReader::new(proof_bytes);
+    source.read_u8_vec(4).ok()?;
+    let num_meta_bytes = source.read_u16().ok()? as usize;
+    source.read_u8_vec(num_meta_bytes).ok()?;
+    let num_modulus_bytes = source.read_u8().ok()? as usize;
+    source.read_u8_vec(num_modulus_bytes + 3).ok()?;
+    source.read_u8().ok()
+}